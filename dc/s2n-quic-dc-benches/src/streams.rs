@@ -75,7 +75,7 @@ pub fn benchmarks(c: &mut Criterion) {
         });
     });
 
-    for protocol in [Protocol::Udp, Protocol::Tcp] {
+    for protocol in [Protocol::Udp, Protocol::Tcp, Protocol::Unix] {
         let _rt = rt.enter();
         let (client, server) = pair(protocol, accept::Flavor::Fifo);
         let name = format!("{protocol:?}").to_lowercase();
@@ -250,6 +250,32 @@ pub struct StreamReadSocketErrored {
     errno: Option<i32>,
 }
 
+/// Emitted when the receive window of a stream is resized
+#[event("stream:read_window_updated")]
+pub struct StreamReadWindowUpdated {
+    /// The size of the window before the update
+    previous_window: u64,
+
+    /// The size of the window after the update
+    #[measure("window", Bytes)]
+    window: u64,
+
+    /// The estimated round trip time used to size the window
+    #[measure("rtt", Duration)]
+    rtt: core::time::Duration,
+
+    #[nominal_counter("reason")]
+    reason: StreamReadWindowUpdateReason,
+}
+
+enum StreamReadWindowUpdateReason {
+    /// The window was grown to keep up with the rate the application is reading data
+    Autotuned,
+
+    /// The window was shrunk since the endpoint exhausted its receive window memory budget
+    MemoryPressure,
+}
+
 // NOTE - This event MUST come last, since connection-level aggregation depends on it
 #[event("connection:closed")]
 // #[checkpoint("latency")]
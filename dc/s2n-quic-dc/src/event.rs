@@ -36,7 +36,8 @@ pub mod metrics {
     pub mod aggregate {
         pub use crate::event::generated::metrics::aggregate::*;
         pub use s2n_quic_core::event::metrics::aggregate::{
-            info, AsVariant, BoolRecorder, Info, Metric, NominalRecorder, Recorder, Registry, Units,
+            info, openmetrics, AsVariant, BoolRecorder, Info, Metric, NominalRecorder, Recorder,
+            Registry, Units,
         };
 
         pub mod probe {
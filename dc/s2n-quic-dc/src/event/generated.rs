@@ -996,6 +996,32 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " Emitted when the receive window of a stream is resized"]
+    pub struct StreamReadWindowUpdated {
+        #[doc = " The size of the window before the update"]
+        pub previous_window: u64,
+        #[doc = " The size of the window after the update"]
+        pub window: u64,
+        #[doc = " The estimated round trip time used to size the window"]
+        pub rtt: core::time::Duration,
+        pub reason: StreamReadWindowUpdateReason,
+    }
+    #[cfg(any(test, feature = "testing"))]
+    impl crate::event::snapshot::Fmt for StreamReadWindowUpdated {
+        fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+            let mut fmt = fmt.debug_struct("StreamReadWindowUpdated");
+            fmt.field("previous_window", &self.previous_window);
+            fmt.field("window", &self.window);
+            fmt.field("rtt", &self.rtt);
+            fmt.field("reason", &self.reason);
+            fmt.finish()
+        }
+    }
+    impl Event for StreamReadWindowUpdated {
+        const NAME: &'static str = "stream:read_window_updated";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     pub struct ConnectionClosed {}
     #[cfg(any(test, feature = "testing"))]
     impl crate::event::snapshot::Fmt for ConnectionClosed {
@@ -1009,6 +1035,37 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    pub enum StreamReadWindowUpdateReason {
+        #[non_exhaustive]
+        #[doc = " The window was grown to keep up with the rate the application is reading data"]
+        Autotuned {},
+        #[non_exhaustive]
+        #[doc = " The window was shrunk since the endpoint exhausted its receive window memory budget"]
+        MemoryPressure {},
+    }
+    impl aggregate::AsVariant for StreamReadWindowUpdateReason {
+        const VARIANTS: &'static [aggregate::info::Variant] = &[
+            aggregate::info::variant::Builder {
+                name: aggregate::info::Str::new("AUTOTUNED\0"),
+                id: 0usize,
+            }
+            .build(),
+            aggregate::info::variant::Builder {
+                name: aggregate::info::Str::new("MEMORY_PRESSURE\0"),
+                id: 1usize,
+            }
+            .build(),
+        ];
+        #[inline]
+        fn variant_idx(&self) -> usize {
+            match self {
+                Self::Autotuned { .. } => 0usize,
+                Self::MemoryPressure { .. } => 1usize,
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     pub struct EndpointInitialized<'a> {
         pub acceptor_addr: SocketAddress<'a>,
         pub handshake_addr: SocketAddress<'a>,
@@ -2240,6 +2297,22 @@ pub mod tracing {
             tracing :: event ! (target : "stream_read_socket_errored" , parent : id , tracing :: Level :: DEBUG , { capacity = tracing :: field :: debug (capacity) , errno = tracing :: field :: debug (errno) });
         }
         #[inline]
+        fn on_stream_read_window_updated(
+            &self,
+            context: &Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::StreamReadWindowUpdated,
+        ) {
+            let id = context.id();
+            let api::StreamReadWindowUpdated {
+                previous_window,
+                window,
+                rtt,
+                reason,
+            } = event;
+            tracing :: event ! (target : "stream_read_window_updated" , parent : id , tracing :: Level :: DEBUG , { previous_window = tracing :: field :: debug (previous_window) , window = tracing :: field :: debug (window) , rtt = tracing :: field :: debug (rtt) , reason = tracing :: field :: debug (reason) });
+        }
+        #[inline]
         fn on_connection_closed(
             &self,
             context: &Self::ConnectionContext,
@@ -3602,6 +3675,34 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " Emitted when the receive window of a stream is resized"]
+    pub struct StreamReadWindowUpdated {
+        #[doc = " The size of the window before the update"]
+        pub previous_window: u64,
+        #[doc = " The size of the window after the update"]
+        pub window: u64,
+        #[doc = " The estimated round trip time used to size the window"]
+        pub rtt: core::time::Duration,
+        pub reason: StreamReadWindowUpdateReason,
+    }
+    impl IntoEvent<api::StreamReadWindowUpdated> for StreamReadWindowUpdated {
+        #[inline]
+        fn into_event(self) -> api::StreamReadWindowUpdated {
+            let StreamReadWindowUpdated {
+                previous_window,
+                window,
+                rtt,
+                reason,
+            } = self;
+            api::StreamReadWindowUpdated {
+                previous_window: previous_window.into_event(),
+                window: window.into_event(),
+                rtt: rtt.into_event(),
+                reason: reason.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     pub struct ConnectionClosed {}
     impl IntoEvent<api::ConnectionClosed> for ConnectionClosed {
         #[inline]
@@ -3611,6 +3712,23 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    pub enum StreamReadWindowUpdateReason {
+        #[doc = " The window was grown to keep up with the rate the application is reading data"]
+        Autotuned,
+        #[doc = " The window was shrunk since the endpoint exhausted its receive window memory budget"]
+        MemoryPressure,
+    }
+    impl IntoEvent<api::StreamReadWindowUpdateReason> for StreamReadWindowUpdateReason {
+        #[inline]
+        fn into_event(self) -> api::StreamReadWindowUpdateReason {
+            use api::StreamReadWindowUpdateReason::*;
+            match self {
+                Self::Autotuned => Autotuned {},
+                Self::MemoryPressure => MemoryPressure {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     pub struct EndpointInitialized<'a> {
         pub acceptor_addr: SocketAddress<'a>,
         pub handshake_addr: SocketAddress<'a>,
@@ -4767,6 +4885,18 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `StreamReadWindowUpdated` event is triggered"]
+        #[inline]
+        fn on_stream_read_window_updated(
+            &self,
+            context: &Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::StreamReadWindowUpdated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `ConnectionClosed` event is triggered"]
         #[inline]
         fn on_connection_closed(
@@ -5451,6 +5581,16 @@ mod traits {
                 .on_stream_read_socket_errored(context, meta, event);
         }
         #[inline]
+        fn on_stream_read_window_updated(
+            &self,
+            context: &Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::StreamReadWindowUpdated,
+        ) {
+            self.as_ref()
+                .on_stream_read_window_updated(context, meta, event);
+        }
+        #[inline]
         fn on_connection_closed(
             &self,
             context: &Self::ConnectionContext,
@@ -6102,6 +6242,16 @@ mod traits {
             (self.1).on_stream_read_socket_errored(&context.1, meta, event);
         }
         #[inline]
+        fn on_stream_read_window_updated(
+            &self,
+            context: &Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::StreamReadWindowUpdated,
+        ) {
+            (self.0).on_stream_read_window_updated(&context.0, meta, event);
+            (self.1).on_stream_read_window_updated(&context.1, meta, event);
+        }
+        #[inline]
         fn on_connection_closed(
             &self,
             context: &Self::ConnectionContext,
@@ -7010,6 +7160,8 @@ mod traits {
         fn on_stream_read_socket_blocked(&self, event: builder::StreamReadSocketBlocked);
         #[doc = "Publishes a `StreamReadSocketErrored` event to the publisher's subscriber"]
         fn on_stream_read_socket_errored(&self, event: builder::StreamReadSocketErrored);
+        #[doc = "Publishes a `StreamReadWindowUpdated` event to the publisher's subscriber"]
+        fn on_stream_read_window_updated(&self, event: builder::StreamReadWindowUpdated);
         #[doc = "Publishes a `ConnectionClosed` event to the publisher's subscriber"]
         fn on_connection_closed(&self, event: builder::ConnectionClosed);
         #[doc = r" Returns the QUIC version negotiated for the current connection, if any"]
@@ -7211,6 +7363,15 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_stream_read_window_updated(&self, event: builder::StreamReadWindowUpdated) {
+            let event = event.into_event();
+            self.subscriber
+                .on_stream_read_window_updated(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_connection_closed(&self, event: builder::ConnectionClosed) {
             let event = event.into_event();
             self.subscriber
@@ -8012,6 +8173,7 @@ pub mod testing {
         pub stream_read_socket_flushed: AtomicU64,
         pub stream_read_socket_blocked: AtomicU64,
         pub stream_read_socket_errored: AtomicU64,
+        pub stream_read_window_updated: AtomicU64,
         pub connection_closed: AtomicU64,
         pub endpoint_initialized: AtomicU64,
         pub path_secret_map_initialized: AtomicU64,
@@ -8112,6 +8274,7 @@ pub mod testing {
                 stream_read_socket_flushed: AtomicU64::new(0),
                 stream_read_socket_blocked: AtomicU64::new(0),
                 stream_read_socket_errored: AtomicU64::new(0),
+                stream_read_window_updated: AtomicU64::new(0),
                 connection_closed: AtomicU64::new(0),
                 endpoint_initialized: AtomicU64::new(0),
                 path_secret_map_initialized: AtomicU64::new(0),
@@ -8627,6 +8790,21 @@ pub mod testing {
                 self.output.lock().unwrap().push(out);
             }
         }
+        fn on_stream_read_window_updated(
+            &self,
+            _context: &Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::StreamReadWindowUpdated,
+        ) {
+            self.stream_read_window_updated
+                .fetch_add(1, Ordering::Relaxed);
+            if self.location.is_some() {
+                let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
+                let event = crate::event::snapshot::Fmt::to_snapshot(event);
+                let out = format!("{meta:?} {event:?}");
+                self.output.lock().unwrap().push(out);
+            }
+        }
         fn on_connection_closed(
             &self,
             _context: &Self::ConnectionContext,
@@ -9059,6 +9237,7 @@ pub mod testing {
         pub stream_read_socket_flushed: AtomicU64,
         pub stream_read_socket_blocked: AtomicU64,
         pub stream_read_socket_errored: AtomicU64,
+        pub stream_read_window_updated: AtomicU64,
         pub connection_closed: AtomicU64,
         pub endpoint_initialized: AtomicU64,
         pub path_secret_map_initialized: AtomicU64,
@@ -9149,6 +9328,7 @@ pub mod testing {
                 stream_read_socket_flushed: AtomicU64::new(0),
                 stream_read_socket_blocked: AtomicU64::new(0),
                 stream_read_socket_errored: AtomicU64::new(0),
+                stream_read_window_updated: AtomicU64::new(0),
                 connection_closed: AtomicU64::new(0),
                 endpoint_initialized: AtomicU64::new(0),
                 path_secret_map_initialized: AtomicU64::new(0),
@@ -9789,6 +9969,16 @@ pub mod testing {
                 self.output.lock().unwrap().push(out);
             }
         }
+        fn on_stream_read_window_updated(&self, event: builder::StreamReadWindowUpdated) {
+            self.stream_read_window_updated
+                .fetch_add(1, Ordering::Relaxed);
+            let event = event.into_event();
+            if self.location.is_some() {
+                let event = crate::event::snapshot::Fmt::to_snapshot(&event);
+                let out = format!("{event:?}");
+                self.output.lock().unwrap().push(out);
+            }
+        }
         fn on_connection_closed(&self, event: builder::ConnectionClosed) {
             self.connection_closed.fetch_add(1, Ordering::Relaxed);
             let event = event.into_event();
@@ -44,6 +44,7 @@ pub struct Context<R: Recorder> {
     stream_read_socket_flushed: AtomicU64,
     stream_read_socket_blocked: AtomicU64,
     stream_read_socket_errored: AtomicU64,
+    stream_read_window_updated: AtomicU64,
     connection_closed: AtomicU64,
 }
 impl<S: event::Subscriber> event::Subscriber for Subscriber<S>
@@ -76,6 +77,7 @@ where
             stream_read_socket_flushed: AtomicU64::new(0),
             stream_read_socket_blocked: AtomicU64::new(0),
             stream_read_socket_errored: AtomicU64::new(0),
+            stream_read_window_updated: AtomicU64::new(0),
             connection_closed: AtomicU64::new(0),
         }
     }
@@ -300,6 +302,19 @@ where
             .on_stream_read_socket_errored(&context.recorder, meta, event);
     }
     #[inline]
+    fn on_stream_read_window_updated(
+        &self,
+        context: &Self::ConnectionContext,
+        meta: &api::ConnectionMeta,
+        event: &api::StreamReadWindowUpdated,
+    ) {
+        context
+            .stream_read_window_updated
+            .fetch_add(1, Ordering::Relaxed);
+        self.subscriber
+            .on_stream_read_window_updated(&context.recorder, meta, event);
+    }
+    #[inline]
     fn on_connection_closed(
         &self,
         context: &Self::ConnectionContext,
@@ -385,6 +400,10 @@ impl<R: Recorder> Drop for Context<R> {
             "stream_read_socket_errored",
             self.stream_read_socket_errored.load(Ordering::Relaxed) as _,
         );
+        self.recorder.increment_counter(
+            "stream_read_window_updated",
+            self.stream_read_window_updated.load(Ordering::Relaxed) as _,
+        );
         self.recorder.increment_counter(
             "connection_closed",
             self.connection_closed.load(Ordering::Relaxed) as _,
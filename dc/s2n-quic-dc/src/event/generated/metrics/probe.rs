@@ -58,39 +58,40 @@ mod counter {
                 124usize => Self(stream_read_socket_flushed__committed__total),
                 127usize => Self(stream_read_socket_blocked),
                 130usize => Self(stream_read_socket_errored),
-                132usize => Self(connection_closed),
-                133usize => Self(endpoint_initialized),
-                138usize => Self(path_secret_map_initialized),
-                140usize => Self(path_secret_map_uninitialized),
-                144usize => Self(path_secret_map_background_handshake_requested),
-                146usize => Self(path_secret_map_entry_inserted),
-                148usize => Self(path_secret_map_entry_ready),
-                150usize => Self(path_secret_map_entry_replaced),
-                152usize => Self(path_secret_map_id_entry_evicted),
-                155usize => Self(path_secret_map_address_entry_evicted),
-                158usize => Self(unknown_path_secret_packet_sent),
-                160usize => Self(unknown_path_secret_packet_received),
-                162usize => Self(unknown_path_secret_packet_accepted),
-                164usize => Self(unknown_path_secret_packet_rejected),
-                166usize => Self(unknown_path_secret_packet_dropped),
-                168usize => Self(key_accepted),
-                171usize => Self(replay_definitely_detected),
-                172usize => Self(replay_potentially_detected),
-                174usize => Self(replay_detected_packet_sent),
-                176usize => Self(replay_detected_packet_received),
-                178usize => Self(replay_detected_packet_accepted),
-                180usize => Self(replay_detected_packet_rejected),
-                182usize => Self(replay_detected_packet_dropped),
-                184usize => Self(stale_key_packet_sent),
-                186usize => Self(stale_key_packet_received),
-                188usize => Self(stale_key_packet_accepted),
-                190usize => Self(stale_key_packet_rejected),
-                192usize => Self(stale_key_packet_dropped),
-                194usize => Self(path_secret_map_address_cache_accessed),
-                197usize => Self(path_secret_map_address_cache_accessed_hit),
-                200usize => Self(path_secret_map_id_cache_accessed),
-                202usize => Self(path_secret_map_id_cache_accessed_hit),
-                204usize => Self(path_secret_map_cleaner_cycled),
+                132usize => Self(stream_read_window_updated),
+                136usize => Self(connection_closed),
+                137usize => Self(endpoint_initialized),
+                142usize => Self(path_secret_map_initialized),
+                144usize => Self(path_secret_map_uninitialized),
+                148usize => Self(path_secret_map_background_handshake_requested),
+                150usize => Self(path_secret_map_entry_inserted),
+                152usize => Self(path_secret_map_entry_ready),
+                154usize => Self(path_secret_map_entry_replaced),
+                156usize => Self(path_secret_map_id_entry_evicted),
+                159usize => Self(path_secret_map_address_entry_evicted),
+                162usize => Self(unknown_path_secret_packet_sent),
+                164usize => Self(unknown_path_secret_packet_received),
+                166usize => Self(unknown_path_secret_packet_accepted),
+                168usize => Self(unknown_path_secret_packet_rejected),
+                170usize => Self(unknown_path_secret_packet_dropped),
+                172usize => Self(key_accepted),
+                175usize => Self(replay_definitely_detected),
+                176usize => Self(replay_potentially_detected),
+                178usize => Self(replay_detected_packet_sent),
+                180usize => Self(replay_detected_packet_received),
+                182usize => Self(replay_detected_packet_accepted),
+                184usize => Self(replay_detected_packet_rejected),
+                186usize => Self(replay_detected_packet_dropped),
+                188usize => Self(stale_key_packet_sent),
+                190usize => Self(stale_key_packet_received),
+                192usize => Self(stale_key_packet_accepted),
+                194usize => Self(stale_key_packet_rejected),
+                196usize => Self(stale_key_packet_dropped),
+                198usize => Self(path_secret_map_address_cache_accessed),
+                201usize => Self(path_secret_map_address_cache_accessed_hit),
+                204usize => Self(path_secret_map_id_cache_accessed),
+                206usize => Self(path_secret_map_id_cache_accessed_hit),
+                208usize => Self(path_secret_map_cleaner_cycled),
                 _ => unreachable!("invalid info: {info:?}"),
             }
         }
@@ -184,6 +185,8 @@ mod counter {
             fn stream_read_socket_blocked(value: u64);
             # [link_name = s2n_quic_dc__event__counter__stream_read_socket_errored]
             fn stream_read_socket_errored(value: u64);
+            # [link_name = s2n_quic_dc__event__counter__stream_read_window_updated]
+            fn stream_read_window_updated(value: u64);
             # [link_name = s2n_quic_dc__event__counter__connection_closed]
             fn connection_closed(value: u64);
             # [link_name = s2n_quic_dc__event__counter__endpoint_initialized]
@@ -267,10 +270,10 @@ mod counter {
                     37usize => Self(acceptor_udp_packet_received__is_fin_known),
                     80usize => Self(stream_write_shutdown__background),
                     120usize => Self(stream_read_shutdown__background),
-                    136usize => Self(endpoint_initialized__tcp),
-                    137usize => Self(endpoint_initialized__udp),
-                    196usize => Self(path_secret_map_address_cache_accessed__hit),
-                    201usize => Self(path_secret_map_id_cache_accessed__hit),
+                    140usize => Self(endpoint_initialized__tcp),
+                    141usize => Self(endpoint_initialized__udp),
+                    200usize => Self(path_secret_map_address_cache_accessed__hit),
+                    205usize => Self(path_secret_map_id_cache_accessed__hit),
                     _ => unreachable!("invalid info: {info:?}"),
                 }
             }
@@ -321,35 +324,36 @@ mod counter {
                     23usize => Self(acceptor_tcp_packet_dropped__reason),
                     39usize => Self(acceptor_udp_packet_dropped__reason),
                     44usize => Self(acceptor_stream_pruned__reason),
-                    134usize => Self(endpoint_initialized__acceptor__protocol),
-                    135usize => Self(endpoint_initialized__handshake__protocol),
-                    145usize => {
+                    135usize => Self(stream_read_window_updated__reason),
+                    138usize => Self(endpoint_initialized__acceptor__protocol),
+                    139usize => Self(endpoint_initialized__handshake__protocol),
+                    149usize => {
                         Self(path_secret_map_background_handshake_requested__peer_address__protocol)
                     }
-                    147usize => Self(path_secret_map_entry_inserted__peer_address__protocol),
-                    149usize => Self(path_secret_map_entry_ready__peer_address__protocol),
-                    151usize => Self(path_secret_map_entry_replaced__peer_address__protocol),
-                    153usize => Self(path_secret_map_id_entry_evicted__peer_address__protocol),
-                    156usize => Self(path_secret_map_address_entry_evicted__peer_address__protocol),
-                    159usize => Self(unknown_path_secret_packet_sent__peer_address__protocol),
-                    161usize => Self(unknown_path_secret_packet_received__peer_address__protocol),
-                    163usize => Self(unknown_path_secret_packet_accepted__peer_address__protocol),
-                    165usize => Self(unknown_path_secret_packet_rejected__peer_address__protocol),
-                    167usize => Self(unknown_path_secret_packet_dropped__peer_address__protocol),
-                    175usize => Self(replay_detected_packet_sent__peer_address__protocol),
-                    177usize => Self(replay_detected_packet_received__peer_address__protocol),
-                    179usize => Self(replay_detected_packet_accepted__peer_address__protocol),
-                    181usize => Self(replay_detected_packet_rejected__peer_address__protocol),
-                    183usize => Self(replay_detected_packet_dropped__peer_address__protocol),
-                    185usize => Self(stale_key_packet_sent__peer_address__protocol),
-                    187usize => Self(stale_key_packet_received__peer_address__protocol),
-                    189usize => Self(stale_key_packet_accepted__peer_address__protocol),
-                    191usize => Self(stale_key_packet_rejected__peer_address__protocol),
-                    193usize => Self(stale_key_packet_dropped__peer_address__protocol),
-                    195usize => {
+                    151usize => Self(path_secret_map_entry_inserted__peer_address__protocol),
+                    153usize => Self(path_secret_map_entry_ready__peer_address__protocol),
+                    155usize => Self(path_secret_map_entry_replaced__peer_address__protocol),
+                    157usize => Self(path_secret_map_id_entry_evicted__peer_address__protocol),
+                    160usize => Self(path_secret_map_address_entry_evicted__peer_address__protocol),
+                    163usize => Self(unknown_path_secret_packet_sent__peer_address__protocol),
+                    165usize => Self(unknown_path_secret_packet_received__peer_address__protocol),
+                    167usize => Self(unknown_path_secret_packet_accepted__peer_address__protocol),
+                    169usize => Self(unknown_path_secret_packet_rejected__peer_address__protocol),
+                    171usize => Self(unknown_path_secret_packet_dropped__peer_address__protocol),
+                    179usize => Self(replay_detected_packet_sent__peer_address__protocol),
+                    181usize => Self(replay_detected_packet_received__peer_address__protocol),
+                    183usize => Self(replay_detected_packet_accepted__peer_address__protocol),
+                    185usize => Self(replay_detected_packet_rejected__peer_address__protocol),
+                    187usize => Self(replay_detected_packet_dropped__peer_address__protocol),
+                    189usize => Self(stale_key_packet_sent__peer_address__protocol),
+                    191usize => Self(stale_key_packet_received__peer_address__protocol),
+                    193usize => Self(stale_key_packet_accepted__peer_address__protocol),
+                    195usize => Self(stale_key_packet_rejected__peer_address__protocol),
+                    197usize => Self(stale_key_packet_dropped__peer_address__protocol),
+                    199usize => {
                         Self(path_secret_map_address_cache_accessed__peer_address__protocol)
                     }
-                    198usize => {
+                    202usize => {
                         Self(path_secret_map_address_cache_accessed_hit__peer_address__protocol)
                     }
                     _ => unreachable!("invalid info: {info:?}"),
@@ -392,6 +396,12 @@ mod counter {
                     variant: u64,
                     variant_name: &info::Str,
                 );
+                # [link_name = s2n_quic_dc__event__counter__nominal__stream_read_window_updated__reason]
+                fn stream_read_window_updated__reason(
+                    value: u64,
+                    variant: u64,
+                    variant_name: &info::Str,
+                );
                 # [link_name = s2n_quic_dc__event__counter__nominal__endpoint_initialized__acceptor__protocol]
                 fn endpoint_initialized__acceptor__protocol(
                     value: u64,
@@ -616,35 +626,37 @@ mod measure {
                 128usize => Self(stream_read_socket_blocked__conn),
                 129usize => Self(stream_read_socket_blocked__capacity),
                 131usize => Self(stream_read_socket_errored__capacity),
-                139usize => Self(path_secret_map_initialized__capacity),
-                141usize => Self(path_secret_map_uninitialized__capacity),
-                142usize => Self(path_secret_map_uninitialized__entries),
-                143usize => Self(path_secret_map_uninitialized__lifetime),
-                154usize => Self(path_secret_map_id_entry_evicted__age),
-                157usize => Self(path_secret_map_address_entry_evicted__age),
-                169usize => Self(key_accepted__gap),
-                170usize => Self(key_accepted__forward_shift),
-                173usize => Self(replay_potentially_detected__gap),
-                199usize => Self(path_secret_map_address_cache_accessed_hit__age),
-                203usize => Self(path_secret_map_id_cache_accessed_hit__age),
-                205usize => Self(path_secret_map_cleaner_cycled__entries__id),
-                206usize => Self(path_secret_map_cleaner_cycled__entries__id__retired),
-                207usize => Self(path_secret_map_cleaner_cycled__entries__id__active),
-                208usize => Self(path_secret_map_cleaner_cycled__entries__id__active__utilization),
-                209usize => Self(path_secret_map_cleaner_cycled__entries__id__utilization),
-                210usize => Self(path_secret_map_cleaner_cycled__entries__id__utilization__initial),
-                211usize => Self(path_secret_map_cleaner_cycled__entries__address),
-                212usize => Self(path_secret_map_cleaner_cycled__entries__address__active),
-                213usize => {
+                133usize => Self(stream_read_window_updated__window),
+                134usize => Self(stream_read_window_updated__rtt),
+                143usize => Self(path_secret_map_initialized__capacity),
+                145usize => Self(path_secret_map_uninitialized__capacity),
+                146usize => Self(path_secret_map_uninitialized__entries),
+                147usize => Self(path_secret_map_uninitialized__lifetime),
+                158usize => Self(path_secret_map_id_entry_evicted__age),
+                161usize => Self(path_secret_map_address_entry_evicted__age),
+                173usize => Self(key_accepted__gap),
+                174usize => Self(key_accepted__forward_shift),
+                177usize => Self(replay_potentially_detected__gap),
+                203usize => Self(path_secret_map_address_cache_accessed_hit__age),
+                207usize => Self(path_secret_map_id_cache_accessed_hit__age),
+                209usize => Self(path_secret_map_cleaner_cycled__entries__id),
+                210usize => Self(path_secret_map_cleaner_cycled__entries__id__retired),
+                211usize => Self(path_secret_map_cleaner_cycled__entries__id__active),
+                212usize => Self(path_secret_map_cleaner_cycled__entries__id__active__utilization),
+                213usize => Self(path_secret_map_cleaner_cycled__entries__id__utilization),
+                214usize => Self(path_secret_map_cleaner_cycled__entries__id__utilization__initial),
+                215usize => Self(path_secret_map_cleaner_cycled__entries__address),
+                216usize => Self(path_secret_map_cleaner_cycled__entries__address__active),
+                217usize => {
                     Self(path_secret_map_cleaner_cycled__entries__address__active__utilization)
                 }
-                214usize => Self(path_secret_map_cleaner_cycled__entries__address__retired),
-                215usize => Self(path_secret_map_cleaner_cycled__entries__address__utilization),
-                216usize => {
+                218usize => Self(path_secret_map_cleaner_cycled__entries__address__retired),
+                219usize => Self(path_secret_map_cleaner_cycled__entries__address__utilization),
+                220usize => {
                     Self(path_secret_map_cleaner_cycled__entries__address__utilization__initial)
                 }
-                217usize => Self(path_secret_map_cleaner_cycled__handshake_requests),
-                218usize => Self(path_secret_map_cleaner_cycled__handshake_requests__retired),
+                221usize => Self(path_secret_map_cleaner_cycled__handshake_requests),
+                222usize => Self(path_secret_map_cleaner_cycled__handshake_requests__retired),
                 _ => unreachable!("invalid info: {info:?}"),
             }
         }
@@ -780,6 +792,10 @@ mod measure {
             fn stream_read_socket_blocked__capacity(value: u64);
             # [link_name = s2n_quic_dc__event__measure__stream_read_socket_errored__capacity]
             fn stream_read_socket_errored__capacity(value: u64);
+            # [link_name = s2n_quic_dc__event__measure__stream_read_window_updated__window]
+            fn stream_read_window_updated__window(value: u64);
+            # [link_name = s2n_quic_dc__event__measure__stream_read_window_updated__rtt]
+            fn stream_read_window_updated__rtt(value: u64);
             # [link_name = s2n_quic_dc__event__measure__path_secret_map_initialized__capacity]
             fn path_secret_map_initialized__capacity(value: u64);
             # [link_name = s2n_quic_dc__event__measure__path_secret_map_uninitialized__capacity]
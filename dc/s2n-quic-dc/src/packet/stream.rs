@@ -8,10 +8,12 @@ use zerocopy::{FromBytes, Unaligned};
 
 pub mod decoder;
 pub mod encoder;
+pub mod flags;
 pub mod id;
 
 type RelativeRetransmissionOffset = u32;
 
+pub use flags::Flags;
 pub use id::Id;
 
 #[derive(Clone, Copy, Debug)]
@@ -5,7 +5,7 @@ use crate::{
     credentials::Credentials,
     crypto,
     packet::{
-        stream::{self, RelativeRetransmissionOffset, Tag},
+        stream::{self, Flags, RelativeRetransmissionOffset, Tag},
         WireVersion,
     },
 };
@@ -54,6 +54,7 @@ pub struct Owned {
     pub tag: Tag,
     pub wire_version: WireVersion,
     pub credentials: Credentials,
+    pub flags: Flags,
     pub source_queue_id: Option<VarInt>,
     pub stream_id: stream::Id,
     pub original_packet_number: PacketNumber,
@@ -77,6 +78,7 @@ impl<'a> From<Packet<'a>> for Owned {
             tag: packet.tag,
             wire_version: packet.wire_version,
             credentials: packet.credentials,
+            flags: packet.flags,
             source_queue_id: packet.source_queue_id,
             stream_id: packet.stream_id,
             original_packet_number: packet.original_packet_number,
@@ -97,6 +99,7 @@ pub struct Packet<'a> {
     tag: Tag,
     wire_version: WireVersion,
     credentials: Credentials,
+    flags: Flags,
     source_queue_id: Option<VarInt>,
     stream_id: stream::Id,
     original_packet_number: PacketNumber,
@@ -118,6 +121,7 @@ impl fmt::Debug for Packet<'_> {
             .field("tag", &self.tag)
             .field("wire_version", &self.wire_version)
             .field("credentials", &self.credentials)
+            .field("flags", &self.flags)
             .field("source_queue_id", &self.source_queue_id)
             .field("stream_id", &self.stream_id)
             .field("packet_number", &self.packet_number())
@@ -146,6 +150,11 @@ impl Packet<'_> {
         &self.credentials
     }
 
+    #[inline]
+    pub fn flags(&self) -> Flags {
+        self.flags
+    }
+
     #[inline]
     pub fn source_queue_id(&self) -> Option<VarInt> {
         self.source_queue_id
@@ -414,7 +423,7 @@ impl Packet<'_> {
         let (_credentials, buffer) = buffer.decode::<Credentials>()?;
         let (_wire_version, buffer) = buffer.decode::<WireVersion>()?;
 
-        let (_source_control_port, buffer) = buffer.decode::<u16>()?;
+        let (_flags, buffer) = buffer.decode::<Flags>()?;
 
         let (stream_id, buffer) = buffer.decode::<stream::Id>()?;
 
@@ -494,6 +503,7 @@ impl Packet<'_> {
             tag,
             wire_version,
             credentials,
+            flags,
             source_queue_id,
             stream_id,
             original_packet_number,
@@ -525,9 +535,9 @@ impl Packet<'_> {
             let (credentials, buffer) = buffer.decode()?;
             let (wire_version, buffer) = buffer.decode()?;
 
-            // unused space - was source_control_port when we did port migration but that has
-            // been replaced with `source_queue_id`, which is more flexible
-            let (_source_control_port, buffer) = buffer.decode::<u16>()?;
+            // was source_control_port when we did port migration but that has been replaced
+            // with `source_queue_id`, which is more flexible
+            let (flags, buffer) = buffer.decode()?;
 
             let (stream_id, buffer) = buffer.decode::<stream::Id>()?;
 
@@ -590,6 +600,7 @@ impl Packet<'_> {
                 tag,
                 wire_version,
                 credentials,
+                flags,
                 source_queue_id,
                 stream_id,
                 original_packet_number,
@@ -641,6 +652,7 @@ impl Packet<'_> {
             tag,
             wire_version,
             credentials,
+            flags,
             source_queue_id,
             stream_id,
             original_packet_number,
@@ -5,7 +5,7 @@ use crate::{
     credentials::Credentials,
     crypto::{self, KeyPhase},
     packet::{
-        stream::{self, RelativeRetransmissionOffset, Tag},
+        stream::{self, Flags, RelativeRetransmissionOffset, Tag},
         WireVersion,
     },
 };
@@ -34,6 +34,7 @@ pub fn encode<H, CD, P, C>(
     payload: &mut P,
     crypto: &C,
     credentials: &Credentials,
+    flags: Flags,
 ) -> usize
 where
     H: buffer::reader::Storage<Error = core::convert::Infallible>,
@@ -48,6 +49,7 @@ where
         packet_space,
         crypto.key_phase(),
         credentials,
+        flags,
         source_queue_id,
         stream_id,
         packet_number,
@@ -98,6 +100,7 @@ where
         assert!(remaining.is_empty());
         assert_eq!(packet.payload().len(), payload_len);
         assert_eq!(packet.packet_number(), packet_number);
+        assert_eq!(packet.flags(), flags);
     }
 
     packet_len
@@ -131,6 +134,8 @@ where
         packet_space,
         KeyPhase::Zero,
         credentials,
+        // probes are only sent on datagram transports so there's nothing to offer
+        Flags::empty(),
         source_queue_id,
         stream_id,
         packet_number,
@@ -180,6 +185,7 @@ fn encode_header<H, CD, P>(
     packet_space: stream::PacketSpace,
     key_phase: KeyPhase,
     credentials: &Credentials,
+    flags: Flags,
     source_queue_id: Option<VarInt>,
     stream_id: stream::Id,
     packet_number: VarInt,
@@ -214,9 +220,9 @@ where
     // wire version - we only support `0` currently
     encoder.encode(&WireVersion::ZERO);
 
-    // unused space - was source_control_port when we did port migration but that has
-    // been replaced with `source_queue_id`, which is more flexible
-    encoder.encode(&0u16);
+    // was source_control_port when we did port migration but that has been replaced with
+    // `source_queue_id`, which is more flexible
+    encoder.encode(&flags);
 
     encoder.encode(&stream_id);
     encoder.encode(&source_queue_id);
@@ -0,0 +1,42 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use s2n_codec::{decoder_value, Encoder, EncoderValue};
+
+bitflags::bitflags! {
+    /// Options the sender of a stream packet is offering to its peer
+    ///
+    /// These occupy the space that was previously used for the source control port. Unknown bits
+    /// are preserved and ignored so new options can be added without breaking older peers.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct Flags: u16 {
+        /// The sender's socket never leaves the local host and it is willing to skip sealing
+        /// packets after the first one in each direction
+        ///
+        /// The mode is only used once both peers have offered it in an authenticated packet.
+        const SAME_HOST = 1;
+    }
+}
+
+impl Flags {
+    #[inline]
+    pub const fn is_same_host(&self) -> bool {
+        self.contains(Self::SAME_HOST)
+    }
+}
+
+decoder_value!(
+    impl<'a> Flags {
+        fn decode(buffer: Buffer) -> Result<Self> {
+            let (flags, buffer) = buffer.decode::<u16>()?;
+            Ok((Self::from_bits_retain(flags), buffer))
+        }
+    }
+);
+
+impl EncoderValue for Flags {
+    #[inline]
+    fn encode<E: Encoder>(&self, encoder: &mut E) {
+        self.bits().encode(encoder);
+    }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod key;
+pub mod keylog;
 pub mod map;
 #[doc(hidden)]
 pub mod receiver;
@@ -2,9 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::{map, schedule};
+use crate::packet::stream;
 use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use s2n_quic_core::{packet::KeyPhase, time::Clock};
 
+#[cfg(test)]
+mod tests;
+
 pub mod seal {
     use super::*;
     use crate::crypto::{awslc, seal};
@@ -21,6 +25,7 @@ pub mod seal {
         ku: schedule::SealUpdate,
         key_phase: KeyPhase,
         encrypted_records: AtomicU64,
+        same_host: SameHost,
     }
 
     impl Application {
@@ -31,9 +36,33 @@ pub mod seal {
                 ku,
                 key_phase: KeyPhase::Zero,
                 encrypted_records: AtomicU64::new(0),
+                same_host: SameHost::Disabled,
             }
         }
 
+        /// Offers the same-host mode to the peer
+        ///
+        /// This must only be enabled for transports that never leave the local host.
+        #[inline]
+        pub(crate) fn offer_same_host(&mut self) {
+            self.same_host = SameHost::Offered;
+        }
+
+        /// Called once the peer has offered the same-host mode in an authenticated packet
+        ///
+        /// If the mode was offered by both peers, only the first packet is sealed and the rest
+        /// of the payloads are left in plaintext.
+        #[inline]
+        pub(crate) fn on_same_host_offered(&mut self) {
+            self.same_host.on_peer_offer();
+        }
+
+        /// Returns the [`stream::Flags`] to include in the packets sealed by this key
+        #[inline]
+        pub fn stream_flags(&self) -> stream::Flags {
+            self.same_host.stream_flags()
+        }
+
         #[inline]
         pub fn needs_update(&self) -> bool {
             //= https://www.rfc-editor.org/rfc/rfc9001#section-6.6
@@ -94,6 +123,11 @@ pub mod seal {
             extra_payload: Option<&[u8]>,
             payload_and_tag: &mut [u8],
         ) {
+            if self.same_host.is_negotiated() && packet_number > 0 {
+                super::same_host::seal(self.tag_len(), extra_payload, payload_and_tag);
+                return;
+            }
+
             self.encrypted_records.fetch_add(1, Ordering::Relaxed);
             self.sealer
                 .encrypt(packet_number, header, extra_payload, payload_and_tag)
@@ -199,6 +233,7 @@ pub mod open {
         key_phase: KeyPhase,
         dedup: map::Dedup,
         needs_update: AtomicBool,
+        same_host: SameHost,
     }
 
     impl Application {
@@ -215,11 +250,46 @@ pub mod open {
                 key_phase: KeyPhase::Zero,
                 dedup,
                 needs_update: AtomicBool::new(false),
+                same_host: SameHost::Disabled,
             }
         }
 
         with_dedup!();
 
+        /// Offers the same-host mode to the peer
+        ///
+        /// This must only be enabled for transports that never leave the local host.
+        #[inline]
+        pub(crate) fn offer_same_host(&mut self) {
+            self.same_host = SameHost::Offered;
+        }
+
+        /// Returns `true` if the same-host mode was offered to the peer and is waiting on the
+        /// peer to offer it back
+        #[inline]
+        pub fn is_awaiting_same_host_offer(&self) -> bool {
+            matches!(self.same_host, SameHost::Offered)
+        }
+
+        /// Called once the peer has offered the same-host mode in an authenticated packet
+        ///
+        /// If the mode was offered by both peers, packets with a zeroed tag are accepted as
+        /// plaintext.
+        #[inline]
+        pub(crate) fn on_same_host_offered(&mut self) {
+            self.same_host.on_peer_offer();
+        }
+
+        #[inline]
+        fn is_plaintext(&self, packet_number: u64, tag: &[u8]) -> bool {
+            // the first packet is always sealed so the peer proves it has the path secret.
+            //
+            // The peer may still send sealed packets while its half of the negotiation is in
+            // flight. Those are told apart by the tag, since a sealed packet has a zero tag
+            // with negligible probability.
+            self.same_host.is_negotiated() && packet_number > 0 && super::same_host::is_zero(tag)
+        }
+
         #[inline]
         pub fn needs_update(&self) -> bool {
             self.needs_update.load(Ordering::Relaxed)
@@ -266,6 +336,11 @@ pub mod open {
             tag: &[u8],
             payload_out: &mut UninitSlice,
         ) -> open::Result {
+            if self.is_plaintext(packet_number, tag) {
+                payload_out.copy_from_slice(payload_in);
+                return self.on_decrypt_success(payload_out);
+            }
+
             let opener = match key_phase {
                 KeyPhase::Zero => &self.openers[0],
                 KeyPhase::One => &self.openers[1],
@@ -298,6 +373,15 @@ pub mod open {
             header: &[u8],
             payload_and_tag: &mut [u8],
         ) -> open::Result {
+            let tag_len = self.tag_len();
+            let tag_offset = payload_and_tag
+                .len()
+                .checked_sub(tag_len)
+                .ok_or(open::Error::InvalidTag)?;
+            if self.is_plaintext(packet_number, &payload_and_tag[tag_offset..]) {
+                return self.on_decrypt_success(payload_and_tag.into());
+            }
+
             let opener = match key_phase {
                 KeyPhase::Zero => &self.openers[0],
                 KeyPhase::One => &self.openers[1],
@@ -407,3 +491,56 @@ pub mod open {
         }
     }
 }
+
+/// The state of the same-host mode for an application key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SameHost {
+    /// The transport may leave the local host so every packet is sealed
+    Disabled,
+    /// The mode was offered to the peer but the peer hasn't offered it back yet
+    Offered,
+    /// Both peers have offered the mode
+    Negotiated,
+}
+
+impl SameHost {
+    #[inline]
+    fn on_peer_offer(&mut self) {
+        if *self == Self::Offered {
+            *self = Self::Negotiated;
+        }
+    }
+
+    #[inline]
+    fn is_negotiated(&self) -> bool {
+        *self == Self::Negotiated
+    }
+
+    #[inline]
+    fn stream_flags(&self) -> stream::Flags {
+        match self {
+            Self::Disabled => stream::Flags::empty(),
+            Self::Offered | Self::Negotiated => stream::Flags::SAME_HOST,
+        }
+    }
+}
+
+/// Framing for packets sent over a same-host transport after the mode has been negotiated
+///
+/// The payload is left in plaintext and the tag is zeroed, which is how the receiver tells
+/// these packets apart from sealed ones.
+mod same_host {
+    #[inline]
+    pub fn seal(tag_len: usize, extra_payload: Option<&[u8]>, payload_and_tag: &mut [u8]) {
+        let extra_in = extra_payload.unwrap_or(&[][..]);
+        let inline_len = payload_and_tag.len() - tag_len - extra_in.len();
+        let (extra_out, tag) = payload_and_tag[inline_len..].split_at_mut(extra_in.len());
+        extra_out.copy_from_slice(extra_in);
+        tag.fill(0);
+    }
+
+    #[inline]
+    pub fn is_zero(tag: &[u8]) -> bool {
+        tag.iter().all(|b| *b == 0)
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::crypto::{
+    self,
+    open::{Application as _, Error},
+    seal::Application as _,
+};
+use s2n_quic_core::{endpoint, varint::VarInt};
+
+const HEADER: &[u8] = b"header";
+const PAYLOAD: &[u8] = b"hello from the same host";
+
+fn pair(same_host: bool) -> (seal::Application, open::Application) {
+    let export_secret = [42; schedule::EXPORT_SECRET_LEN];
+    let ciphersuite = schedule::Ciphersuite::AES_GCM_128_SHA256;
+    let version = crate::SUPPORTED_VERSIONS[0];
+    let key_id = VarInt::from_u8(1);
+
+    let client =
+        schedule::Secret::new(ciphersuite, version, endpoint::Type::Client, &export_secret);
+    let server =
+        schedule::Secret::new(ciphersuite, version, endpoint::Type::Server, &export_secret);
+
+    let (sealer, sealer_ku, _, _) = client.application_pair(key_id, schedule::Initiator::Local);
+    let (_, _, opener, opener_ku) = server.application_pair(key_id, schedule::Initiator::Remote);
+
+    let mut sealer = seal::Application::new(sealer, sealer_ku);
+    let mut opener = open::Application::new(opener, opener_ku, map::Dedup::disabled());
+
+    if same_host {
+        sealer.offer_same_host();
+        opener.offer_same_host();
+    }
+
+    (sealer, opener)
+}
+
+/// Simulates both peers receiving an authenticated packet offering the same-host mode
+fn negotiate(sealer: &mut seal::Application, opener: &mut open::Application) {
+    sealer.on_same_host_offered();
+    if opener.is_awaiting_same_host_offer() {
+        opener.on_same_host_offered();
+    }
+}
+
+fn seal(sealer: &seal::Application, packet_number: u64) -> Vec<u8> {
+    let mut packet = PAYLOAD.to_vec();
+    packet.resize(PAYLOAD.len() + sealer.tag_len(), 0);
+    sealer.encrypt(packet_number, HEADER, None, &mut packet);
+    packet
+}
+
+fn open(
+    opener: &open::Application,
+    packet_number: u64,
+    packet: &[u8],
+) -> crypto::open::Result<Vec<u8>> {
+    let mut packet = packet.to_vec();
+    opener.decrypt_in_place(KeyPhase::Zero, packet_number, HEADER, &mut packet)?;
+    packet.truncate(packet.len() - opener.tag_len());
+    Ok(packet)
+}
+
+#[test]
+fn same_host_round_trip() {
+    let (mut sealer, mut opener) = pair(true);
+    assert!(sealer.stream_flags().is_same_host());
+    assert!(opener.is_awaiting_same_host_offer());
+
+    // the first packet is always sealed
+    let first = seal(&sealer, 0);
+    assert_ne!(&first[..PAYLOAD.len()], PAYLOAD);
+    assert_eq!(open(&opener, 0, &first).unwrap(), PAYLOAD);
+
+    negotiate(&mut sealer, &mut opener);
+    assert!(!opener.is_awaiting_same_host_offer());
+
+    // the rest are in plaintext
+    for packet_number in 1..4 {
+        let packet = seal(&sealer, packet_number);
+        assert_eq!(&packet[..PAYLOAD.len()], PAYLOAD);
+        assert_eq!(open(&opener, packet_number, &packet).unwrap(), PAYLOAD);
+    }
+
+    // plaintext packets don't count towards the confidentiality limit
+    for packet_number in 4..=seal::TEST_MAX_RECORDS {
+        let packet = seal(&sealer, packet_number);
+        open(&opener, packet_number, &packet).unwrap();
+    }
+    assert!(!sealer.needs_update());
+}
+
+#[test]
+fn same_host_extra_payload() {
+    let (mut sealer, mut opener) = pair(true);
+    negotiate(&mut sealer, &mut opener);
+
+    let first = seal(&sealer, 0);
+    open(&opener, 0, &first).unwrap();
+
+    let (inline, extra) = PAYLOAD.split_at(4);
+    let mut packet = inline.to_vec();
+    packet.resize(PAYLOAD.len() + sealer.tag_len(), 0);
+    sealer.encrypt(1, HEADER, Some(extra), &mut packet);
+
+    assert_eq!(open(&opener, 1, &packet).unwrap(), PAYLOAD);
+}
+
+#[test]
+fn same_host_requires_negotiation() {
+    let (sealer, opener) = pair(true);
+
+    // packets are sealed until the peer offers the mode
+    let packet = seal(&sealer, 1);
+    assert_ne!(&packet[..PAYLOAD.len()], PAYLOAD);
+    assert_eq!(open(&opener, 1, &packet).unwrap(), PAYLOAD);
+
+    // plaintext packets aren't accepted until the peer offers the mode
+    let mut plaintext = PAYLOAD.to_vec();
+    plaintext.resize(PAYLOAD.len() + sealer.tag_len(), 0);
+    assert_eq!(open(&opener, 1, &plaintext), Err(Error::InvalidTag));
+
+    // the first packet is never accepted in plaintext
+    let (mut sealer, mut opener) = pair(true);
+    negotiate(&mut sealer, &mut opener);
+    assert_eq!(open(&opener, 0, &plaintext), Err(Error::InvalidTag));
+}
+
+#[test]
+fn same_host_sealed_after_negotiation() {
+    // the peer may keep sealing packets until it sees our offer
+    let (sealer, _) = pair(true);
+    let (mut peer_sealer, mut opener) = pair(true);
+    negotiate(&mut peer_sealer, &mut opener);
+
+    for packet_number in 0..4 {
+        let packet = seal(&sealer, packet_number);
+        assert_eq!(open(&opener, packet_number, &packet).unwrap(), PAYLOAD);
+    }
+
+    // a sealed packet with a corrupted tag is still rejected
+    let mut packet = seal(&sealer, 4);
+    *packet.last_mut().unwrap() ^= 1;
+    assert_eq!(open(&opener, 4, &packet), Err(Error::InvalidTag));
+}
+
+#[test]
+fn same_host_mismatch() {
+    // a peer which doesn't offer the mode never switches to plaintext
+    let (mut sealer, mut opener) = pair(false);
+    assert!(sealer.stream_flags().is_empty());
+    assert!(!opener.is_awaiting_same_host_offer());
+    negotiate(&mut sealer, &mut opener);
+
+    let packet = seal(&sealer, 1);
+    assert_ne!(&packet[..PAYLOAD.len()], PAYLOAD);
+    assert_eq!(open(&opener, 1, &packet).unwrap(), PAYLOAD);
+
+    // and doesn't accept plaintext packets from a peer that thinks it was negotiated
+    let (mut same_host_sealer, mut same_host_opener) = pair(true);
+    negotiate(&mut same_host_sealer, &mut same_host_opener);
+
+    let packet = seal(&same_host_sealer, 1);
+    assert_eq!(open(&opener, 1, &packet), Err(Error::InvalidTag));
+}
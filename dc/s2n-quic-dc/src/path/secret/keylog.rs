@@ -0,0 +1,245 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Key logging for path secrets
+//!
+//! This is the dcQUIC equivalent of the `SSLKEYLOGFILE` format. Each time a path secret is
+//! inserted into a [`Map`](super::Map), a line is emitted containing the credential ID along with
+//! the exported secret and ciphersuite. All of the application and control keys for the path can
+//! be derived from this line, which allows tools (e.g., the Wireshark dissector) to decrypt
+//! captured traffic.
+//!
+//! **WARNING**: Anyone with access to the key log can decrypt all traffic using the logged
+//! secrets. This should only ever be enabled for debugging.
+
+use crate::{
+    credentials::Id,
+    path::secret::schedule::{Ciphersuite, ExportSecret, EXPORT_SECRET_LEN},
+};
+use core::fmt;
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    sync::Mutex,
+};
+use zeroize::Zeroizing;
+
+/// The environment variable used to configure the path of the key log file
+pub const ENV_VAR: &str = "DC_QUIC_KEYLOGFILE";
+
+/// The label at the start of each path secret line
+pub const LABEL: &str = "DCQUIC_PATH_SECRET";
+
+/// A callback which is invoked for each path secret inserted into the map
+pub type Callback = Box<dyn Fn(&Entry) + Send + Sync>;
+
+/// A single path secret in the key log
+pub struct Entry<'a> {
+    pub id: &'a Id,
+    pub ciphersuite: Ciphersuite,
+    pub export_secret: &'a ExportSecret,
+}
+
+impl fmt::Debug for Entry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // don't print the secret
+        f.debug_struct("Entry")
+            .field("id", self.id)
+            .field("ciphersuite", &self.ciphersuite)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for Entry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{LABEL} ")?;
+        write_hex(f, &self.id[..])?;
+        write!(f, " {} ", ciphersuite_name(self.ciphersuite))?;
+        write_hex(f, &self.export_secret[..])?;
+        Ok(())
+    }
+}
+
+/// An owned path secret, parsed from a key log line
+pub struct Owned {
+    pub id: Id,
+    pub ciphersuite: Ciphersuite,
+    pub export_secret: Zeroizing<ExportSecret>,
+}
+
+impl fmt::Debug for Owned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_entry().fmt(f)
+    }
+}
+
+impl Owned {
+    #[inline]
+    pub fn as_entry(&self) -> Entry {
+        Entry {
+            id: &self.id,
+            ciphersuite: self.ciphersuite,
+            export_secret: &self.export_secret,
+        }
+    }
+
+    /// Parses a single key log line
+    ///
+    /// Returns `None` for comments, blank lines, unknown labels and malformed entries.
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.split_ascii_whitespace();
+
+        if parts.next()? != LABEL {
+            return None;
+        }
+
+        let mut id = Id::default();
+        read_hex(parts.next()?, &mut id[..])?;
+
+        let ciphersuite = parse_ciphersuite(parts.next()?)?;
+
+        let mut export_secret = Zeroizing::new([0u8; EXPORT_SECRET_LEN]);
+        read_hex(parts.next()?, &mut export_secret[..])?;
+
+        // make sure there isn't any trailing data
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            id,
+            ciphersuite,
+            export_secret,
+        })
+    }
+}
+
+/// Appends key log lines to a file
+pub struct Writer {
+    file: Mutex<File>,
+}
+
+impl Writer {
+    /// Opens the file configured by the [`ENV_VAR`] environment variable, if any
+    pub fn from_env() -> Option<Self> {
+        let path = std::env::var_os(ENV_VAR)?;
+
+        match Self::open(&path) {
+            Ok(writer) => Some(writer),
+            Err(error) => {
+                tracing::warn!(?path, %error, "could not open dcQUIC key log file");
+                None
+            }
+        }
+    }
+
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = OpenOptions::new().append(true).create(true).open(path)?;
+        let file = Mutex::new(file);
+        Ok(Self { file })
+    }
+
+    pub fn write(&self, entry: &Entry) {
+        // format the line up front so it's written with a single call
+        let line = Zeroizing::new(format!("{entry}\n"));
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(error) = file.write_all(line.as_bytes()) {
+            tracing::warn!(%error, "could not write to dcQUIC key log file");
+        }
+    }
+}
+
+impl From<Writer> for Callback {
+    #[inline]
+    fn from(writer: Writer) -> Self {
+        Box::new(move |entry| writer.write(entry))
+    }
+}
+
+fn ciphersuite_name(ciphersuite: Ciphersuite) -> &'static str {
+    match ciphersuite {
+        Ciphersuite::AES_GCM_128_SHA256 => "AES_GCM_128_SHA256",
+        Ciphersuite::AES_GCM_256_SHA384 => "AES_GCM_256_SHA384",
+    }
+}
+
+fn parse_ciphersuite(name: &str) -> Option<Ciphersuite> {
+    match name {
+        "AES_GCM_128_SHA256" => Some(Ciphersuite::AES_GCM_128_SHA256),
+        "AES_GCM_256_SHA384" => Some(Ciphersuite::AES_GCM_256_SHA384),
+        _ => None,
+    }
+}
+
+fn write_hex(f: &mut fmt::Formatter, bytes: &[u8]) -> fmt::Result {
+    for byte in bytes {
+        write!(f, "{byte:02x}")?;
+    }
+    Ok(())
+}
+
+fn read_hex(input: &str, out: &mut [u8]) -> Option<()> {
+    let input = input.as_bytes();
+
+    if input.len() != out.len() * 2 {
+        return None;
+    }
+
+    for (byte, chunk) in out.iter_mut().zip(input.chunks_exact(2)) {
+        let hi = (chunk[0] as char).to_digit(16)?;
+        let lo = (chunk[1] as char).to_digit(16)?;
+        *byte = (hi << 4 | lo) as u8;
+    }
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bolero::check;
+
+    #[test]
+    fn round_trip() {
+        check!()
+            .with_type::<(Id, Ciphersuite, ExportSecret)>()
+            .for_each(|(id, ciphersuite, export_secret)| {
+                let entry = Entry {
+                    id,
+                    ciphersuite: *ciphersuite,
+                    export_secret,
+                };
+                let line = entry.to_string();
+                let parsed = Owned::parse(&line).unwrap();
+                assert_eq!(&parsed.id, id);
+                assert_eq!(parsed.ciphersuite, *ciphersuite);
+                assert_eq!(&*parsed.export_secret, export_secret);
+            });
+    }
+
+    #[test]
+    fn invalid_lines() {
+        for line in [
+            "",
+            "# comment",
+            "CLIENT_RANDOM 00 00",
+            "DCQUIC_PATH_SECRET",
+            "DCQUIC_PATH_SECRET 00 AES_GCM_128_SHA256 00",
+            "DCQUIC_PATH_SECRET 000102030405060708090a0b0c0d0e0f CHACHA20 0000000000000000000000000000000000000000000000000000000000000000",
+            "DCQUIC_PATH_SECRET 000102030405060708090a0b0c0d0e0f AES_GCM_128_SHA256 0000000000000000000000000000000000000000000000000000000000000000 extra",
+            "DCQUIC_PATH_SECRET 000102030405060708090a0b0c0d0e0g AES_GCM_128_SHA256 0000000000000000000000000000000000000000000000000000000000000000",
+        ] {
+            assert!(Owned::parse(line).is_none(), "{line:?}");
+        }
+    }
+
+    #[test]
+    fn parse_fuzz() {
+        check!().for_each(|bytes| {
+            if let Ok(line) = core::str::from_utf8(bytes) {
+                let _ = Owned::parse(line);
+            }
+        });
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
     credentials::{Credentials, Id},
     event,
     packet::{secret_control as control, Packet},
-    path::secret::{open, seal, stateless_reset},
+    path::secret::{keylog, open, seal, stateless_reset},
     stream::TransportFeatures,
 };
 use core::fmt;
@@ -107,6 +107,15 @@ impl Map {
         self.store.register_request_handshake(cb);
     }
 
+    /// Registers a callback which is invoked with each new path secret inserted into the map
+    ///
+    /// By default, secrets are written to the file named in the `DC_QUIC_KEYLOGFILE` environment
+    /// variable, if set. Registering a callback replaces that behavior. See [`keylog`] for the
+    /// format and the associated risks.
+    pub fn register_key_log(&self, cb: keylog::Callback) {
+        self.store.register_key_log(cb);
+    }
+
     /// Gets the [`Peer`] entry for the given address
     ///
     /// NOTE: This function is used to track cache hit ratios so it
@@ -125,6 +134,12 @@ impl Map {
         Some(Peer::new(&entry, self))
     }
 
+    /// Returns the address of the peer that the path secret `id` was negotiated with
+    pub fn peer_addr_for_id(&self, id: &Id) -> Option<SocketAddr> {
+        let entry = self.store.get_by_id_untracked(id)?;
+        Some(*entry.peer())
+    }
+
     /// Retrieve a sealer by path secret ID.
     ///
     /// Generally callers should prefer to use one of the `pair` APIs; this is primarily useful for
@@ -10,7 +10,7 @@ use crate::{
     credentials::{self, Credentials},
     packet::{secret_control as control, WireVersion},
     path::secret::{
-        open, receiver,
+        keylog, open, receiver,
         schedule::{self, Initiator},
         seal, sender,
     },
@@ -148,6 +148,10 @@ impl Entry {
         self.secret.id()
     }
 
+    pub fn key_log_entry(&self) -> keylog::Entry {
+        self.secret.key_log_entry()
+    }
+
     pub fn set_accessed_id(&self) {
         self.accessed.fetch_or(0b10, Ordering::Relaxed);
     }
@@ -201,6 +205,7 @@ impl Entry {
             initiator,
             // we don't need to dedup locally-initiated openers
             Dedup::disabled(),
+            features,
         );
 
         let control = if features.is_reliable() {
@@ -234,6 +239,7 @@ impl Entry {
             initiator,
             // Remote application keys need to be de-duplicated
             Dedup::new(self.clone(), key_id, map),
+            features,
         );
 
         let control = if features.is_reliable() {
@@ -323,12 +329,23 @@ pub struct ApplicationPair {
 }
 
 impl ApplicationPair {
-    fn new(secret: &schedule::Secret, key_id: VarInt, initiator: Initiator, dedup: Dedup) -> Self {
+    fn new(
+        secret: &schedule::Secret,
+        key_id: VarInt,
+        initiator: Initiator,
+        dedup: Dedup,
+        features: &TransportFeatures,
+    ) -> Self {
         let (sealer, sealer_ku, opener, opener_ku) = secret.application_pair(key_id, initiator);
 
-        let sealer = seal::Application::new(sealer, sealer_ku);
+        let mut sealer = seal::Application::new(sealer, sealer_ku);
+
+        let mut opener = open::Application::new(opener, opener_ku, dedup);
 
-        let opener = open::Application::new(opener, opener_ku, dedup);
+        if features.is_same_host() {
+            sealer.offer_same_host();
+            opener.offer_same_host();
+        }
 
         Self { sealer, opener }
     }
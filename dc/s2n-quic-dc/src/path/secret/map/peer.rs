@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::{dc, seal, Bidirectional, Credentials, Entry, Map, TransportFeatures};
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 
 pub struct Peer {
     entry: Arc<Entry>,
@@ -30,6 +30,12 @@ impl Peer {
         (keys, self.entry.parameters())
     }
 
+    /// Returns the address of the peer that the secret was negotiated with
+    #[inline]
+    pub fn peer_addr(&self) -> &SocketAddr {
+        self.entry.peer()
+    }
+
     #[inline]
     pub fn map(&self) -> &Map {
         &self.map
@@ -7,7 +7,7 @@ use crate::{
     crypto,
     event::{self, EndpointPublisher as _, IntoEvent as _},
     packet::{secret_control as control, Packet},
-    path::secret::receiver,
+    path::secret::{keylog, receiver},
 };
 use s2n_quic_core::{
     inet::SocketAddress,
@@ -200,6 +200,9 @@ where
     #[allow(clippy::type_complexity)]
     pub(super) request_handshake: RwLock<Option<Box<dyn Fn(SocketAddr) + Send + Sync>>>,
 
+    // Writes newly inserted path secrets to a key log, if configured.
+    key_log: RwLock<Option<keylog::Callback>>,
+
     cleaner: Cleaner,
 
     // Avoids allocating/deallocating on each cleaner run.
@@ -287,6 +290,7 @@ where
             clock,
             subscriber,
             request_handshake: RwLock::new(None),
+            key_log: RwLock::new(keylog::Writer::from_env().map(keylog::Callback::from)),
             mk_application_data: RwLock::new(None),
         };
 
@@ -385,6 +389,10 @@ where
             .unwrap_or_else(|e| e.into_inner()) = Some(cb);
     }
 
+    fn register_key_log(&self, cb: keylog::Callback) {
+        *self.key_log.write().unwrap_or_else(|e| e.into_inner()) = Some(cb);
+    }
+
     fn handle_unknown_secret(
         &self,
         packet: &control::unknown_path_secret::Packet,
@@ -626,6 +634,15 @@ where
             }
         }
 
+        if let Some(key_log) = self
+            .key_log
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_deref()
+        {
+            (key_log)(&entry.key_log_entry());
+        }
+
         self.subscriber().on_path_secret_map_entry_inserted(
             event::builder::PathSecretMapEntryInserted {
                 peer_address: SocketAddress::from(*peer).into_event(),
@@ -672,6 +689,10 @@ where
         self.register_request_handshake(cb);
     }
 
+    fn register_key_log(&self, cb: keylog::Callback) {
+        self.register_key_log(cb);
+    }
+
     #[allow(clippy::type_complexity)]
     fn register_make_application_data(
         &self,
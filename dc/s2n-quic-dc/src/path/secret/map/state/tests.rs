@@ -75,6 +75,37 @@ fn thread_shutdown() {
     panic!("thread did not shut down after {max_time:?}");
 }
 
+#[test]
+fn key_log() {
+    let signer = stateless_reset::Signer::new(b"secret");
+    let map = State::new(signer, 10, Clock, tracing::Subscriber::default());
+    map.cleaner.stop();
+
+    let lines = Arc::new(Mutex::new(vec![]));
+    let lines_cb = lines.clone();
+    map.register_key_log(Box::new(move |entry| {
+        lines_cb.lock().unwrap().push(entry.to_string());
+    }));
+
+    let entry = fake_entry(1);
+    map.test_insert(entry.clone());
+
+    let lines = lines.lock().unwrap();
+    assert_eq!(lines.len(), 1);
+
+    let logged = keylog::Owned::parse(&lines[0]).unwrap();
+    assert_eq!(&logged.id, entry.id());
+
+    // the logged secret should be sufficient to derive the same path secret
+    let secret = schedule::Secret::new(
+        logged.ciphersuite,
+        dc::SUPPORTED_VERSIONS[0],
+        s2n_quic_core::endpoint::Type::Server,
+        &logged.export_secret,
+    );
+    assert_eq!(secret.id(), entry.id());
+}
+
 #[derive(Debug, Default)]
 struct Model {
     invariants: HashSet<Invariant>,
@@ -5,7 +5,7 @@ use super::{entry::ApplicationData, Entry};
 use crate::{
     credentials::{Credentials, Id},
     packet::{secret_control as control, Packet, WireVersion},
-    path::secret::{receiver, stateless_reset},
+    path::secret::{keylog, receiver, stateless_reset},
 };
 use core::time::Duration;
 use s2n_codec::EncoderBuffer;
@@ -46,6 +46,8 @@ pub trait Store: 'static + Send + Sync {
 
     fn register_request_handshake(&self, cb: Box<dyn Fn(SocketAddr) + Send + Sync>);
 
+    fn register_key_log(&self, cb: keylog::Callback);
+
     fn check_dedup(
         &self,
         entry: &Entry,
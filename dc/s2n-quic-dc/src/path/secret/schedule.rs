@@ -167,6 +167,21 @@ impl Secret {
         &self.id
     }
 
+    #[inline]
+    pub fn ciphersuite(&self) -> Ciphersuite {
+        self.ciphersuite
+    }
+
+    /// Returns the secret in a format suitable for writing to a key log
+    #[inline]
+    pub fn key_log_entry(&self) -> super::keylog::Entry {
+        super::keylog::Entry {
+            id: &self.id,
+            ciphersuite: self.ciphersuite,
+            export_secret: &self.export_secret,
+        }
+    }
+
     #[inline]
     pub fn application_pair(
         &self,
@@ -40,6 +40,12 @@ bitflags::bitflags! {
         const STREAM = 3;
         /// The underlying transport provides connections between peers
         const CONNECTED = 4;
+        /// The underlying transport never leaves the local host and the peer runs as the same user
+        ///
+        /// The same-host mode is offered to the peer in the stream packet flags. Once both peers
+        /// have offered it, packets after the first one in each direction skip AEAD sealing and
+        /// rely on the host for confidentiality and integrity.
+        const SAME_HOST = 8;
     }
 }
 
@@ -60,13 +66,18 @@ macro_rules! is_feature {
 }
 
 impl TransportFeatures {
-    pub const TCP: Self = Self::all();
+    pub const TCP: Self = Self::RELIABLE
+        .union(Self::FLOW_CONTROL)
+        .union(Self::STREAM)
+        .union(Self::CONNECTED);
     pub const UDP: Self = Self::empty();
+    pub const UNIX: Self = Self::TCP.union(Self::SAME_HOST);
 
     is_feature!(is_reliable, RELIABLE);
     is_feature!(is_flow_controlled, FLOW_CONTROL);
     is_feature!(is_stream, STREAM);
     is_feature!(is_connected, CONNECTED);
+    is_feature!(is_same_host, SAME_HOST);
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
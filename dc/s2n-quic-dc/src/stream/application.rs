@@ -119,8 +119,8 @@ where
         let mut s = f.debug_struct("Stream");
 
         for (name, addr) in [
-            ("peer_addr", self.peer_addr()),
-            ("local_addr", self.local_addr()),
+            ("peer_addr", self.peer_address()),
+            ("local_addr", self.local_address()),
         ] {
             if let Ok(addr) = addr {
                 s.field(name, &addr);
@@ -145,6 +145,18 @@ where
         self.read.local_addr()
     }
 
+    /// Returns the address of the peer, including peers which aren't addressed by IP
+    #[inline]
+    pub fn peer_address(&self) -> io::Result<socket::Address> {
+        self.read.peer_address()
+    }
+
+    /// Returns the local address, including sockets which aren't addressed by IP
+    #[inline]
+    pub fn local_address(&self) -> io::Result<socket::Address> {
+        self.read.local_address()
+    }
+
     #[inline]
     pub fn protocol(&self) -> socket::Protocol {
         self.read.protocol()
@@ -13,8 +13,8 @@ use crate::{
         socket::Protocol,
     },
 };
-use std::{io, net::SocketAddr, time::Duration};
-use tokio::net::TcpStream;
+use std::{io, net::SocketAddr, path::Path, time::Duration};
+use tokio::net::{TcpStream, UnixStream};
 
 /// Connects using the UDP transport layer
 ///
@@ -138,6 +138,69 @@ where
     Ok(stream)
 }
 
+/// Connects using a Unix domain socket
+///
+/// Both endpoints are on the same host so, if the acceptor runs as the same user and offers the
+/// same-host mode back, only the first packet in each direction is sealed with the path secret.
+/// The remaining packets are sent in plaintext.
+///
+/// Callers should send data immediately after calling this to ensure minimal
+/// credential reordering.
+#[inline]
+pub async fn connect_unix<H, P, Sub>(
+    handshake: H,
+    acceptor_path: P,
+    env: &Environment<Sub>,
+) -> io::Result<Stream<Sub>>
+where
+    H: core::future::Future<Output = io::Result<secret::map::Peer>>,
+    P: AsRef<Path>,
+    Sub: event::Subscriber + Clone,
+{
+    let (socket, entry) = tokio::try_join!(UnixStream::connect(acceptor_path), handshake)?;
+
+    connect_unix_with(entry, socket, env).await
+}
+
+/// Connects with a pre-existing Unix domain socket
+///
+/// Callers should send data immediately after calling this to ensure minimal
+/// credential reordering.
+///
+/// # Note
+///
+/// The socket must be connected to an acceptor that has the path secret for `entry`
+#[inline]
+pub async fn connect_unix_with<Sub>(
+    entry: secret::map::Peer,
+    socket: UnixStream,
+    env: &Environment<Sub>,
+) -> io::Result<Stream<Sub>>
+where
+    Sub: event::Subscriber + Clone,
+{
+    // Unix sockets don't have IP addresses so the stream tracks the address the secret was
+    // negotiated with. The socket address is available with `Stream::peer_address`.
+    let peer_addr = (*entry.peer_addr()).into();
+    let features = env::unix::features(&socket)?;
+
+    let peer = env::unix::Registered {
+        socket,
+        peer_addr,
+        features,
+        recv_buffer: recv_buffer(),
+    };
+
+    let stream = endpoint::open_stream(env, entry, peer, None)?;
+
+    // build the stream inside the application context
+    let stream = stream.connect()?;
+
+    debug_assert_eq!(stream.protocol(), Protocol::Unix);
+
+    Ok(stream)
+}
+
 #[inline]
 fn recv_buffer() -> recv::shared::RecvBuffer {
     // TODO replace this with a parameter once everything is in place
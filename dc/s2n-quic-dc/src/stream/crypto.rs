@@ -99,6 +99,14 @@ impl Crypto {
         result
     }
 
+    /// Called when the peer offered the same-host mode in an authenticated packet
+    #[inline]
+    pub fn on_same_host_offered(&self) {
+        // the locks are taken one at a time so this can't deadlock with a concurrent seal or open
+        self.app_opener.lock().unwrap().on_same_host_offered();
+        self.app_sealer.lock().unwrap().on_same_host_offered();
+    }
+
     #[inline]
     pub fn control_sealer(&self) -> Option<&seal::control::Stream> {
         self.control_sealer.as_ref()
@@ -182,6 +182,7 @@ where
         features,
         recv_buffer,
         endpoint_type,
+        env.recv_window_budget(),
         &now,
     );
 
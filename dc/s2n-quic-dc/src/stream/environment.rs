@@ -29,6 +29,8 @@ pub trait Environment {
     fn subscriber(&self) -> &Self::Subscriber;
     fn clock(&self) -> Self::Clock;
     fn gso(&self) -> features::Gso;
+    /// Returns the memory budget for autotuning stream receive windows
+    fn recv_window_budget(&self) -> recv::flow::Budget;
     fn reader_rt(&self) -> runtime::ArcHandle<Self::Subscriber>;
     fn spawn_reader<F: 'static + Send + Future<Output = ()>>(&self, f: F);
     fn writer_rt(&self) -> runtime::ArcHandle<Self::Subscriber>;
@@ -88,6 +90,13 @@ where
         }
     }
 
+    fn recv_window_budget(&self) -> recv::flow::Budget {
+        match self {
+            Either::A(a) => a.recv_window_budget(),
+            Either::B(b) => b.recv_window_budget(),
+        }
+    }
+
     fn reader_rt(&self) -> runtime::ArcHandle<Self::Subscriber> {
         match self {
             Either::A(a) => a.reader_rt(),
@@ -6,6 +6,7 @@ use crate::{
     event,
     stream::{
         environment::udp::Config as PoolConfig,
+        recv::flow as recv_flow,
         runtime::{bach as runtime, ArcHandle},
         server::accept,
         socket,
@@ -23,6 +24,7 @@ where
     Sub: event::Subscriber,
 {
     gso: Option<features::Gso>,
+    recv_window: Option<recv_flow::Config>,
     socket_options: Option<socket::Options>,
     pool: Option<PoolConfig>,
     threads: Option<usize>,
@@ -46,6 +48,7 @@ where
     pub fn new(subscriber: Sub) -> Self {
         Self {
             gso: None,
+            recv_window: None,
             socket_options: None,
             threads: None,
             acceptor: None,
@@ -64,6 +67,14 @@ where
         self
     }
 
+    /// Configures the autotuning limits for stream receive windows
+    ///
+    /// The budget is shared by all of the streams created by the environment.
+    pub fn with_recv_window(mut self, config: recv_flow::Config) -> Self {
+        self.recv_window = Some(config);
+        self
+    }
+
     pub fn with_socket_options(mut self, socket_options: socket::Options) -> Self {
         self.socket_options = Some(socket_options);
         self
@@ -78,6 +89,7 @@ where
     pub fn build(self) -> io::Result<Environment<Sub>> {
         let Self {
             gso,
+            recv_window,
             socket_options,
             pool,
             threads,
@@ -89,12 +101,14 @@ where
             // component that controls send quantums
             features::gso::MAX_SEGMENTS.into()
         });
+        let recv_window_budget = recv_flow::Budget::new(recv_window.unwrap_or_default());
         let socket_options = socket_options.unwrap_or_default();
 
         let rt = Arc::new(runtime::Handle::current());
 
         let mut env = Environment {
             gso,
+            recv_window_budget,
             socket_options,
             rt,
             recv_pool: None,
@@ -114,6 +128,7 @@ where
 #[derive(Clone)]
 pub struct Environment<Sub> {
     gso: features::Gso,
+    recv_window_budget: recv_flow::Budget,
     socket_options: socket::Options,
     rt: Arc<runtime::Handle>,
     subscriber: Sub,
@@ -182,6 +197,11 @@ where
         self.gso.clone()
     }
 
+    #[inline]
+    fn recv_window_budget(&self) -> recv_flow::Budget {
+        self.recv_window_budget.clone()
+    }
+
     #[inline]
     fn reader_rt(&self) -> ArcHandle<Self::Subscriber> {
         self.rt.clone()
@@ -6,6 +6,7 @@ use crate::{
     event,
     stream::{
         environment::udp::Config as PoolConfig,
+        recv::flow as recv_flow,
         runtime::{tokio as runtime, ArcHandle},
         server::accept,
         socket,
@@ -17,6 +18,7 @@ use std::{io, net::SocketAddr, sync::Arc};
 pub mod pool;
 pub mod tcp;
 pub mod udp;
+pub mod unix;
 
 pub struct Builder<Sub>
 where
@@ -24,6 +26,7 @@ where
 {
     clock: Option<Clock>,
     gso: Option<features::Gso>,
+    recv_window: Option<recv_flow::Config>,
     socket_options: Option<socket::Options>,
     reader_rt: Option<runtime::Shared<Sub>>,
     writer_rt: Option<runtime::Shared<Sub>>,
@@ -51,6 +54,7 @@ where
         Self {
             clock: None,
             gso: None,
+            recv_window: None,
             socket_options: None,
             reader_rt: None,
             writer_rt: None,
@@ -72,6 +76,14 @@ where
         self
     }
 
+    /// Configures the autotuning limits for stream receive windows
+    ///
+    /// The budget is shared by all of the streams created by the environment.
+    pub fn with_recv_window(mut self, config: recv_flow::Config) -> Self {
+        self.recv_window = Some(config);
+        self
+    }
+
     pub fn with_socket_options(mut self, socket_options: socket::Options) -> Self {
         self.socket_options = Some(socket_options);
         self
@@ -87,6 +99,7 @@ where
         let Self {
             clock,
             gso,
+            recv_window,
             socket_options,
             reader_rt,
             writer_rt,
@@ -102,6 +115,7 @@ where
             // component that controls send quantums
             features::gso::MAX_SEGMENTS.into()
         });
+        let recv_window_budget = recv_flow::Budget::new(recv_window.unwrap_or_default());
         let socket_options = socket_options.unwrap_or_default();
 
         let thread_count = threads.unwrap_or_else(|| {
@@ -131,6 +145,7 @@ where
         let mut env = Environment {
             clock,
             gso,
+            recv_window_budget,
             socket_options,
             reader_rt,
             writer_rt,
@@ -151,6 +166,7 @@ where
 pub struct Environment<Sub> {
     clock: Clock,
     gso: features::Gso,
+    recv_window_budget: recv_flow::Budget,
     socket_options: socket::Options,
     reader_rt: runtime::Shared<Sub>,
     writer_rt: runtime::Shared<Sub>,
@@ -220,6 +236,11 @@ where
         self.gso.clone()
     }
 
+    #[inline]
+    fn recv_window_budget(&self) -> recv_flow::Budget {
+        self.recv_window_budget.clone()
+    }
+
     #[inline]
     fn reader_rt(&self) -> ArcHandle<Self::Subscriber> {
         self.reader_rt.handle()
@@ -0,0 +1,109 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    event,
+    stream::{
+        environment::{tokio::Environment, Peer, SetupResult, SocketSet},
+        recv::shared::RecvBuffer,
+        TransportFeatures,
+    },
+};
+use s2n_quic_core::inet::SocketAddress;
+use std::io;
+use tokio::net::UnixStream;
+
+/// Returns the [`TransportFeatures`] for a connected Unix domain socket
+///
+/// Unix domain sockets never leave the local host but the [`TransportFeatures::SAME_HOST`] mode
+/// is only offered if the peer process runs as the same user, as reported by `SO_PEERCRED`.
+/// Streams with peers owned by other users seal every packet like they would over TCP.
+pub fn features(socket: &UnixStream) -> io::Result<TransportFeatures> {
+    let peer = socket.peer_cred()?;
+    // SAFETY: geteuid is always successful
+    let uid = unsafe { libc::geteuid() };
+
+    if peer.uid() == uid {
+        Ok(TransportFeatures::UNIX)
+    } else {
+        Ok(TransportFeatures::UNIX.difference(TransportFeatures::SAME_HOST))
+    }
+}
+
+/// A socket that is already registered with the application runtime
+pub struct Registered {
+    pub socket: UnixStream,
+    /// The address of the peer that the path secret was negotiated with
+    pub peer_addr: SocketAddress,
+    /// The features of the socket, as returned by [`features`]
+    pub features: TransportFeatures,
+    pub recv_buffer: RecvBuffer,
+}
+
+impl<Sub> Peer<Environment<Sub>> for Registered
+where
+    Sub: event::Subscriber + Clone,
+{
+    type ReadWorkerSocket = ();
+    type WriteWorkerSocket = ();
+
+    fn features(&self) -> TransportFeatures {
+        self.features
+    }
+
+    #[inline]
+    fn setup(
+        self,
+        _env: &Environment<Sub>,
+    ) -> SetupResult<Self::ReadWorkerSocket, Self::WriteWorkerSocket> {
+        let remote_addr = self.peer_addr;
+        let application = Box::new(self.socket);
+        let socket = SocketSet {
+            application,
+            read_worker: None,
+            write_worker: None,
+            remote_addr,
+            source_queue_id: None,
+        };
+        Ok((socket, self.recv_buffer))
+    }
+}
+
+/// A socket that should be reregistered with the application runtime
+pub struct Reregistered {
+    pub socket: UnixStream,
+    /// The address of the peer that the path secret was negotiated with
+    pub peer_addr: SocketAddress,
+    /// The features of the socket, as returned by [`features`]
+    pub features: TransportFeatures,
+    pub recv_buffer: RecvBuffer,
+}
+
+impl<Sub> Peer<Environment<Sub>> for Reregistered
+where
+    Sub: event::Subscriber + Clone,
+{
+    type ReadWorkerSocket = ();
+    type WriteWorkerSocket = ();
+
+    fn features(&self) -> TransportFeatures {
+        self.features
+    }
+
+    #[inline]
+    fn setup(
+        self,
+        _env: &Environment<Sub>,
+    ) -> SetupResult<Self::ReadWorkerSocket, Self::WriteWorkerSocket> {
+        let remote_addr = self.peer_addr;
+        let application = Box::new(self.socket.into_std()?);
+        let socket = SocketSet {
+            application,
+            read_worker: None,
+            write_worker: None,
+            remote_addr,
+            source_queue_id: None,
+        };
+        Ok((socket, self.recv_buffer))
+    }
+}
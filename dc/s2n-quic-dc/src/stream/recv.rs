@@ -6,6 +6,7 @@ pub mod application;
 pub(crate) mod buffer;
 pub mod dispatch;
 mod error;
+pub mod flow;
 mod packet;
 mod probes;
 pub mod shared;
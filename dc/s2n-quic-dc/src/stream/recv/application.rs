@@ -63,8 +63,8 @@ where
         let mut s = f.debug_struct("Reader");
 
         for (name, addr) in [
-            ("peer_addr", self.peer_addr()),
-            ("local_addr", self.local_addr()),
+            ("peer_addr", self.peer_address()),
+            ("local_addr", self.local_address()),
         ] {
             if let Ok(addr) = addr {
                 s.field(name, &addr);
@@ -130,6 +130,22 @@ where
         self.0.sockets.read_application().local_addr()
     }
 
+    /// Returns the address of the peer, including peers which aren't addressed by IP
+    #[inline]
+    pub fn peer_address(&self) -> io::Result<socket::Address> {
+        self.0.shared.common.ensure_open()?;
+        if let Some(addr) = self.0.sockets.read_application().peer_address()? {
+            return Ok(addr);
+        }
+        Ok(SocketAddr::from(self.0.shared.remote_addr()).into())
+    }
+
+    /// Returns the local address, including sockets which aren't addressed by IP
+    #[inline]
+    pub fn local_address(&self) -> io::Result<socket::Address> {
+        self.0.sockets.read_application().local_address()
+    }
+
     #[inline]
     pub fn protocol(&self) -> socket::Protocol {
         self.0.sockets.protocol()
@@ -0,0 +1,345 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Receive window autotuning
+//!
+//! Streams start with the receive window negotiated in the `ApplicationParams`. A window that's
+//! too small limits the throughput of a stream on paths with a large bandwidth-delay product
+//! (BDP), while configuring large windows for every stream wastes memory on idle streams.
+//!
+//! Similar to Linux's TCP receive buffer autotuning, the [`Window`] measures how much data the
+//! application reads over each round trip and grows the window to twice that amount, which
+//! allows the peer to double its sending rate every round trip until it's no longer limited by
+//! flow control.
+//!
+//! Any growth past the initial window is reserved from a [`Budget`] which is shared by all of
+//! the streams on an endpoint. Once the budget is exhausted, streams stop growing and shrink
+//! their windows back towards the initial value to make room for other streams.
+
+use core::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+use s2n_quic_core::{ensure, time::Timestamp, varint::VarInt};
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// The maximum size a single stream's receive window can grow to
+    pub max_window: u64,
+    /// The total number of bytes the endpoint can reserve for growing receive windows
+    pub budget: u64,
+}
+
+impl Default for Config {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            max_window: 64 * 1024 * 1024,
+            budget: 1024 * 1024 * 1024,
+        }
+    }
+}
+
+impl Config {
+    /// Returns a configuration that keeps windows at their initial size
+    #[inline]
+    pub fn disabled() -> Self {
+        Self {
+            max_window: 0,
+            budget: 0,
+        }
+    }
+}
+
+/// The memory budget for receive windows that's shared between streams on an endpoint
+#[derive(Clone)]
+pub struct Budget(Arc<BudgetState>);
+
+struct BudgetState {
+    config: Config,
+    reserved: AtomicU64,
+}
+
+impl Default for Budget {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Config::default())
+    }
+}
+
+impl fmt::Debug for Budget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Budget")
+            .field("config", &self.0.config)
+            .field("reserved", &self.reserved())
+            .finish()
+    }
+}
+
+impl Budget {
+    #[inline]
+    pub fn new(config: Config) -> Self {
+        Self(Arc::new(BudgetState {
+            config,
+            reserved: AtomicU64::new(0),
+        }))
+    }
+
+    #[inline]
+    pub fn config(&self) -> &Config {
+        &self.0.config
+    }
+
+    /// Returns the number of bytes currently reserved by streams
+    #[inline]
+    pub fn reserved(&self) -> u64 {
+        self.0.reserved.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.reserved() >= self.0.config.budget
+    }
+
+    /// Reserves up to `amount` bytes from the budget and returns the amount reserved
+    #[inline]
+    fn reserve(&self, amount: u64) -> u64 {
+        let limit = self.0.config.budget;
+        let mut granted = 0;
+        let _ = self
+            .0
+            .reserved
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |reserved| {
+                granted = limit.saturating_sub(reserved).min(amount);
+                ensure!(granted > 0, None);
+                Some(reserved + granted)
+            });
+        granted
+    }
+
+    #[inline]
+    fn release(&self, amount: u64) {
+        ensure!(amount > 0);
+        let prev = self.0.reserved.fetch_sub(amount, Ordering::Relaxed);
+        debug_assert!(prev >= amount, "budget underflow");
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reason {
+    Autotuned,
+    MemoryPressure,
+}
+
+/// A change in the size of the receive window
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Update {
+    pub previous_window: u64,
+    pub window: u64,
+    pub rtt: Duration,
+    pub reason: Reason,
+}
+
+/// The receive window for a single stream
+pub struct Window {
+    budget: Budget,
+    initial: u64,
+    window: u64,
+    /// The amount of the window past `initial` that's been reserved from the budget
+    reserved: u64,
+    /// The time and read offset at the start of the current measurement
+    epoch: Option<(Timestamp, VarInt)>,
+}
+
+impl fmt::Debug for Window {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Window")
+            .field("initial", &self.initial)
+            .field("window", &self.window)
+            .field("reserved", &self.reserved)
+            .finish()
+    }
+}
+
+impl Window {
+    #[inline]
+    pub fn new(initial: VarInt, budget: Budget) -> Self {
+        let initial = initial.as_u64();
+        Self {
+            budget,
+            initial,
+            window: initial,
+            reserved: 0,
+            epoch: None,
+        }
+    }
+
+    #[inline]
+    pub fn get(&self) -> VarInt {
+        VarInt::new(self.window).unwrap_or(VarInt::MAX)
+    }
+
+    /// Called when the application has read up to `offset`
+    ///
+    /// Returns an [`Update`] if the window changed size.
+    #[inline]
+    pub fn on_read(&mut self, offset: VarInt, rtt: Duration, now: Timestamp) -> Option<Update> {
+        let Some((start, start_offset)) = self.epoch else {
+            self.epoch = Some((now, offset));
+            return None;
+        };
+
+        // wait at least a round trip before taking a measurement
+        let elapsed = now.saturating_duration_since(start);
+        ensure!(!rtt.is_zero() && elapsed >= rtt, None);
+        self.epoch = Some((now, offset));
+
+        let previous_window = self.window;
+
+        if self.budget.is_exhausted() && self.reserved > 0 {
+            // halve the window until we give back everything past the initial value
+            let target = (self.window / 2).max(self.initial);
+            let released = (self.window - target).min(self.reserved);
+            self.budget.release(released);
+            self.reserved -= released;
+            self.window -= released;
+
+            return Some(Update {
+                previous_window,
+                window: self.window,
+                rtt,
+                reason: Reason::MemoryPressure,
+            });
+        }
+
+        // scale the amount of data read to a single round trip
+        let read = offset.as_u64().saturating_sub(start_offset.as_u64()) as u128;
+        let read = read * rtt.as_micros() / elapsed.as_micros().max(1);
+        let read = read.min(u64::MAX as u128) as u64;
+
+        // give the peer room to double its rate over the next round trip
+        let target = read
+            .saturating_mul(2)
+            .min(self.budget.config().max_window)
+            .min(VarInt::MAX.as_u64());
+        ensure!(target > self.window, None);
+
+        let granted = self.budget.reserve(target - self.window);
+        ensure!(granted > 0, None);
+        self.reserved += granted;
+        self.window += granted;
+
+        Some(Update {
+            previous_window,
+            window: self.window,
+            rtt,
+            reason: Reason::Autotuned,
+        })
+    }
+}
+
+impl Drop for Window {
+    #[inline]
+    fn drop(&mut self) {
+        self.budget.release(self.reserved);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::time::{Clock as _, NoopClock};
+
+    const RTT: Duration = Duration::from_millis(10);
+
+    fn window(initial: u64, config: Config) -> Window {
+        Window::new(VarInt::new(initial).unwrap(), Budget::new(config))
+    }
+
+    #[test]
+    fn grows_with_read_rate() {
+        let mut window = window(
+            1000,
+            Config {
+                max_window: 10_000,
+                budget: 100_000,
+            },
+        );
+        let mut now = NoopClock.get_time();
+        let mut offset = 0u64;
+
+        assert_eq!(window.on_read(VarInt::ZERO, RTT, now), None);
+
+        // the peer fills the window every round trip
+        let mut sizes = vec![];
+        for _ in 0..6 {
+            now += RTT;
+            offset += window.get().as_u64();
+            if let Some(update) = window.on_read(VarInt::new(offset).unwrap(), RTT, now) {
+                assert_eq!(update.reason, Reason::Autotuned);
+                sizes.push(update.window);
+            }
+        }
+
+        assert_eq!(sizes, [2000, 4000, 8000, 10_000]);
+        assert_eq!(window.budget.reserved(), 9000);
+
+        drop(window);
+    }
+
+    #[test]
+    fn idle_stream_does_not_grow() {
+        let mut window = window(1000, Config::default());
+        let mut now = NoopClock.get_time();
+
+        assert_eq!(window.on_read(VarInt::ZERO, RTT, now), None);
+        for _ in 0..4 {
+            now += RTT;
+            // the application only reads a fraction of the window each round trip
+            assert_eq!(window.on_read(VarInt::from_u32(100), RTT, now), None);
+        }
+        assert_eq!(window.get(), VarInt::from_u32(1000));
+    }
+
+    #[test]
+    fn budget_limits_growth() {
+        let budget = Budget::new(Config {
+            max_window: 100_000,
+            budget: 1500,
+        });
+        let mut a = Window::new(VarInt::from_u32(1000), budget.clone());
+        let mut b = Window::new(VarInt::from_u32(1000), budget.clone());
+        let now = NoopClock.get_time();
+
+        for window in [&mut a, &mut b] {
+            window.on_read(VarInt::ZERO, RTT, now);
+        }
+
+        let now = now + RTT;
+        let update = a.on_read(VarInt::from_u32(1000), RTT, now).unwrap();
+        assert_eq!(update.window, 2000);
+
+        // only part of the growth is available
+        let update = b.on_read(VarInt::from_u32(1000), RTT, now).unwrap();
+        assert_eq!(update.window, 1500);
+        assert_eq!(budget.reserved(), 1500);
+
+        // both streams shrink once the budget is exhausted
+        let now = now + RTT;
+        let update = a.on_read(VarInt::from_u32(3000), RTT, now).unwrap();
+        assert_eq!(update.reason, Reason::MemoryPressure);
+        assert_eq!(update.window, 1000);
+        assert_eq!(budget.reserved(), 500);
+
+        // and the freed space is available to other streams
+        let update = b.on_read(VarInt::from_u32(2000), RTT, now).unwrap();
+        assert_eq!(update.reason, Reason::Autotuned);
+        assert_eq!(update.window, 2000);
+
+        drop(b);
+        drop(a);
+        assert_eq!(budget.reserved(), 0);
+    }
+}
@@ -95,12 +95,13 @@ impl State {
         features: TransportFeatures,
         buffer: RecvBuffer,
         endpoint: endpoint::Type,
+        window_budget: recv::flow::Budget,
         clock: &C,
     ) -> Self
     where
         C: Clock + ?Sized,
     {
-        let receiver = recv::state::State::new(stream_id, params, features, clock);
+        let receiver = recv::state::State::new(stream_id, params, features, window_budget, clock);
         let reassembler = Default::default();
         let is_owned_socket = matches!(buffer, Either::A(recv::buffer::Local { .. }));
         let inner = Inner {
@@ -390,6 +391,8 @@ impl Inner {
         let clock = clock::Cached::new(&shared.clock);
         let clock = &clock;
 
+        self.receiver.set_rtt(shared.sender.smoothed_rtt());
+
         // try copying data out of the reassembler into the application buffer
         self.receiver
             .on_read_buffer(&mut self.reassembler, out_buf, clock);
@@ -448,6 +451,26 @@ impl Inner {
                 .on_timeout(clock, || shared.last_peer_activity());
         }
 
+        if let Some(update) = self.receiver.take_window_update() {
+            use event::ConnectionPublisher as _;
+
+            shared
+                .publisher_with_timestamp(clock.get_time())
+                .on_stream_read_window_updated(event::builder::StreamReadWindowUpdated {
+                    previous_window: update.previous_window,
+                    window: update.window,
+                    rtt: update.rtt,
+                    reason: match update.reason {
+                        recv::flow::Reason::Autotuned => {
+                            event::builder::StreamReadWindowUpdateReason::Autotuned
+                        }
+                        recv::flow::Reason::MemoryPressure => {
+                            event::builder::StreamReadWindowUpdateReason::MemoryPressure
+                        }
+                    },
+                });
+        }
+
         // indicate to the caller if we need to transmit an ACK
         self.receiver.should_transmit()
     }
@@ -561,6 +584,8 @@ where
                 }
 
                 let source_queue_id = packet.source_queue_id();
+                let offers_same_host = packet.flags().is_same_host();
+                let mut on_same_host_offered = false;
 
                 let _ = self.shared.crypto.open_with(
                     |opener| {
@@ -591,12 +616,20 @@ where
                             self.remote_queue_id = source_queue_id;
                         }
 
+                        // the flags are only trusted once the packet has been authenticated
+                        on_same_host_offered =
+                            offers_same_host && opener.is_awaiting_same_host_offer();
+
                         <Result<_, recv::Error>>::Ok(())
                     },
                     self.clock,
                     &self.shared.subscriber,
                 );
 
+                if on_same_host_offered {
+                    self.shared.crypto.on_same_host_offered();
+                }
+
                 if IS_STREAM {
                     self.receiver.check_error()?;
                 }
@@ -11,7 +11,7 @@ use crate::{
         recv::{
             ack,
             error::{self, Error},
-            packet, probes,
+            flow, packet, probes,
         },
         TransportFeatures, DEFAULT_IDLE_TIMEOUT,
     },
@@ -48,7 +48,10 @@ pub struct State {
     _should_transmit: bool,
     is_reliable: bool,
     max_data: VarInt,
-    max_data_window: VarInt,
+    max_data_window: flow::Window,
+    /// The most recent RTT estimate for the stream, used to tune `max_data_window`
+    rtt: Duration,
+    window_update: Option<flow::Update>,
     error: Option<Error>,
     fin_ack_packet_number: Option<VarInt>,
     features: TransportFeatures,
@@ -60,6 +63,7 @@ impl State {
         stream_id: stream::Id,
         params: &ApplicationParams,
         features: TransportFeatures,
+        window_budget: flow::Budget,
         clock: &C,
     ) -> Self
     where
@@ -88,7 +92,9 @@ impl State {
             tick_timer,
             _should_transmit: false,
             max_data: initial_max_data,
-            max_data_window: initial_max_data,
+            max_data_window: flow::Window::new(initial_max_data, window_budget),
+            rtt: crate::recovery::rtt_estimator().smoothed_rtt(),
+            window_update: None,
             error: None,
             fin_ack_packet_number: None,
             features,
@@ -117,6 +123,18 @@ impl State {
         true
     }
 
+    /// Updates the RTT estimate used to size the receive window
+    #[inline]
+    pub fn set_rtt(&mut self, rtt: Duration) {
+        self.rtt = rtt;
+    }
+
+    /// Returns the most recent change to the receive window, if any
+    #[inline]
+    pub fn take_window_update(&mut self) -> Option<flow::Update> {
+        self.window_update.take()
+    }
+
     #[inline]
     pub fn stop_sending(&mut self, error: s2n_quic_core::application::Error) {
         // if we've already received everything then no need to notify the peer to stop
@@ -125,7 +143,7 @@ impl State {
     }
 
     #[inline]
-    pub fn on_read_buffer<B, C, Clk>(&mut self, out_buf: &mut B, chunk: &mut C, _clock: &Clk)
+    pub fn on_read_buffer<B, C, Clk>(&mut self, out_buf: &mut B, chunk: &mut C, clock: &Clk)
     where
         B: buffer::Duplex<Error = core::convert::Infallible>,
         C: buffer::writer::Storage,
@@ -136,10 +154,15 @@ impl State {
             out_buf.infallible_copy_into(chunk);
         }
 
+        // flow-controlled transports already size their own windows
+        if !self.features.is_flow_controlled() {
+            self.tune_max_data_window(out_buf.current_offset(), clock);
+        }
+
         // record our new max data value
         let new_max_data = out_buf
             .current_offset()
-            .saturating_add(self.max_data_window);
+            .saturating_add(self.max_data_window.get());
 
         if new_max_data > self.max_data {
             self.max_data = new_max_data;
@@ -162,6 +185,30 @@ impl State {
         }
     }
 
+    #[inline]
+    fn tune_max_data_window<Clk>(&mut self, offset: VarInt, clock: &Clk)
+    where
+        Clk: Clock + ?Sized,
+    {
+        let Some(update) = self
+            .max_data_window
+            .on_read(offset, self.rtt, clock.get_time())
+        else {
+            return;
+        };
+
+        tracing::debug!(?update, "max_data_window");
+
+        // merge with any update that hasn't been reported yet
+        self.window_update = Some(match self.window_update.take() {
+            Some(prev) => flow::Update {
+                previous_window: prev.previous_window,
+                ..update
+            },
+            None => update,
+        });
+    }
+
     #[inline]
     pub fn precheck_stream_packet(
         &mut self,
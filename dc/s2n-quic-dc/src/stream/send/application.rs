@@ -59,8 +59,8 @@ where
         let mut s = f.debug_struct("Writer");
 
         for (name, addr) in [
-            ("peer_addr", self.peer_addr()),
-            ("local_addr", self.local_addr()),
+            ("peer_addr", self.peer_address()),
+            ("local_addr", self.local_address()),
         ] {
             if let Ok(addr) = addr {
                 s.field(name, &addr);
@@ -86,6 +86,22 @@ where
         self.0.sockets.write_application().local_addr()
     }
 
+    /// Returns the address of the peer, including peers which aren't addressed by IP
+    #[inline]
+    pub fn peer_address(&self) -> io::Result<socket::Address> {
+        self.0.shared.common.ensure_open()?;
+        if let Some(addr) = self.0.sockets.write_application().peer_address()? {
+            return Ok(addr);
+        }
+        Ok(SocketAddr::from(self.0.shared.remote_addr()).into())
+    }
+
+    /// Returns the local address, including sockets which aren't addressed by IP
+    #[inline]
+    pub fn local_address(&self) -> io::Result<socket::Address> {
+        self.0.sockets.write_application().local_address()
+    }
+
     #[inline]
     pub fn protocol(&self) -> socket::Protocol {
         self.0.sockets.protocol()
@@ -244,6 +260,7 @@ where
                             &self.shared.sender.packet_number,
                             sealer,
                             self.shared.credentials(),
+                            sealer.stream_flags(),
                             &stream_id,
                             local_queue_id,
                             &clock::Cached::new(&self.shared.clock),
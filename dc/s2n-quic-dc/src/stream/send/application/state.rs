@@ -44,6 +44,7 @@ impl State {
         packet_number: &packet_number::Counter,
         encrypt_key: &E,
         credentials: &Credentials,
+        flags: stream::Flags,
         stream_id: &stream::Id,
         source_queue_id: Option<VarInt>,
         clock: &Clk,
@@ -114,6 +115,7 @@ impl State {
                     &mut reader,
                     encrypt_key,
                     credentials,
+                    flags,
                 );
 
                 // buffer is clamped to u16::MAX so this is safe to cast without loss
@@ -14,6 +14,7 @@ use crate::{
 use core::{
     fmt,
     sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
 };
 use crossbeam_queue::SegQueue;
 use s2n_quic_core::recovery::bandwidth::Bandwidth;
@@ -36,6 +37,8 @@ pub struct State {
     pub path: path::State,
     pub worker_waker: WorkerWaker,
     bandwidth: AtomicU64,
+    /// The smoothed RTT of the stream, in microseconds
+    smoothed_rtt: AtomicU64,
     /// A channel sender for pushing transmission information to the worker task
     ///
     /// We use an unbounded sender since we already rely on flow control to apply backpressure
@@ -63,11 +66,14 @@ impl State {
     ) -> Self {
         let path = path::State::new(path);
         let bandwidth = bandwidth.map(|v| v.serialize()).unwrap_or(u64::MAX).into();
+        let smoothed_rtt = crate::recovery::rtt_estimator().smoothed_rtt();
+        let smoothed_rtt = (smoothed_rtt.as_micros() as u64).into();
         Self {
             flow,
             packet_number: Default::default(),
             path,
             bandwidth,
+            smoothed_rtt,
             // this will get set once the waker spawns
             worker_waker: Default::default(),
             worker_queue: Default::default(),
@@ -86,6 +92,20 @@ impl State {
         self.bandwidth.store(value.serialize(), Ordering::Relaxed);
     }
 
+    /// Returns the smoothed RTT estimate of the stream
+    ///
+    /// This is also used by the receiver to tune its flow control window.
+    #[inline]
+    pub fn smoothed_rtt(&self) -> Duration {
+        Duration::from_micros(self.smoothed_rtt.load(Ordering::Relaxed))
+    }
+
+    #[inline]
+    pub fn set_smoothed_rtt(&self, value: Duration) {
+        self.smoothed_rtt
+            .store(value.as_micros() as u64, Ordering::Relaxed);
+    }
+
     #[inline]
     pub fn pop_worker_message(&self) -> Option<Message> {
         self.worker_queue.pop()
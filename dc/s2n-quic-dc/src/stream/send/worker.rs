@@ -20,7 +20,10 @@ use crate::{
         Actor, TransportFeatures,
     },
 };
-use core::task::{Context, Poll};
+use core::{
+    task::{Context, Poll},
+    time::Duration,
+};
 use s2n_quic_core::{
     endpoint, ensure,
     inet::{ExplicitCongestionNotification, SocketAddress},
@@ -91,6 +94,7 @@ struct Snapshot {
     next_expected_control_packet: VarInt,
     timeout: Option<Timestamp>,
     bandwidth: Bandwidth,
+    smoothed_rtt: Duration,
     error: Option<Error>,
 }
 
@@ -129,6 +133,10 @@ impl Snapshot {
             shared.sender.set_bandwidth(self.bandwidth);
         }
 
+        if initial.smoothed_rtt != self.smoothed_rtt {
+            shared.sender.set_smoothed_rtt(self.smoothed_rtt);
+        }
+
         if let Some(error) = self.error {
             if initial.error.is_none() {
                 shared.sender.flow.set_error(error);
@@ -493,6 +501,7 @@ where
             next_expected_control_packet: self.sender.next_expected_control_packet,
             timeout: self.sender.next_expiration(),
             bandwidth: self.sender.cca.bandwidth(),
+            smoothed_rtt: self.sender.rtt_estimator.smoothed_rtt(),
             error: self.sender.error,
         }
     }
@@ -0,0 +1,270 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    either::Either,
+    event::{self, IntoEvent, Subscriber},
+    msg,
+    path::secret,
+    stream::{
+        endpoint,
+        environment::{
+            tokio::{self as env, Environment},
+            Environment as _,
+        },
+        recv,
+        server::{self, accept},
+        socket::Ext as _,
+    },
+};
+use core::{ops::ControlFlow, time::Duration};
+use s2n_codec::DecoderError;
+use s2n_quic_core::time::Clock;
+use std::io;
+use tokio::{
+    io::AsyncWriteExt as _,
+    net::{UnixListener, UnixStream},
+    task::JoinSet,
+};
+use tracing::debug;
+
+/// The maximum amount of time a client has to send its initial packet after connecting
+const INITIAL_PACKET_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The maximum number of bytes buffered while waiting for a complete initial packet
+const MAX_INITIAL_PACKET_LEN: usize = 10_000;
+
+/// Accepts streams over a Unix domain socket
+///
+/// Streams accepted by this acceptor offer the same-host mode, in which only the first packet in
+/// each direction is sealed with the path secret, to clients running as the same user.
+pub struct Acceptor<Sub>
+where
+    Sub: Subscriber + Clone,
+{
+    socket: UnixListener,
+    backlog: usize,
+    context: Context<Sub>,
+}
+
+impl<Sub> Acceptor<Sub>
+where
+    Sub: event::Subscriber + Clone,
+{
+    #[inline]
+    pub fn new(
+        socket: UnixListener,
+        sender: &accept::Sender<Sub>,
+        env: &Environment<Sub>,
+        secrets: &secret::Map,
+        backlog: usize,
+        accept_flavor: accept::Flavor,
+    ) -> Self {
+        let context = Context {
+            sender: sender.clone(),
+            env: env.clone(),
+            secrets: secrets.clone(),
+            accept_flavor,
+        };
+
+        Self {
+            socket,
+            backlog: backlog.max(1),
+            context,
+        }
+    }
+
+    pub async fn run(self) {
+        let drop_guard = DropLog;
+        let mut pending = JoinSet::new();
+
+        loop {
+            // only accept new sockets if we have capacity to process them
+            let can_accept = pending.len() < self.backlog;
+
+            tokio::select! {
+                res = self.socket.accept(), if can_accept => {
+                    match res {
+                        Ok((socket, _addr)) => {
+                            let context = self.context.clone();
+                            pending.spawn(async move {
+                                let res =
+                                    tokio::time::timeout(INITIAL_PACKET_TIMEOUT, context.accept(socket))
+                                        .await;
+                                match res {
+                                    Ok(Ok(flow)) => flow,
+                                    Ok(Err(error)) => {
+                                        debug!(%error, "could not accept unix stream");
+                                        ControlFlow::Continue(())
+                                    }
+                                    Err(_) => {
+                                        debug!("unix stream did not send an initial packet in time");
+                                        ControlFlow::Continue(())
+                                    }
+                                }
+                            });
+                        }
+                        Err(error) => {
+                            debug!(%error, "unix acceptor IO error");
+                        }
+                    }
+                }
+                Some(res) = pending.join_next(), if !pending.is_empty() => {
+                    if matches!(res, Ok(ControlFlow::Break(()))) {
+                        break;
+                    }
+                }
+            }
+        }
+
+        drop(drop_guard);
+    }
+}
+
+struct Context<Sub>
+where
+    Sub: Subscriber + Clone,
+{
+    sender: accept::Sender<Sub>,
+    env: Environment<Sub>,
+    secrets: secret::Map,
+    accept_flavor: accept::Flavor,
+}
+
+impl<Sub> Clone for Context<Sub>
+where
+    Sub: Subscriber + Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            env: self.env.clone(),
+            secrets: self.secrets.clone(),
+            accept_flavor: self.accept_flavor,
+        }
+    }
+}
+
+impl<Sub> Context<Sub>
+where
+    Sub: Subscriber + Clone,
+{
+    async fn accept(self, mut socket: UnixStream) -> io::Result<ControlFlow<()>> {
+        let mut recv_buffer = msg::recv::Message::new(u16::MAX);
+        let packet = Self::recv_initial_packet(&socket, &mut recv_buffer).await?;
+
+        let now = self.env.clock().get_time();
+
+        let meta = event::api::ConnectionMeta {
+            id: 0, // TODO use an actual connection ID
+            timestamp: now.into_event(),
+        };
+        let info = event::api::ConnectionInfo {};
+
+        let subscriber_ctx = self
+            .env
+            .subscriber()
+            .create_connection_context(&meta, &info);
+
+        let recv_buffer = recv::buffer::Local::new(recv_buffer, None);
+        let recv_buffer = Either::A(recv_buffer);
+
+        // track the address the client negotiated the secret with, same as the client does.
+        // Unknown credentials are rejected when accepting the stream.
+        let peer_addr = self
+            .secrets
+            .peer_addr_for_id(&packet.credentials.id)
+            .map(Into::into)
+            .unwrap_or_default();
+        let features = env::unix::features(&socket)?;
+
+        let peer = env::unix::Reregistered {
+            socket,
+            peer_addr,
+            features,
+            recv_buffer,
+        };
+
+        let stream = match endpoint::accept_stream(
+            now,
+            &self.env,
+            peer,
+            &packet,
+            &self.secrets,
+            subscriber_ctx,
+            None,
+        ) {
+            Ok(stream) => stream,
+            Err(error) => {
+                if let Some(env::unix::Reregistered { socket: s, .. }) = error.peer {
+                    socket = s;
+                    if !error.secret_control.is_empty() {
+                        // ignore any errors since this is just for responding to invalid connect
+                        // attempts
+                        let _ = socket.write_all(&error.secret_control).await;
+                    }
+                }
+                return Err(error.error);
+            }
+        };
+
+        let res = match self.accept_flavor {
+            accept::Flavor::Fifo => self.sender.send_back(stream),
+            accept::Flavor::Lifo => self.sender.send_front(stream),
+        };
+
+        match res {
+            Ok(prev) => {
+                if let Some(stream) = prev {
+                    stream.prune(
+                        event::builder::AcceptorStreamPruneReason::AcceptQueueCapacityExceeded,
+                    );
+                }
+                Ok(ControlFlow::Continue(()))
+            }
+            Err(_) => {
+                debug!("application accept queue dropped; shutting down");
+                Ok(ControlFlow::Break(()))
+            }
+        }
+    }
+
+    async fn recv_initial_packet(
+        socket: &UnixStream,
+        recv_buffer: &mut msg::recv::Message,
+    ) -> io::Result<server::InitialPacket> {
+        loop {
+            if recv_buffer.payload_len() > MAX_INITIAL_PACKET_LEN {
+                let error = DecoderError::UnexpectedBytes(recv_buffer.payload_len());
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    error.to_string(),
+                ));
+            }
+
+            let len = socket.recv_buffer(recv_buffer).await?;
+
+            match server::InitialPacket::peek(recv_buffer, 16) {
+                Ok(packet) => return Ok(packet),
+                // we don't have enough bytes buffered so try reading more
+                Err(DecoderError::UnexpectedEof(_)) if len > 0 => continue,
+                Err(error) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        error.to_string(),
+                    ))
+                }
+            }
+        }
+    }
+}
+
+struct DropLog;
+
+impl Drop for DropLog {
+    #[inline]
+    fn drop(&mut self) {
+        debug!("unix acceptor task has been dropped");
+    }
+}
@@ -3,6 +3,7 @@
 
 use super::TransportFeatures;
 
+mod address;
 pub mod application;
 #[cfg(any(test, feature = "testing"))]
 mod bach;
@@ -15,6 +16,7 @@ mod tracing;
 
 pub use self::tracing::Tracing;
 pub use crate::socket::*;
+pub use address::Address;
 pub use application::Application;
 pub use handle::{Ext, Flags, Socket};
 pub use send_only::SendOnly;
@@ -26,12 +28,14 @@ pub type ArcApplication = std::sync::Arc<dyn Application>;
 pub enum Protocol {
     Tcp,
     Udp,
+    Unix,
     Other(&'static str),
 }
 
 impl Protocol {
     s2n_quic_core::state::is!(is_tcp, Tcp);
     s2n_quic_core::state::is!(is_udp, Udp);
+    s2n_quic_core::state::is!(is_unix, Unix);
 
     #[inline]
     pub fn is_other(&self) -> bool {
@@ -0,0 +1,61 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt;
+use std::{net::SocketAddr, os::unix::net::SocketAddr as UnixSocketAddr};
+
+/// The address of a stream socket or its peer
+///
+/// Unlike [`SocketAddr`], this can also represent Unix domain sockets, which aren't assigned IP
+/// addresses.
+#[derive(Clone, Debug)]
+pub enum Address {
+    Inet(SocketAddr),
+    Unix(UnixSocketAddr),
+}
+
+impl Address {
+    /// Returns the IP address, if this isn't a Unix domain socket
+    #[inline]
+    pub fn as_inet(&self) -> Option<&SocketAddr> {
+        match self {
+            Self::Inet(addr) => Some(addr),
+            Self::Unix(_) => None,
+        }
+    }
+
+    /// Returns the Unix domain socket address, if this is one
+    #[inline]
+    pub fn as_unix(&self) -> Option<&UnixSocketAddr> {
+        match self {
+            Self::Inet(_) => None,
+            Self::Unix(addr) => Some(addr),
+        }
+    }
+}
+
+impl From<SocketAddr> for Address {
+    #[inline]
+    fn from(addr: SocketAddr) -> Self {
+        Self::Inet(addr)
+    }
+}
+
+impl From<UnixSocketAddr> for Address {
+    #[inline]
+    fn from(addr: UnixSocketAddr) -> Self {
+        Self::Unix(addr)
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Inet(addr) => addr.fmt(f),
+            Self::Unix(addr) => match addr.as_pathname() {
+                Some(path) => write!(f, "unix:{}", path.display()),
+                None => f.write_str("unix:(unnamed)"),
+            },
+        }
+    }
+}
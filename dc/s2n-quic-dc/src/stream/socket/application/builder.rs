@@ -72,6 +72,27 @@ mod tokio_impl {
             Ok(v)
         }
     }
+
+    impl Builder for std::os::unix::net::UnixStream {
+        #[inline]
+        fn build(self: Box<Self>) -> io::Result<ArcApplication> {
+            let v = tokio::net::UnixStream::from_std(*self)?;
+            let v = Tracing(v);
+            let v = application::Single(v);
+            let v = Arc::new(v);
+            Ok(v)
+        }
+    }
+
+    impl Builder for tokio::net::UnixStream {
+        #[inline]
+        fn build(self: Box<Self>) -> io::Result<ArcApplication> {
+            let v = Tracing(*self);
+            let v = application::Single(v);
+            let v = Arc::new(v);
+            Ok(v)
+        }
+    }
 }
 
 #[cfg(feature = "tokio")]
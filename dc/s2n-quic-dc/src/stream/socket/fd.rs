@@ -1,6 +1,13 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+//! Socket operations on plain file descriptors
+//!
+//! Streams call these from their readiness-based `poll_send` and `poll_recv` paths. They don't
+//! use io_uring. Each stream owns its socket, so a ring per stream would cost more than the
+//! syscalls it saves. Moving streams onto a shared ring would need a completion-based socket
+//! trait. The io_uring backend in `s2n-quic-platform` only drives the endpoint's shared sockets.
+
 use s2n_quic_core::ensure;
 use std::{io, os::fd::AsRawFd};
 
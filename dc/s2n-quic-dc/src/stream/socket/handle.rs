@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use super::{Protocol, TransportFeatures};
+use super::{Address, Protocol, TransportFeatures};
 use crate::msg::{self, addr::Addr, cmsg};
 use core::task::{Context, Poll};
 use s2n_quic_core::inet::ExplicitCongestionNotification;
@@ -23,6 +23,21 @@ pub trait Socket: 'static + Send + Sync {
         Ok(self.local_addr()?.port())
     }
 
+    /// Returns the local address for the socket, including sockets which aren't addressed by IP
+    #[inline]
+    fn local_address(&self) -> io::Result<Address> {
+        Ok(self.local_addr()?.into())
+    }
+
+    /// Returns the address of the connected peer, if the socket is connected to one which isn't
+    /// addressed by IP
+    ///
+    /// Otherwise, the peer address is tracked by the stream.
+    #[inline]
+    fn peer_address(&self) -> io::Result<Option<Address>> {
+        Ok(None)
+    }
+
     fn protocol(&self) -> Protocol;
 
     /// Returns the [`TransportFeatures`] that the socket supports
@@ -122,6 +137,16 @@ macro_rules! impl_box {
                 (**self).local_addr()
             }
 
+            #[inline(always)]
+            fn local_address(&self) -> io::Result<Address> {
+                (**self).local_address()
+            }
+
+            #[inline(always)]
+            fn peer_address(&self) -> io::Result<Option<Address>> {
+                (**self).peer_address()
+            }
+
             #[inline(always)]
             fn protocol(&self) -> Protocol {
                 (**self).protocol()
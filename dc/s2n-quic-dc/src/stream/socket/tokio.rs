@@ -1,7 +1,8 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use super::{fd, Protocol, Socket, TransportFeatures};
+use super::{fd, Address, Protocol, Socket, TransportFeatures};
 
 mod tcp;
 mod udp;
+mod unix;
@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use super::{Protocol, Socket, TransportFeatures};
+use super::{Address, Protocol, Socket, TransportFeatures};
 use crate::msg::{addr::Addr, cmsg};
 use core::task::{Context, Poll};
 use s2n_quic_core::inet::ExplicitCongestionNotification;
@@ -20,6 +20,16 @@ impl<S: Socket> Socket for Tracing<S> {
         self.0.local_addr()
     }
 
+    #[inline(always)]
+    fn local_address(&self) -> io::Result<Address> {
+        self.0.local_address()
+    }
+
+    #[inline(always)]
+    fn peer_address(&self) -> io::Result<Option<Address>> {
+        self.0.peer_address()
+    }
+
     #[inline]
     fn protocol(&self) -> Protocol {
         self.0.protocol()
@@ -37,7 +47,7 @@ impl<S: Socket> Socket for Tracing<S> {
         trace!(
             operation = %"poll_peek_len",
             protocol = ?self.protocol(),
-            local_addr = ?self.local_addr(),
+            local_addr = ?self.local_address(),
             result = ?result,
         );
 
@@ -58,7 +68,7 @@ impl<S: Socket> Socket for Tracing<S> {
             Poll::Ready(Ok(_)) => trace!(
                 operation = %"poll_recv",
                 protocol = ?self.protocol(),
-                local_addr = ?self.local_addr(),
+                local_addr = ?self.local_address(),
                 remote_addr = ?addr,
                 ecn = ?cmsg.ecn(),
                 segments = buffer.len(),
@@ -72,7 +82,7 @@ impl<S: Socket> Socket for Tracing<S> {
             _ => trace!(
                 operation = %"poll_recv",
                 protocol = ?self.protocol(),
-                local_addr = ?self.local_addr(),
+                local_addr = ?self.local_address(),
                 segments = buffer.len(),
                 buffer_len = {
                     let v: usize = buffer.iter().map(|s| s.len()).sum();
@@ -97,7 +107,7 @@ impl<S: Socket> Socket for Tracing<S> {
         trace!(
             operation = %"try_send",
             protocol = ?self.protocol(),
-            local_addr = ?self.local_addr(),
+            local_addr = ?self.local_address(),
             remote_addr = ?addr,
             ?ecn,
             segments = buffer.len(),
@@ -125,7 +135,7 @@ impl<S: Socket> Socket for Tracing<S> {
         trace!(
             operation = %"poll_send",
             protocol = ?self.protocol(),
-            local_addr = ?self.local_addr(),
+            local_addr = ?self.local_address(),
             remote_addr = ?addr,
             ?ecn,
             segments = buffer.len(),
@@ -147,7 +157,7 @@ impl<S: Socket> Socket for Tracing<S> {
         trace!(
             operation = %"send_finish",
             protocol = ?self.protocol(),
-            local_addr = ?self.local_addr(),
+            local_addr = ?self.local_address(),
             result = ?result,
         );
 
@@ -13,14 +13,21 @@ use crate::{
         socket::Protocol,
     },
 };
-use s2n_quic_core::dc::{self, ApplicationParams};
+use s2n_quic_core::{
+    dc::{self, ApplicationParams},
+    varint::VarInt,
+};
 use s2n_quic_platform::socket;
 use std::{
     cell::RefCell,
     collections::HashMap,
     io,
-    net::{IpAddr, SocketAddr},
-    sync::Arc,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU16, Ordering},
+        Arc,
+    },
 };
 use tracing::Instrument;
 
@@ -47,11 +54,20 @@ pub(crate) const MAX_DATAGRAM_SIZE: u16 = if cfg!(target_os = "linux") {
 
 type Env = Either<tokio::Environment<Subscriber>, bach::Environment<Subscriber>>;
 
+/// Overrides the initial receive window for the endpoint's streams
+fn set_max_data(params: &mut ApplicationParams, max_data: Option<VarInt>) {
+    if let Some(max_data) = max_data {
+        params.remote_max_data = max_data;
+        params.local_recv_max_data = max_data;
+    }
+}
+
 #[derive(Clone)]
 pub struct Client {
     map: secret::Map,
     env: Env,
     mtu: Option<u16>,
+    max_data: Option<VarInt>,
 }
 
 impl Default for Client {
@@ -93,6 +109,7 @@ impl Client {
     fn params(&self) -> ApplicationParams {
         let mut params = dc::testing::TEST_APPLICATION_PARAMS;
         params.max_datagram_size = self.mtu.unwrap_or(MAX_DATAGRAM_SIZE).into();
+        set_max_data(&mut params, self.max_data);
         params
     }
 
@@ -178,6 +195,13 @@ impl Client {
             (Protocol::Udp, Either::B(env)) => {
                 stream_client::bach::connect_udp(handshake, server_addr, env).await
             }
+            (Protocol::Unix, Either::A(env)) => {
+                let path = server.unix_path.as_ref().expect("missing unix socket path");
+                stream_client::tokio::connect_unix(handshake, &**path, env).await
+            }
+            (Protocol::Unix, Either::B(_env)) => {
+                todo!("unix sockets are not implemented in bach");
+            }
             (Protocol::Other(name), _) => {
                 todo!("protocol {name:?} not implemented")
             }
@@ -217,6 +241,8 @@ pub mod client {
     pub struct Builder {
         map_capacity: usize,
         mtu: Option<u16>,
+        max_data: Option<VarInt>,
+        recv_window: Option<recv::flow::Config>,
         subscriber: event::testing::Subscriber,
         pooled: bool,
     }
@@ -226,6 +252,8 @@ pub mod client {
             Self {
                 map_capacity: 16,
                 mtu: None,
+                max_data: None,
+                recv_window: None,
                 subscriber: event::testing::Subscriber::no_snapshot(),
                 pooled: DEFAULT_POOLED,
             }
@@ -243,6 +271,17 @@ pub mod client {
             self
         }
 
+        /// Sets the initial flow control windows for streams
+        pub fn max_data(mut self, max_data: VarInt) -> Self {
+            self.max_data = Some(max_data);
+            self
+        }
+
+        pub fn recv_window(mut self, config: recv::flow::Config) -> Self {
+            self.recv_window = Some(config);
+            self
+        }
+
         pub fn subscriber(mut self, subscriber: event::testing::Subscriber) -> Self {
             self.subscriber = subscriber;
             self
@@ -252,6 +291,8 @@ pub mod client {
             let Self {
                 map_capacity,
                 mtu,
+                max_data,
+                recv_window,
                 subscriber,
                 pooled,
             } = self;
@@ -268,6 +309,10 @@ pub mod client {
                         .with_threads(TEST_THREADS)
                         .with_socket_options(options);
 
+                    if let Some(config) = recv_window {
+                        env = env.with_recv_window(config);
+                    }
+
                     if $pooled {
                         let pool = udp::Config::new(map.clone());
                         env = env.with_pool(pool);
@@ -283,7 +328,12 @@ pub mod client {
                 Either::A(build!(tokio, pooled, "127.0.0.1:0"))
             };
 
-            Client { map, env, mtu }
+            Client {
+                map,
+                env,
+                mtu,
+                max_data,
+            }
         }
     }
 }
@@ -325,6 +375,10 @@ impl Server {
         Self::builder().udp()
     }
 
+    pub fn unix() -> server::Builder {
+        Self::builder().unix()
+    }
+
     pub fn local_addr(&self) -> SocketAddr {
         self.as_ref().local_addr
     }
@@ -385,13 +439,16 @@ pub mod server {
         pub(super) map: secret::Map,
         pub(super) protocol: Protocol,
         pub(super) local_addr: SocketAddr,
+        pub(super) unix_path: Option<Arc<PathBuf>>,
         pub(super) mtu: Option<u16>,
+        pub(super) max_data: Option<VarInt>,
     }
 
     impl Handle {
         pub(super) fn params(&self) -> ApplicationParams {
             let mut params = dc::testing::TEST_APPLICATION_PARAMS;
             params.max_datagram_size = self.mtu.unwrap_or(MAX_DATAGRAM_SIZE).into();
+            set_max_data(&mut params, self.max_data);
             params
         }
     }
@@ -404,6 +461,7 @@ pub mod server {
 
     pub(super) struct AddrReservation {
         local_addr: SocketAddr,
+        unix_path: Option<Arc<PathBuf>>,
     }
 
     impl core::ops::Deref for AddrReservation {
@@ -417,6 +475,9 @@ pub mod server {
     impl Drop for AddrReservation {
         fn drop(&mut self) {
             SERVERS.with(|servers| servers.borrow_mut().remove(&self.local_addr));
+            if let Some(path) = self.unix_path.as_deref() {
+                let _ = std::fs::remove_file(path);
+            }
         }
     }
 
@@ -427,6 +488,8 @@ pub mod server {
         map_capacity: usize,
         linger: Option<Duration>,
         mtu: Option<u16>,
+        max_data: Option<VarInt>,
+        recv_window: Option<recv::flow::Config>,
         subscriber: event::testing::Subscriber,
         pooled: bool,
         port: u16,
@@ -441,6 +504,8 @@ pub mod server {
                 map_capacity: 16,
                 linger: None,
                 mtu: None,
+                max_data: None,
+                recv_window: None,
                 subscriber: event::testing::Subscriber::no_snapshot(),
                 pooled: DEFAULT_POOLED,
                 port: 0,
@@ -459,6 +524,11 @@ pub mod server {
             self
         }
 
+        pub fn unix(mut self) -> Self {
+            self.protocol = Protocol::Unix;
+            self
+        }
+
         pub fn port(mut self, port: u16) -> Self {
             self.port = port;
             self
@@ -494,6 +564,17 @@ pub mod server {
             self
         }
 
+        /// Sets the initial flow control windows for streams
+        pub fn max_data(mut self, max_data: VarInt) -> Self {
+            self.max_data = Some(max_data);
+            self
+        }
+
+        pub fn recv_window(mut self, config: recv::flow::Config) -> Self {
+            self.recv_window = Some(config);
+            self
+        }
+
         pub fn subscriber(mut self, subscriber: event::testing::Subscriber) -> Self {
             self.subscriber = subscriber;
             self
@@ -507,6 +588,8 @@ pub mod server {
                 map_capacity,
                 linger,
                 mtu,
+                max_data,
+                recv_window,
                 subscriber,
                 pooled,
                 port,
@@ -531,6 +614,10 @@ pub mod server {
                         .with_threads(TEST_THREADS)
                         .with_socket_options(options.clone());
 
+                    if let Some(config) = recv_window {
+                        env = env.with_recv_window(config);
+                    }
+
                     if $pooled {
                         let mut pool = udp::Config::new(map.clone());
                         pool.accept_flavor = flavor;
@@ -545,6 +632,7 @@ pub mod server {
 
             let (drop_handle_sender, drop_handle_receiver) = drop_handle::new();
             let (stats_sender, stats_worker, stats) = stats::channel();
+            let mut unix_path = None;
 
             let local_addr = if ::bach::is_active() {
                 assert_eq!(Protocol::Udp, protocol, "bach only supports UDP currently");
@@ -589,6 +677,27 @@ pub mod server {
 
                         local_addr
                     }
+                    Protocol::Unix => {
+                        static NEXT_ID: AtomicU16 = AtomicU16::new(1);
+                        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+
+                        let path = std::env::temp_dir()
+                            .join(format!("s2n-quic-dc-{}-{id}.sock", std::process::id()));
+                        let _ = std::fs::remove_file(&path);
+                        let socket = ::tokio::net::UnixListener::bind(&path).unwrap();
+                        unix_path = Some(Arc::new(path));
+
+                        let acceptor = stream_server::tokio::unix::Acceptor::new(
+                            socket, &sender, &env, &map, backlog, flavor,
+                        );
+                        let acceptor = drop_handle_receiver.wrap(acceptor.run());
+                        let acceptor = acceptor.instrument(tracing::info_span!("unix"));
+                        ::tokio::task::spawn(acceptor);
+
+                        // Unix sockets don't have an address so give each server a unique one to
+                        // key the path secret
+                        (Ipv4Addr::new(127, 0, 0, 2), id).into()
+                    }
                     Protocol::Other(name) => {
                         todo!("protocol {name:?} not implemented")
                     }
@@ -618,7 +727,9 @@ pub mod server {
                 map,
                 protocol,
                 local_addr,
+                unix_path: unix_path.clone(),
                 mtu,
+                max_data,
             };
 
             if ::bach::is_active() {
@@ -631,7 +742,10 @@ pub mod server {
                 stats: stats_sender,
                 drop_handle: drop_handle_sender,
                 subscriber: test_subscriber,
-                addr_reservation: Arc::new(AddrReservation { local_addr }),
+                addr_reservation: Arc::new(AddrReservation {
+                    local_addr,
+                    unix_path,
+                }),
             }
         }
     }
@@ -5,5 +5,6 @@ mod accept_queue;
 mod deterministic;
 mod idle_timeout;
 mod key_update;
+mod recv_window;
 mod request_response;
 mod rpc;
@@ -28,17 +28,34 @@ async fn no_key_update() {
     test(TEST_MAX_RECORDS - 10, 0).await;
 }
 
+/// This test checks that keys are not updated over same-host transports, since only the first
+/// packet is sealed once both peers have offered the mode.
+#[tokio::test]
+async fn same_host_key_update() {
+    test_with(Server::unix().build(), TEST_MAX_RECORDS, 0).await;
+}
+
 async fn test(num_packets: u64, expected_key_updates: u64) {
+    test_with(Server::tcp().build(), num_packets, expected_key_updates).await;
+}
+
+async fn test_with(server: Server, num_packets: u64, expected_key_updates: u64) {
     init_tracing();
 
     let client = Client::default();
-    let server = Server::tcp().build();
     let client_subscriber = client.subscriber();
     let server_subscriber = server.subscriber();
 
     tokio::try_join!(
         async {
             let mut a = client.connect_to(&server).await?;
+
+            // exchange a packet in each direction so both peers have seen each other's flags
+            a.write_all(b"hello").await?;
+            let mut greeting = [0; 2];
+            a.read_exact(&mut greeting).await?;
+            assert_eq!(&greeting, b"hi");
+
             // send enough packets to trigger a key update. This value is lower
             // when debug_assertions are enabled (see TEST_MAX_RECORDS in path/secret/key.rs)
             for _ in 0..num_packets {
@@ -59,6 +76,12 @@ async fn test(num_packets: u64, expected_key_updates: u64) {
         .instrument(info_span!("client")),
         async {
             let (mut b, _) = server.accept().await.expect("accept");
+
+            let mut greeting = [0; 5];
+            b.read_exact(&mut greeting).await.unwrap();
+            assert_eq!(&greeting, b"hello");
+            b.write_all(b"hi").await.unwrap();
+
             let mut buffer = vec![];
             b.read_to_end(&mut buffer).await.unwrap();
 
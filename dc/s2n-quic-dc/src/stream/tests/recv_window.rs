@@ -0,0 +1,143 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    stream::{
+        recv::flow,
+        testing::{Client, Server},
+    },
+    testing::{ext::*, sim_with_rtt, spawn},
+};
+use core::time::Duration;
+use s2n_quic_core::varint::VarInt;
+use std::sync::{atomic::Ordering, Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+const INITIAL_WINDOW: u32 = 64 * 1024;
+const RESPONSE_LEN: usize = 8 * 1024 * 1024;
+
+struct Download {
+    /// The response throughput in bytes per second
+    throughput: f64,
+    window_updates: u64,
+}
+
+/// Downloads a large response over a path with the given RTT
+fn download(rtt: Duration, recv_window: flow::Config) -> Download {
+    let result = Arc::new(Mutex::new(None));
+
+    sim_with_rtt(rtt, || {
+        let result = result.clone();
+        async move {
+            let client = Client::builder()
+                .max_data(VarInt::from_u32(INITIAL_WINDOW))
+                .recv_window(recv_window)
+                .build();
+            let mut stream = client.connect_sim("server:443").await.unwrap();
+
+            stream.write_all(b"download").await.unwrap();
+            stream.shutdown().await.unwrap();
+
+            let start = bach::time::Instant::now();
+            let mut len = 0;
+            let mut buffer = vec![0; 64 * 1024];
+            loop {
+                let read = stream.read(&mut buffer).await.unwrap();
+                if read == 0 {
+                    break;
+                }
+                len += read;
+            }
+            let elapsed = start.elapsed();
+
+            assert_eq!(len, RESPONSE_LEN);
+
+            let window_updates = client
+                .subscriber()
+                .stream_read_window_updated
+                .load(Ordering::Relaxed);
+
+            *result.lock().unwrap() = Some(Download {
+                throughput: len as f64 / elapsed.as_secs_f64(),
+                window_updates,
+            });
+        }
+        .group("client")
+        .primary()
+        .spawn();
+
+        async move {
+            let server = Server::udp()
+                .port(443)
+                .max_data(VarInt::from_u32(INITIAL_WINDOW))
+                .build();
+
+            while let Ok((mut stream, _addr)) = server.accept().await {
+                spawn(async move {
+                    let mut request = vec![];
+                    stream.read_to_end(&mut request).await.unwrap();
+
+                    let response = vec![42; RESPONSE_LEN];
+                    stream.write_all(&response).await.unwrap();
+                });
+            }
+        }
+        .group("server")
+        .spawn();
+    });
+
+    let result = result.lock().unwrap().take();
+    result.expect("download did not complete")
+}
+
+/// Shows that autotuning allows throughput to scale past the initial window on high-BDP paths
+#[test]
+fn throughput_scales_with_bdp() {
+    for rtt in [5, 20, 50] {
+        let rtt = Duration::from_millis(rtt);
+
+        // without autotuning the stream can send at most a single window every round trip
+        let window_limit = INITIAL_WINDOW as f64 / rtt.as_secs_f64();
+
+        let fixed = download(rtt, flow::Config::disabled());
+        assert_eq!(fixed.window_updates, 0);
+        assert!(
+            fixed.throughput <= window_limit * 1.1,
+            "rtt={rtt:?} throughput={} limit={window_limit}",
+            fixed.throughput
+        );
+
+        let tuned = download(rtt, flow::Config::default());
+        assert!(tuned.window_updates > 0);
+        assert!(
+            tuned.throughput > fixed.throughput * 4.0,
+            "rtt={rtt:?} tuned={} fixed={}",
+            tuned.throughput,
+            fixed.throughput
+        );
+    }
+}
+
+/// Shows that the endpoint memory budget caps how far windows can grow
+#[test]
+fn budget_limits_throughput() {
+    let rtt = Duration::from_millis(20);
+
+    let unlimited = download(rtt, flow::Config::default());
+    let limited = download(
+        rtt,
+        flow::Config {
+            budget: INITIAL_WINDOW as u64,
+            ..Default::default()
+        },
+    );
+
+    // the window can grow to at most twice the initial value
+    let window_limit = 2.0 * INITIAL_WINDOW as f64 / rtt.as_secs_f64();
+    assert!(
+        limited.throughput <= window_limit * 1.1,
+        "throughput={} limit={window_limit}",
+        limited.throughput
+    );
+    assert!(unlimited.throughput > limited.throughput * 2.0);
+}
@@ -336,7 +336,7 @@ impl Harness {
                 loop {
                     info!("accepting");
                     let (stream, peer_addr) = server.accept().await.unwrap();
-                    info!(%peer_addr, local_addr = %stream.local_addr().unwrap());
+                    info!(%peer_addr, local_addr = %stream.local_address().unwrap());
 
                     spawn(
                         check_server(stream, config, requests.clone())
@@ -370,7 +370,7 @@ impl Harness {
                     info!("connecting");
 
                     let stream = client.connect_to(&server).await.unwrap();
-                    info!(peer_addr = %stream.peer_addr().unwrap(), local_addr = %stream.local_addr().unwrap());
+                    info!(peer_addr = %stream.peer_address().unwrap(), local_addr = %stream.local_address().unwrap());
 
                     check_client(stream, config, requests).await;
                 }
@@ -403,7 +403,7 @@ impl Harness {
                     let task = async move {
                         info!("connecting");
                         let stream = client.connect_to(&server).await.unwrap();
-                        info!(peer_addr = %stream.peer_addr().unwrap(), local_addr = %stream.local_addr().unwrap());
+                        info!(peer_addr = %stream.peer_address().unwrap(), local_addr = %stream.local_address().unwrap());
 
                         check_client(stream, config, requests).await;
 
@@ -713,6 +713,54 @@ mod udp {
     tokio_fuzz_test!();
 }
 
+mod unix {
+    use super::*;
+
+    fn harness() -> Harness {
+        Harness {
+            protocol: Protocol::Unix,
+            ..Default::default()
+        }
+    }
+
+    tests!(tokio_test);
+    tokio_fuzz_test!();
+
+    #[tokio::test]
+    async fn addresses() {
+        crate::testing::init_tracing();
+
+        let client = testing::Client::default();
+        let server = testing::Server::unix().build();
+
+        let (client_stream, (server_stream, peer_addr)) =
+            tokio::try_join!(client.connect_to(&server), server.accept()).unwrap();
+
+        // the IP addresses are the ones the path secret was negotiated with
+        assert_eq!(client_stream.peer_addr().unwrap(), server.local_addr());
+        assert_eq!(server_stream.peer_addr().unwrap(), peer_addr);
+        assert!(!peer_addr.ip().is_unspecified());
+
+        // the sockets themselves aren't addressed by IP
+        assert!(client_stream.local_addr().is_err());
+        assert!(server_stream.local_addr().is_err());
+
+        let server_path = server_stream.local_address().unwrap();
+        let server_path = server_path.as_unix().unwrap().as_pathname().unwrap();
+        let client_peer = client_stream.peer_address().unwrap();
+        assert_eq!(
+            client_peer.as_unix().unwrap().as_pathname(),
+            Some(server_path)
+        );
+
+        // the client's socket is unnamed
+        let client_path = client_stream.local_address().unwrap();
+        assert!(client_path.as_unix().unwrap().is_unnamed());
+        let server_peer = server_stream.peer_address().unwrap();
+        assert!(server_peer.as_unix().unwrap().is_unnamed());
+    }
+}
+
 mod udp_sim {
     use super::*;
 
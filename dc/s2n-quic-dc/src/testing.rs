@@ -138,10 +138,16 @@ impl tracing_subscriber::fmt::time::FormatTime for Uptime {
 
 /// Runs a function in a deterministic, discrete event simulation environment
 pub fn sim(f: impl FnOnce()) {
+    // 1ms RTT
+    sim_with_rtt(Duration::from_millis(1), f)
+}
+
+/// Runs a function in a deterministic, discrete event simulation environment with the
+/// provided network round trip time
+pub fn sim_with_rtt(rtt: Duration, f: impl FnOnce()) {
     init_tracing();
 
-    // 1ms RTT
-    let net_delay = Duration::from_micros(500);
+    let net_delay = rtt / 2;
     let queues = bach::environment::net::queue::Fixed::default().with_net_latency(net_delay);
     let mut rt = bach::environment::default::Runtime::new().with_net_queues(Some(Box::new(queues)));
     rt.run(f);
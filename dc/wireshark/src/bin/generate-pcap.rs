@@ -2,17 +2,32 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use s2n_codec::{EncoderBuffer, EncoderValue};
-use std::{io::Write, net::Ipv4Addr, path::Path};
+use s2n_quic_core::{buffer::reader::Incremental, endpoint, varint::VarInt};
+use s2n_quic_dc::{
+    credentials::Credentials,
+    packet::stream,
+    path::secret::schedule::{Ciphersuite, Initiator, Secret},
+};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    net::Ipv4Addr,
+    path::Path,
+};
 
 const MAGIC_NUMBER: u32 = 0xa1b2c3d4;
 
 fn main() {
     // https://wiki.wireshark.org/Development/LibpcapFileFormat#overview
     let out_dir = std::env::args().nth(1).unwrap_or_default();
+    let out_dir = Path::new(&out_dir);
 
-    let output = Path::new(&out_dir).join("datagram.pcap");
+    datagrams(out_dir);
+    streams(out_dir);
+}
 
-    let mut output = std::io::BufWriter::new(std::fs::File::create(output).unwrap());
+fn create_pcap(path: &Path, max_length: u32) -> BufWriter<File> {
+    let mut output = BufWriter::new(File::create(path).unwrap());
 
     output.write_all(&MAGIC_NUMBER.to_ne_bytes()).unwrap();
 
@@ -26,13 +41,19 @@ fn main() {
     // Sigfigs. Wireshark says this should always be zero.
     output.write_all(&0u32.to_ne_bytes()).unwrap();
 
-    let max_length = 500u32;
     // Snaplen, maximum capture length.
     output.write_all(&max_length.to_ne_bytes()).unwrap();
 
     // Network kind. We're writing ethernet packets.
     output.write_all(&1u32.to_ne_bytes()).unwrap();
 
+    output
+}
+
+fn datagrams(out_dir: &Path) {
+    let max_length = 500u32;
+    let mut output = create_pcap(&out_dir.join("datagram.pcap"), max_length);
+
     let timestamp_start = 1716923147u32;
     for idx in 0..100_000 {
         output.write_all(&timestamp_start.to_ne_bytes()).unwrap();
@@ -122,6 +143,118 @@ fn main() {
     }
 }
 
+/// Writes encrypted stream packets along with a key log that can be used to decrypt them
+///
+/// The packets can be decrypted in Wireshark by pointing `DC_QUIC_KEYLOGFILE` at the generated
+/// `stream.keylog` file.
+pub fn streams(out_dir: &Path) {
+    let max_length = 1500u32;
+    let mut output = create_pcap(&out_dir.join("stream.pcap"), max_length);
+
+    let export_secret = [0x42; 32];
+    let client = Secret::new(
+        Ciphersuite::AES_GCM_128_SHA256,
+        s2n_quic_dc::SUPPORTED_VERSIONS[0],
+        endpoint::Type::Client,
+        &export_secret,
+    );
+    let server = Secret::new(
+        Ciphersuite::AES_GCM_128_SHA256,
+        s2n_quic_dc::SUPPORTED_VERSIONS[0],
+        endpoint::Type::Server,
+        &export_secret,
+    );
+
+    std::fs::write(
+        out_dir.join("stream.keylog"),
+        format!("{}\n", client.key_log_entry()),
+    )
+    .unwrap();
+
+    let timestamp_start = 1716923147u32;
+    for key_id in 0..100u64 {
+        let key_id = VarInt::new(key_id).unwrap();
+        let stream_id = stream::Id {
+            queue_id: key_id,
+            is_reliable: false,
+            is_bidirectional: true,
+        };
+
+        // the client initiates the stream and the server responds
+        let (client_sealer, ..) = client.application_pair(key_id, Initiator::Local);
+        let (server_sealer, ..) = server.application_pair(key_id, Initiator::Remote);
+
+        let request = format!("request for stream {key_id} ").repeat(50);
+        let response = format!("response for stream {key_id} ").repeat(100);
+
+        for (sealer, message, credentials_id) in [
+            (&client_sealer, request, client.id()),
+            (&server_sealer, response, server.id()),
+        ] {
+            let credentials = Credentials {
+                id: *credentials_id,
+                key_id,
+            };
+            let message = message.as_bytes();
+            let mut packet_number = VarInt::ZERO;
+
+            for (idx, chunk) in message.chunks(1000).enumerate() {
+                let offset = VarInt::try_from(idx * 1000).unwrap();
+                let is_fin = offset.as_u64() as usize + chunk.len() == message.len();
+                let mut chunk = chunk;
+                let mut reader = Incremental::new(offset);
+                let mut payload = reader.with_storage(&mut chunk, is_fin).unwrap();
+
+                let mut dcquic = vec![0; max_length as usize];
+                let len = stream::encoder::encode(
+                    EncoderBuffer::new(&mut dcquic),
+                    None,
+                    stream_id,
+                    packet_number,
+                    VarInt::ZERO,
+                    VarInt::ZERO,
+                    &mut &[][..],
+                    VarInt::ZERO,
+                    &(),
+                    &mut payload,
+                    sealer,
+                    &credentials,
+                    stream::Flags::empty(),
+                );
+                dcquic.truncate(len);
+                packet_number = packet_number.checked_add_usize(1).unwrap();
+
+                output.write_all(&timestamp_start.to_ne_bytes()).unwrap();
+                // micros -- we always have zeros for this field.
+                output.write_all(&0u32.to_ne_bytes()).unwrap();
+
+                let mut packet = Packet::new();
+
+                packet.add_ethernet();
+                packet.add_ip(None);
+                packet.add_udp();
+                packet.buffer.extend_from_slice(&dcquic);
+
+                packet.ip_fill_length();
+                packet.udp_fill_length();
+
+                assert!(packet.buffer.len() <= max_length as usize);
+
+                // Captured length.
+                output
+                    .write_all(&u32::try_from(packet.buffer.len()).unwrap().to_ne_bytes())
+                    .unwrap();
+                // Real length.
+                output
+                    .write_all(&u32::try_from(packet.buffer.len()).unwrap().to_ne_bytes())
+                    .unwrap();
+
+                output.write_all(&packet.buffer).unwrap();
+            }
+        }
+    }
+}
+
 struct Packet {
     buffer: Vec<u8>,
 
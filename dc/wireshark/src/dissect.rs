@@ -4,11 +4,15 @@
 use crate::{
     buffer::Buffer,
     field::Registration,
+    secrets::Secrets,
     value::Parsed,
     wireshark::{Info, Item, Node},
 };
 use s2n_codec::DecoderBufferMut;
-use s2n_quic_core::{frame::FrameMut, varint::VarInt};
+use s2n_quic_core::{
+    frame::{self, FrameMut},
+    varint::VarInt,
+};
 use s2n_quic_dc::packet::{self, stream, WireVersion};
 
 #[derive(Clone, Copy, Debug)]
@@ -18,10 +22,12 @@ pub enum Protocol {
     Udp,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn segment<T: Node>(
     tree: &mut T,
     root: &mut impl Item,
     fields: &Registration,
+    secrets: &Secrets,
     ptag: Parsed<packet::Tag>,
     buffer: &mut Buffer,
     info: &mut impl Info,
@@ -31,7 +37,7 @@ pub fn segment<T: Node>(
         packet::Tag::Stream(tag) => {
             root.append_text(c" Stream");
             let tag = ptag.map(|_| tag);
-            stream(tree, fields, tag, buffer, info)
+            stream(tree, fields, secrets, tag, buffer, info)
         }
         packet::Tag::Control(tag) => {
             match protocol {
@@ -59,10 +65,12 @@ pub fn segment<T: Node>(
 pub fn stream<T: Node>(
     tree: &mut T,
     fields: &Registration,
+    secrets: &Secrets,
     tag: Parsed<stream::Tag>,
     buffer: &mut Buffer,
     info: &mut impl Info,
 ) -> Option<()> {
+    let tag_offset = tag.offset;
     let tag_item = tag.record(buffer, tree, fields.tag);
 
     let mut tag_tree = tree.add_subtree(tag_item, fields.tag_subtree);
@@ -88,9 +96,10 @@ pub fn stream<T: Node>(
     let wire_version = buffer.consume::<WireVersion>()?;
     wire_version.record(buffer, tree, fields.wire_version);
 
-    // unused space - was source_control_port when we did port migration but that has
-    // been replaced with `source_queue_id`, which is more flexible
-    let _source_control_port = buffer.consume::<u16>()?;
+    // was source_control_port when we did port migration but that has been replaced with
+    // `source_queue_id`, which is more flexible
+    let flags = buffer.consume::<u16>()?;
+    flags.record(buffer, tree, fields.stream_flags);
 
     let stream_id = buffer.consume()?;
     let stream_id = record_stream_id(tree, fields, buffer, stream_id);
@@ -155,6 +164,46 @@ pub fn stream<T: Node>(
     let auth_tag = buffer.consume_bytes(16)?;
     auth_tag.record(buffer, tree, fields.auth_tag);
 
+    let is_fin = final_offset.is_some_and(|final_offset| {
+        let stream_end = stream_offset
+            .value
+            .checked_add(payload_len.value)
+            .unwrap_or(VarInt::MAX);
+        final_offset == stream_end
+    });
+
+    let mut decrypted = if payload.len > 0 {
+        secrets.decrypt_stream(&buffer.packet[tag_offset..buffer.offset])
+    } else {
+        None
+    };
+
+    if let Some(decrypted) = &mut decrypted {
+        let item = payload
+            .with(&decrypted[..])
+            .record(buffer, tree, fields.decrypted_payload);
+        let mut tree = tree.add_subtree(item, fields.decrypted_payload_subtree);
+
+        // the payload is the data of a single STREAM frame, described by the packet header
+        let stream_frame = frame::Stream {
+            stream_id: stream_id.queue_id,
+            offset: stream_offset.value,
+            is_last_frame: true,
+            is_fin,
+            data: DecoderBufferMut::new(decrypted),
+        };
+        let parsed = payload.with(());
+        let stream_frame = FrameMut::Stream(stream_frame);
+        dissect_frame(
+            &mut tree,
+            fields,
+            buffer,
+            parsed,
+            stream_frame,
+            &mut Seen::default(),
+        );
+    }
+
     info.append_delim(" ");
 
     let space = if tag.packet_space().is_recovery() {
@@ -163,23 +212,16 @@ pub fn stream<T: Node>(
         ""
     };
 
-    let fin = if let Some(final_offset) = final_offset {
-        let stream_end = stream_offset
-            .value
-            .checked_add(payload_len.value)
-            .unwrap_or(VarInt::MAX);
+    let fin = if is_fin { ", FIN" } else { "" };
 
-        if final_offset == stream_end {
-            ", FIN"
-        } else {
-            ""
-        }
+    let decrypted = if decrypted.is_some() {
+        ", DECRYPTED"
     } else {
         ""
     };
 
     info.append(format_args!(
-        "Stream(ID={}{space}, PN={},{control_info} LEN={}{fin})",
+        "Stream(ID={}{space}, PN={},{control_info} LEN={}{fin}{decrypted})",
         key_id.value, packet_number.value, payload.len
     ));
 
@@ -269,9 +311,7 @@ pub fn control_frames<T: Node>(
     let tree = &mut tree;
     let mut control_data_owned = control_data.value.to_vec();
 
-    let mut has_ack = false;
-    let mut has_max_data = false;
-    let mut has_close = false;
+    let mut seen = Seen::default();
 
     let mut offset = 0;
     let mut decoder = DecoderBufferMut::new(&mut control_data_owned);
@@ -292,95 +332,13 @@ pub fn control_frames<T: Node>(
         };
         offset += len;
 
-        match frame {
-            FrameMut::Padding(_) => {
-                // do nothing
-            }
-            FrameMut::Ping(_) => {
-                // do nothing
-            }
-            FrameMut::Ack(ack) => {
-                // TODO fix the tests to not assume a single occurrence of each field
-                if cfg!(test) && has_ack {
-                    continue;
-                }
-
-                has_ack = true;
-                parsed
-                    .with(ack.ack_delay())
-                    .record(buffer, tree, fields.ack_delay);
-
-                // FIXME: Look into using FT_FRAMENUM, but that is limited to 32-bit numbers, so
-                // maybe too small?
-                let ranges = ack.ack_ranges();
-
-                // TODO fix the tests to not assume a single occurrence of each field
-                #[cfg(test)]
-                let ranges = ranges.take(1);
-
-                for range in ranges {
-                    let start = parsed.with(range.start().as_u64());
-                    let end = parsed.with(range.end().as_u64());
-
-                    let range = end.record(buffer, tree, fields.ackd_packet);
-                    let mut range_tree = tree.add_subtree(range, fields.ack_range_subtree);
-                    start.record(buffer, &mut range_tree, fields.ack_range_min);
-                    end.record(buffer, &mut range_tree, fields.ack_range_max);
-                }
-
-                if let Some(ecn) = ack.ecn_counts {
-                    parsed
-                        .with(ecn.ect_0_count)
-                        .record(buffer, tree, fields.ect_0_count);
-                    parsed
-                        .with(ecn.ect_1_count)
-                        .record(buffer, tree, fields.ect_1_count);
-                    parsed
-                        .with(ecn.ce_count)
-                        .record(buffer, tree, fields.ce_count);
-                }
-            }
-            FrameMut::MaxData(frame) => {
-                // TODO fix the tests to not assume a single occurrence of each field
-                if cfg!(test) && has_max_data {
-                    continue;
-                }
-
-                has_max_data = true;
-                parsed
-                    .with(frame.maximum_data)
-                    .record(buffer, tree, fields.max_data);
-            }
-            FrameMut::ConnectionClose(frame) => {
-                // TODO fix the tests to not assume a single occurrence of each field
-                if cfg!(test) && has_close {
-                    continue;
-                }
-
-                has_close = true;
-                parsed
-                    .with(frame.error_code)
-                    .record(buffer, tree, fields.close_error_code);
-                if let Some(frame_type) = frame.frame_type {
-                    parsed
-                        .with(frame_type)
-                        .record(buffer, tree, fields.close_frame_type);
-                }
-                if let Some(reason) = frame.reason {
-                    parsed
-                        .with(reason)
-                        .record(buffer, tree, fields.close_reason);
-                }
-            }
-            // FIXME: add "other" handling
-            _ => continue,
-        }
+        dissect_frame(tree, fields, buffer, parsed, frame, &mut seen);
     }
 
     for (was_observed, label) in [
-        (has_ack, "ACK"),
-        (has_max_data, "MAX_DATA"),
-        (has_close, "CONNECTION_CLOSE"),
+        (seen.ack, "ACK"),
+        (seen.max_data, "MAX_DATA"),
+        (seen.close, "CONNECTION_CLOSE"),
     ] {
         if was_observed {
             if info.is_empty() {
@@ -393,6 +351,116 @@ pub fn control_frames<T: Node>(
     }
 }
 
+/// The frame types which have been dissected in a packet
+#[derive(Default)]
+struct Seen {
+    ack: bool,
+    max_data: bool,
+    close: bool,
+}
+
+fn dissect_frame<T: Node>(
+    tree: &mut T,
+    fields: &Registration,
+    buffer: &mut Buffer,
+    parsed: Parsed<()>,
+    frame: FrameMut,
+    seen: &mut Seen,
+) {
+    match frame {
+        FrameMut::Padding(_) => {
+            // do nothing
+        }
+        FrameMut::Ping(_) => {
+            // do nothing
+        }
+        FrameMut::Ack(ack) => {
+            // TODO fix the tests to not assume a single occurrence of each field
+            if cfg!(test) && seen.ack {
+                return;
+            }
+
+            seen.ack = true;
+            parsed
+                .with(ack.ack_delay())
+                .record(buffer, tree, fields.ack_delay);
+
+            // FIXME: Look into using FT_FRAMENUM, but that is limited to 32-bit numbers, so
+            // maybe too small?
+            let ranges = ack.ack_ranges();
+
+            // TODO fix the tests to not assume a single occurrence of each field
+            #[cfg(test)]
+            let ranges = ranges.take(1);
+
+            for range in ranges {
+                let start = parsed.with(range.start().as_u64());
+                let end = parsed.with(range.end().as_u64());
+
+                let range = end.record(buffer, tree, fields.ackd_packet);
+                let mut range_tree = tree.add_subtree(range, fields.ack_range_subtree);
+                start.record(buffer, &mut range_tree, fields.ack_range_min);
+                end.record(buffer, &mut range_tree, fields.ack_range_max);
+            }
+
+            if let Some(ecn) = ack.ecn_counts {
+                parsed
+                    .with(ecn.ect_0_count)
+                    .record(buffer, tree, fields.ect_0_count);
+                parsed
+                    .with(ecn.ect_1_count)
+                    .record(buffer, tree, fields.ect_1_count);
+                parsed
+                    .with(ecn.ce_count)
+                    .record(buffer, tree, fields.ce_count);
+            }
+        }
+        FrameMut::MaxData(frame) => {
+            // TODO fix the tests to not assume a single occurrence of each field
+            if cfg!(test) && seen.max_data {
+                return;
+            }
+
+            seen.max_data = true;
+            parsed
+                .with(frame.maximum_data)
+                .record(buffer, tree, fields.max_data);
+        }
+        FrameMut::ConnectionClose(frame) => {
+            // TODO fix the tests to not assume a single occurrence of each field
+            if cfg!(test) && seen.close {
+                return;
+            }
+
+            seen.close = true;
+            parsed
+                .with(frame.error_code)
+                .record(buffer, tree, fields.close_error_code);
+            if let Some(frame_type) = frame.frame_type {
+                parsed
+                    .with(frame_type)
+                    .record(buffer, tree, fields.close_frame_type);
+            }
+            if let Some(reason) = frame.reason {
+                parsed
+                    .with(reason)
+                    .record(buffer, tree, fields.close_reason);
+            }
+        }
+        FrameMut::Stream(frame) => {
+            parsed
+                .with(frame.offset)
+                .record(buffer, tree, fields.stream_frame_offset);
+            parsed
+                .with(frame.data.len() as u64)
+                .record(buffer, tree, fields.stream_frame_len);
+            tree.add_boolean(buffer, fields.stream_frame_fin, parsed.with(frame.is_fin));
+        }
+        // FIXME: add "other" handling
+        _ => {}
+    }
+}
+
 fn record_stream_id<T: Node>(
     tree: &mut T,
     fields: &Registration,
@@ -20,6 +20,7 @@ pub struct Registration {
     pub all_subtree: i32,
     pub tag_subtree: i32,
     pub control_data_subtree: i32,
+    pub decrypted_payload_subtree: i32,
 
     pub ack_range_subtree: i32,
     pub ack_range_min: i32,
@@ -39,6 +40,7 @@ pub struct Registration {
     pub path_secret_id: i32,
     pub key_id: i32,
     pub source_control_port: i32,
+    pub stream_flags: i32,
     pub source_queue_id: i32,
     pub packet_number: i32,
     pub payload_len: i32,
@@ -49,6 +51,7 @@ pub struct Registration {
     pub control_data: i32,
     pub payload: i32,
     pub auth_tag: i32,
+    pub decrypted_payload: i32,
 
     pub is_bidirectional: i32,
     pub is_reliable: i32,
@@ -65,6 +68,10 @@ pub struct Registration {
     pub ce_count: i32,
     pub max_data: i32,
 
+    pub stream_frame_offset: i32,
+    pub stream_frame_len: i32,
+    pub stream_frame_fin: i32,
+
     pub close_error_code: i32,
     pub close_frame_type: i32,
     pub close_reason: i32,
@@ -199,6 +206,7 @@ fn init() -> Registration {
         all_subtree: register_subtree(),
         tag_subtree: register_subtree(),
         control_data_subtree: register_subtree(),
+        decrypted_payload_subtree: register_subtree(),
         ack_range_subtree: register_subtree(),
         tag: protocol
             .field(c"Tag", c"dcquic.tag", UINT8, BASE_HEX, c"dcQUIC packet tag")
@@ -323,6 +331,15 @@ fn init() -> Registration {
                 c"source control port",
             )
             .register(),
+        stream_flags: protocol
+            .field(
+                c"Flags",
+                c"dcquic.stream_flags",
+                UINT16,
+                BASE_HEX,
+                c"Options offered by the sender, such as the same-host mode",
+            )
+            .register(),
         source_queue_id: protocol
             .field(
                 c"Source Queue ID",
@@ -407,6 +424,15 @@ fn init() -> Registration {
                 c"",
             )
             .register(),
+        decrypted_payload: protocol
+            .field(
+                c"Decrypted payload",
+                c"dcquic.decrypted_payload",
+                BYTES,
+                BASE_NONE,
+                c"Payload decrypted with a path secret from the DC_QUIC_KEYLOGFILE key log",
+            )
+            .register(),
         is_bidirectional: protocol
             .field(
                 c"Is bidirectional?",
@@ -515,6 +541,33 @@ fn init() -> Registration {
                 c"",
             )
             .register(),
+        stream_frame_offset: protocol
+            .field(
+                c"Stream Frame Offset",
+                c"dcquic.stream_frame.offset",
+                UINT64,
+                BASE_DEC,
+                c"",
+            )
+            .register(),
+        stream_frame_len: protocol
+            .field(
+                c"Stream Frame Length",
+                c"dcquic.stream_frame.len",
+                UINT64,
+                BASE_DEC,
+                c"",
+            )
+            .register(),
+        stream_frame_fin: protocol
+            .field(
+                c"Is fin?",
+                c"dcquic.stream_frame.fin",
+                BOOLEAN,
+                BASE_NONE,
+                c"Does the frame end the stream?",
+            )
+            .register(),
         close_error_code: protocol
             .field(
                 c"Close Error Code",
@@ -6,6 +6,7 @@ mod dissect;
 mod field;
 #[cfg(not(test))]
 mod plugin;
+mod secrets;
 mod value;
 /// This wraps the underlying sys APIs in structures that support a cfg(test) mode that doesn't rely on Wireshark.
 mod wireshark;
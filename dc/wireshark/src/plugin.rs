@@ -1,7 +1,9 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{buffer::Buffer, dissect, field, field::Registration, wireshark::Item, wireshark_sys};
+use crate::{
+    buffer::Buffer, dissect, field, field::Registration, secrets, wireshark::Item, wireshark_sys,
+};
 use std::{ffi::CStr, sync::OnceLock};
 
 #[no_mangle]
@@ -117,6 +119,7 @@ unsafe extern "C" fn dissect_heur_udp<Ret: From<bool>>(
             &mut tree,
             &mut root,
             fields,
+            secrets::get(),
             tag,
             &mut buffer,
             &mut info,
@@ -170,6 +173,7 @@ unsafe extern "C" fn dissect_heur_tcp<Ret: From<bool>>(
             &mut tree,
             &mut root,
             fields,
+            secrets::get(),
             tag,
             &mut buffer,
             &mut info,
@@ -0,0 +1,375 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Path secrets loaded from a dcQUIC key log, used for decrypting payloads.
+//!
+//! The key log is produced by `s2n_quic_dc::path::secret::Map` when the `DC_QUIC_KEYLOGFILE`
+//! environment variable is set. The plugin reads the same variable, so setting it for both the
+//! application and Wireshark is enough to get decrypted payloads.
+
+use s2n_codec::DecoderBufferMut;
+use s2n_quic_core::{endpoint, packet::KeyPhase, varint::VarInt};
+use s2n_quic_dc::{
+    credentials::{Credentials, Id},
+    crypto::{self, awslc},
+    packet::stream::decoder::Packet,
+    path::secret::{
+        keylog,
+        schedule::{Initiator, OpenUpdate, Secret},
+    },
+};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fs::File,
+    io::{BufRead, BufReader, Seek, SeekFrom},
+    path::PathBuf,
+    sync::{Mutex, OnceLock},
+};
+
+/// The maximum number of key updates we'll search through when looking for the key for the first
+/// packet with a set of credentials
+const MAX_KEY_UPDATES: usize = 16;
+
+/// The number of key updates we'll look ahead once we know which key protects the credentials
+///
+/// Senders only update the key after the peer has acknowledged the current one so packets are
+/// rarely more than a single update ahead.
+const KEY_UPDATE_WINDOW: usize = 2;
+
+const TAG_LEN: usize = 16;
+
+static SECRETS: OnceLock<Secrets> = OnceLock::new();
+
+#[cfg_attr(test, allow(dead_code))]
+pub fn get() -> &'static Secrets {
+    SECRETS.get_or_init(Secrets::from_env)
+}
+
+#[derive(Default)]
+pub struct Secrets {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    secrets: HashMap<Id, keylog::Owned>,
+    /// The keys derived for each set of credentials we've seen a packet for
+    keys: HashMap<Credentials, Keys>,
+    source: Option<Source>,
+}
+
+/// A key log file that is incrementally read as it grows
+struct Source {
+    path: PathBuf,
+    offset: u64,
+}
+
+impl Source {
+    fn refresh(&mut self, secrets: &mut HashMap<Id, keylog::Owned>) {
+        let Ok(mut file) = File::open(&self.path) else {
+            return;
+        };
+
+        let Ok(len) = file.metadata().map(|m| m.len()) else {
+            return;
+        };
+
+        // the file was truncated so start from the beginning
+        if len < self.offset {
+            self.offset = 0;
+        }
+
+        if len == self.offset || file.seek(SeekFrom::Start(self.offset)).is_err() {
+            return;
+        }
+
+        let mut reader = BufReader::new(file);
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match reader.read_line(&mut line) {
+                // only consume complete lines so we don't miss an entry that's still being written
+                Ok(len) if len > 0 && line.ends_with('\n') => {
+                    self.offset += len as u64;
+                    if let Some(secret) = keylog::Owned::parse(&line) {
+                        secrets.insert(secret.id, secret);
+                    }
+                }
+                _ => break,
+            }
+        }
+    }
+}
+
+impl Secrets {
+    fn from_env() -> Self {
+        match std::env::var_os(keylog::ENV_VAR) {
+            Some(path) => Self::from_path(path),
+            None => Self::default(),
+        }
+    }
+
+    /// Loads secrets from the key log at `path`, picking up new entries as they're appended
+    pub fn from_path<P: Into<PathBuf>>(path: P) -> Self {
+        let source = Source {
+            path: path.into(),
+            offset: 0,
+        };
+        let state = State {
+            secrets: Default::default(),
+            keys: Default::default(),
+            source: Some(source),
+        };
+        Self {
+            state: Mutex::new(state),
+        }
+    }
+
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn insert(&self, secret: keylog::Owned) {
+        self.state.lock().unwrap().secrets.insert(secret.id, secret);
+    }
+
+    /// Decrypts the payload of a single stream packet
+    ///
+    /// Returns `None` if the path secret is unknown or none of the candidate keys authenticate the
+    /// packet.
+    pub fn decrypt_stream(&self, packet: &[u8]) -> Option<Vec<u8>> {
+        let mut scratch = packet.to_vec();
+        let (credentials, key_phase, is_reliable) = {
+            let (packet, _) =
+                Packet::decode(DecoderBufferMut::new(&mut scratch), (), TAG_LEN).ok()?;
+            (
+                *packet.credentials(),
+                packet.tag().key_phase(),
+                packet.stream_id().is_reliable,
+            )
+        };
+
+        let mut state = self.state.lock().unwrap();
+        let State {
+            secrets,
+            keys,
+            source,
+        } = &mut *state;
+
+        let keys = match keys.entry(credentials) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                if !secrets.contains_key(&credentials.id) {
+                    if let Some(source) = source {
+                        source.refresh(secrets);
+                    }
+                }
+
+                let secret = secrets.get(&credentials.id)?;
+                entry.insert(Keys::new(secret, credentials.key_id))
+            }
+        };
+
+        keys.decrypt(key_phase, is_reliable, packet)
+    }
+}
+
+/// The keys which may protect the stream packets for a single set of credentials
+struct Keys {
+    /// The keys for each endpoint and stream initiator
+    ///
+    /// We don't know which endpoint sent a packet or which one initiated the stream. Both
+    /// directions of a bidirectional stream share the same credentials so more than one of these
+    /// can match. The keys are ordered by the most recent one to authenticate a packet.
+    candidates: [Key; 4],
+}
+
+impl Keys {
+    fn new(secret: &keylog::Owned, key_id: VarInt) -> Self {
+        // The key schedule is symmetric so we derive the secret from both endpoints' point of
+        // view. Each endpoint's openers decrypt the packets sent by its peer.
+        let [client, server] = [endpoint::Type::Client, endpoint::Type::Server].map(|endpoint| {
+            Secret::new(
+                secret.ciphersuite,
+                s2n_quic_dc::SUPPORTED_VERSIONS[0],
+                endpoint,
+                &secret.export_secret,
+            )
+        });
+
+        let candidates = [
+            (&client, Initiator::Local),
+            (&client, Initiator::Remote),
+            (&server, Initiator::Local),
+            (&server, Initiator::Remote),
+        ]
+        .map(|(secret, initiator)| Key::new(secret, key_id, initiator));
+
+        Self { candidates }
+    }
+
+    fn decrypt(
+        &mut self,
+        key_phase: KeyPhase,
+        is_reliable: bool,
+        packet: &[u8],
+    ) -> Option<Vec<u8>> {
+        let (index, payload) =
+            self.candidates
+                .iter_mut()
+                .enumerate()
+                .find_map(|(index, key)| {
+                    let payload = key.decrypt(key_phase, is_reliable, packet)?;
+                    Some((index, payload))
+                })?;
+
+        self.candidates[index].is_confirmed = true;
+
+        // the next packet is most likely sent in the same direction
+        self.candidates[..=index].rotate_right(1);
+
+        Some(payload)
+    }
+}
+
+/// A single candidate key, along with the key updates derived from it
+struct Key {
+    /// Set once the key has authenticated a packet
+    is_confirmed: bool,
+    /// The number of key updates applied to `opener`
+    generation: usize,
+    opener: awslc::open::Application,
+    opener_ku: OpenUpdate,
+    /// The key before the last update, for packets which were reordered across the update
+    previous: Option<awslc::open::Application>,
+    control_opener: awslc::open::control::Stream,
+}
+
+impl Key {
+    fn new(secret: &Secret, key_id: VarInt, initiator: Initiator) -> Self {
+        let (_sealer, _sealer_ku, opener, opener_ku) = secret.application_pair(key_id, initiator);
+        let (_control_sealer, control_opener) = secret.control_pair(key_id, initiator);
+
+        Self {
+            is_confirmed: false,
+            generation: 0,
+            opener,
+            opener_ku,
+            previous: None,
+            control_opener,
+        }
+    }
+
+    fn decrypt(
+        &mut self,
+        key_phase: KeyPhase,
+        is_reliable: bool,
+        packet: &[u8],
+    ) -> Option<Vec<u8>> {
+        if phase(self.generation) == key_phase {
+            if let Some(payload) = open(&self.opener, &self.control_opener, is_reliable, packet) {
+                return Some(payload);
+            }
+        } else if let Some(previous) = &self.previous {
+            if let Some(payload) = open(previous, &self.control_opener, is_reliable, packet) {
+                return Some(payload);
+            }
+        }
+
+        // The first packet we see may have been sent after any number of key updates
+        let max_updates = if self.is_confirmed {
+            KEY_UPDATE_WINDOW
+        } else {
+            MAX_KEY_UPDATES
+        };
+
+        // look ahead for the key update which protects the packet
+        let (mut opener, mut opener_ku) = self.opener_ku.next();
+        for updates in 1..=max_updates {
+            if updates > 1 {
+                (opener, opener_ku) = opener_ku.next();
+            }
+
+            if phase(self.generation + updates) != key_phase {
+                continue;
+            }
+
+            if let Some(payload) = open(&opener, &self.control_opener, is_reliable, packet) {
+                let previous = core::mem::replace(&mut self.opener, opener);
+                self.previous = Some(previous);
+                self.opener_ku = opener_ku;
+                self.generation += updates;
+                return Some(payload);
+            }
+        }
+
+        None
+    }
+}
+
+fn phase(generation: usize) -> KeyPhase {
+    if generation % 2 == 0 {
+        KeyPhase::Zero
+    } else {
+        KeyPhase::One
+    }
+}
+
+fn open(
+    opener: &awslc::open::Application,
+    control_opener: &awslc::open::control::Stream,
+    is_reliable: bool,
+    packet: &[u8],
+) -> Option<Vec<u8>> {
+    let mut scratch = packet.to_vec();
+    let (mut packet, _) = Packet::decode(DecoderBufferMut::new(&mut scratch), (), TAG_LEN).ok()?;
+
+    let opener = AnyPhase(opener);
+    let res = if is_reliable {
+        packet.decrypt_in_place(&opener, &crypto::open::control::stream::Reliable::default())
+    } else {
+        packet.decrypt_in_place(&opener, control_opener)
+    };
+
+    res.ok()?;
+    Some(packet.payload().to_vec())
+}
+
+/// Wraps a single-generation key to accept the key phase we've already matched
+struct AnyPhase<'a>(&'a awslc::open::Application);
+
+impl crypto::open::Application for AnyPhase<'_> {
+    #[inline]
+    fn tag_len(&self) -> usize {
+        crypto::open::Application::tag_len(self.0)
+    }
+
+    #[inline]
+    fn decrypt(
+        &self,
+        _key_phase: KeyPhase,
+        packet_number: u64,
+        header: &[u8],
+        payload_in: &[u8],
+        tag: &[u8],
+        payload_out: &mut crypto::UninitSlice,
+    ) -> crypto::open::Result {
+        self.0.decrypt(
+            KeyPhase::Zero,
+            packet_number,
+            header,
+            payload_in,
+            tag,
+            payload_out,
+        )
+    }
+
+    #[inline]
+    fn decrypt_in_place(
+        &self,
+        _key_phase: KeyPhase,
+        packet_number: u64,
+        header: &[u8],
+        payload_and_tag: &mut [u8],
+    ) -> crypto::open::Result {
+        self.0
+            .decrypt_in_place(KeyPhase::Zero, packet_number, header, payload_and_tag)
+    }
+}
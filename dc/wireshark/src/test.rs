@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{buffer::Buffer, dissect, value::Parsed};
+use crate::{buffer::Buffer, dissect, secrets::Secrets, value::Parsed};
 use s2n_codec::EncoderBuffer;
 use s2n_quic_core::{
     buffer::{reader::Storage, Reader},
@@ -18,6 +18,7 @@ use std::{collections::HashMap, num::NonZeroU16, ptr, time::Duration};
 #[derive(Clone, Debug, bolero::TypeGenerator)]
 struct StreamPacket {
     credentials: s2n_quic_dc::credentials::Credentials,
+    flags: u16,
     source_queue_id: Option<VarInt>,
     stream_id: stream::Id,
     packet_space: stream::PacketSpace,
@@ -61,6 +62,7 @@ fn check_stream_parse() {
                 &mut packet.payload,
                 &key,
                 &packet.credentials,
+                stream::Flags::from_bits_retain(packet.flags),
             );
 
             let fields = crate::field::get();
@@ -68,11 +70,23 @@ fn check_stream_parse() {
 
             let mut buffer = unsafe { Buffer::new(ptr::null_mut(), &buffer[..length]) };
             let tag: Parsed<packet::stream::Tag> = buffer.consume().unwrap();
-            assert!(dissect::stream(&mut tracker, fields, tag, &mut buffer, &mut ()).is_some());
+            assert!(dissect::stream(
+                &mut tracker,
+                fields,
+                &Secrets::default(),
+                tag,
+                &mut buffer,
+                &mut ()
+            )
+            .is_some());
             let tag: Parsed<u8> = tag.map(|v| v.into());
 
             assert_eq!(tracker.remove(fields.tag), Field::Integer(tag.value as u64));
             assert_eq!(tracker.remove(fields.wire_version), Field::Integer(0));
+            assert_eq!(
+                tracker.remove(fields.stream_flags),
+                Field::Integer(packet.flags as u64)
+            );
             assert_eq!(
                 tracker.remove(fields.path_secret_id),
                 Field::Slice(packet.credentials.id.to_vec())
@@ -179,6 +193,284 @@ fn check_stream_parse() {
         });
 }
 
+#[derive(Clone, Debug, bolero::TypeGenerator)]
+struct EncryptedStreamPacket {
+    export_secret: [u8; 32],
+    use_aes_256: bool,
+    key_id: VarInt,
+    stream_id: stream::Id,
+    packet_number: VarInt,
+    next_expected_control_packet: VarInt,
+    payload: Data,
+    sender_is_client: bool,
+    initiator_is_sender: bool,
+    #[generator(0..4)]
+    key_updates: u8,
+    know_secret: bool,
+}
+
+#[test]
+fn check_stream_decrypt() {
+    use s2n_quic_core::endpoint;
+    use s2n_quic_dc::path::secret::{
+        keylog,
+        schedule::{Ciphersuite, Initiator, Secret},
+    };
+
+    // Initialize field IDs.
+    let _ = crate::field::get();
+
+    bolero::check!()
+        .with_type()
+        .for_each(|packet: &EncryptedStreamPacket| {
+            let mut packet = packet.clone();
+            let ciphersuite = if packet.use_aes_256 {
+                Ciphersuite::AES_GCM_256_SHA384
+            } else {
+                Ciphersuite::AES_GCM_128_SHA256
+            };
+            let sender = if packet.sender_is_client {
+                endpoint::Type::Client
+            } else {
+                endpoint::Type::Server
+            };
+            let initiator = if packet.initiator_is_sender {
+                Initiator::Local
+            } else {
+                Initiator::Remote
+            };
+
+            let secret = Secret::new(
+                ciphersuite,
+                s2n_quic_dc::SUPPORTED_VERSIONS[0],
+                sender,
+                &packet.export_secret,
+            );
+            let credentials = s2n_quic_dc::credentials::Credentials {
+                id: *secret.id(),
+                key_id: packet.key_id,
+            };
+
+            let (mut sealer, mut sealer_ku, _, _) =
+                secret.application_pair(packet.key_id, initiator);
+            for _ in 0..packet.key_updates {
+                (sealer, sealer_ku) = sealer_ku.next();
+            }
+            let key_phase = if packet.key_updates % 2 == 0 {
+                KeyPhase::Zero
+            } else {
+                KeyPhase::One
+            };
+            let sealer = PhasedKey(sealer, key_phase);
+
+            let sent_payload = packet.payload;
+            let mut buffer = vec![
+                0;
+                sent_payload.buffered_len()
+                    + s2n_quic_dc::packet::stream::encoder::MAX_HEADER_LEN
+                    + s2n_quic_dc::packet::stream::encoder::MAX_RETRANSMISSION_HEADER_LEN
+            ];
+            let length = s2n_quic_dc::packet::stream::encoder::encode(
+                EncoderBuffer::new(&mut buffer),
+                None,
+                packet.stream_id,
+                packet.packet_number,
+                packet.next_expected_control_packet,
+                VarInt::ZERO,
+                &mut Data::new(0),
+                VarInt::ZERO,
+                &(),
+                &mut packet.payload,
+                &sealer,
+                &credentials,
+                stream::Flags::empty(),
+            );
+
+            let secrets = Secrets::default();
+            if packet.know_secret {
+                let mut line = String::new();
+                use std::fmt::Write;
+                write!(line, "{}", secret.key_log_entry()).unwrap();
+                secrets.insert(keylog::Owned::parse(&line).unwrap());
+            }
+
+            let fields = crate::field::get();
+            let mut tracker = Tracker::default();
+            let mut info = String::new();
+
+            let mut buffer = unsafe { Buffer::new(ptr::null_mut(), &buffer[..length]) };
+            let tag: Parsed<packet::stream::Tag> = buffer.consume().unwrap();
+            assert!(
+                dissect::stream(&mut tracker, fields, &secrets, tag, &mut buffer, &mut info)
+                    .is_some()
+            );
+
+            let decrypted = tracker.take(fields.decrypted_payload);
+
+            if packet.know_secret && sent_payload.buffered_len() > 0 {
+                let Some(Field::Slice(decrypted)) = decrypted else {
+                    panic!("expected a decrypted payload");
+                };
+                assert_eq!(decrypted.len(), sent_payload.buffered_len());
+                sent_payload.receive_at(sent_payload.offset(), &[decrypted]);
+                assert!(info.contains("DECRYPTED"), "{info}");
+
+                // the decrypted payload is dissected as a STREAM frame
+                let offset = sent_payload.current_offset().as_u64();
+                let len = sent_payload.buffered_len() as u64;
+                let is_fin = sent_payload
+                    .final_offset()
+                    .is_some_and(|final_offset| final_offset.as_u64() == offset + len);
+                assert_eq!(
+                    tracker.remove(fields.stream_frame_offset),
+                    Field::Integer(offset)
+                );
+                assert_eq!(tracker.remove(fields.stream_frame_len), Field::Integer(len));
+                assert_eq!(
+                    tracker.remove(fields.stream_frame_fin),
+                    Field::Integer(is_fin as u64)
+                );
+            } else {
+                assert_eq!(decrypted, None);
+                assert!(!info.contains("DECRYPTED"), "{info}");
+                assert_eq!(tracker.take(fields.stream_frame_offset), None);
+            }
+        });
+}
+
+#[test]
+fn stream_decrypt_key_updates() {
+    use s2n_quic_core::endpoint;
+    use s2n_quic_dc::path::secret::{
+        keylog,
+        schedule::{Ciphersuite, Initiator, Secret},
+    };
+
+    let secret = Secret::new(
+        Ciphersuite::AES_GCM_128_SHA256,
+        s2n_quic_dc::SUPPORTED_VERSIONS[0],
+        endpoint::Type::Server,
+        &[42; 32],
+    );
+    let credentials = s2n_quic_dc::credentials::Credentials {
+        id: *secret.id(),
+        key_id: VarInt::from_u8(3),
+    };
+
+    let secrets = Secrets::default();
+    let mut line = String::new();
+    use std::fmt::Write;
+    write!(line, "{}", secret.key_log_entry()).unwrap();
+    secrets.insert(keylog::Owned::parse(&line).unwrap());
+
+    // packets are sent across several key updates, including one which is reordered across an
+    // update and one which skips an update
+    for (packet_number, key_updates) in [0u8, 1, 1, 2, 1, 3, 5].into_iter().enumerate() {
+        let (mut sealer, mut sealer_ku, _, _) =
+            secret.application_pair(credentials.key_id, Initiator::Remote);
+        for _ in 0..key_updates {
+            (sealer, sealer_ku) = sealer_ku.next();
+        }
+        let key_phase = if key_updates % 2 == 0 {
+            KeyPhase::Zero
+        } else {
+            KeyPhase::One
+        };
+        let sealer = PhasedKey(sealer, key_phase);
+
+        let mut payload = Data::new(16);
+        let mut buffer = vec![
+            0;
+            payload.buffered_len()
+                + s2n_quic_dc::packet::stream::encoder::MAX_HEADER_LEN
+                + s2n_quic_dc::packet::stream::encoder::MAX_RETRANSMISSION_HEADER_LEN
+        ];
+        let length = s2n_quic_dc::packet::stream::encoder::encode(
+            EncoderBuffer::new(&mut buffer),
+            None,
+            stream::Id::default(),
+            VarInt::from_u8(packet_number as u8),
+            VarInt::ZERO,
+            VarInt::ZERO,
+            &mut Data::new(0),
+            VarInt::ZERO,
+            &(),
+            &mut payload,
+            &sealer,
+            &credentials,
+            stream::Flags::empty(),
+        );
+
+        let decrypted = secrets.decrypt_stream(&buffer[..length]);
+        assert!(
+            decrypted.is_some(),
+            "packet {packet_number} after {key_updates} key updates"
+        );
+    }
+}
+
+#[path = "bin/generate-pcap.rs"]
+#[allow(dead_code)]
+mod generate_pcap;
+
+#[test]
+fn generated_stream_pcap() {
+    // Initialize field IDs.
+    let _ = crate::field::get();
+
+    let out_dir = std::env::temp_dir().join(format!("dcquic-pcap-{}", std::process::id()));
+    std::fs::create_dir_all(&out_dir).unwrap();
+    generate_pcap::streams(&out_dir);
+
+    let secrets = Secrets::from_path(out_dir.join("stream.keylog"));
+    let pcap = std::fs::read(out_dir.join("stream.pcap")).unwrap();
+
+    // skip the pcap file header
+    let mut records = &pcap[24..];
+    let mut packets = 0;
+
+    while !records.is_empty() {
+        let (record_header, rest) = records.split_at(16);
+        let len = u32::from_ne_bytes(record_header[8..12].try_into().unwrap()) as usize;
+        let (frame, rest) = rest.split_at(len);
+        records = rest;
+
+        // skip the ethernet, IPv4 and UDP headers
+        let packet = &frame[14 + 20 + 8..];
+
+        let fields = crate::field::get();
+        let mut tracker = Tracker::default();
+        let mut info = String::new();
+        let mut buffer = unsafe { Buffer::new(ptr::null_mut(), packet) };
+        let tag = buffer.consume().unwrap();
+        dissect::segment(
+            &mut tracker,
+            &mut (),
+            fields,
+            &secrets,
+            tag,
+            &mut buffer,
+            &mut info,
+            dissect::Protocol::Udp,
+        )
+        .unwrap();
+        assert!(buffer.is_empty());
+        assert!(info.contains("DECRYPTED"), "{info}");
+
+        let Field::Slice(payload) = tracker.remove(fields.decrypted_payload) else {
+            panic!("expected a decrypted payload");
+        };
+        let payload = String::from_utf8(payload).unwrap();
+        assert!(payload.contains("for stream"), "{payload:?}");
+
+        packets += 1;
+    }
+
+    assert!(packets > 0);
+
+    std::fs::remove_dir_all(&out_dir).unwrap();
+}
+
 #[derive(Clone, Debug, bolero::TypeGenerator)]
 struct DatagramPacket {
     credentials: s2n_quic_dc::credentials::Credentials,
@@ -548,7 +840,14 @@ fn random_stream_packets() {
             return;
         };
         // May fail to parse, but shouldn't panic.
-        let _ = dissect::stream(&mut tracker, fields, tag, &mut buffer, &mut ());
+        let _ = dissect::stream(
+            &mut tracker,
+            fields,
+            &Secrets::default(),
+            tag,
+            &mut buffer,
+            &mut (),
+        );
     });
 }
 
@@ -569,6 +868,7 @@ fn random_segments() {
             &mut tracker,
             &mut (),
             fields,
+            &Secrets::default(),
             tag,
             &mut buffer,
             &mut (),
@@ -715,6 +1015,30 @@ impl crate::wireshark::Item for () {
     }
 }
 
+/// Reports a specific key phase for a key derived after a number of key updates
+struct PhasedKey(s2n_quic_dc::crypto::awslc::seal::Application, KeyPhase);
+
+impl s2n_quic_dc::crypto::seal::Application for PhasedKey {
+    fn key_phase(&self) -> KeyPhase {
+        self.1
+    }
+
+    fn tag_len(&self) -> usize {
+        self.0.tag_len()
+    }
+
+    fn encrypt(
+        &self,
+        packet_number: u64,
+        header: &[u8],
+        extra_payload: Option<&[u8]>,
+        payload_and_tag: &mut [u8],
+    ) {
+        self.0
+            .encrypt(packet_number, header, extra_payload, payload_and_tag)
+    }
+}
+
 struct TestKey(KeyPhase);
 
 impl s2n_quic_dc::crypto::seal::Application for TestKey {
@@ -918,6 +918,30 @@ enum RetryDiscardReason<'a> {
     InvalidIntegrityTag,
 }
 
+/// The outcome of a connection attempt which wasn't allowed by the endpoint limiter
+enum ConnectionAttemptOutcome {
+    /// The peer was asked to validate its address with a Retry packet
+    Retry,
+    /// The connection attempt was silently dropped
+    Drop,
+    /// The connection attempt was rejected with a CONNECTION_CLOSE
+    Close,
+}
+
+/// What caused the TLS provider to reload its configuration
+enum TlsConfigReloadCause {
+    /// The application requested the reload
+    Requested,
+    /// The certificate or key files were modified
+    FileChanged,
+}
+
+/// The family of a socket address
+enum AddressFamily {
+    IpV4,
+    IpV6,
+}
+
 enum MigrationDenyReason {
     BlockedPort,
     PortScopeChanged,
@@ -455,6 +455,17 @@ struct DcPathCreated<'a> {
     path: &'a (dyn core::any::Any + Send + 'static),
 }
 
+#[event("connectivity:happy_eyeballs_winner")]
+/// The connection won a Happy Eyeballs race and was returned to the application
+struct HappyEyeballsWinner<'a> {
+    remote_address: SocketAddress<'a>,
+    #[nominal_counter("family")]
+    address_family: AddressFamily,
+    /// The number of connection attempts started during the race, including the winner
+    #[measure("attempts")]
+    attempts: u32,
+}
+
 // NOTE - This event MUST come last, since connection-level aggregation depends on it
 #[event("connectivity:connection_closed")]
 //= https://tools.ietf.org/id/draft-marx-qlog-event-definitions-quic-h3-02#5.1.3
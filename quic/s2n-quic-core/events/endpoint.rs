@@ -75,3 +75,26 @@ struct EndpointConnectionAttemptFailed {
     #[nominal_counter("error")]
     error: crate::connection::Error,
 }
+
+#[event("transport:connection_attempt_limited")]
+#[subject(endpoint)]
+/// A connection attempt was deferred or rejected by the endpoint limiter
+struct EndpointConnectionAttemptLimited<'a> {
+    remote_address: SocketAddress<'a>,
+    #[nominal_counter("outcome")]
+    outcome: ConnectionAttemptOutcome,
+}
+
+#[event("security:tls_config_reloaded")]
+#[subject(endpoint)]
+/// The TLS provider attempted to reload its certificates and trust store
+///
+/// If the reload failed, the previous configuration continues to be used.
+struct TlsConfigReloaded<'a> {
+    #[nominal_counter("cause")]
+    cause: TlsConfigReloadCause,
+    #[bool_counter("success")]
+    success: bool,
+    /// The reason the reload failed
+    error: Option<&'a str>,
+}
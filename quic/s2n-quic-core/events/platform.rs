@@ -96,6 +96,54 @@ impl From<PlatformRxError> for std::io::Error {
     }
 }
 
+#[event("platform:rx_queue_overflow")]
+#[subject(endpoint)]
+/// Emitted when the kernel dropped datagrams because the socket receive buffer was full
+///
+/// These datagrams never reach the endpoint, so without this event the drops are
+/// indistinguishable from loss in the network.
+struct PlatformRxQueueOverflow {
+    /// The number of datagrams dropped since the last event
+    #[measure("dropped")]
+    #[counter("dropped.total")]
+    dropped: usize,
+}
+
+#[event("platform:socket_memory")]
+#[subject(endpoint)]
+/// Emitted periodically with the memory usage of the socket
+struct PlatformSocketMemory {
+    /// The number of bytes allocated to datagrams waiting in the receive queue
+    #[measure("rx.allocated", Bytes)]
+    rx_allocated: usize,
+
+    /// The size of the receive buffer
+    #[measure("rx.capacity", Bytes)]
+    rx_capacity: usize,
+
+    /// The number of bytes allocated to datagrams waiting in the send queue
+    #[measure("tx.allocated", Bytes)]
+    tx_allocated: usize,
+
+    /// The size of the send buffer
+    #[measure("tx.capacity", Bytes)]
+    tx_capacity: usize,
+
+    /// The total number of datagrams the kernel has dropped on the socket
+    dropped: usize,
+}
+
+#[event("platform:xdp_fallback")]
+#[subject(endpoint)]
+/// Emitted when AF_XDP sockets could not be configured and the endpoint has fallen back to
+/// standard UDP sockets
+struct PlatformXdpFallback<'a> {
+    /// A description of the error that prevented AF_XDP from being used
+    reason: &'a str,
+    /// The error code returned by the platform, if any
+    errno: Option<i32>,
+}
+
 #[event("platform:feature_configured")]
 #[subject(endpoint)]
 /// Emitted when a platform feature is configured
@@ -115,6 +163,14 @@ enum PlatformFeatureConfiguration {
     Gro { enabled: bool },
     /// Emitted when ECN support is configured
     Ecn { enabled: bool },
+    /// Emitted when reporting of receive queue overflows is configured
+    RxQueueOverflow { enabled: bool },
+    /// Emitted when pacing offload to the kernel is configured
+    PacingOffload { enabled: bool },
+    /// Emitted when AF_XDP sockets are configured
+    ///
+    /// If this is disabled, the endpoint has fallen back to standard UDP sockets.
+    Xdp { enabled: bool },
     /// Emitted when the base maximum transmission unit is configured
     BaseMtu { mtu: u16 },
     /// Emitted when the initial maximum transmission unit is configured
@@ -3,7 +3,7 @@
 #[cfg(feature = "alloc")]
 use crate::application::ServerName;
 use crate::{
-    ack,
+    ack, application,
     event::{api::SocketAddress, IntoEvent},
     inet, recovery, stream,
     transport::parameters::{
@@ -40,6 +40,9 @@ pub const ANTI_AMPLIFICATION_MULTIPLIER: u8 = 3;
 
 pub const DEFAULT_STREAM_BATCH_SIZE: u8 = 1;
 
+/// The smallest amount of out-of-order CRYPTO data an endpoint is allowed to limit buffering to
+pub const MIN_CRYPTO_BUFFER_SIZE: u32 = 4096;
+
 #[non_exhaustive]
 #[derive(Debug)]
 pub struct ConnectionInfo<'a> {
@@ -80,7 +83,7 @@ impl<'a> HandshakeInfo<'a> {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Limits {
     pub(crate) max_idle_timeout: MaxIdleTimeout,
     pub(crate) data_window: InitialMaxData,
@@ -104,6 +107,13 @@ pub struct Limits {
     pub(crate) migration_support: MigrationSupport,
     pub(crate) anti_amplification_multiplier: u8,
     pub(crate) stream_batch_size: u8,
+    pub(crate) max_received_bytes: Option<u64>,
+    pub(crate) max_stream_lifetime: Option<Duration>,
+    pub(crate) min_receive_throughput: Option<u64>,
+    pub(crate) receive_throughput_grace_period: Duration,
+    pub(crate) max_crypto_buffer_size: Option<u32>,
+    pub(crate) quota_error_code: application::Error,
+    pub(crate) dscp: inet::Dscp,
 }
 
 impl Default for Limits {
@@ -151,6 +161,13 @@ impl Limits {
             migration_support: MigrationSupport::RECOMMENDED,
             anti_amplification_multiplier: ANTI_AMPLIFICATION_MULTIPLIER,
             stream_batch_size: DEFAULT_STREAM_BATCH_SIZE,
+            max_received_bytes: None,
+            max_stream_lifetime: None,
+            min_receive_throughput: None,
+            receive_throughput_grace_period: Duration::ZERO,
+            max_crypto_buffer_size: None,
+            quota_error_code: application::Error::UNKNOWN,
+            dscp: inet::Dscp::DEFAULT,
         }
     }
 
@@ -314,6 +331,89 @@ impl Limits {
         Ok(self)
     }
 
+    /// Limits the total number of bytes a connection may receive (default: unlimited)
+    ///
+    /// The count includes all UDP payload bytes received on any path. Once the quota is exceeded,
+    /// the connection is closed with the configured [quota error code](Self::with_quota_error_code).
+    pub fn with_max_received_bytes(mut self, value: u64) -> Result<Self, ValidationError> {
+        self.max_received_bytes = Some(value);
+        Ok(self)
+    }
+
+    /// Limits how long any single stream may stay open (default: unlimited)
+    ///
+    /// Stream lifetimes are checked once per second, so a stream may outlive the limit by up to
+    /// a second before the connection is closed with the configured
+    /// [quota error code](Self::with_quota_error_code).
+    pub fn with_max_stream_lifetime(mut self, value: Duration) -> Result<Self, ValidationError> {
+        ensure!(
+            value > Duration::ZERO,
+            Err(ValidationError("provided value must be greater than zero"))
+        );
+
+        self.max_stream_lifetime = Some(value);
+        Ok(self)
+    }
+
+    /// Requires the peer to deliver at least `bytes_per_second` while it owes stream data
+    /// (default: disabled)
+    ///
+    /// The throughput is measured once per second, starting after `grace_period` has elapsed
+    /// since the connection was created. The peer owes data while the application is blocked
+    /// reading a stream or the peer has left gaps in the data it sent. Connections that fall
+    /// below the minimum during that time are closed with the configured
+    /// [quota error code](Self::with_quota_error_code).
+    pub fn with_min_receive_throughput(
+        mut self,
+        bytes_per_second: u64,
+        grace_period: Duration,
+    ) -> Result<Self, ValidationError> {
+        ensure!(
+            bytes_per_second > 0,
+            Err(ValidationError(
+                "bytes_per_second must be greater than zero"
+            ))
+        );
+
+        self.min_receive_throughput = Some(bytes_per_second);
+        self.receive_throughput_grace_period = grace_period;
+        Ok(self)
+    }
+
+    /// Limits the number of out-of-order CRYPTO bytes buffered in each packet space
+    /// (default: unlimited)
+    ///
+    /// Peers exceeding the limit are closed with a `CRYPTO_BUFFER_EXCEEDED` transport error.
+    /// The value must be at least 4096 bytes.
+    pub fn with_max_crypto_buffer_size(mut self, value: u32) -> Result<Self, ValidationError> {
+        ensure!(
+            value >= MIN_CRYPTO_BUFFER_SIZE,
+            Err(ValidationError("provided value must be at least 4096"))
+        );
+
+        self.max_crypto_buffer_size = Some(value);
+        Ok(self)
+    }
+
+    /// Sets the application error code used when closing a connection that exceeded a quota
+    /// (default: 0)
+    pub fn with_quota_error_code(
+        mut self,
+        value: application::Error,
+    ) -> Result<Self, ValidationError> {
+        self.quota_error_code = value;
+        Ok(self)
+    }
+
+    /// Sets the DSCP marking for all of the packets sent on the connection (default: 0)
+    ///
+    /// The code point is written to the IPv4 TOS or IPv6 Traffic Class field alongside the ECN
+    /// markings, which allows the network to prioritize the connection's traffic.
+    pub fn with_dscp(mut self, value: inet::Dscp) -> Result<Self, ValidationError> {
+        self.dscp = value;
+        Ok(self)
+    }
+
     #[cfg(feature = "unstable-limits")]
     setter!(
         /// Limit how many bytes the Server sends prior to address validation (default: 3)
@@ -422,6 +522,43 @@ impl Limits {
     pub fn stream_batch_size(&self) -> u8 {
         self.stream_batch_size
     }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn max_received_bytes(&self) -> Option<u64> {
+        self.max_received_bytes
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn max_stream_lifetime(&self) -> Option<Duration> {
+        self.max_stream_lifetime
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn min_receive_throughput(&self) -> Option<(u64, Duration)> {
+        self.min_receive_throughput
+            .map(|rate| (rate, self.receive_throughput_grace_period))
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn max_crypto_buffer_size(&self) -> Option<u32> {
+        self.max_crypto_buffer_size
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn quota_error_code(&self) -> application::Error {
+        self.quota_error_code
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn dscp(&self) -> inet::Dscp {
+        self.dscp
+    }
 }
 
 #[must_use]
@@ -436,6 +573,11 @@ impl<'a> UpdatableLimits<'a> {
     pub fn with_stream_batch_size(&mut self, size: u8) {
         self.0.stream_batch_size = size;
     }
+
+    /// Updates the DSCP marking for the packets sent after the handshake
+    pub fn with_dscp(&mut self, dscp: inet::Dscp) {
+        self.0.dscp = dscp;
+    }
 }
 
 /// Creates limits for a given connection
@@ -464,6 +606,7 @@ impl Limiter for Limits {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::varint::VarInt;
 
     // Local max data limits should be <= u32::MAX
     #[test]
@@ -492,4 +635,40 @@ mod tests {
         updatable_limits.with_stream_batch_size(new_size);
         assert_eq!(limits.stream_batch_size, new_size);
     }
+
+    #[test]
+    fn quota_validation() {
+        let limits = Limits::default();
+        assert!(limits.with_max_stream_lifetime(Duration::ZERO).is_err());
+        assert!(limits
+            .with_min_receive_throughput(0, Duration::from_secs(1))
+            .is_err());
+        assert!(limits
+            .with_max_crypto_buffer_size(MIN_CRYPTO_BUFFER_SIZE - 1)
+            .is_err());
+
+        let limits = limits
+            .with_max_received_bytes(1000)
+            .unwrap()
+            .with_max_stream_lifetime(Duration::from_secs(5))
+            .unwrap()
+            .with_min_receive_throughput(100, Duration::from_secs(2))
+            .unwrap()
+            .with_max_crypto_buffer_size(MIN_CRYPTO_BUFFER_SIZE)
+            .unwrap()
+            .with_quota_error_code(application::Error::from(VarInt::from_u8(7)))
+            .unwrap();
+
+        assert_eq!(limits.max_received_bytes(), Some(1000));
+        assert_eq!(limits.max_stream_lifetime(), Some(Duration::from_secs(5)));
+        assert_eq!(
+            limits.min_receive_throughput(),
+            Some((100, Duration::from_secs(2)))
+        );
+        assert_eq!(
+            limits.max_crypto_buffer_size(),
+            Some(MIN_CRYPTO_BUFFER_SIZE)
+        );
+        assert_eq!(*limits.quota_error_code(), 7);
+    }
 }
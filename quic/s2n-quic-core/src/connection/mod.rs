@@ -5,7 +5,9 @@ pub mod close;
 pub mod error;
 pub mod id;
 pub mod limits;
+pub mod stats;
 
 pub use error::{Error, ProcessingError};
 pub use id::{InitialId, LocalId, PeerId, UnboundedId};
 pub use limits::Limits;
+pub use stats::Stats;
@@ -0,0 +1,167 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Point-in-time statistics for a connection
+
+use crate::inet::{ExplicitCongestionNotification, SocketAddress};
+use core::{ops, time::Duration};
+
+/// A snapshot of a connection's statistics
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Stats {
+    /// Statistics for the path the connection is currently using
+    pub path: Path,
+    /// Counters accumulated across all of the connection's paths
+    pub counters: Counters,
+    /// The number of streams in each state
+    pub streams: Streams,
+}
+
+/// Statistics for a single network path
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Path {
+    pub local_address: SocketAddress,
+    pub remote_address: SocketAddress,
+    /// The maximum size of a datagram that can be sent on the path
+    pub mtu: u16,
+    pub rtt: Rtt,
+    /// The congestion window, in bytes
+    pub congestion_window: u32,
+    /// The number of congestion controlled bytes which haven't been acknowledged or declared lost
+    pub bytes_in_flight: u32,
+    /// The rate at which packets are paced, in bytes per second
+    ///
+    /// This is `None` if the congestion controller isn't pacing packets.
+    pub pacing_rate: Option<u64>,
+    /// Counters for the packets sent and received on the path
+    pub counters: Counters,
+}
+
+/// The values of a path's round-trip time estimator
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Rtt {
+    /// The most recent RTT sample
+    pub latest: Duration,
+    /// The minimum RTT sample observed, adjusted for persistent congestion
+    pub min: Duration,
+    /// The exponentially-weighted moving average of the RTT samples
+    pub smoothed: Duration,
+    /// The mean deviation of the RTT samples
+    pub variance: Duration,
+}
+
+/// Counters for the packets sent and received by a connection
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Counters {
+    pub packets_sent: u64,
+    pub bytes_sent: u64,
+    /// The number of packets which were successfully processed
+    pub packets_received: u64,
+    /// The number of bytes in datagrams received for the connection
+    pub bytes_received: u64,
+    pub packets_lost: u64,
+    /// The number of congestion controlled bytes in lost packets
+    pub bytes_lost: u64,
+    /// The ECN markings of sent packets
+    pub ecn_sent: EcnCounts,
+    /// The ECN markings of received packets
+    pub ecn_received: EcnCounts,
+}
+
+impl ops::AddAssign for Counters {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.packets_sent += rhs.packets_sent;
+        self.bytes_sent += rhs.bytes_sent;
+        self.packets_received += rhs.packets_received;
+        self.bytes_received += rhs.bytes_received;
+        self.packets_lost += rhs.packets_lost;
+        self.bytes_lost += rhs.bytes_lost;
+        self.ecn_sent += rhs.ecn_sent;
+        self.ecn_received += rhs.ecn_received;
+    }
+}
+
+/// The number of packets with each ECN codepoint
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EcnCounts {
+    pub ect_0: u64,
+    pub ect_1: u64,
+    pub ce: u64,
+}
+
+impl EcnCounts {
+    /// Increments the count for the given explicit congestion notification
+    #[inline]
+    pub fn increment(&mut self, ecn: ExplicitCongestionNotification) {
+        match ecn {
+            ExplicitCongestionNotification::Ect0 => self.ect_0 += 1,
+            ExplicitCongestionNotification::Ect1 => self.ect_1 += 1,
+            ExplicitCongestionNotification::Ce => self.ce += 1,
+            ExplicitCongestionNotification::NotEct => {}
+        }
+    }
+}
+
+impl ops::AddAssign for EcnCounts {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.ect_0 += rhs.ect_0;
+        self.ect_1 += rhs.ect_1;
+        self.ce += rhs.ce;
+    }
+}
+
+/// The number of open streams in each state
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Streams {
+    /// The number of bidirectional streams opened by the local endpoint
+    pub local_bidirectional: u64,
+    /// The number of unidirectional streams opened by the local endpoint
+    pub local_unidirectional: u64,
+    /// The number of bidirectional streams opened by the peer
+    pub remote_bidirectional: u64,
+    /// The number of unidirectional streams opened by the peer
+    pub remote_unidirectional: u64,
+    /// The states of the sending parts of the streams
+    pub send: SendStreams,
+    /// The states of the receiving parts of the streams
+    pub receive: ReceiveStreams,
+}
+
+/// The number of sending parts of streams in each state
+///
+/// See [RFC 9000 Section 3.1](https://www.rfc-editor.org/rfc/rfc9000#section-3.1).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SendStreams {
+    /// Streams in the "Ready" or "Send" states, which are still accepting data
+    pub send: u64,
+    /// Streams which have sent all of their data and are waiting for it to be acknowledged
+    pub data_sent: u64,
+    /// Streams which have had all of their data acknowledged
+    pub data_recvd: u64,
+    /// Streams which were reset and are waiting for the reset to be acknowledged
+    pub reset_sent: u64,
+    /// Streams which were reset and the reset was acknowledged
+    pub reset_recvd: u64,
+}
+
+/// The number of receiving parts of streams in each state
+///
+/// See [RFC 9000 Section 3.2](https://www.rfc-editor.org/rfc/rfc9000#section-3.2).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ReceiveStreams {
+    /// Streams in the "Recv", "Size Known" or "Data Recvd" states
+    pub recv: u64,
+    /// Streams which have had all of their data read by the application
+    pub data_read: u64,
+    /// Streams which were reset by the peer
+    pub reset: u64,
+}
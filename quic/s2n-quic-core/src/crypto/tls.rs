@@ -17,6 +17,9 @@ pub mod testing;
 #[cfg(all(feature = "alloc", any(test, feature = "testing")))]
 pub mod null;
 
+#[cfg(feature = "std")]
+pub mod reload;
+
 #[cfg(feature = "alloc")]
 pub mod slow_tls;
 
@@ -144,6 +147,10 @@ pub trait Context<Crypto: crate::crypto::CryptoSuite> {
         named_group: NamedGroup,
     ) -> Result<(), crate::transport::Error>;
 
+    /// Called with the DER-encoded SubjectPublicKeyInfo of the peer when it authenticated with
+    /// a [raw public key](https://www.rfc-editor.org/rfc/rfc7250)
+    fn on_peer_public_key(&mut self, public_key: Bytes) -> Result<(), crate::transport::Error>;
+
     //= https://www.rfc-editor.org/rfc/rfc9001#section-4.1.1
     //# The TLS handshake is considered complete when the
     //# TLS stack has reported that the handshake is complete.  This happens
@@ -204,10 +211,109 @@ pub trait Endpoint: 'static + Sized + Send {
         server_name: crate::application::ServerName,
     ) -> Self::Session;
 
+    /// Creates a client session with per-connection overrides
+    ///
+    /// The default implementation only supports empty options. Endpoints that can apply the
+    /// overrides should implement this function and return an error for any override they can't
+    /// apply, rather than ignoring it.
+    fn new_client_session_with_options<Params: s2n_codec::EncoderValue>(
+        &mut self,
+        transport_parameters: &Params,
+        server_name: crate::application::ServerName,
+        options: &ClientOptions,
+    ) -> Result<Self::Session, Error> {
+        if !options.is_empty() {
+            return Err(Error::INTERNAL_ERROR
+                .with_reason("the TLS provider does not support per-connection options"));
+        }
+
+        Ok(self.new_client_session(transport_parameters, server_name))
+    }
+
+    /// Publishes any events from the endpoint, such as the outcome of reloading its certificates
+    #[inline]
+    fn publish_events<Pub: crate::event::EndpointPublisher>(&mut self, publisher: &mut Pub) {
+        let _ = publisher;
+    }
+
     /// The maximum length of a tag for any algorithm that may be negotiated
     fn max_tag_length(&self) -> usize;
 }
 
+/// Per-connection overrides for a client TLS session
+///
+/// Any value that isn't set falls back to the configuration of the TLS provider.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ClientOptions {
+    /// The application protocols (ALPN) to offer to the server, in order of preference
+    pub application_protocols: Option<Vec<Bytes>>,
+    /// The certificate and private key to present if the server requests client authentication
+    pub identity: Option<ClientIdentity>,
+    /// A session ticket from a previous connection to resume
+    pub session_ticket: Option<Bytes>,
+}
+
+#[cfg(feature = "alloc")]
+impl ClientOptions {
+    /// Returns `true` if no overrides have been set
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    #[must_use]
+    pub fn with_application_protocols<P: IntoIterator<Item = I>, I: Into<Bytes>>(
+        mut self,
+        protocols: P,
+    ) -> Self {
+        self.application_protocols = Some(protocols.into_iter().map(Into::into).collect());
+        self
+    }
+
+    #[must_use]
+    pub fn with_identity(mut self, identity: ClientIdentity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    #[must_use]
+    pub fn with_session_ticket<T: Into<Bytes>>(mut self, ticket: T) -> Self {
+        self.session_ticket = Some(ticket.into());
+        self
+    }
+}
+
+/// A PEM-encoded certificate chain and private key used for client authentication
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ClientIdentity {
+    pub certificate_chain: Bytes,
+    pub private_key: Bytes,
+}
+
+#[cfg(feature = "alloc")]
+impl ClientIdentity {
+    #[inline]
+    pub fn new<C: Into<Bytes>, K: Into<Bytes>>(certificate_chain: C, private_key: K) -> Self {
+        Self {
+            certificate_chain: certificate_chain.into(),
+            private_key: private_key.into(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Debug for ClientIdentity {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        // don't leak the private key into logs
+        f.debug_struct("ClientIdentity")
+            .field("certificate_chain", &self.certificate_chain)
+            .finish_non_exhaustive()
+    }
+}
+
 #[cfg(feature = "alloc")]
 pub trait Session: crate::crypto::CryptoSuite + Sized + Send + Debug {
     fn poll<C: Context<Self>>(
@@ -0,0 +1,289 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reloads a TLS configuration from PEM files while the endpoint is running
+//!
+//! A [`Reloader`] builds a configuration from the contents of [`Files`] and hands it out to new
+//! connections. The files are reloaded when the application calls [`Reloader::reload`] or when
+//! a background thread notices they were modified. If a reload fails, the previous configuration
+//! continues to be used.
+//! Connections which have already started keep the configuration they were created with.
+
+use crate::event;
+use alloc::sync::Arc;
+use core::{
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+use std::{path::PathBuf, sync::Mutex, thread, time::SystemTime};
+
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+
+/// The default interval at which files are checked for modifications
+const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The PEM files which a TLS configuration is loaded from
+#[derive(Clone, Debug)]
+pub struct Files {
+    certificate: PathBuf,
+    private_key: PathBuf,
+    trusted_certificate: Option<PathBuf>,
+    watch_interval: Option<Duration>,
+}
+
+impl Files {
+    /// Loads the certificate chain and private key from the provided paths
+    pub fn new<C: Into<PathBuf>, K: Into<PathBuf>>(certificate: C, private_key: K) -> Self {
+        Self {
+            certificate: certificate.into(),
+            private_key: private_key.into(),
+            trusted_certificate: None,
+            watch_interval: Some(DEFAULT_WATCH_INTERVAL),
+        }
+    }
+
+    /// Loads the certificates which are trusted to authenticate the peer from the provided path
+    #[must_use]
+    pub fn with_trusted_certificate<T: Into<PathBuf>>(mut self, trusted_certificate: T) -> Self {
+        self.trusted_certificate = Some(trusted_certificate.into());
+        self
+    }
+
+    /// Sets how often the files are checked for modifications (default: 5 seconds)
+    ///
+    /// The files are checked on a background thread which exits once every handle to the
+    /// [`Reloader`] has been dropped.
+    #[must_use]
+    pub fn with_watch_interval(mut self, interval: Duration) -> Self {
+        self.watch_interval = Some(interval);
+        self
+    }
+
+    /// Only reloads the files when [`Reloader::reload`] is called
+    #[must_use]
+    pub fn without_watching(mut self) -> Self {
+        self.watch_interval = None;
+        self
+    }
+
+    fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+        [&self.certificate, &self.private_key]
+            .into_iter()
+            .chain(self.trusted_certificate.as_ref())
+    }
+
+    fn read(&self) -> Result<Pem, Error> {
+        let read = |path: &PathBuf| {
+            std::fs::read_to_string(path)
+                .map_err(|err| format!("could not read {}: {err}", path.display()))
+        };
+
+        Ok(Pem {
+            certificate: read(&self.certificate)?,
+            private_key: read(&self.private_key)?,
+            trusted_certificate: self.trusted_certificate.as_ref().map(read).transpose()?,
+        })
+    }
+
+    fn modified(&self) -> Vec<Option<SystemTime>> {
+        self.paths()
+            .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+            .collect()
+    }
+}
+
+/// The contents of [`Files`] which a configuration is built from
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct Pem {
+    pub certificate: String,
+    pub private_key: String,
+    pub trusted_certificate: Option<String>,
+}
+
+impl fmt::Debug for Pem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // don't leak the private key into logs
+        f.debug_struct("Pem")
+            .field("certificate", &self.certificate)
+            .field("trusted_certificate", &self.trusted_certificate)
+            .finish_non_exhaustive()
+    }
+}
+
+type Build<C> = dyn Fn(&Pem) -> Result<C, Error> + Send + Sync;
+
+/// Holds the most recently loaded configuration
+///
+/// Cloning the reloader returns a handle to the same configuration, which can be used to reload
+/// it after it has been passed to an endpoint.
+pub struct Reloader<C> {
+    state: Arc<State<C>>,
+    watcher: Option<Arc<Watcher>>,
+}
+
+struct State<C> {
+    files: Files,
+    build: Box<Build<C>>,
+    current: Mutex<Arc<C>>,
+    modified: Mutex<Vec<Option<SystemTime>>>,
+    outcomes: Mutex<Vec<Outcome>>,
+    has_outcomes: AtomicBool,
+}
+
+/// Stops the thread watching the files when the last [`Reloader`] is dropped
+struct Watcher {
+    thread: thread::Thread,
+    is_stopped: Arc<AtomicBool>,
+}
+
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        self.is_stopped.store(true, Ordering::Release);
+        self.thread.unpark();
+    }
+}
+
+struct Outcome {
+    cause: event::builder::TlsConfigReloadCause,
+    error: Option<String>,
+}
+
+impl<C> Clone for Reloader<C> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            watcher: self.watcher.clone(),
+        }
+    }
+}
+
+impl<C> fmt::Debug for Reloader<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Reloader")
+            .field("files", &self.state.files)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: 'static + Send + Sync> Reloader<C> {
+    /// Loads the files and builds the initial configuration with `build`
+    ///
+    /// Returns an error if the initial configuration can't be built or the thread watching the
+    /// files can't be started.
+    pub fn new<B>(files: Files, build: B) -> Result<Self, Error>
+    where
+        B: 'static + Fn(&Pem) -> Result<C, Error> + Send + Sync,
+    {
+        // read the modification times first so changes made while loading are picked up
+        let modified = files.modified();
+        let config = build(&files.read()?)?;
+
+        let state = Arc::new(State {
+            files,
+            build: Box::new(build),
+            current: Mutex::new(Arc::new(config)),
+            modified: Mutex::new(modified),
+            outcomes: Mutex::new(Vec::new()),
+            has_outcomes: AtomicBool::new(false),
+        });
+
+        let watcher = match state.files.watch_interval {
+            Some(interval) => Some(Arc::new(State::watch(state.clone(), interval)?)),
+            None => None,
+        };
+
+        Ok(Self { state, watcher })
+    }
+
+    /// Reloads the files and replaces the configuration used for new connections
+    ///
+    /// If the files can't be loaded, the previous configuration is kept and the error is
+    /// returned.
+    pub fn reload(&self) -> Result<(), Error> {
+        let mut modified = self.state.modified.lock().unwrap();
+        *modified = self.state.files.modified();
+        self.state
+            .load(event::builder::TlsConfigReloadCause::Requested)
+    }
+
+    /// Returns the configuration to use for a new connection
+    ///
+    /// This never touches the file system, so it's cheap to call from the endpoint.
+    pub fn config(&self) -> Arc<C> {
+        self.state.current.lock().unwrap().clone()
+    }
+
+    /// Publishes the outcome of any reloads since the last call
+    pub fn publish_events(&self, publisher: &mut dyn event::EndpointPublisher) {
+        // avoid taking the lock for every connection
+        if !self.state.has_outcomes.swap(false, Ordering::Acquire) {
+            return;
+        }
+
+        let outcomes = core::mem::take(&mut *self.state.outcomes.lock().unwrap());
+        for outcome in outcomes {
+            publisher.on_tls_config_reloaded(event::builder::TlsConfigReloaded {
+                cause: outcome.cause,
+                success: outcome.error.is_none(),
+                error: outcome.error.as_deref(),
+            });
+        }
+    }
+}
+
+impl<C: 'static + Send + Sync> State<C> {
+    /// Spawns a thread which reloads the files whenever they are modified
+    fn watch(state: Arc<Self>, interval: Duration) -> Result<Watcher, Error> {
+        let is_stopped = Arc::new(AtomicBool::new(false));
+        let handle = thread::Builder::new()
+            .name("s2n-quic-tls-reload".into())
+            .spawn({
+                let is_stopped = is_stopped.clone();
+                move || loop {
+                    // the watcher unparks the thread when it's dropped
+                    thread::park_timeout(interval);
+                    if is_stopped.load(Ordering::Acquire) {
+                        return;
+                    }
+                    state.poll_watch();
+                }
+            })?;
+
+        Ok(Watcher {
+            thread: handle.thread().clone(),
+            is_stopped,
+        })
+    }
+
+    fn poll_watch(&self) {
+        let mut last_modified = self.modified.lock().unwrap();
+        let modified = self.files.modified();
+        if modified == *last_modified {
+            return;
+        }
+        *last_modified = modified;
+
+        // the error is reported with the event
+        let _ = self.load(event::builder::TlsConfigReloadCause::FileChanged);
+    }
+
+    fn load(&self, cause: event::builder::TlsConfigReloadCause) -> Result<(), Error> {
+        // only the swap happens under the lock so readers never wait on the file system
+        let result = self.files.read().and_then(|pem| (self.build)(&pem));
+
+        let result = result.map(|config| {
+            *self.current.lock().unwrap() = Arc::new(config);
+        });
+
+        let error = result.as_ref().err().map(|error| error.to_string());
+        self.outcomes.lock().unwrap().push(Outcome { cause, error });
+        self.has_outcomes.store(true, Ordering::Release);
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests;
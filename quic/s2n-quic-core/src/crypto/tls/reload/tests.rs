@@ -0,0 +1,146 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use std::{
+    fs,
+    path::Path,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A directory containing a certificate and key file which is removed on drop
+struct Dir(PathBuf);
+
+impl Dir {
+    fn new() -> Self {
+        static ID: AtomicUsize = AtomicUsize::new(0);
+        let id = ID.fetch_add(1, Ordering::Relaxed);
+        let path =
+            std::env::temp_dir().join(format!("s2n-quic-reload-{}-{id}", std::process::id()));
+        fs::create_dir_all(&path).unwrap();
+        let dir = Self(path);
+        dir.write("cert", "cert-1");
+        dir.write("key", "key-1");
+        dir
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.0.join(name)
+    }
+
+    fn write(&self, name: &str, contents: &str) {
+        fs::write(self.path(name), contents).unwrap();
+    }
+
+    fn files(&self) -> Files {
+        Files::new(self.path("cert"), self.path("key"))
+    }
+}
+
+impl Drop for Dir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// "Builds" a configuration by concatenating the files, rejecting empty certificates
+fn build(pem: &Pem) -> Result<String, Error> {
+    if pem.certificate.is_empty() {
+        return Err("empty certificate".into());
+    }
+    Ok(format!("{}/{}", pem.certificate, pem.private_key))
+}
+
+fn publish(reloader: &Reloader<String>) -> event::testing::Publisher {
+    let mut publisher = event::testing::Publisher::no_snapshot();
+    reloader.publish_events(&mut publisher);
+    publisher
+}
+
+#[test]
+fn reload_on_demand() {
+    let dir = Dir::new();
+    let reloader = Reloader::new(dir.files().without_watching(), build).unwrap();
+    assert_eq!(*reloader.config(), "cert-1/key-1");
+
+    // the initial load doesn't emit an event
+    assert_eq!(publish(&reloader).tls_config_reloaded, 0);
+
+    dir.write("cert", "cert-2");
+    dir.write("key", "key-2");
+
+    // nothing changes until the reload is requested
+    assert_eq!(*reloader.config(), "cert-1/key-1");
+
+    reloader.reload().unwrap();
+    assert_eq!(*reloader.config(), "cert-2/key-2");
+    assert_eq!(publish(&reloader).tls_config_reloaded, 1);
+    assert_eq!(publish(&reloader).tls_config_reloaded, 0);
+}
+
+#[test]
+fn failed_reload_keeps_config() {
+    let dir = Dir::new();
+    let reloader = Reloader::new(dir.files().without_watching(), build).unwrap();
+
+    dir.write("cert", "");
+    assert!(reloader.reload().is_err());
+    assert_eq!(*reloader.config(), "cert-1/key-1");
+
+    fs::remove_file(dir.path("key")).unwrap();
+    assert!(reloader.reload().is_err());
+    assert_eq!(*reloader.config(), "cert-1/key-1");
+
+    assert_eq!(publish(&reloader).tls_config_reloaded, 2);
+}
+
+#[test]
+fn reload_on_change() {
+    let dir = Dir::new();
+    let files = dir.files().with_watch_interval(Duration::from_millis(1));
+    let reloader = Reloader::new(files, build).unwrap();
+
+    // unmodified files aren't reloaded
+    std::thread::sleep(Duration::from_millis(20));
+    assert_eq!(*reloader.config(), "cert-1/key-1");
+    assert_eq!(publish(&reloader).tls_config_reloaded, 0);
+
+    // make sure the modification time changes on file systems with a coarse resolution
+    let modified = fs::metadata(dir.path("cert")).unwrap().modified().unwrap();
+    while fs::metadata(dir.path("cert")).unwrap().modified().unwrap() == modified {
+        std::thread::sleep(Duration::from_millis(10));
+        dir.write("cert", "cert-2");
+    }
+
+    // the files are reloaded in the background
+    while *reloader.config() != "cert-2/key-1" {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    assert_eq!(publish(&reloader).tls_config_reloaded, 1);
+}
+
+#[test]
+fn watcher_stops_on_drop() {
+    let dir = Dir::new();
+    let files = dir.files().with_watch_interval(Duration::from_secs(60));
+    let reloader = Reloader::new(files, build).unwrap();
+    let state = Arc::downgrade(&reloader.state);
+
+    // the watching thread releases the state without waiting for the interval to elapse
+    drop(reloader.clone());
+    assert!(state.upgrade().is_some());
+    drop(reloader);
+    while state.upgrade().is_some() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn initial_load_failure() {
+    let dir = Dir::new();
+    let files = Files::new(dir.path("cert"), Path::new("missing"));
+    assert!(Reloader::new(files, build).is_err());
+
+    dir.write("cert", "");
+    assert!(Reloader::new(dir.files(), build).is_err());
+}
@@ -48,6 +48,27 @@ impl<E: tls::Endpoint> tls::Endpoint for SlowEndpoint<E> {
         }
     }
 
+    fn new_client_session_with_options<Params: s2n_codec::EncoderValue>(
+        &mut self,
+        transport_parameters: &Params,
+        server_name: application::ServerName,
+        options: &tls::ClientOptions,
+    ) -> Result<Self::Session, tls::Error> {
+        let inner_session = self.endpoint.new_client_session_with_options(
+            transport_parameters,
+            server_name,
+            options,
+        )?;
+        Ok(SlowSession {
+            defer: DEFER_COUNT,
+            inner_session,
+        })
+    }
+
+    fn publish_events<Pub: crate::event::EndpointPublisher>(&mut self, publisher: &mut Pub) {
+        self.endpoint.publish_events(publisher)
+    }
+
     fn max_tag_length(&self) -> usize {
         self.endpoint.max_tag_length()
     }
@@ -211,6 +232,10 @@ where
         self.0.on_key_exchange_group(named_group)
     }
 
+    fn on_peer_public_key(&mut self, public_key: tls::Bytes) -> Result<(), transport::Error> {
+        self.0.on_peer_public_key(public_key)
+    }
+
     fn on_tls_context(&mut self, context: Box<dyn Any + Send>) {
         self.0.on_tls_context(context)
     }
@@ -403,6 +403,7 @@ where
     pub server_name: Option<Bytes>,
     pub application_protocol: Option<Bytes>,
     pub key_exchange_group: Option<NamedGroup>,
+    pub peer_public_key: Option<Bytes>,
     pub transport_parameters: Option<Bytes>,
     endpoint: endpoint::Type,
     pub state: State,
@@ -424,6 +425,7 @@ where
             .field("sni", &self.server_name)
             .field("application_protocol", &self.application_protocol)
             .field("key_exchange_group", &self.key_exchange_group)
+            .field("peer_public_key", &self.peer_public_key)
             .field("transport_parameters", &self.transport_parameters)
             .field("endpoint", &self.endpoint)
             .finish()
@@ -445,6 +447,7 @@ where
             server_name: None,
             application_protocol: None,
             key_exchange_group: None,
+            peer_public_key: None,
             transport_parameters: None,
             endpoint,
             state,
@@ -751,6 +754,12 @@ where
         Ok(())
     }
 
+    fn on_peer_public_key(&mut self, public_key: Bytes) -> Result<(), transport::Error> {
+        self.log("peer public key");
+        self.peer_public_key = Some(public_key);
+        Ok(())
+    }
+
     fn on_handshake_complete(&mut self) -> Result<(), transport::Error> {
         assert!(
             !self.handshake_complete,
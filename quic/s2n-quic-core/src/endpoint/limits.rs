@@ -75,6 +75,12 @@ pub struct ConnectionAttempt<'a> {
     /// This address comes from the datagram
     pub remote_address: SocketAddress<'a>,
     pub timestamp: Timestamp,
+
+    /// The Destination Connection ID of the Initial packet which started the attempt
+    ///
+    /// Clients use the same value when retransmitting the packet, so this can be used to
+    /// recognize retransmitted attempts.
+    pub destination_connection_id: &'a [u8],
 }
 
 impl<'a> ConnectionAttempt<'a> {
@@ -90,6 +96,15 @@ impl<'a> ConnectionAttempt<'a> {
             connection_count,
             remote_address: remote_address.into_event(),
             timestamp,
+            destination_connection_id: &[],
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn with_destination_connection_id(self, destination_connection_id: &'a [u8]) -> Self {
+        Self {
+            destination_connection_id,
+            ..self
         }
     }
 }
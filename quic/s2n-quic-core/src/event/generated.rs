@@ -1287,6 +1287,109 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The outcome of a connection attempt which wasn't allowed by the endpoint limiter"]
+    pub enum ConnectionAttemptOutcome {
+        #[non_exhaustive]
+        #[doc = " The peer was asked to validate its address with a Retry packet"]
+        Retry {},
+        #[non_exhaustive]
+        #[doc = " The connection attempt was silently dropped"]
+        Drop {},
+        #[non_exhaustive]
+        #[doc = " The connection attempt was rejected with a CONNECTION_CLOSE"]
+        Close {},
+    }
+    impl aggregate::AsVariant for ConnectionAttemptOutcome {
+        const VARIANTS: &'static [aggregate::info::Variant] = &[
+            aggregate::info::variant::Builder {
+                name: aggregate::info::Str::new("RETRY\0"),
+                id: 0usize,
+            }
+            .build(),
+            aggregate::info::variant::Builder {
+                name: aggregate::info::Str::new("DROP\0"),
+                id: 1usize,
+            }
+            .build(),
+            aggregate::info::variant::Builder {
+                name: aggregate::info::Str::new("CLOSE\0"),
+                id: 2usize,
+            }
+            .build(),
+        ];
+        #[inline]
+        fn variant_idx(&self) -> usize {
+            match self {
+                Self::Retry { .. } => 0usize,
+                Self::Drop { .. } => 1usize,
+                Self::Close { .. } => 2usize,
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " What caused the TLS provider to reload its configuration"]
+    pub enum TlsConfigReloadCause {
+        #[non_exhaustive]
+        #[doc = " The application requested the reload"]
+        Requested {},
+        #[non_exhaustive]
+        #[doc = " The certificate or key files were modified"]
+        FileChanged {},
+    }
+    impl aggregate::AsVariant for TlsConfigReloadCause {
+        const VARIANTS: &'static [aggregate::info::Variant] = &[
+            aggregate::info::variant::Builder {
+                name: aggregate::info::Str::new("REQUESTED\0"),
+                id: 0usize,
+            }
+            .build(),
+            aggregate::info::variant::Builder {
+                name: aggregate::info::Str::new("FILE_CHANGED\0"),
+                id: 1usize,
+            }
+            .build(),
+        ];
+        #[inline]
+        fn variant_idx(&self) -> usize {
+            match self {
+                Self::Requested { .. } => 0usize,
+                Self::FileChanged { .. } => 1usize,
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The family of a socket address"]
+    pub enum AddressFamily {
+        #[non_exhaustive]
+        IpV4 {},
+        #[non_exhaustive]
+        IpV6 {},
+    }
+    impl aggregate::AsVariant for AddressFamily {
+        const VARIANTS: &'static [aggregate::info::Variant] = &[
+            aggregate::info::variant::Builder {
+                name: aggregate::info::Str::new("IP_V4\0"),
+                id: 0usize,
+            }
+            .build(),
+            aggregate::info::variant::Builder {
+                name: aggregate::info::Str::new("IP_V6\0"),
+                id: 1usize,
+            }
+            .build(),
+        ];
+        #[inline]
+        fn variant_idx(&self) -> usize {
+            match self {
+                Self::IpV4 { .. } => 0usize,
+                Self::IpV6 { .. } => 1usize,
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     pub enum MigrationDenyReason {
         #[non_exhaustive]
         BlockedPort {},
@@ -2712,6 +2815,28 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The connection won a Happy Eyeballs race and was returned to the application"]
+    pub struct HappyEyeballsWinner<'a> {
+        pub remote_address: SocketAddress<'a>,
+        pub address_family: AddressFamily,
+        #[doc = " The number of connection attempts started during the race, including the winner"]
+        pub attempts: u32,
+    }
+    #[cfg(any(test, feature = "testing"))]
+    impl<'a> crate::event::snapshot::Fmt for HappyEyeballsWinner<'a> {
+        fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+            let mut fmt = fmt.debug_struct("HappyEyeballsWinner");
+            fmt.field("remote_address", &self.remote_address);
+            fmt.field("address_family", &self.address_family);
+            fmt.field("attempts", &self.attempts);
+            fmt.finish()
+        }
+    }
+    impl<'a> Event for HappyEyeballsWinner<'a> {
+        const NAME: &'static str = "connectivity:happy_eyeballs_winner";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Connection closed"]
     pub struct ConnectionClosed {
         pub error: crate::connection::Error,
@@ -2861,6 +2986,49 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " A connection attempt was deferred or rejected by the endpoint limiter"]
+    pub struct EndpointConnectionAttemptLimited<'a> {
+        pub remote_address: SocketAddress<'a>,
+        pub outcome: ConnectionAttemptOutcome,
+    }
+    #[cfg(any(test, feature = "testing"))]
+    impl<'a> crate::event::snapshot::Fmt for EndpointConnectionAttemptLimited<'a> {
+        fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+            let mut fmt = fmt.debug_struct("EndpointConnectionAttemptLimited");
+            fmt.field("remote_address", &self.remote_address);
+            fmt.field("outcome", &self.outcome);
+            fmt.finish()
+        }
+    }
+    impl<'a> Event for EndpointConnectionAttemptLimited<'a> {
+        const NAME: &'static str = "transport:connection_attempt_limited";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The TLS provider attempted to reload its certificates and trust store"]
+    #[doc = ""]
+    #[doc = " If the reload failed, the previous configuration continues to be used."]
+    pub struct TlsConfigReloaded<'a> {
+        pub cause: TlsConfigReloadCause,
+        pub success: bool,
+        #[doc = " The reason the reload failed"]
+        pub error: Option<&'a str>,
+    }
+    #[cfg(any(test, feature = "testing"))]
+    impl<'a> crate::event::snapshot::Fmt for TlsConfigReloaded<'a> {
+        fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+            let mut fmt = fmt.debug_struct("TlsConfigReloaded");
+            fmt.field("cause", &self.cause);
+            fmt.field("success", &self.success);
+            fmt.field("error", &self.error);
+            fmt.finish()
+        }
+    }
+    impl<'a> Event for TlsConfigReloaded<'a> {
+        const NAME: &'static str = "security:tls_config_reloaded";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Emitted when the platform sends at least one packet"]
     pub struct PlatformTx {
         #[doc = " The number of packets sent"]
@@ -2961,6 +3129,79 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " Emitted when the kernel dropped datagrams because the socket receive buffer was full"]
+    #[doc = ""]
+    #[doc = " These datagrams never reach the endpoint, so without this event the drops are"]
+    #[doc = " indistinguishable from loss in the network."]
+    pub struct PlatformRxQueueOverflow {
+        #[doc = " The number of datagrams dropped since the last event"]
+        pub dropped: usize,
+    }
+    #[cfg(any(test, feature = "testing"))]
+    impl crate::event::snapshot::Fmt for PlatformRxQueueOverflow {
+        fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+            let mut fmt = fmt.debug_struct("PlatformRxQueueOverflow");
+            fmt.field("dropped", &self.dropped);
+            fmt.finish()
+        }
+    }
+    impl Event for PlatformRxQueueOverflow {
+        const NAME: &'static str = "platform:rx_queue_overflow";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " Emitted periodically with the memory usage of the socket"]
+    pub struct PlatformSocketMemory {
+        #[doc = " The number of bytes allocated to datagrams waiting in the receive queue"]
+        pub rx_allocated: usize,
+        #[doc = " The size of the receive buffer"]
+        pub rx_capacity: usize,
+        #[doc = " The number of bytes allocated to datagrams waiting in the send queue"]
+        pub tx_allocated: usize,
+        #[doc = " The size of the send buffer"]
+        pub tx_capacity: usize,
+        #[doc = " The total number of datagrams the kernel has dropped on the socket"]
+        pub dropped: usize,
+    }
+    #[cfg(any(test, feature = "testing"))]
+    impl crate::event::snapshot::Fmt for PlatformSocketMemory {
+        fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+            let mut fmt = fmt.debug_struct("PlatformSocketMemory");
+            fmt.field("rx_allocated", &self.rx_allocated);
+            fmt.field("rx_capacity", &self.rx_capacity);
+            fmt.field("tx_allocated", &self.tx_allocated);
+            fmt.field("tx_capacity", &self.tx_capacity);
+            fmt.field("dropped", &self.dropped);
+            fmt.finish()
+        }
+    }
+    impl Event for PlatformSocketMemory {
+        const NAME: &'static str = "platform:socket_memory";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " Emitted when AF_XDP sockets could not be configured and the endpoint has fallen back to"]
+    #[doc = " standard UDP sockets"]
+    pub struct PlatformXdpFallback<'a> {
+        #[doc = " A description of the error that prevented AF_XDP from being used"]
+        pub reason: &'a str,
+        #[doc = " The error code returned by the platform, if any"]
+        pub errno: Option<i32>,
+    }
+    #[cfg(any(test, feature = "testing"))]
+    impl<'a> crate::event::snapshot::Fmt for PlatformXdpFallback<'a> {
+        fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+            let mut fmt = fmt.debug_struct("PlatformXdpFallback");
+            fmt.field("reason", &self.reason);
+            fmt.field("errno", &self.errno);
+            fmt.finish()
+        }
+    }
+    impl<'a> Event for PlatformXdpFallback<'a> {
+        const NAME: &'static str = "platform:xdp_fallback";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Emitted when a platform feature is configured"]
     pub struct PlatformFeatureConfigured {
         pub configuration: PlatformFeatureConfiguration,
@@ -3053,6 +3294,17 @@ pub mod api {
         #[doc = " Emitted when ECN support is configured"]
         Ecn { enabled: bool },
         #[non_exhaustive]
+        #[doc = " Emitted when reporting of receive queue overflows is configured"]
+        RxQueueOverflow { enabled: bool },
+        #[non_exhaustive]
+        #[doc = " Emitted when pacing offload to the kernel is configured"]
+        PacingOffload { enabled: bool },
+        #[non_exhaustive]
+        #[doc = " Emitted when AF_XDP sockets are configured"]
+        #[doc = ""]
+        #[doc = " If this is disabled, the endpoint has fallen back to standard UDP sockets."]
+        Xdp { enabled: bool },
+        #[non_exhaustive]
         #[doc = " Emitted when the base maximum transmission unit is configured"]
         BaseMtu { mtu: u16 },
         #[non_exhaustive]
@@ -3080,20 +3332,35 @@ pub mod api {
             }
             .build(),
             aggregate::info::variant::Builder {
-                name: aggregate::info::Str::new("BASE_MTU\0"),
+                name: aggregate::info::Str::new("RX_QUEUE_OVERFLOW\0"),
                 id: 3usize,
             }
             .build(),
             aggregate::info::variant::Builder {
-                name: aggregate::info::Str::new("INITIAL_MTU\0"),
+                name: aggregate::info::Str::new("PACING_OFFLOAD\0"),
                 id: 4usize,
             }
             .build(),
             aggregate::info::variant::Builder {
-                name: aggregate::info::Str::new("MAX_MTU\0"),
+                name: aggregate::info::Str::new("XDP\0"),
                 id: 5usize,
             }
             .build(),
+            aggregate::info::variant::Builder {
+                name: aggregate::info::Str::new("BASE_MTU\0"),
+                id: 6usize,
+            }
+            .build(),
+            aggregate::info::variant::Builder {
+                name: aggregate::info::Str::new("INITIAL_MTU\0"),
+                id: 7usize,
+            }
+            .build(),
+            aggregate::info::variant::Builder {
+                name: aggregate::info::Str::new("MAX_MTU\0"),
+                id: 8usize,
+            }
+            .build(),
         ];
         #[inline]
         fn variant_idx(&self) -> usize {
@@ -3101,9 +3368,12 @@ pub mod api {
                 Self::Gso { .. } => 0usize,
                 Self::Gro { .. } => 1usize,
                 Self::Ecn { .. } => 2usize,
-                Self::BaseMtu { .. } => 3usize,
-                Self::InitialMtu { .. } => 4usize,
-                Self::MaxMtu { .. } => 5usize,
+                Self::RxQueueOverflow { .. } => 3usize,
+                Self::PacingOffload { .. } => 4usize,
+                Self::Xdp { .. } => 5usize,
+                Self::BaseMtu { .. } => 6usize,
+                Self::InitialMtu { .. } => 7usize,
+                Self::MaxMtu { .. } => 8usize,
             }
         }
     }
@@ -4254,6 +4524,21 @@ pub mod tracing {
             tracing :: event ! (target : "dc_path_created" , parent : id , tracing :: Level :: DEBUG , { path = tracing :: field :: debug (path) });
         }
         #[inline]
+        fn on_happy_eyeballs_winner(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::HappyEyeballsWinner,
+        ) {
+            let id = context.id();
+            let api::HappyEyeballsWinner {
+                remote_address,
+                address_family,
+                attempts,
+            } = event;
+            tracing :: event ! (target : "happy_eyeballs_winner" , parent : id , tracing :: Level :: DEBUG , { remote_address = tracing :: field :: debug (remote_address) , address_family = tracing :: field :: debug (address_family) , attempts = tracing :: field :: debug (attempts) });
+        }
+        #[inline]
         fn on_connection_closed(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -4339,6 +4624,33 @@ pub mod tracing {
             tracing :: event ! (target : "endpoint_connection_attempt_failed" , parent : parent , tracing :: Level :: DEBUG , { error = tracing :: field :: debug (error) });
         }
         #[inline]
+        fn on_endpoint_connection_attempt_limited(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointConnectionAttemptLimited,
+        ) {
+            let parent = self.parent(meta);
+            let api::EndpointConnectionAttemptLimited {
+                remote_address,
+                outcome,
+            } = event;
+            tracing :: event ! (target : "endpoint_connection_attempt_limited" , parent : parent , tracing :: Level :: DEBUG , { remote_address = tracing :: field :: debug (remote_address) , outcome = tracing :: field :: debug (outcome) });
+        }
+        #[inline]
+        fn on_tls_config_reloaded(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::TlsConfigReloaded,
+        ) {
+            let parent = self.parent(meta);
+            let api::TlsConfigReloaded {
+                cause,
+                success,
+                error,
+            } = event;
+            tracing :: event ! (target : "tls_config_reloaded" , parent : parent , tracing :: Level :: DEBUG , { cause = tracing :: field :: debug (cause) , success = tracing :: field :: debug (success) , error = tracing :: field :: debug (error) });
+        }
+        #[inline]
         fn on_platform_tx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTx) {
             let parent = self.parent(meta);
             let api::PlatformTx {
@@ -4375,6 +4687,42 @@ pub mod tracing {
             tracing :: event ! (target : "platform_rx_error" , parent : parent , tracing :: Level :: DEBUG , { errno = tracing :: field :: debug (errno) });
         }
         #[inline]
+        fn on_platform_rx_queue_overflow(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformRxQueueOverflow,
+        ) {
+            let parent = self.parent(meta);
+            let api::PlatformRxQueueOverflow { dropped } = event;
+            tracing :: event ! (target : "platform_rx_queue_overflow" , parent : parent , tracing :: Level :: DEBUG , { dropped = tracing :: field :: debug (dropped) });
+        }
+        #[inline]
+        fn on_platform_socket_memory(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformSocketMemory,
+        ) {
+            let parent = self.parent(meta);
+            let api::PlatformSocketMemory {
+                rx_allocated,
+                rx_capacity,
+                tx_allocated,
+                tx_capacity,
+                dropped,
+            } = event;
+            tracing :: event ! (target : "platform_socket_memory" , parent : parent , tracing :: Level :: DEBUG , { rx_allocated = tracing :: field :: debug (rx_allocated) , rx_capacity = tracing :: field :: debug (rx_capacity) , tx_allocated = tracing :: field :: debug (tx_allocated) , tx_capacity = tracing :: field :: debug (tx_capacity) , dropped = tracing :: field :: debug (dropped) });
+        }
+        #[inline]
+        fn on_platform_xdp_fallback(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformXdpFallback,
+        ) {
+            let parent = self.parent(meta);
+            let api::PlatformXdpFallback { reason, errno } = event;
+            tracing :: event ! (target : "platform_xdp_fallback" , parent : parent , tracing :: Level :: DEBUG , { reason = tracing :: field :: debug (reason) , errno = tracing :: field :: debug (errno) });
+        }
+        #[inline]
         fn on_platform_feature_configured(
             &mut self,
             meta: &api::EndpointMeta,
@@ -5349,6 +5697,61 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " The outcome of a connection attempt which wasn't allowed by the endpoint limiter"]
+    pub enum ConnectionAttemptOutcome {
+        #[doc = " The peer was asked to validate its address with a Retry packet"]
+        Retry,
+        #[doc = " The connection attempt was silently dropped"]
+        Drop,
+        #[doc = " The connection attempt was rejected with a CONNECTION_CLOSE"]
+        Close,
+    }
+    impl IntoEvent<api::ConnectionAttemptOutcome> for ConnectionAttemptOutcome {
+        #[inline]
+        fn into_event(self) -> api::ConnectionAttemptOutcome {
+            use api::ConnectionAttemptOutcome::*;
+            match self {
+                Self::Retry => Retry {},
+                Self::Drop => Drop {},
+                Self::Close => Close {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " What caused the TLS provider to reload its configuration"]
+    pub enum TlsConfigReloadCause {
+        #[doc = " The application requested the reload"]
+        Requested,
+        #[doc = " The certificate or key files were modified"]
+        FileChanged,
+    }
+    impl IntoEvent<api::TlsConfigReloadCause> for TlsConfigReloadCause {
+        #[inline]
+        fn into_event(self) -> api::TlsConfigReloadCause {
+            use api::TlsConfigReloadCause::*;
+            match self {
+                Self::Requested => Requested {},
+                Self::FileChanged => FileChanged {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The family of a socket address"]
+    pub enum AddressFamily {
+        IpV4,
+        IpV6,
+    }
+    impl IntoEvent<api::AddressFamily> for AddressFamily {
+        #[inline]
+        fn into_event(self) -> api::AddressFamily {
+            use api::AddressFamily::*;
+            match self {
+                Self::IpV4 => IpV4 {},
+                Self::IpV6 => IpV6 {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     pub enum MigrationDenyReason {
         BlockedPort,
         PortScopeChanged,
@@ -6467,6 +6870,29 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " The connection won a Happy Eyeballs race and was returned to the application"]
+    pub struct HappyEyeballsWinner<'a> {
+        pub remote_address: SocketAddress<'a>,
+        pub address_family: AddressFamily,
+        #[doc = " The number of connection attempts started during the race, including the winner"]
+        pub attempts: u32,
+    }
+    impl<'a> IntoEvent<api::HappyEyeballsWinner<'a>> for HappyEyeballsWinner<'a> {
+        #[inline]
+        fn into_event(self) -> api::HappyEyeballsWinner<'a> {
+            let HappyEyeballsWinner {
+                remote_address,
+                address_family,
+                attempts,
+            } = self;
+            api::HappyEyeballsWinner {
+                remote_address: remote_address.into_event(),
+                address_family: address_family.into_event(),
+                attempts: attempts.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     #[doc = " Connection closed"]
     pub struct ConnectionClosed {
         pub error: crate::connection::Error,
@@ -6596,6 +7022,52 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " A connection attempt was deferred or rejected by the endpoint limiter"]
+    pub struct EndpointConnectionAttemptLimited<'a> {
+        pub remote_address: SocketAddress<'a>,
+        pub outcome: ConnectionAttemptOutcome,
+    }
+    impl<'a> IntoEvent<api::EndpointConnectionAttemptLimited<'a>>
+        for EndpointConnectionAttemptLimited<'a>
+    {
+        #[inline]
+        fn into_event(self) -> api::EndpointConnectionAttemptLimited<'a> {
+            let EndpointConnectionAttemptLimited {
+                remote_address,
+                outcome,
+            } = self;
+            api::EndpointConnectionAttemptLimited {
+                remote_address: remote_address.into_event(),
+                outcome: outcome.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The TLS provider attempted to reload its certificates and trust store"]
+    #[doc = ""]
+    #[doc = " If the reload failed, the previous configuration continues to be used."]
+    pub struct TlsConfigReloaded<'a> {
+        pub cause: TlsConfigReloadCause,
+        pub success: bool,
+        #[doc = " The reason the reload failed"]
+        pub error: Option<&'a str>,
+    }
+    impl<'a> IntoEvent<api::TlsConfigReloaded<'a>> for TlsConfigReloaded<'a> {
+        #[inline]
+        fn into_event(self) -> api::TlsConfigReloaded<'a> {
+            let TlsConfigReloaded {
+                cause,
+                success,
+                error,
+            } = self;
+            api::TlsConfigReloaded {
+                cause: cause.into_event(),
+                success: success.into_event(),
+                error: error.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     #[doc = " Emitted when the platform sends at least one packet"]
     pub struct PlatformTx {
         #[doc = " The number of packets sent"]
@@ -6696,6 +7168,76 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " Emitted when the kernel dropped datagrams because the socket receive buffer was full"]
+    #[doc = ""]
+    #[doc = " These datagrams never reach the endpoint, so without this event the drops are"]
+    #[doc = " indistinguishable from loss in the network."]
+    pub struct PlatformRxQueueOverflow {
+        #[doc = " The number of datagrams dropped since the last event"]
+        pub dropped: usize,
+    }
+    impl IntoEvent<api::PlatformRxQueueOverflow> for PlatformRxQueueOverflow {
+        #[inline]
+        fn into_event(self) -> api::PlatformRxQueueOverflow {
+            let PlatformRxQueueOverflow { dropped } = self;
+            api::PlatformRxQueueOverflow {
+                dropped: dropped.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Emitted periodically with the memory usage of the socket"]
+    pub struct PlatformSocketMemory {
+        #[doc = " The number of bytes allocated to datagrams waiting in the receive queue"]
+        pub rx_allocated: usize,
+        #[doc = " The size of the receive buffer"]
+        pub rx_capacity: usize,
+        #[doc = " The number of bytes allocated to datagrams waiting in the send queue"]
+        pub tx_allocated: usize,
+        #[doc = " The size of the send buffer"]
+        pub tx_capacity: usize,
+        #[doc = " The total number of datagrams the kernel has dropped on the socket"]
+        pub dropped: usize,
+    }
+    impl IntoEvent<api::PlatformSocketMemory> for PlatformSocketMemory {
+        #[inline]
+        fn into_event(self) -> api::PlatformSocketMemory {
+            let PlatformSocketMemory {
+                rx_allocated,
+                rx_capacity,
+                tx_allocated,
+                tx_capacity,
+                dropped,
+            } = self;
+            api::PlatformSocketMemory {
+                rx_allocated: rx_allocated.into_event(),
+                rx_capacity: rx_capacity.into_event(),
+                tx_allocated: tx_allocated.into_event(),
+                tx_capacity: tx_capacity.into_event(),
+                dropped: dropped.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Emitted when AF_XDP sockets could not be configured and the endpoint has fallen back to"]
+    #[doc = " standard UDP sockets"]
+    pub struct PlatformXdpFallback<'a> {
+        #[doc = " A description of the error that prevented AF_XDP from being used"]
+        pub reason: &'a str,
+        #[doc = " The error code returned by the platform, if any"]
+        pub errno: Option<i32>,
+    }
+    impl<'a> IntoEvent<api::PlatformXdpFallback<'a>> for PlatformXdpFallback<'a> {
+        #[inline]
+        fn into_event(self) -> api::PlatformXdpFallback<'a> {
+            let PlatformXdpFallback { reason, errno } = self;
+            api::PlatformXdpFallback {
+                reason: reason.into_event(),
+                errno: errno.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     #[doc = " Emitted when a platform feature is configured"]
     pub struct PlatformFeatureConfigured {
         pub configuration: PlatformFeatureConfiguration,
@@ -6780,6 +7322,14 @@ pub mod builder {
         Gro { enabled: bool },
         #[doc = " Emitted when ECN support is configured"]
         Ecn { enabled: bool },
+        #[doc = " Emitted when reporting of receive queue overflows is configured"]
+        RxQueueOverflow { enabled: bool },
+        #[doc = " Emitted when pacing offload to the kernel is configured"]
+        PacingOffload { enabled: bool },
+        #[doc = " Emitted when AF_XDP sockets are configured"]
+        #[doc = ""]
+        #[doc = " If this is disabled, the endpoint has fallen back to standard UDP sockets."]
+        Xdp { enabled: bool },
         #[doc = " Emitted when the base maximum transmission unit is configured"]
         BaseMtu { mtu: u16 },
         #[doc = " Emitted when the initial maximum transmission unit is configured"]
@@ -6801,6 +7351,15 @@ pub mod builder {
                 Self::Ecn { enabled } => Ecn {
                     enabled: enabled.into_event(),
                 },
+                Self::RxQueueOverflow { enabled } => RxQueueOverflow {
+                    enabled: enabled.into_event(),
+                },
+                Self::PacingOffload { enabled } => PacingOffload {
+                    enabled: enabled.into_event(),
+                },
+                Self::Xdp { enabled } => Xdp {
+                    enabled: enabled.into_event(),
+                },
                 Self::BaseMtu { mtu } => BaseMtu {
                     mtu: mtu.into_event(),
                 },
@@ -7510,6 +8069,18 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `HappyEyeballsWinner` event is triggered"]
+        #[inline]
+        fn on_happy_eyeballs_winner(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::HappyEyeballsWinner,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `ConnectionClosed` event is triggered"]
         #[inline]
         fn on_connection_closed(
@@ -7592,6 +8163,26 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `EndpointConnectionAttemptLimited` event is triggered"]
+        #[inline]
+        fn on_endpoint_connection_attempt_limited(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointConnectionAttemptLimited,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `TlsConfigReloaded` event is triggered"]
+        #[inline]
+        fn on_tls_config_reloaded(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::TlsConfigReloaded,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `PlatformTx` event is triggered"]
         #[inline]
         fn on_platform_tx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTx) {
@@ -7616,6 +8207,36 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `PlatformRxQueueOverflow` event is triggered"]
+        #[inline]
+        fn on_platform_rx_queue_overflow(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformRxQueueOverflow,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PlatformSocketMemory` event is triggered"]
+        #[inline]
+        fn on_platform_socket_memory(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformSocketMemory,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `PlatformXdpFallback` event is triggered"]
+        #[inline]
+        fn on_platform_xdp_fallback(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformXdpFallback,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `PlatformFeatureConfigured` event is triggered"]
         #[inline]
         fn on_platform_feature_configured(
@@ -8216,6 +8837,16 @@ mod traits {
             (self.1).on_dc_path_created(&mut context.1, meta, event);
         }
         #[inline]
+        fn on_happy_eyeballs_winner(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::HappyEyeballsWinner,
+        ) {
+            (self.0).on_happy_eyeballs_winner(&mut context.0, meta, event);
+            (self.1).on_happy_eyeballs_winner(&mut context.1, meta, event);
+        }
+        #[inline]
         fn on_connection_closed(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -8289,6 +8920,24 @@ mod traits {
             (self.1).on_endpoint_connection_attempt_failed(meta, event);
         }
         #[inline]
+        fn on_endpoint_connection_attempt_limited(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointConnectionAttemptLimited,
+        ) {
+            (self.0).on_endpoint_connection_attempt_limited(meta, event);
+            (self.1).on_endpoint_connection_attempt_limited(meta, event);
+        }
+        #[inline]
+        fn on_tls_config_reloaded(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::TlsConfigReloaded,
+        ) {
+            (self.0).on_tls_config_reloaded(meta, event);
+            (self.1).on_tls_config_reloaded(meta, event);
+        }
+        #[inline]
         fn on_platform_tx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTx) {
             (self.0).on_platform_tx(meta, event);
             (self.1).on_platform_tx(meta, event);
@@ -8309,6 +8958,33 @@ mod traits {
             (self.1).on_platform_rx_error(meta, event);
         }
         #[inline]
+        fn on_platform_rx_queue_overflow(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformRxQueueOverflow,
+        ) {
+            (self.0).on_platform_rx_queue_overflow(meta, event);
+            (self.1).on_platform_rx_queue_overflow(meta, event);
+        }
+        #[inline]
+        fn on_platform_socket_memory(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformSocketMemory,
+        ) {
+            (self.0).on_platform_socket_memory(meta, event);
+            (self.1).on_platform_socket_memory(meta, event);
+        }
+        #[inline]
+        fn on_platform_xdp_fallback(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformXdpFallback,
+        ) {
+            (self.0).on_platform_xdp_fallback(meta, event);
+            (self.1).on_platform_xdp_fallback(meta, event);
+        }
+        #[inline]
         fn on_platform_feature_configured(
             &mut self,
             meta: &api::EndpointMeta,
@@ -8398,6 +9074,13 @@ mod traits {
             &mut self,
             event: builder::EndpointConnectionAttemptFailed,
         );
+        #[doc = "Publishes a `EndpointConnectionAttemptLimited` event to the publisher's subscriber"]
+        fn on_endpoint_connection_attempt_limited(
+            &mut self,
+            event: builder::EndpointConnectionAttemptLimited,
+        );
+        #[doc = "Publishes a `TlsConfigReloaded` event to the publisher's subscriber"]
+        fn on_tls_config_reloaded(&mut self, event: builder::TlsConfigReloaded);
         #[doc = "Publishes a `PlatformTx` event to the publisher's subscriber"]
         fn on_platform_tx(&mut self, event: builder::PlatformTx);
         #[doc = "Publishes a `PlatformTxError` event to the publisher's subscriber"]
@@ -8406,6 +9089,12 @@ mod traits {
         fn on_platform_rx(&mut self, event: builder::PlatformRx);
         #[doc = "Publishes a `PlatformRxError` event to the publisher's subscriber"]
         fn on_platform_rx_error(&mut self, event: builder::PlatformRxError);
+        #[doc = "Publishes a `PlatformRxQueueOverflow` event to the publisher's subscriber"]
+        fn on_platform_rx_queue_overflow(&mut self, event: builder::PlatformRxQueueOverflow);
+        #[doc = "Publishes a `PlatformSocketMemory` event to the publisher's subscriber"]
+        fn on_platform_socket_memory(&mut self, event: builder::PlatformSocketMemory);
+        #[doc = "Publishes a `PlatformXdpFallback` event to the publisher's subscriber"]
+        fn on_platform_xdp_fallback(&mut self, event: builder::PlatformXdpFallback);
         #[doc = "Publishes a `PlatformFeatureConfigured` event to the publisher's subscriber"]
         fn on_platform_feature_configured(&mut self, event: builder::PlatformFeatureConfigured);
         #[doc = "Publishes a `PlatformEventLoopWakeup` event to the publisher's subscriber"]
@@ -8496,6 +9185,22 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_endpoint_connection_attempt_limited(
+            &mut self,
+            event: builder::EndpointConnectionAttemptLimited,
+        ) {
+            let event = event.into_event();
+            self.subscriber
+                .on_endpoint_connection_attempt_limited(&self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_tls_config_reloaded(&mut self, event: builder::TlsConfigReloaded) {
+            let event = event.into_event();
+            self.subscriber.on_tls_config_reloaded(&self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_platform_tx(&mut self, event: builder::PlatformTx) {
             let event = event.into_event();
             self.subscriber.on_platform_tx(&self.meta, &event);
@@ -8520,6 +9225,26 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_platform_rx_queue_overflow(&mut self, event: builder::PlatformRxQueueOverflow) {
+            let event = event.into_event();
+            self.subscriber
+                .on_platform_rx_queue_overflow(&self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_platform_socket_memory(&mut self, event: builder::PlatformSocketMemory) {
+            let event = event.into_event();
+            self.subscriber
+                .on_platform_socket_memory(&self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_platform_xdp_fallback(&mut self, event: builder::PlatformXdpFallback) {
+            let event = event.into_event();
+            self.subscriber.on_platform_xdp_fallback(&self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_platform_feature_configured(&mut self, event: builder::PlatformFeatureConfigured) {
             let event = event.into_event();
             self.subscriber
@@ -8654,6 +9379,8 @@ mod traits {
         fn on_dc_state_changed(&mut self, event: builder::DcStateChanged);
         #[doc = "Publishes a `DcPathCreated` event to the publisher's subscriber"]
         fn on_dc_path_created(&mut self, event: builder::DcPathCreated);
+        #[doc = "Publishes a `HappyEyeballsWinner` event to the publisher's subscriber"]
+        fn on_happy_eyeballs_winner(&mut self, event: builder::HappyEyeballsWinner);
         #[doc = "Publishes a `ConnectionClosed` event to the publisher's subscriber"]
         fn on_connection_closed(&mut self, event: builder::ConnectionClosed);
         #[doc = r" Returns the QUIC version negotiated for the current connection, if any"]
@@ -9123,6 +9850,15 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_happy_eyeballs_winner(&mut self, event: builder::HappyEyeballsWinner) {
+            let event = event.into_event();
+            self.subscriber
+                .on_happy_eyeballs_winner(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_connection_closed(&mut self, event: builder::ConnectionClosed) {
             let event = event.into_event();
             self.subscriber
@@ -9157,10 +9893,15 @@ pub mod testing {
             pub endpoint_datagram_received: u64,
             pub endpoint_datagram_dropped: u64,
             pub endpoint_connection_attempt_failed: u64,
+            pub endpoint_connection_attempt_limited: u64,
+            pub tls_config_reloaded: u64,
             pub platform_tx: u64,
             pub platform_tx_error: u64,
             pub platform_rx: u64,
             pub platform_rx_error: u64,
+            pub platform_rx_queue_overflow: u64,
+            pub platform_socket_memory: u64,
+            pub platform_xdp_fallback: u64,
             pub platform_feature_configured: u64,
             pub platform_event_loop_wakeup: u64,
             pub platform_event_loop_sleep: u64,
@@ -9203,10 +9944,15 @@ pub mod testing {
                     endpoint_datagram_received: 0,
                     endpoint_datagram_dropped: 0,
                     endpoint_connection_attempt_failed: 0,
+                    endpoint_connection_attempt_limited: 0,
+                    tls_config_reloaded: 0,
                     platform_tx: 0,
                     platform_tx_error: 0,
                     platform_rx: 0,
                     platform_rx_error: 0,
+                    platform_rx_queue_overflow: 0,
+                    platform_socket_memory: 0,
+                    platform_xdp_fallback: 0,
                     platform_feature_configured: 0,
                     platform_event_loop_wakeup: 0,
                     platform_event_loop_sleep: 0,
@@ -9299,6 +10045,28 @@ pub mod testing {
                 let out = format!("{meta:?} {event:?}");
                 self.output.push(out);
             }
+            fn on_endpoint_connection_attempt_limited(
+                &mut self,
+                meta: &api::EndpointMeta,
+                event: &api::EndpointConnectionAttemptLimited,
+            ) {
+                self.endpoint_connection_attempt_limited += 1;
+                let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
+                let event = crate::event::snapshot::Fmt::to_snapshot(event);
+                let out = format!("{meta:?} {event:?}");
+                self.output.push(out);
+            }
+            fn on_tls_config_reloaded(
+                &mut self,
+                meta: &api::EndpointMeta,
+                event: &api::TlsConfigReloaded,
+            ) {
+                self.tls_config_reloaded += 1;
+                let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
+                let event = crate::event::snapshot::Fmt::to_snapshot(event);
+                let out = format!("{meta:?} {event:?}");
+                self.output.push(out);
+            }
             fn on_platform_tx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTx) {
                 self.platform_tx += 1;
                 let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
@@ -9335,6 +10103,39 @@ pub mod testing {
                 let out = format!("{meta:?} {event:?}");
                 self.output.push(out);
             }
+            fn on_platform_rx_queue_overflow(
+                &mut self,
+                meta: &api::EndpointMeta,
+                event: &api::PlatformRxQueueOverflow,
+            ) {
+                self.platform_rx_queue_overflow += 1;
+                let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
+                let event = crate::event::snapshot::Fmt::to_snapshot(event);
+                let out = format!("{meta:?} {event:?}");
+                self.output.push(out);
+            }
+            fn on_platform_socket_memory(
+                &mut self,
+                meta: &api::EndpointMeta,
+                event: &api::PlatformSocketMemory,
+            ) {
+                self.platform_socket_memory += 1;
+                let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
+                let event = crate::event::snapshot::Fmt::to_snapshot(event);
+                let out = format!("{meta:?} {event:?}");
+                self.output.push(out);
+            }
+            fn on_platform_xdp_fallback(
+                &mut self,
+                meta: &api::EndpointMeta,
+                event: &api::PlatformXdpFallback,
+            ) {
+                self.platform_xdp_fallback += 1;
+                let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
+                let event = crate::event::snapshot::Fmt::to_snapshot(event);
+                let out = format!("{meta:?} {event:?}");
+                self.output.push(out);
+            }
             fn on_platform_feature_configured(
                 &mut self,
                 meta: &api::EndpointMeta,
@@ -9431,6 +10232,7 @@ pub mod testing {
         pub bbr_state_changed: u64,
         pub dc_state_changed: u64,
         pub dc_path_created: u64,
+        pub happy_eyeballs_winner: u64,
         pub connection_closed: u64,
         pub version_information: u64,
         pub endpoint_packet_sent: u64,
@@ -9439,10 +10241,15 @@ pub mod testing {
         pub endpoint_datagram_received: u64,
         pub endpoint_datagram_dropped: u64,
         pub endpoint_connection_attempt_failed: u64,
+        pub endpoint_connection_attempt_limited: u64,
+        pub tls_config_reloaded: u64,
         pub platform_tx: u64,
         pub platform_tx_error: u64,
         pub platform_rx: u64,
         pub platform_rx_error: u64,
+        pub platform_rx_queue_overflow: u64,
+        pub platform_socket_memory: u64,
+        pub platform_xdp_fallback: u64,
         pub platform_feature_configured: u64,
         pub platform_event_loop_wakeup: u64,
         pub platform_event_loop_sleep: u64,
@@ -9524,6 +10331,7 @@ pub mod testing {
                 bbr_state_changed: 0,
                 dc_state_changed: 0,
                 dc_path_created: 0,
+                happy_eyeballs_winner: 0,
                 connection_closed: 0,
                 version_information: 0,
                 endpoint_packet_sent: 0,
@@ -9532,10 +10340,15 @@ pub mod testing {
                 endpoint_datagram_received: 0,
                 endpoint_datagram_dropped: 0,
                 endpoint_connection_attempt_failed: 0,
+                endpoint_connection_attempt_limited: 0,
+                tls_config_reloaded: 0,
                 platform_tx: 0,
                 platform_tx_error: 0,
                 platform_rx: 0,
                 platform_rx_error: 0,
+                platform_rx_queue_overflow: 0,
+                platform_socket_memory: 0,
+                platform_xdp_fallback: 0,
                 platform_feature_configured: 0,
                 platform_event_loop_wakeup: 0,
                 platform_event_loop_sleep: 0,
@@ -10196,6 +11009,20 @@ pub mod testing {
                 self.output.push(out);
             }
         }
+        fn on_happy_eyeballs_winner(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::HappyEyeballsWinner,
+        ) {
+            self.happy_eyeballs_winner += 1;
+            if self.location.is_some() {
+                let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
+                let event = crate::event::snapshot::Fmt::to_snapshot(event);
+                let out = format!("{meta:?} {event:?}");
+                self.output.push(out);
+            }
+        }
         fn on_connection_closed(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -10287,6 +11114,28 @@ pub mod testing {
             let out = format!("{meta:?} {event:?}");
             self.output.push(out);
         }
+        fn on_endpoint_connection_attempt_limited(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointConnectionAttemptLimited,
+        ) {
+            self.endpoint_connection_attempt_limited += 1;
+            let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
+            let event = crate::event::snapshot::Fmt::to_snapshot(event);
+            let out = format!("{meta:?} {event:?}");
+            self.output.push(out);
+        }
+        fn on_tls_config_reloaded(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::TlsConfigReloaded,
+        ) {
+            self.tls_config_reloaded += 1;
+            let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
+            let event = crate::event::snapshot::Fmt::to_snapshot(event);
+            let out = format!("{meta:?} {event:?}");
+            self.output.push(out);
+        }
         fn on_platform_tx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTx) {
             self.platform_tx += 1;
             let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
@@ -10315,6 +11164,39 @@ pub mod testing {
             let out = format!("{meta:?} {event:?}");
             self.output.push(out);
         }
+        fn on_platform_rx_queue_overflow(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformRxQueueOverflow,
+        ) {
+            self.platform_rx_queue_overflow += 1;
+            let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
+            let event = crate::event::snapshot::Fmt::to_snapshot(event);
+            let out = format!("{meta:?} {event:?}");
+            self.output.push(out);
+        }
+        fn on_platform_socket_memory(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformSocketMemory,
+        ) {
+            self.platform_socket_memory += 1;
+            let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
+            let event = crate::event::snapshot::Fmt::to_snapshot(event);
+            let out = format!("{meta:?} {event:?}");
+            self.output.push(out);
+        }
+        fn on_platform_xdp_fallback(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformXdpFallback,
+        ) {
+            self.platform_xdp_fallback += 1;
+            let meta = crate::event::snapshot::Fmt::to_snapshot(meta);
+            let event = crate::event::snapshot::Fmt::to_snapshot(event);
+            let out = format!("{meta:?} {event:?}");
+            self.output.push(out);
+        }
         fn on_platform_feature_configured(
             &mut self,
             meta: &api::EndpointMeta,
@@ -10410,6 +11292,7 @@ pub mod testing {
         pub bbr_state_changed: u64,
         pub dc_state_changed: u64,
         pub dc_path_created: u64,
+        pub happy_eyeballs_winner: u64,
         pub connection_closed: u64,
         pub version_information: u64,
         pub endpoint_packet_sent: u64,
@@ -10418,10 +11301,15 @@ pub mod testing {
         pub endpoint_datagram_received: u64,
         pub endpoint_datagram_dropped: u64,
         pub endpoint_connection_attempt_failed: u64,
+        pub endpoint_connection_attempt_limited: u64,
+        pub tls_config_reloaded: u64,
         pub platform_tx: u64,
         pub platform_tx_error: u64,
         pub platform_rx: u64,
         pub platform_rx_error: u64,
+        pub platform_rx_queue_overflow: u64,
+        pub platform_socket_memory: u64,
+        pub platform_xdp_fallback: u64,
         pub platform_feature_configured: u64,
         pub platform_event_loop_wakeup: u64,
         pub platform_event_loop_sleep: u64,
@@ -10493,6 +11381,7 @@ pub mod testing {
                 bbr_state_changed: 0,
                 dc_state_changed: 0,
                 dc_path_created: 0,
+                happy_eyeballs_winner: 0,
                 connection_closed: 0,
                 version_information: 0,
                 endpoint_packet_sent: 0,
@@ -10501,10 +11390,15 @@ pub mod testing {
                 endpoint_datagram_received: 0,
                 endpoint_datagram_dropped: 0,
                 endpoint_connection_attempt_failed: 0,
+                endpoint_connection_attempt_limited: 0,
+                tls_config_reloaded: 0,
                 platform_tx: 0,
                 platform_tx_error: 0,
                 platform_rx: 0,
                 platform_rx_error: 0,
+                platform_rx_queue_overflow: 0,
+                platform_socket_memory: 0,
+                platform_xdp_fallback: 0,
                 platform_feature_configured: 0,
                 platform_event_loop_wakeup: 0,
                 platform_event_loop_sleep: 0,
@@ -10565,6 +11459,23 @@ pub mod testing {
             let out = format!("{event:?}");
             self.output.push(out);
         }
+        fn on_endpoint_connection_attempt_limited(
+            &mut self,
+            event: builder::EndpointConnectionAttemptLimited,
+        ) {
+            self.endpoint_connection_attempt_limited += 1;
+            let event = event.into_event();
+            let event = crate::event::snapshot::Fmt::to_snapshot(&event);
+            let out = format!("{event:?}");
+            self.output.push(out);
+        }
+        fn on_tls_config_reloaded(&mut self, event: builder::TlsConfigReloaded) {
+            self.tls_config_reloaded += 1;
+            let event = event.into_event();
+            let event = crate::event::snapshot::Fmt::to_snapshot(&event);
+            let out = format!("{event:?}");
+            self.output.push(out);
+        }
         fn on_platform_tx(&mut self, event: builder::PlatformTx) {
             self.platform_tx += 1;
             let event = event.into_event();
@@ -10593,6 +11504,27 @@ pub mod testing {
             let out = format!("{event:?}");
             self.output.push(out);
         }
+        fn on_platform_rx_queue_overflow(&mut self, event: builder::PlatformRxQueueOverflow) {
+            self.platform_rx_queue_overflow += 1;
+            let event = event.into_event();
+            let event = crate::event::snapshot::Fmt::to_snapshot(&event);
+            let out = format!("{event:?}");
+            self.output.push(out);
+        }
+        fn on_platform_socket_memory(&mut self, event: builder::PlatformSocketMemory) {
+            self.platform_socket_memory += 1;
+            let event = event.into_event();
+            let event = crate::event::snapshot::Fmt::to_snapshot(&event);
+            let out = format!("{event:?}");
+            self.output.push(out);
+        }
+        fn on_platform_xdp_fallback(&mut self, event: builder::PlatformXdpFallback) {
+            self.platform_xdp_fallback += 1;
+            let event = event.into_event();
+            let event = crate::event::snapshot::Fmt::to_snapshot(&event);
+            let out = format!("{event:?}");
+            self.output.push(out);
+        }
         fn on_platform_feature_configured(&mut self, event: builder::PlatformFeatureConfigured) {
             self.platform_feature_configured += 1;
             let event = event.into_event();
@@ -11053,6 +11985,15 @@ pub mod testing {
                 self.output.push(out);
             }
         }
+        fn on_happy_eyeballs_winner(&mut self, event: builder::HappyEyeballsWinner) {
+            self.happy_eyeballs_winner += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                let event = crate::event::snapshot::Fmt::to_snapshot(&event);
+                let out = format!("{event:?}");
+                self.output.push(out);
+            }
+        }
         fn on_connection_closed(&mut self, event: builder::ConnectionClosed) {
             self.connection_closed += 1;
             let event = event.into_event();
@@ -72,6 +72,7 @@ pub struct Context<R: Recorder> {
     bbr_state_changed: u64,
     dc_state_changed: u64,
     dc_path_created: u64,
+    happy_eyeballs_winner: u64,
     connection_closed: u64,
 }
 impl<S: event::Subscriber> event::Subscriber for Subscriber<S>
@@ -132,6 +133,7 @@ where
             bbr_state_changed: 0,
             dc_state_changed: 0,
             dc_path_created: 0,
+            happy_eyeballs_winner: 0,
             connection_closed: 0,
         }
     }
@@ -646,6 +648,17 @@ where
             .on_dc_path_created(&mut context.recorder, meta, event);
     }
     #[inline]
+    fn on_happy_eyeballs_winner(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &api::ConnectionMeta,
+        event: &api::HappyEyeballsWinner,
+    ) {
+        context.happy_eyeballs_winner += 1;
+        self.subscriber
+            .on_happy_eyeballs_winner(&mut context.recorder, meta, event);
+    }
+    #[inline]
     fn on_connection_closed(
         &mut self,
         context: &mut Self::ConnectionContext,
@@ -765,6 +778,8 @@ impl<R: Recorder> Drop for Context<R> {
             .increment_counter("dc_state_changed", self.dc_state_changed as _);
         self.recorder
             .increment_counter("dc_path_created", self.dc_path_created as _);
+        self.recorder
+            .increment_counter("happy_eyeballs_winner", self.happy_eyeballs_winner as _);
         self.recorder
             .increment_counter("connection_closed", self.connection_closed as _);
     }
@@ -13,7 +13,7 @@ use crate::event::{
     },
 };
 use alloc::{boxed::Box, vec::Vec};
-static INFO: &[Info; 164usize] = &[
+static INFO: &[Info; 181usize] = &[
     info::Builder {
         id: 0usize,
         name: Str::new("application_protocol_information\0"),
@@ -712,288 +712,390 @@ static INFO: &[Info; 164usize] = &[
     .build(),
     info::Builder {
         id: 116usize,
-        name: Str::new("connection_closed\0"),
+        name: Str::new("happy_eyeballs_winner\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
         id: 117usize,
+        name: Str::new("happy_eyeballs_winner.family\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 118usize,
+        name: Str::new("happy_eyeballs_winner.attempts\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 119usize,
+        name: Str::new("connection_closed\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 120usize,
         name: Str::new("connection_closed.latency\0"),
         units: Units::Duration,
     }
     .build(),
     info::Builder {
-        id: 118usize,
+        id: 121usize,
         name: Str::new("connection_closed.error\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 119usize,
+        id: 122usize,
         name: Str::new("version_information\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 120usize,
+        id: 123usize,
         name: Str::new("endpoint_packet_sent\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 121usize,
+        id: 124usize,
         name: Str::new("endpoint_packet_received\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 122usize,
+        id: 125usize,
         name: Str::new("endpoint_datagram_sent\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 123usize,
+        id: 126usize,
         name: Str::new("endpoint_datagram_sent.bytes\0"),
         units: Units::Bytes,
     }
     .build(),
     info::Builder {
-        id: 124usize,
+        id: 127usize,
         name: Str::new("endpoint_datagram_sent.bytes.total\0"),
         units: Units::Bytes,
     }
     .build(),
     info::Builder {
-        id: 125usize,
+        id: 128usize,
         name: Str::new("endpoint_datagram_sent.gso_offset\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 126usize,
+        id: 129usize,
         name: Str::new("endpoint_datagram_received\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 127usize,
+        id: 130usize,
         name: Str::new("endpoint_datagram_received.bytes\0"),
         units: Units::Bytes,
     }
     .build(),
     info::Builder {
-        id: 128usize,
+        id: 131usize,
         name: Str::new("endpoint_datagram_received.bytes.total\0"),
         units: Units::Bytes,
     }
     .build(),
     info::Builder {
-        id: 129usize,
+        id: 132usize,
         name: Str::new("endpoint_datagram_dropped\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 130usize,
+        id: 133usize,
         name: Str::new("endpoint_datagram_dropped.bytes\0"),
         units: Units::Bytes,
     }
     .build(),
     info::Builder {
-        id: 131usize,
+        id: 134usize,
         name: Str::new("endpoint_datagram_dropped.bytes.total\0"),
         units: Units::Bytes,
     }
     .build(),
     info::Builder {
-        id: 132usize,
+        id: 135usize,
         name: Str::new("endpoint_datagram_dropped.reason\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 133usize,
+        id: 136usize,
         name: Str::new("endpoint_connection_attempt_failed\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 134usize,
+        id: 137usize,
         name: Str::new("endpoint_connection_attempt_failed.error\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 135usize,
+        id: 138usize,
+        name: Str::new("endpoint_connection_attempt_limited\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 139usize,
+        name: Str::new("endpoint_connection_attempt_limited.outcome\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 140usize,
+        name: Str::new("tls_config_reloaded\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 141usize,
+        name: Str::new("tls_config_reloaded.cause\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 142usize,
+        name: Str::new("tls_config_reloaded.success\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 143usize,
         name: Str::new("platform_tx\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 136usize,
+        id: 144usize,
         name: Str::new("platform_tx.packets.total\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 137usize,
+        id: 145usize,
         name: Str::new("platform_tx.packets\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 138usize,
+        id: 146usize,
         name: Str::new("platform_tx.syscalls.total\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 139usize,
+        id: 147usize,
         name: Str::new("platform_tx.syscalls\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 140usize,
+        id: 148usize,
         name: Str::new("platform_tx.syscalls.blocked.total\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 141usize,
+        id: 149usize,
         name: Str::new("platform_tx.syscalls.blocked\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 142usize,
+        id: 150usize,
         name: Str::new("platform_tx.errors.total\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 143usize,
+        id: 151usize,
         name: Str::new("platform_tx.errors\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 144usize,
+        id: 152usize,
         name: Str::new("platform_tx.errors.dropped.total\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 145usize,
+        id: 153usize,
         name: Str::new("platform_tx.errors.dropped\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 146usize,
+        id: 154usize,
         name: Str::new("platform_tx_error\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 147usize,
+        id: 155usize,
         name: Str::new("platform_rx\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 148usize,
+        id: 156usize,
         name: Str::new("platform_rx.packets.total\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 149usize,
+        id: 157usize,
         name: Str::new("platform_rx.packets\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 150usize,
+        id: 158usize,
         name: Str::new("platform_rx.syscalls.total\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 151usize,
+        id: 159usize,
         name: Str::new("platform_rx.syscalls\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 152usize,
+        id: 160usize,
         name: Str::new("platform_rx.syscalls.blocked.total\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 153usize,
+        id: 161usize,
         name: Str::new("platform_rx.syscalls.blocked\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 154usize,
+        id: 162usize,
         name: Str::new("platform_rx.errors.total\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 155usize,
+        id: 163usize,
         name: Str::new("platform_rx.errors\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 156usize,
+        id: 164usize,
         name: Str::new("platform_rx.errors.dropped.total\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 157usize,
+        id: 165usize,
         name: Str::new("platform_rx.errors.dropped\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 158usize,
+        id: 166usize,
         name: Str::new("platform_rx_error\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 159usize,
+        id: 167usize,
+        name: Str::new("platform_rx_queue_overflow\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 168usize,
+        name: Str::new("platform_rx_queue_overflow.dropped.total\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 169usize,
+        name: Str::new("platform_rx_queue_overflow.dropped\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 170usize,
+        name: Str::new("platform_socket_memory\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 171usize,
+        name: Str::new("platform_socket_memory.rx.allocated\0"),
+        units: Units::Bytes,
+    }
+    .build(),
+    info::Builder {
+        id: 172usize,
+        name: Str::new("platform_socket_memory.rx.capacity\0"),
+        units: Units::Bytes,
+    }
+    .build(),
+    info::Builder {
+        id: 173usize,
+        name: Str::new("platform_socket_memory.tx.allocated\0"),
+        units: Units::Bytes,
+    }
+    .build(),
+    info::Builder {
+        id: 174usize,
+        name: Str::new("platform_socket_memory.tx.capacity\0"),
+        units: Units::Bytes,
+    }
+    .build(),
+    info::Builder {
+        id: 175usize,
+        name: Str::new("platform_xdp_fallback\0"),
+        units: Units::None,
+    }
+    .build(),
+    info::Builder {
+        id: 176usize,
         name: Str::new("platform_feature_configured\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 160usize,
+        id: 177usize,
         name: Str::new("platform_event_loop_wakeup\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 161usize,
+        id: 178usize,
         name: Str::new("platform_event_loop_sleep\0"),
         units: Units::None,
     }
     .build(),
     info::Builder {
-        id: 162usize,
+        id: 179usize,
         name: Str::new("platform_event_loop_sleep.processing_duration\0"),
         units: Units::Duration,
     }
     .build(),
     info::Builder {
-        id: 163usize,
+        id: 180usize,
         name: Str::new("platform_event_loop_started\0"),
         units: Units::None,
     }
@@ -1006,15 +1108,15 @@ pub struct ConnectionContext {
 }
 pub struct Subscriber<R: Registry> {
     #[allow(dead_code)]
-    counters: Box<[R::Counter; 78usize]>,
+    counters: Box<[R::Counter; 85usize]>,
     #[allow(dead_code)]
-    bool_counters: Box<[R::BoolCounter; 3usize]>,
+    bool_counters: Box<[R::BoolCounter; 4usize]>,
     #[allow(dead_code)]
     nominal_counters: Box<[R::NominalCounter]>,
     #[allow(dead_code)]
-    nominal_counter_offsets: Box<[usize; 29usize]>,
+    nominal_counter_offsets: Box<[usize; 32usize]>,
     #[allow(dead_code)]
-    measures: Box<[R::Measure; 38usize]>,
+    measures: Box<[R::Measure; 44usize]>,
     #[allow(dead_code)]
     gauges: Box<[R::Gauge; 0usize]>,
     #[allow(dead_code)]
@@ -1041,11 +1143,11 @@ impl<R: Registry> Subscriber<R> {
     #[allow(unused_mut)]
     #[inline]
     pub fn new(registry: R) -> Self {
-        let mut counters = Vec::with_capacity(78usize);
-        let mut bool_counters = Vec::with_capacity(3usize);
-        let mut nominal_counters = Vec::with_capacity(29usize);
-        let mut nominal_counter_offsets = Vec::with_capacity(29usize);
-        let mut measures = Vec::with_capacity(38usize);
+        let mut counters = Vec::with_capacity(85usize);
+        let mut bool_counters = Vec::with_capacity(4usize);
+        let mut nominal_counters = Vec::with_capacity(32usize);
+        let mut nominal_counter_offsets = Vec::with_capacity(32usize);
+        let mut measures = Vec::with_capacity(44usize);
         let mut gauges = Vec::with_capacity(0usize);
         let mut timers = Vec::with_capacity(15usize);
         let mut nominal_timers = Vec::with_capacity(1usize);
@@ -1104,33 +1206,41 @@ impl<R: Registry> Subscriber<R> {
         counters.push(registry.register_counter(&INFO[115usize]));
         counters.push(registry.register_counter(&INFO[116usize]));
         counters.push(registry.register_counter(&INFO[119usize]));
-        counters.push(registry.register_counter(&INFO[120usize]));
-        counters.push(registry.register_counter(&INFO[121usize]));
         counters.push(registry.register_counter(&INFO[122usize]));
-        counters.push(registry.register_counter(&INFO[126usize]));
+        counters.push(registry.register_counter(&INFO[123usize]));
+        counters.push(registry.register_counter(&INFO[124usize]));
+        counters.push(registry.register_counter(&INFO[125usize]));
         counters.push(registry.register_counter(&INFO[129usize]));
-        counters.push(registry.register_counter(&INFO[133usize]));
-        counters.push(registry.register_counter(&INFO[135usize]));
+        counters.push(registry.register_counter(&INFO[132usize]));
         counters.push(registry.register_counter(&INFO[136usize]));
         counters.push(registry.register_counter(&INFO[138usize]));
         counters.push(registry.register_counter(&INFO[140usize]));
-        counters.push(registry.register_counter(&INFO[142usize]));
+        counters.push(registry.register_counter(&INFO[143usize]));
         counters.push(registry.register_counter(&INFO[144usize]));
         counters.push(registry.register_counter(&INFO[146usize]));
-        counters.push(registry.register_counter(&INFO[147usize]));
         counters.push(registry.register_counter(&INFO[148usize]));
         counters.push(registry.register_counter(&INFO[150usize]));
         counters.push(registry.register_counter(&INFO[152usize]));
         counters.push(registry.register_counter(&INFO[154usize]));
+        counters.push(registry.register_counter(&INFO[155usize]));
         counters.push(registry.register_counter(&INFO[156usize]));
         counters.push(registry.register_counter(&INFO[158usize]));
-        counters.push(registry.register_counter(&INFO[159usize]));
         counters.push(registry.register_counter(&INFO[160usize]));
-        counters.push(registry.register_counter(&INFO[161usize]));
-        counters.push(registry.register_counter(&INFO[163usize]));
+        counters.push(registry.register_counter(&INFO[162usize]));
+        counters.push(registry.register_counter(&INFO[164usize]));
+        counters.push(registry.register_counter(&INFO[166usize]));
+        counters.push(registry.register_counter(&INFO[167usize]));
+        counters.push(registry.register_counter(&INFO[168usize]));
+        counters.push(registry.register_counter(&INFO[170usize]));
+        counters.push(registry.register_counter(&INFO[175usize]));
+        counters.push(registry.register_counter(&INFO[176usize]));
+        counters.push(registry.register_counter(&INFO[177usize]));
+        counters.push(registry.register_counter(&INFO[178usize]));
+        counters.push(registry.register_counter(&INFO[180usize]));
         bool_counters.push(registry.register_bool_counter(&INFO[24usize]));
         bool_counters.push(registry.register_bool_counter(&INFO[34usize]));
         bool_counters.push(registry.register_bool_counter(&INFO[97usize]));
+        bool_counters.push(registry.register_bool_counter(&INFO[142usize]));
         {
             #[allow(unused_imports)]
             use api::*;
@@ -1420,12 +1530,23 @@ impl<R: Registry> Subscriber<R> {
                 debug_assert_ne!(count, 0, "field type needs at least one variant");
                 nominal_counter_offsets.push(offset);
             }
+            {
+                let offset = nominal_counters.len();
+                let mut count = 0;
+                for variant in <AddressFamily as AsVariant>::VARIANTS.iter() {
+                    nominal_counters
+                        .push(registry.register_nominal_counter(&INFO[117usize], variant));
+                    count += 1;
+                }
+                debug_assert_ne!(count, 0, "field type needs at least one variant");
+                nominal_counter_offsets.push(offset);
+            }
             {
                 let offset = nominal_counters.len();
                 let mut count = 0;
                 for variant in <crate::connection::Error as AsVariant>::VARIANTS.iter() {
                     nominal_counters
-                        .push(registry.register_nominal_counter(&INFO[118usize], variant));
+                        .push(registry.register_nominal_counter(&INFO[121usize], variant));
                     count += 1;
                 }
                 debug_assert_ne!(count, 0, "field type needs at least one variant");
@@ -1436,7 +1557,7 @@ impl<R: Registry> Subscriber<R> {
                 let mut count = 0;
                 for variant in <DatagramDropReason as AsVariant>::VARIANTS.iter() {
                     nominal_counters
-                        .push(registry.register_nominal_counter(&INFO[132usize], variant));
+                        .push(registry.register_nominal_counter(&INFO[135usize], variant));
                     count += 1;
                 }
                 debug_assert_ne!(count, 0, "field type needs at least one variant");
@@ -1447,7 +1568,29 @@ impl<R: Registry> Subscriber<R> {
                 let mut count = 0;
                 for variant in <crate::connection::Error as AsVariant>::VARIANTS.iter() {
                     nominal_counters
-                        .push(registry.register_nominal_counter(&INFO[134usize], variant));
+                        .push(registry.register_nominal_counter(&INFO[137usize], variant));
+                    count += 1;
+                }
+                debug_assert_ne!(count, 0, "field type needs at least one variant");
+                nominal_counter_offsets.push(offset);
+            }
+            {
+                let offset = nominal_counters.len();
+                let mut count = 0;
+                for variant in <ConnectionAttemptOutcome as AsVariant>::VARIANTS.iter() {
+                    nominal_counters
+                        .push(registry.register_nominal_counter(&INFO[139usize], variant));
+                    count += 1;
+                }
+                debug_assert_ne!(count, 0, "field type needs at least one variant");
+                nominal_counter_offsets.push(offset);
+            }
+            {
+                let offset = nominal_counters.len();
+                let mut count = 0;
+                for variant in <TlsConfigReloadCause as AsVariant>::VARIANTS.iter() {
+                    nominal_counters
+                        .push(registry.register_nominal_counter(&INFO[141usize], variant));
                     count += 1;
                 }
                 debug_assert_ne!(count, 0, "field type needs at least one variant");
@@ -1475,23 +1618,29 @@ impl<R: Registry> Subscriber<R> {
         measures.push(registry.register_measure(&INFO[104usize]));
         measures.push(registry.register_measure(&INFO[105usize]));
         measures.push(registry.register_measure(&INFO[106usize]));
-        measures.push(registry.register_measure(&INFO[123usize]));
-        measures.push(registry.register_measure(&INFO[124usize]));
-        measures.push(registry.register_measure(&INFO[125usize]));
+        measures.push(registry.register_measure(&INFO[118usize]));
+        measures.push(registry.register_measure(&INFO[126usize]));
         measures.push(registry.register_measure(&INFO[127usize]));
         measures.push(registry.register_measure(&INFO[128usize]));
         measures.push(registry.register_measure(&INFO[130usize]));
         measures.push(registry.register_measure(&INFO[131usize]));
-        measures.push(registry.register_measure(&INFO[137usize]));
-        measures.push(registry.register_measure(&INFO[139usize]));
-        measures.push(registry.register_measure(&INFO[141usize]));
-        measures.push(registry.register_measure(&INFO[143usize]));
+        measures.push(registry.register_measure(&INFO[133usize]));
+        measures.push(registry.register_measure(&INFO[134usize]));
         measures.push(registry.register_measure(&INFO[145usize]));
+        measures.push(registry.register_measure(&INFO[147usize]));
         measures.push(registry.register_measure(&INFO[149usize]));
         measures.push(registry.register_measure(&INFO[151usize]));
         measures.push(registry.register_measure(&INFO[153usize]));
-        measures.push(registry.register_measure(&INFO[155usize]));
         measures.push(registry.register_measure(&INFO[157usize]));
+        measures.push(registry.register_measure(&INFO[159usize]));
+        measures.push(registry.register_measure(&INFO[161usize]));
+        measures.push(registry.register_measure(&INFO[163usize]));
+        measures.push(registry.register_measure(&INFO[165usize]));
+        measures.push(registry.register_measure(&INFO[169usize]));
+        measures.push(registry.register_measure(&INFO[171usize]));
+        measures.push(registry.register_measure(&INFO[172usize]));
+        measures.push(registry.register_measure(&INFO[173usize]));
+        measures.push(registry.register_measure(&INFO[174usize]));
         timers.push(registry.register_timer(&INFO[48usize]));
         timers.push(registry.register_timer(&INFO[49usize]));
         timers.push(registry.register_timer(&INFO[50usize]));
@@ -1505,8 +1654,8 @@ impl<R: Registry> Subscriber<R> {
         timers.push(registry.register_timer(&INFO[111usize]));
         timers.push(registry.register_timer(&INFO[112usize]));
         timers.push(registry.register_timer(&INFO[113usize]));
-        timers.push(registry.register_timer(&INFO[117usize]));
-        timers.push(registry.register_timer(&INFO[162usize]));
+        timers.push(registry.register_timer(&INFO[120usize]));
+        timers.push(registry.register_timer(&INFO[179usize]));
         {
             #[allow(unused_imports)]
             use api::*;
@@ -1605,30 +1754,37 @@ impl<R: Registry> Subscriber<R> {
                 51usize => (&INFO[115usize], entry),
                 52usize => (&INFO[116usize], entry),
                 53usize => (&INFO[119usize], entry),
-                54usize => (&INFO[120usize], entry),
-                55usize => (&INFO[121usize], entry),
-                56usize => (&INFO[122usize], entry),
-                57usize => (&INFO[126usize], entry),
+                54usize => (&INFO[122usize], entry),
+                55usize => (&INFO[123usize], entry),
+                56usize => (&INFO[124usize], entry),
+                57usize => (&INFO[125usize], entry),
                 58usize => (&INFO[129usize], entry),
-                59usize => (&INFO[133usize], entry),
-                60usize => (&INFO[135usize], entry),
-                61usize => (&INFO[136usize], entry),
-                62usize => (&INFO[138usize], entry),
-                63usize => (&INFO[140usize], entry),
-                64usize => (&INFO[142usize], entry),
-                65usize => (&INFO[144usize], entry),
-                66usize => (&INFO[146usize], entry),
-                67usize => (&INFO[147usize], entry),
-                68usize => (&INFO[148usize], entry),
-                69usize => (&INFO[150usize], entry),
-                70usize => (&INFO[152usize], entry),
-                71usize => (&INFO[154usize], entry),
-                72usize => (&INFO[156usize], entry),
-                73usize => (&INFO[158usize], entry),
-                74usize => (&INFO[159usize], entry),
-                75usize => (&INFO[160usize], entry),
-                76usize => (&INFO[161usize], entry),
-                77usize => (&INFO[163usize], entry),
+                59usize => (&INFO[132usize], entry),
+                60usize => (&INFO[136usize], entry),
+                61usize => (&INFO[138usize], entry),
+                62usize => (&INFO[140usize], entry),
+                63usize => (&INFO[143usize], entry),
+                64usize => (&INFO[144usize], entry),
+                65usize => (&INFO[146usize], entry),
+                66usize => (&INFO[148usize], entry),
+                67usize => (&INFO[150usize], entry),
+                68usize => (&INFO[152usize], entry),
+                69usize => (&INFO[154usize], entry),
+                70usize => (&INFO[155usize], entry),
+                71usize => (&INFO[156usize], entry),
+                72usize => (&INFO[158usize], entry),
+                73usize => (&INFO[160usize], entry),
+                74usize => (&INFO[162usize], entry),
+                75usize => (&INFO[164usize], entry),
+                76usize => (&INFO[166usize], entry),
+                77usize => (&INFO[167usize], entry),
+                78usize => (&INFO[168usize], entry),
+                79usize => (&INFO[170usize], entry),
+                80usize => (&INFO[175usize], entry),
+                81usize => (&INFO[176usize], entry),
+                82usize => (&INFO[177usize], entry),
+                83usize => (&INFO[178usize], entry),
+                84usize => (&INFO[180usize], entry),
                 _ => unsafe { core::hint::unreachable_unchecked() },
             })
     }
@@ -1649,6 +1805,7 @@ impl<R: Registry> Subscriber<R> {
                 0usize => (&INFO[24usize], entry),
                 1usize => (&INFO[34usize], entry),
                 2usize => (&INFO[97usize], entry),
+                3usize => (&INFO[142usize], entry),
                 _ => unsafe { core::hint::unreachable_unchecked() },
             })
     }
@@ -1827,21 +1984,39 @@ impl<R: Registry> Subscriber<R> {
                 }
                 26usize => {
                     let offset = *entry;
-                    let variants = <crate::connection::Error as AsVariant>::VARIANTS;
+                    let variants = <AddressFamily as AsVariant>::VARIANTS;
                     let entries = &self.nominal_counters[offset..offset + variants.len()];
-                    (&INFO[118usize], entries, variants)
+                    (&INFO[117usize], entries, variants)
                 }
                 27usize => {
                     let offset = *entry;
-                    let variants = <DatagramDropReason as AsVariant>::VARIANTS;
+                    let variants = <crate::connection::Error as AsVariant>::VARIANTS;
                     let entries = &self.nominal_counters[offset..offset + variants.len()];
-                    (&INFO[132usize], entries, variants)
+                    (&INFO[121usize], entries, variants)
                 }
                 28usize => {
+                    let offset = *entry;
+                    let variants = <DatagramDropReason as AsVariant>::VARIANTS;
+                    let entries = &self.nominal_counters[offset..offset + variants.len()];
+                    (&INFO[135usize], entries, variants)
+                }
+                29usize => {
                     let offset = *entry;
                     let variants = <crate::connection::Error as AsVariant>::VARIANTS;
                     let entries = &self.nominal_counters[offset..offset + variants.len()];
-                    (&INFO[134usize], entries, variants)
+                    (&INFO[137usize], entries, variants)
+                }
+                30usize => {
+                    let offset = *entry;
+                    let variants = <ConnectionAttemptOutcome as AsVariant>::VARIANTS;
+                    let entries = &self.nominal_counters[offset..offset + variants.len()];
+                    (&INFO[139usize], entries, variants)
+                }
+                31usize => {
+                    let offset = *entry;
+                    let variants = <TlsConfigReloadCause as AsVariant>::VARIANTS;
+                    let entries = &self.nominal_counters[offset..offset + variants.len()];
+                    (&INFO[141usize], entries, variants)
                 }
                 _ => unsafe { core::hint::unreachable_unchecked() },
             })
@@ -1882,23 +2057,29 @@ impl<R: Registry> Subscriber<R> {
                 18usize => (&INFO[104usize], entry),
                 19usize => (&INFO[105usize], entry),
                 20usize => (&INFO[106usize], entry),
-                21usize => (&INFO[123usize], entry),
-                22usize => (&INFO[124usize], entry),
-                23usize => (&INFO[125usize], entry),
-                24usize => (&INFO[127usize], entry),
-                25usize => (&INFO[128usize], entry),
-                26usize => (&INFO[130usize], entry),
-                27usize => (&INFO[131usize], entry),
-                28usize => (&INFO[137usize], entry),
-                29usize => (&INFO[139usize], entry),
-                30usize => (&INFO[141usize], entry),
-                31usize => (&INFO[143usize], entry),
-                32usize => (&INFO[145usize], entry),
-                33usize => (&INFO[149usize], entry),
-                34usize => (&INFO[151usize], entry),
-                35usize => (&INFO[153usize], entry),
-                36usize => (&INFO[155usize], entry),
-                37usize => (&INFO[157usize], entry),
+                21usize => (&INFO[118usize], entry),
+                22usize => (&INFO[126usize], entry),
+                23usize => (&INFO[127usize], entry),
+                24usize => (&INFO[128usize], entry),
+                25usize => (&INFO[130usize], entry),
+                26usize => (&INFO[131usize], entry),
+                27usize => (&INFO[133usize], entry),
+                28usize => (&INFO[134usize], entry),
+                29usize => (&INFO[145usize], entry),
+                30usize => (&INFO[147usize], entry),
+                31usize => (&INFO[149usize], entry),
+                32usize => (&INFO[151usize], entry),
+                33usize => (&INFO[153usize], entry),
+                34usize => (&INFO[157usize], entry),
+                35usize => (&INFO[159usize], entry),
+                36usize => (&INFO[161usize], entry),
+                37usize => (&INFO[163usize], entry),
+                38usize => (&INFO[165usize], entry),
+                39usize => (&INFO[169usize], entry),
+                40usize => (&INFO[171usize], entry),
+                41usize => (&INFO[172usize], entry),
+                42usize => (&INFO[173usize], entry),
+                43usize => (&INFO[174usize], entry),
                 _ => unsafe { core::hint::unreachable_unchecked() },
             })
     }
@@ -1941,8 +2122,8 @@ impl<R: Registry> Subscriber<R> {
                 10usize => (&INFO[111usize], entry),
                 11usize => (&INFO[112usize], entry),
                 12usize => (&INFO[113usize], entry),
-                13usize => (&INFO[117usize], entry),
-                14usize => (&INFO[162usize], entry),
+                13usize => (&INFO[120usize], entry),
+                14usize => (&INFO[179usize], entry),
                 _ => unsafe { core::hint::unreachable_unchecked() },
             })
     }
@@ -2808,6 +2989,22 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
         let _ = event;
     }
     #[inline]
+    fn on_happy_eyeballs_winner(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &api::ConnectionMeta,
+        event: &api::HappyEyeballsWinner,
+    ) {
+        #[allow(unused_imports)]
+        use api::*;
+        self.count(116usize, 52usize, 1usize);
+        self.count_nominal(117usize, 26usize, &event.address_family);
+        self.measure(118usize, 21usize, event.attempts);
+        let _ = context;
+        let _ = meta;
+        let _ = event;
+    }
+    #[inline]
     fn on_connection_closed(
         &mut self,
         context: &mut Self::ConnectionContext,
@@ -2816,13 +3013,13 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     ) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(116usize, 52usize, 1usize);
+        self.count(119usize, 53usize, 1usize);
         self.time(
-            117usize,
+            120usize,
             13usize,
             meta.timestamp.saturating_duration_since(context.start_time),
         );
-        self.count_nominal(118usize, 26usize, &event.error);
+        self.count_nominal(121usize, 27usize, &event.error);
         let _ = context;
         let _ = meta;
         let _ = event;
@@ -2835,7 +3032,7 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     ) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(119usize, 53usize, 1usize);
+        self.count(122usize, 54usize, 1usize);
         let _ = event;
         let _ = meta;
     }
@@ -2847,7 +3044,7 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     ) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(120usize, 54usize, 1usize);
+        self.count(123usize, 55usize, 1usize);
         let _ = event;
         let _ = meta;
     }
@@ -2859,7 +3056,7 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     ) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(121usize, 55usize, 1usize);
+        self.count(124usize, 56usize, 1usize);
         let _ = event;
         let _ = meta;
     }
@@ -2871,10 +3068,10 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     ) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(122usize, 56usize, 1usize);
-        self.measure(123usize, 21usize, event.len);
-        self.measure(124usize, 22usize, event.len);
-        self.measure(125usize, 23usize, event.gso_offset);
+        self.count(125usize, 57usize, 1usize);
+        self.measure(126usize, 22usize, event.len);
+        self.measure(127usize, 23usize, event.len);
+        self.measure(128usize, 24usize, event.gso_offset);
         let _ = event;
         let _ = meta;
     }
@@ -2886,9 +3083,9 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     ) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(126usize, 57usize, 1usize);
-        self.measure(127usize, 24usize, event.len);
-        self.measure(128usize, 25usize, event.len);
+        self.count(129usize, 58usize, 1usize);
+        self.measure(130usize, 25usize, event.len);
+        self.measure(131usize, 26usize, event.len);
         let _ = event;
         let _ = meta;
     }
@@ -2900,10 +3097,10 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     ) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(129usize, 58usize, 1usize);
-        self.measure(130usize, 26usize, event.len);
-        self.measure(131usize, 27usize, event.len);
-        self.count_nominal(132usize, 27usize, &event.reason);
+        self.count(132usize, 59usize, 1usize);
+        self.measure(133usize, 27usize, event.len);
+        self.measure(134usize, 28usize, event.len);
+        self.count_nominal(135usize, 28usize, &event.reason);
         let _ = event;
         let _ = meta;
     }
@@ -2915,8 +3112,31 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     ) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(133usize, 59usize, 1usize);
-        self.count_nominal(134usize, 28usize, &event.error);
+        self.count(136usize, 60usize, 1usize);
+        self.count_nominal(137usize, 29usize, &event.error);
+        let _ = event;
+        let _ = meta;
+    }
+    #[inline]
+    fn on_endpoint_connection_attempt_limited(
+        &mut self,
+        meta: &api::EndpointMeta,
+        event: &api::EndpointConnectionAttemptLimited,
+    ) {
+        #[allow(unused_imports)]
+        use api::*;
+        self.count(138usize, 61usize, 1usize);
+        self.count_nominal(139usize, 30usize, &event.outcome);
+        let _ = event;
+        let _ = meta;
+    }
+    #[inline]
+    fn on_tls_config_reloaded(&mut self, meta: &api::EndpointMeta, event: &api::TlsConfigReloaded) {
+        #[allow(unused_imports)]
+        use api::*;
+        self.count(140usize, 62usize, 1usize);
+        self.count_nominal(141usize, 31usize, &event.cause);
+        self.count_bool(142usize, 3usize, event.success);
         let _ = event;
         let _ = meta;
     }
@@ -2924,17 +3144,17 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     fn on_platform_tx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTx) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(135usize, 60usize, 1usize);
-        self.count(136usize, 61usize, event.count);
-        self.measure(137usize, 28usize, event.count);
-        self.count(138usize, 62usize, event.syscalls);
-        self.measure(139usize, 29usize, event.syscalls);
-        self.count(140usize, 63usize, event.blocked_syscalls);
-        self.measure(141usize, 30usize, event.blocked_syscalls);
-        self.count(142usize, 64usize, event.total_errors);
-        self.measure(143usize, 31usize, event.total_errors);
-        self.count(144usize, 65usize, event.dropped_errors);
-        self.measure(145usize, 32usize, event.dropped_errors);
+        self.count(143usize, 63usize, 1usize);
+        self.count(144usize, 64usize, event.count);
+        self.measure(145usize, 29usize, event.count);
+        self.count(146usize, 65usize, event.syscalls);
+        self.measure(147usize, 30usize, event.syscalls);
+        self.count(148usize, 66usize, event.blocked_syscalls);
+        self.measure(149usize, 31usize, event.blocked_syscalls);
+        self.count(150usize, 67usize, event.total_errors);
+        self.measure(151usize, 32usize, event.total_errors);
+        self.count(152usize, 68usize, event.dropped_errors);
+        self.measure(153usize, 33usize, event.dropped_errors);
         let _ = event;
         let _ = meta;
     }
@@ -2942,7 +3162,7 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     fn on_platform_tx_error(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTxError) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(146usize, 66usize, 1usize);
+        self.count(154usize, 69usize, 1usize);
         let _ = event;
         let _ = meta;
     }
@@ -2950,17 +3170,17 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     fn on_platform_rx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformRx) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(147usize, 67usize, 1usize);
-        self.count(148usize, 68usize, event.count);
-        self.measure(149usize, 33usize, event.count);
-        self.count(150usize, 69usize, event.syscalls);
-        self.measure(151usize, 34usize, event.syscalls);
-        self.count(152usize, 70usize, event.blocked_syscalls);
-        self.measure(153usize, 35usize, event.blocked_syscalls);
-        self.count(154usize, 71usize, event.total_errors);
-        self.measure(155usize, 36usize, event.total_errors);
-        self.count(156usize, 72usize, event.dropped_errors);
-        self.measure(157usize, 37usize, event.dropped_errors);
+        self.count(155usize, 70usize, 1usize);
+        self.count(156usize, 71usize, event.count);
+        self.measure(157usize, 34usize, event.count);
+        self.count(158usize, 72usize, event.syscalls);
+        self.measure(159usize, 35usize, event.syscalls);
+        self.count(160usize, 73usize, event.blocked_syscalls);
+        self.measure(161usize, 36usize, event.blocked_syscalls);
+        self.count(162usize, 74usize, event.total_errors);
+        self.measure(163usize, 37usize, event.total_errors);
+        self.count(164usize, 75usize, event.dropped_errors);
+        self.measure(165usize, 38usize, event.dropped_errors);
         let _ = event;
         let _ = meta;
     }
@@ -2968,7 +3188,49 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     fn on_platform_rx_error(&mut self, meta: &api::EndpointMeta, event: &api::PlatformRxError) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(158usize, 73usize, 1usize);
+        self.count(166usize, 76usize, 1usize);
+        let _ = event;
+        let _ = meta;
+    }
+    #[inline]
+    fn on_platform_rx_queue_overflow(
+        &mut self,
+        meta: &api::EndpointMeta,
+        event: &api::PlatformRxQueueOverflow,
+    ) {
+        #[allow(unused_imports)]
+        use api::*;
+        self.count(167usize, 77usize, 1usize);
+        self.count(168usize, 78usize, event.dropped);
+        self.measure(169usize, 39usize, event.dropped);
+        let _ = event;
+        let _ = meta;
+    }
+    #[inline]
+    fn on_platform_socket_memory(
+        &mut self,
+        meta: &api::EndpointMeta,
+        event: &api::PlatformSocketMemory,
+    ) {
+        #[allow(unused_imports)]
+        use api::*;
+        self.count(170usize, 79usize, 1usize);
+        self.measure(171usize, 40usize, event.rx_allocated);
+        self.measure(172usize, 41usize, event.rx_capacity);
+        self.measure(173usize, 42usize, event.tx_allocated);
+        self.measure(174usize, 43usize, event.tx_capacity);
+        let _ = event;
+        let _ = meta;
+    }
+    #[inline]
+    fn on_platform_xdp_fallback(
+        &mut self,
+        meta: &api::EndpointMeta,
+        event: &api::PlatformXdpFallback,
+    ) {
+        #[allow(unused_imports)]
+        use api::*;
+        self.count(175usize, 80usize, 1usize);
         let _ = event;
         let _ = meta;
     }
@@ -2980,7 +3242,7 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     ) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(159usize, 74usize, 1usize);
+        self.count(176usize, 81usize, 1usize);
         let _ = event;
         let _ = meta;
     }
@@ -2992,7 +3254,7 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     ) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(160usize, 75usize, 1usize);
+        self.count(177usize, 82usize, 1usize);
         let _ = event;
         let _ = meta;
     }
@@ -3004,8 +3266,8 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     ) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(161usize, 76usize, 1usize);
-        self.time(162usize, 14usize, event.processing_duration);
+        self.count(178usize, 83usize, 1usize);
+        self.time(179usize, 14usize, event.processing_duration);
         let _ = event;
         let _ = meta;
     }
@@ -3017,7 +3279,7 @@ impl<R: Registry> event::Subscriber for Subscriber<R> {
     ) {
         #[allow(unused_imports)]
         use api::*;
-        self.count(163usize, 77usize, 1usize);
+        self.count(180usize, 84usize, 1usize);
         let _ = event;
         let _ = meta;
     }
@@ -71,32 +71,39 @@ mod counter {
                 107usize => Self(bbr_state_changed),
                 109usize => Self(dc_state_changed),
                 115usize => Self(dc_path_created),
-                116usize => Self(connection_closed),
-                119usize => Self(version_information),
-                120usize => Self(endpoint_packet_sent),
-                121usize => Self(endpoint_packet_received),
-                122usize => Self(endpoint_datagram_sent),
-                126usize => Self(endpoint_datagram_received),
-                129usize => Self(endpoint_datagram_dropped),
-                133usize => Self(endpoint_connection_attempt_failed),
-                135usize => Self(platform_tx),
-                136usize => Self(platform_tx__packets__total),
-                138usize => Self(platform_tx__syscalls__total),
-                140usize => Self(platform_tx__syscalls__blocked__total),
-                142usize => Self(platform_tx__errors__total),
-                144usize => Self(platform_tx__errors__dropped__total),
-                146usize => Self(platform_tx_error),
-                147usize => Self(platform_rx),
-                148usize => Self(platform_rx__packets__total),
-                150usize => Self(platform_rx__syscalls__total),
-                152usize => Self(platform_rx__syscalls__blocked__total),
-                154usize => Self(platform_rx__errors__total),
-                156usize => Self(platform_rx__errors__dropped__total),
-                158usize => Self(platform_rx_error),
-                159usize => Self(platform_feature_configured),
-                160usize => Self(platform_event_loop_wakeup),
-                161usize => Self(platform_event_loop_sleep),
-                163usize => Self(platform_event_loop_started),
+                116usize => Self(happy_eyeballs_winner),
+                119usize => Self(connection_closed),
+                122usize => Self(version_information),
+                123usize => Self(endpoint_packet_sent),
+                124usize => Self(endpoint_packet_received),
+                125usize => Self(endpoint_datagram_sent),
+                129usize => Self(endpoint_datagram_received),
+                132usize => Self(endpoint_datagram_dropped),
+                136usize => Self(endpoint_connection_attempt_failed),
+                138usize => Self(endpoint_connection_attempt_limited),
+                140usize => Self(tls_config_reloaded),
+                143usize => Self(platform_tx),
+                144usize => Self(platform_tx__packets__total),
+                146usize => Self(platform_tx__syscalls__total),
+                148usize => Self(platform_tx__syscalls__blocked__total),
+                150usize => Self(platform_tx__errors__total),
+                152usize => Self(platform_tx__errors__dropped__total),
+                154usize => Self(platform_tx_error),
+                155usize => Self(platform_rx),
+                156usize => Self(platform_rx__packets__total),
+                158usize => Self(platform_rx__syscalls__total),
+                160usize => Self(platform_rx__syscalls__blocked__total),
+                162usize => Self(platform_rx__errors__total),
+                164usize => Self(platform_rx__errors__dropped__total),
+                166usize => Self(platform_rx_error),
+                167usize => Self(platform_rx_queue_overflow),
+                168usize => Self(platform_rx_queue_overflow__dropped__total),
+                170usize => Self(platform_socket_memory),
+                175usize => Self(platform_xdp_fallback),
+                176usize => Self(platform_feature_configured),
+                177usize => Self(platform_event_loop_wakeup),
+                178usize => Self(platform_event_loop_sleep),
+                180usize => Self(platform_event_loop_started),
                 _ => unreachable!("invalid info: {info:?}"),
             }
         }
@@ -212,6 +219,8 @@ mod counter {
             fn dc_state_changed(value: u64);
             # [link_name = s2n_quic__event__counter__dc_path_created]
             fn dc_path_created(value: u64);
+            # [link_name = s2n_quic__event__counter__happy_eyeballs_winner]
+            fn happy_eyeballs_winner(value: u64);
             # [link_name = s2n_quic__event__counter__connection_closed]
             fn connection_closed(value: u64);
             # [link_name = s2n_quic__event__counter__version_information]
@@ -228,6 +237,10 @@ mod counter {
             fn endpoint_datagram_dropped(value: u64);
             # [link_name = s2n_quic__event__counter__endpoint_connection_attempt_failed]
             fn endpoint_connection_attempt_failed(value: u64);
+            # [link_name = s2n_quic__event__counter__endpoint_connection_attempt_limited]
+            fn endpoint_connection_attempt_limited(value: u64);
+            # [link_name = s2n_quic__event__counter__tls_config_reloaded]
+            fn tls_config_reloaded(value: u64);
             # [link_name = s2n_quic__event__counter__platform_tx]
             fn platform_tx(value: u64);
             # [link_name = s2n_quic__event__counter__platform_tx__packets__total]
@@ -256,6 +269,14 @@ mod counter {
             fn platform_rx__errors__dropped__total(value: u64);
             # [link_name = s2n_quic__event__counter__platform_rx_error]
             fn platform_rx_error(value: u64);
+            # [link_name = s2n_quic__event__counter__platform_rx_queue_overflow]
+            fn platform_rx_queue_overflow(value: u64);
+            # [link_name = s2n_quic__event__counter__platform_rx_queue_overflow__dropped__total]
+            fn platform_rx_queue_overflow__dropped__total(value: u64);
+            # [link_name = s2n_quic__event__counter__platform_socket_memory]
+            fn platform_socket_memory(value: u64);
+            # [link_name = s2n_quic__event__counter__platform_xdp_fallback]
+            fn platform_xdp_fallback(value: u64);
             # [link_name = s2n_quic__event__counter__platform_feature_configured]
             fn platform_feature_configured(value: u64);
             # [link_name = s2n_quic__event__counter__platform_event_loop_wakeup]
@@ -276,6 +297,7 @@ mod counter {
                     24usize => Self(packet_lost__is_mtu_probe),
                     34usize => Self(recovery_metrics__congestion_limited),
                     97usize => Self(mtu_updated__search_complete),
+                    142usize => Self(tls_config_reloaded__success),
                     _ => unreachable!("invalid info: {info:?}"),
                 }
             }
@@ -293,6 +315,8 @@ mod counter {
                 fn recovery_metrics__congestion_limited(value: bool);
                 # [link_name = s2n_quic__event__counter__bool__mtu_updated__search_complete]
                 fn mtu_updated__search_complete(value: bool);
+                # [link_name = s2n_quic__event__counter__bool__tls_config_reloaded__success]
+                fn tls_config_reloaded__success(value: bool);
             }
         );
     }
@@ -330,9 +354,12 @@ mod counter {
                     99usize => Self(slow_start_exited__cause),
                     108usize => Self(bbr_state_changed__state),
                     114usize => Self(dc_state_changed__state),
-                    118usize => Self(connection_closed__error),
-                    132usize => Self(endpoint_datagram_dropped__reason),
-                    134usize => Self(endpoint_connection_attempt_failed__error),
+                    117usize => Self(happy_eyeballs_winner__family),
+                    121usize => Self(connection_closed__error),
+                    135usize => Self(endpoint_datagram_dropped__reason),
+                    137usize => Self(endpoint_connection_attempt_failed__error),
+                    139usize => Self(endpoint_connection_attempt_limited__outcome),
+                    141usize => Self(tls_config_reloaded__cause),
                     _ => unreachable!("invalid info: {info:?}"),
                 }
             }
@@ -417,6 +444,12 @@ mod counter {
                 fn bbr_state_changed__state(value: u64, variant: u64, variant_name: &info::Str);
                 # [link_name = s2n_quic__event__counter__nominal__dc_state_changed__state]
                 fn dc_state_changed__state(value: u64, variant: u64, variant_name: &info::Str);
+                # [link_name = s2n_quic__event__counter__nominal__happy_eyeballs_winner__family]
+                fn happy_eyeballs_winner__family(
+                    value: u64,
+                    variant: u64,
+                    variant_name: &info::Str,
+                );
                 # [link_name = s2n_quic__event__counter__nominal__connection_closed__error]
                 fn connection_closed__error(value: u64, variant: u64, variant_name: &info::Str);
                 # [link_name = s2n_quic__event__counter__nominal__endpoint_datagram_dropped__reason]
@@ -431,6 +464,14 @@ mod counter {
                     variant: u64,
                     variant_name: &info::Str,
                 );
+                # [link_name = s2n_quic__event__counter__nominal__endpoint_connection_attempt_limited__outcome]
+                fn endpoint_connection_attempt_limited__outcome(
+                    value: u64,
+                    variant: u64,
+                    variant_name: &info::Str,
+                );
+                # [link_name = s2n_quic__event__counter__nominal__tls_config_reloaded__cause]
+                fn tls_config_reloaded__cause(value: u64, variant: u64, variant_name: &info::Str);
             }
         );
     }
@@ -464,23 +505,29 @@ mod measure {
                 104usize => Self(pacing_rate_updated__bytes_per_second),
                 105usize => Self(pacing_rate_updated__burst_size),
                 106usize => Self(pacing_rate_updated__pacing_gain),
-                123usize => Self(endpoint_datagram_sent__bytes),
-                124usize => Self(endpoint_datagram_sent__bytes__total),
-                125usize => Self(endpoint_datagram_sent__gso_offset),
-                127usize => Self(endpoint_datagram_received__bytes),
-                128usize => Self(endpoint_datagram_received__bytes__total),
-                130usize => Self(endpoint_datagram_dropped__bytes),
-                131usize => Self(endpoint_datagram_dropped__bytes__total),
-                137usize => Self(platform_tx__packets),
-                139usize => Self(platform_tx__syscalls),
-                141usize => Self(platform_tx__syscalls__blocked),
-                143usize => Self(platform_tx__errors),
-                145usize => Self(platform_tx__errors__dropped),
-                149usize => Self(platform_rx__packets),
-                151usize => Self(platform_rx__syscalls),
-                153usize => Self(platform_rx__syscalls__blocked),
-                155usize => Self(platform_rx__errors),
-                157usize => Self(platform_rx__errors__dropped),
+                118usize => Self(happy_eyeballs_winner__attempts),
+                126usize => Self(endpoint_datagram_sent__bytes),
+                127usize => Self(endpoint_datagram_sent__bytes__total),
+                128usize => Self(endpoint_datagram_sent__gso_offset),
+                130usize => Self(endpoint_datagram_received__bytes),
+                131usize => Self(endpoint_datagram_received__bytes__total),
+                133usize => Self(endpoint_datagram_dropped__bytes),
+                134usize => Self(endpoint_datagram_dropped__bytes__total),
+                145usize => Self(platform_tx__packets),
+                147usize => Self(platform_tx__syscalls),
+                149usize => Self(platform_tx__syscalls__blocked),
+                151usize => Self(platform_tx__errors),
+                153usize => Self(platform_tx__errors__dropped),
+                157usize => Self(platform_rx__packets),
+                159usize => Self(platform_rx__syscalls),
+                161usize => Self(platform_rx__syscalls__blocked),
+                163usize => Self(platform_rx__errors),
+                165usize => Self(platform_rx__errors__dropped),
+                169usize => Self(platform_rx_queue_overflow__dropped),
+                171usize => Self(platform_socket_memory__rx__allocated),
+                172usize => Self(platform_socket_memory__rx__capacity),
+                173usize => Self(platform_socket_memory__tx__allocated),
+                174usize => Self(platform_socket_memory__tx__capacity),
                 _ => unreachable!("invalid info: {info:?}"),
             }
         }
@@ -534,6 +581,8 @@ mod measure {
             fn pacing_rate_updated__burst_size(value: u64);
             # [link_name = s2n_quic__event__measure__pacing_rate_updated__pacing_gain]
             fn pacing_rate_updated__pacing_gain(value: u64);
+            # [link_name = s2n_quic__event__measure__happy_eyeballs_winner__attempts]
+            fn happy_eyeballs_winner__attempts(value: u64);
             # [link_name = s2n_quic__event__measure__endpoint_datagram_sent__bytes]
             fn endpoint_datagram_sent__bytes(value: u64);
             # [link_name = s2n_quic__event__measure__endpoint_datagram_sent__bytes__total]
@@ -568,6 +617,16 @@ mod measure {
             fn platform_rx__errors(value: u64);
             # [link_name = s2n_quic__event__measure__platform_rx__errors__dropped]
             fn platform_rx__errors__dropped(value: u64);
+            # [link_name = s2n_quic__event__measure__platform_rx_queue_overflow__dropped]
+            fn platform_rx_queue_overflow__dropped(value: u64);
+            # [link_name = s2n_quic__event__measure__platform_socket_memory__rx__allocated]
+            fn platform_socket_memory__rx__allocated(value: u64);
+            # [link_name = s2n_quic__event__measure__platform_socket_memory__rx__capacity]
+            fn platform_socket_memory__rx__capacity(value: u64);
+            # [link_name = s2n_quic__event__measure__platform_socket_memory__tx__allocated]
+            fn platform_socket_memory__tx__allocated(value: u64);
+            # [link_name = s2n_quic__event__measure__platform_socket_memory__tx__capacity]
+            fn platform_socket_memory__tx__capacity(value: u64);
         }
     );
 }
@@ -608,8 +667,8 @@ mod timer {
                 111usize => Self(dc_state_changed__no_version_negotiated__latency),
                 112usize => Self(dc_state_changed__path_secrets__latency),
                 113usize => Self(dc_state_changed__complete__latency),
-                117usize => Self(connection_closed__latency),
-                162usize => Self(platform_event_loop_sleep__processing_duration),
+                120usize => Self(connection_closed__latency),
+                179usize => Self(platform_event_loop_sleep__processing_duration),
                 _ => unreachable!("invalid info: {info:?}"),
             }
         }
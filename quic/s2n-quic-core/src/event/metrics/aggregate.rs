@@ -6,6 +6,8 @@ pub use crate::event::generated::metrics::aggregate::*;
 
 pub mod info;
 mod metric;
+#[cfg(feature = "std")]
+pub mod openmetrics;
 pub mod probe;
 mod recorder;
 mod variant;
@@ -0,0 +1,594 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A [`Registry`](super::Registry) which aggregates metrics in memory and exports them in the
+//! [OpenMetrics](https://github.com/prometheus/OpenMetrics/blob/main/specification/OpenMetrics.md)
+//! text format
+//!
+//! The output can be scraped by Prometheus by calling [`Registry::encode`] from an HTTP
+//! endpoint. `s2n-quic` provides a minimal handler with the `provider-event-openmetrics` feature.
+//!
+//! Counters are exported as `counter` families, gauges as `gauge` families and measures and
+//! timers as `histogram` families. Nominal metrics include the variant name in a `variant`
+//! label and bool counters include the recorded value in a `value` label.
+//!
+//! Some events record both a total (e.g. `packet_sent.bytes.total`) and a distribution (e.g.
+//! `packet_sent.bytes`) which map to the same family name. In that case the histogram family is
+//! qualified with its type (e.g. `packet_sent_histogram_bytes`) to keep family names unique.
+
+use super::{info, Info, Metric, Units};
+use crate::endpoint;
+use alloc::{collections::BTreeMap, string::String, sync::Arc, vec::Vec};
+use core::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use std::sync::Mutex;
+
+#[cfg(test)]
+mod tests;
+
+/// The `Content-Type` of the encoded metrics
+pub const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// Histogram buckets for metrics measured in seconds
+const DURATION_BUCKETS: &[f64] = &[
+    0.000_001, 0.000_01, 0.000_1, 0.000_25, 0.000_5, 0.001, 0.002_5, 0.005, 0.01, 0.025, 0.05, 0.1,
+    0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0,
+];
+
+/// Histogram buckets for metrics measured in percent
+const PERCENT_BUCKETS: &[f64] = &[
+    0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 100.0,
+];
+
+/// Histogram buckets for all other metrics, in powers of 4
+const COUNT_BUCKETS: &[f64] = &[
+    0.0,
+    1.0,
+    4.0,
+    16.0,
+    64.0,
+    256.0,
+    1024.0,
+    4096.0,
+    16384.0,
+    65536.0,
+    262144.0,
+    1048576.0,
+    4194304.0,
+    16777216.0,
+    67108864.0,
+    268435456.0,
+    1073741824.0,
+    4294967296.0,
+];
+
+/// The registered families, keyed by their name without the unit suffix
+type Families = BTreeMap<(String, Units, Kind), Family>;
+
+#[derive(Clone, Default)]
+pub struct Registry {
+    families: Arc<Mutex<Families>>,
+    /// Labels which are attached to every metric registered through this handle
+    labels: Vec<(&'static str, String)>,
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Registry")
+            .field("labels", &self.labels)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a handle to the same registry which labels all of the metrics registered through
+    /// it with the given endpoint type
+    ///
+    /// This makes it possible to export metrics for clients and servers in the same process
+    /// without the two colliding.
+    pub fn with_endpoint_type(&self, endpoint_type: endpoint::Type) -> Self {
+        let value = match endpoint_type {
+            endpoint::Type::Client => "client",
+            endpoint::Type::Server => "server",
+        };
+        self.with_label("endpoint_type", value)
+    }
+
+    /// Returns a handle to the same registry which labels all of the metrics registered through
+    /// it with `name="value"`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid label name or is reserved by the exporter.
+    pub fn with_label<V: fmt::Display>(&self, name: &'static str, value: V) -> Self {
+        assert!(is_valid_label_name(name), "invalid label name: {name:?}");
+        assert!(
+            !["variant", "value", "le"].contains(&name),
+            "label name {name:?} is reserved"
+        );
+
+        let mut labels = self.labels.clone();
+        let value = value.to_string();
+        if let Some(entry) = labels.iter_mut().find(|(n, _)| *n == name) {
+            entry.1 = value;
+        } else {
+            labels.push((name, value));
+        }
+
+        Self {
+            families: self.families.clone(),
+            labels,
+        }
+    }
+
+    /// Encodes all of the registered metrics in the OpenMetrics text format
+    pub fn encode(&self) -> String {
+        let mut out = String::new();
+        // writing to a String is infallible
+        let _ = self.encode_to(&mut out);
+        out
+    }
+
+    /// Writes all of the registered metrics in the OpenMetrics text format to `out`
+    pub fn encode_to<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        let families = self.families.lock().unwrap_or_else(|err| err.into_inner());
+
+        for ((stem, units, kind), family) in families.iter() {
+            let is_ambiguous = *kind != Kind::Counter
+                && [Kind::Counter, Kind::Gauge, Kind::Histogram]
+                    .iter()
+                    .filter(|other| *other != kind)
+                    .any(|other| families.contains_key(&(stem.clone(), *units, *other)));
+
+            let mut name = stem.clone();
+            if is_ambiguous {
+                name.push('_');
+                name.push_str(kind.as_str());
+            }
+            if let Some(unit) = unit_suffix(*units) {
+                name.push('_');
+                name.push_str(unit);
+            }
+
+            family.encode(&name, out)?;
+        }
+
+        out.write_str("# EOF\n")
+    }
+
+    fn register(
+        &self,
+        info: &'static Info,
+        kind: Kind,
+        extra: Option<(&'static str, &str)>,
+    ) -> Arc<Series> {
+        let stem = family_stem(info, kind);
+
+        let mut labels = String::new();
+        for (name, value) in self.labels.iter() {
+            push_label(&mut labels, name, value);
+        }
+        if let Some((name, value)) = extra {
+            push_label(&mut labels, name, value);
+        }
+
+        let mut families = self.families.lock().unwrap_or_else(|err| err.into_inner());
+        let family = families
+            .entry((stem, info.units, kind))
+            .or_insert_with(|| Family {
+                kind,
+                units: info.units,
+                series: Default::default(),
+            });
+
+        family
+            .series
+            .entry(labels)
+            .or_insert_with(|| Arc::new(Series::new(kind, info.units)))
+            .clone()
+    }
+}
+
+impl super::Registry for Registry {
+    type Counter = Recorder;
+    type BoolCounter = BoolRecorder;
+    type NominalCounter = NominalRecorder;
+    type Measure = Recorder;
+    type Gauge = Recorder;
+    type Timer = Recorder;
+    type NominalTimer = NominalRecorder;
+
+    fn register_counter(&self, info: &'static Info) -> Self::Counter {
+        Recorder(self.register(info, Kind::Counter, None))
+    }
+
+    fn register_bool_counter(&self, info: &'static Info) -> Self::BoolCounter {
+        BoolRecorder {
+            true_count: self.register(info, Kind::Counter, Some(("value", "true"))),
+            false_count: self.register(info, Kind::Counter, Some(("value", "false"))),
+        }
+    }
+
+    fn register_nominal_counter(
+        &self,
+        info: &'static Info,
+        variant: &'static info::Variant,
+    ) -> Self::NominalCounter {
+        NominalRecorder(self.register(info, Kind::Counter, Some(("variant", variant.name))))
+    }
+
+    fn register_measure(&self, info: &'static Info) -> Self::Measure {
+        Recorder(self.register(info, Kind::Histogram, None))
+    }
+
+    fn register_gauge(&self, info: &'static Info) -> Self::Gauge {
+        Recorder(self.register(info, Kind::Gauge, None))
+    }
+
+    fn register_timer(&self, info: &'static Info) -> Self::Timer {
+        Recorder(self.register(info, Kind::Histogram, None))
+    }
+
+    fn register_nominal_timer(
+        &self,
+        info: &'static Info,
+        variant: &'static info::Variant,
+    ) -> Self::NominalTimer {
+        NominalRecorder(self.register(info, Kind::Histogram, Some(("variant", variant.name))))
+    }
+}
+
+#[derive(Debug)]
+pub struct Recorder(Arc<Series>);
+
+impl super::Recorder for Recorder {
+    #[inline]
+    fn record<T: Metric>(&self, info: &'static Info, value: T) {
+        self.0.record(info.units, value);
+    }
+}
+
+#[derive(Debug)]
+pub struct NominalRecorder(Arc<Series>);
+
+impl super::NominalRecorder for NominalRecorder {
+    #[inline]
+    fn record<T: Metric>(&self, info: &'static Info, _variant: &'static info::Variant, value: T) {
+        // the variant was already bound to the series at registration
+        self.0.record(info.units, value);
+    }
+}
+
+#[derive(Debug)]
+pub struct BoolRecorder {
+    true_count: Arc<Series>,
+    false_count: Arc<Series>,
+}
+
+impl super::BoolRecorder for BoolRecorder {
+    #[inline]
+    fn record(&self, info: &'static Info, value: bool) {
+        let series = if value {
+            &self.true_count
+        } else {
+            &self.false_count
+        };
+        series.record(info.units, 1u64);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Kind {
+    Counter,
+    Gauge,
+    Histogram,
+}
+
+impl Kind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+            Self::Histogram => "histogram",
+        }
+    }
+}
+
+struct Family {
+    kind: Kind,
+    units: Units,
+    /// The registered series, keyed by their encoded labels
+    series: BTreeMap<String, Arc<Series>>,
+}
+
+impl Family {
+    fn encode<W: fmt::Write>(&self, name: &str, out: &mut W) -> fmt::Result {
+        writeln!(out, "# TYPE {name} {}", self.kind.as_str())?;
+        if let Some(unit) = unit_suffix(self.units) {
+            writeln!(out, "# UNIT {name} {unit}")?;
+        }
+
+        for (labels, series) in self.series.iter() {
+            series.encode(name, labels, out)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+enum Series {
+    Counter(Counter),
+    Gauge(Gauge),
+    Histogram(Histogram),
+}
+
+impl Series {
+    fn new(kind: Kind, units: Units) -> Self {
+        match kind {
+            Kind::Counter => Self::Counter(Counter {
+                value: AtomicU64::new(0),
+                is_duration: units == Units::Duration,
+            }),
+            Kind::Gauge => Self::Gauge(Gauge::default()),
+            Kind::Histogram => Self::Histogram(Histogram::new(units)),
+        }
+    }
+
+    #[inline]
+    fn record<T: Metric>(&self, units: Units, value: T) {
+        match self {
+            Self::Counter(counter) => counter.record(value),
+            Self::Gauge(gauge) => gauge.record(units, value),
+            Self::Histogram(histogram) => histogram.record(units, value),
+        }
+    }
+
+    fn encode<W: fmt::Write>(&self, name: &str, labels: &str, out: &mut W) -> fmt::Result {
+        match self {
+            Self::Counter(counter) => counter.encode(name, labels, out),
+            Self::Gauge(gauge) => gauge.encode(name, labels, out),
+            Self::Histogram(histogram) => histogram.encode(name, labels, out),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Counter {
+    /// The total value in microseconds for durations and the base unit otherwise
+    value: AtomicU64,
+    is_duration: bool,
+}
+
+impl Counter {
+    #[inline]
+    fn record<T: Metric>(&self, value: T) {
+        let value = if self.is_duration {
+            value.as_duration().as_micros() as u64
+        } else {
+            value.as_u64()
+        };
+        self.value.fetch_add(value, Ordering::Relaxed);
+    }
+
+    fn encode<W: fmt::Write>(&self, name: &str, labels: &str, out: &mut W) -> fmt::Result {
+        let value = self.value.load(Ordering::Relaxed);
+        write!(out, "{name}_total")?;
+        write_labels(out, labels, None)?;
+        // durations are tracked in micros but exported in seconds
+        if self.is_duration {
+            writeln!(out, " {}", value as f64 / 1_000_000.0)
+        } else {
+            writeln!(out, " {value}")
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Gauge {
+    value: AtomicF64,
+}
+
+impl Gauge {
+    #[inline]
+    fn record<T: Metric>(&self, units: Units, value: T) {
+        self.value.store(as_f64(units, value));
+    }
+
+    fn encode<W: fmt::Write>(&self, name: &str, labels: &str, out: &mut W) -> fmt::Result {
+        out.write_str(name)?;
+        write_labels(out, labels, None)?;
+        writeln!(out, " {}", Float(self.value.load()))
+    }
+}
+
+#[derive(Debug)]
+struct Histogram {
+    bounds: &'static [f64],
+    /// Per-bucket counts with a trailing `+Inf` bucket
+    buckets: Vec<AtomicU64>,
+    sum: AtomicF64,
+}
+
+impl Histogram {
+    fn new(units: Units) -> Self {
+        let bounds = match units {
+            Units::Duration => DURATION_BUCKETS,
+            Units::Percent => PERCENT_BUCKETS,
+            _ => COUNT_BUCKETS,
+        };
+        let buckets = (0..=bounds.len()).map(|_| AtomicU64::new(0)).collect();
+        Self {
+            bounds,
+            buckets,
+            sum: Default::default(),
+        }
+    }
+
+    #[inline]
+    fn record<T: Metric>(&self, units: Units, value: T) {
+        let value = as_f64(units, value);
+        let idx = self.bounds.partition_point(|bound| *bound < value);
+        self.buckets[idx].fetch_add(1, Ordering::Relaxed);
+        self.sum.add(value);
+    }
+
+    fn encode<W: fmt::Write>(&self, name: &str, labels: &str, out: &mut W) -> fmt::Result {
+        let mut count = 0;
+        for (idx, bucket) in self.buckets.iter().enumerate() {
+            count += bucket.load(Ordering::Relaxed);
+            let le = self.bounds.get(idx).copied().unwrap_or(f64::INFINITY);
+            write!(out, "{name}_bucket")?;
+            write_labels(out, labels, Some(Float(le)))?;
+            writeln!(out, " {count}")?;
+        }
+
+        write!(out, "{name}_count")?;
+        write_labels(out, labels, None)?;
+        writeln!(out, " {count}")?;
+
+        write!(out, "{name}_sum")?;
+        write_labels(out, labels, None)?;
+        writeln!(out, " {}", Float(self.sum.load()))
+    }
+}
+
+#[derive(Debug, Default)]
+struct AtomicF64(AtomicU64);
+
+impl AtomicF64 {
+    #[inline]
+    fn load(&self) -> f64 {
+        f64::from_bits(self.0.load(Ordering::Relaxed))
+    }
+
+    #[inline]
+    fn store(&self, value: f64) {
+        self.0.store(value.to_bits(), Ordering::Relaxed);
+    }
+
+    #[inline]
+    fn add(&self, value: f64) {
+        let _ = self
+            .0
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                Some((f64::from_bits(current) + value).to_bits())
+            });
+    }
+}
+
+/// Formats a float in a way that's accepted by OpenMetrics parsers
+struct Float(f64);
+
+impl fmt::Display for Float {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = self.0;
+        if value.is_nan() {
+            f.write_str("NaN")
+        } else if value.is_infinite() {
+            f.write_str(if value > 0.0 { "+Inf" } else { "-Inf" })
+        } else if value.fract() == 0.0 && value.abs() < 1e15 {
+            write!(f, "{value:.1}")
+        } else {
+            write!(f, "{value}")
+        }
+    }
+}
+
+/// Converts the value into the base unit of the exported metric
+#[inline]
+fn as_f64<T: Metric>(units: Units, value: T) -> f64 {
+    if units == Units::Duration {
+        value.as_duration().as_secs_f64()
+    } else {
+        value.as_f64()
+    }
+}
+
+fn unit_suffix(units: Units) -> Option<&'static str> {
+    match units {
+        Units::Bytes => Some("bytes"),
+        Units::Duration => Some("seconds"),
+        Units::Percent => Some("percent"),
+        _ => None,
+    }
+}
+
+/// Derives the OpenMetrics family name, without the unit suffix, from the metric info
+///
+/// Metric names use `.` as a separator (e.g. `datagram_sent.bytes.total`), which are replaced
+/// with `_`. Counters have their `_total` suffix stripped, since it's appended to the sample
+/// name, and the unit is stripped so it can be appended to the final family name.
+fn family_stem(info: &Info, kind: Kind) -> String {
+    let mut name: String = info
+        .name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == ':' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    if kind == Kind::Counter {
+        if let Some(stripped) = name.strip_suffix("_total") {
+            name.truncate(stripped.len());
+        }
+    }
+
+    if let Some(unit) = unit_suffix(info.units) {
+        if let Some(stripped) = name.strip_suffix(unit).and_then(|s| s.strip_suffix('_')) {
+            name.truncate(stripped.len());
+        }
+    }
+
+    name
+}
+
+fn is_valid_label_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    (first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.starts_with("__")
+}
+
+fn push_label(labels: &mut String, name: &str, value: &str) {
+    if !labels.is_empty() {
+        labels.push(',');
+    }
+    labels.push_str(name);
+    labels.push_str("=\"");
+    for c in value.chars() {
+        match c {
+            '\\' => labels.push_str("\\\\"),
+            '"' => labels.push_str("\\\""),
+            '\n' => labels.push_str("\\n"),
+            c => labels.push(c),
+        }
+    }
+    labels.push('"');
+}
+
+fn write_labels<W: fmt::Write>(out: &mut W, labels: &str, le: Option<Float>) -> fmt::Result {
+    match (labels.is_empty(), le) {
+        (true, None) => Ok(()),
+        (true, Some(le)) => write!(out, "{{le=\"{le}\"}}"),
+        (false, None) => write!(out, "{{{labels}}}"),
+        (false, Some(le)) => write!(out, "{{{labels},le=\"{le}\"}}"),
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::event::metrics::aggregate::{
+    info::{self, Str},
+    BoolRecorder as _, NominalRecorder as _, Recorder as _, Registry as _,
+};
+use core::time::Duration;
+
+static REQUESTS: Info = info::Builder {
+    id: 0,
+    name: Str::new("request.total\0"),
+    units: Units::None,
+}
+.build();
+
+static SENT: Info = info::Builder {
+    id: 1,
+    name: Str::new("datagram_sent.bytes.total\0"),
+    units: Units::Bytes,
+}
+.build();
+
+static HANDSHAKE: Info = info::Builder {
+    id: 2,
+    name: Str::new("handshake.latency\0"),
+    units: Units::Duration,
+}
+.build();
+
+static CWND: Info = info::Builder {
+    id: 3,
+    name: Str::new("congestion_window\0"),
+    units: Units::Bytes,
+}
+.build();
+
+static FLAG: Info = info::Builder {
+    id: 4,
+    name: Str::new("is_enabled\0"),
+    units: Units::None,
+}
+.build();
+
+static IDLE: Info = info::Builder {
+    id: 5,
+    name: Str::new("idle.time\0"),
+    units: Units::Duration,
+}
+.build();
+
+static VARIANT: info::Variant = info::variant::Builder {
+    id: 0,
+    name: Str::new("IDLE_TIMEOUT\0"),
+}
+.build();
+
+#[test]
+fn family_stems() {
+    assert_eq!(family_stem(&REQUESTS, Kind::Counter), "request");
+    assert_eq!(family_stem(&SENT, Kind::Counter), "datagram_sent");
+    assert_eq!(
+        family_stem(&HANDSHAKE, Kind::Histogram),
+        "handshake_latency"
+    );
+    assert_eq!(family_stem(&CWND, Kind::Gauge), "congestion_window");
+}
+
+#[test]
+fn ambiguous_families() {
+    static SENT_SIZE: Info = info::Builder {
+        id: 6,
+        name: Str::new("datagram_sent.bytes\0"),
+        units: Units::Bytes,
+    }
+    .build();
+
+    let registry = Registry::new();
+    registry.register_counter(&SENT).record(&SENT, 100u64);
+    registry
+        .register_measure(&SENT_SIZE)
+        .record(&SENT_SIZE, 100u64);
+
+    let encoded = registry.encode();
+    assert!(encoded.contains("# TYPE datagram_sent_bytes counter\n"));
+    assert!(encoded.contains("datagram_sent_bytes_total 100\n"));
+    assert!(encoded.contains("# TYPE datagram_sent_histogram_bytes histogram\n"));
+    assert!(encoded.contains("datagram_sent_histogram_bytes_count 1\n"));
+}
+
+#[test]
+fn counters() {
+    let registry = Registry::new();
+
+    let requests = registry.register_counter(&REQUESTS);
+    requests.record(&REQUESTS, 1u64);
+    requests.record(&REQUESTS, 2u64);
+
+    let idle = registry.register_counter(&IDLE);
+    idle.record(&IDLE, Duration::from_millis(1500));
+
+    let flag = registry.register_bool_counter(&FLAG);
+    flag.record(&FLAG, true);
+    flag.record(&FLAG, false);
+    flag.record(&FLAG, true);
+
+    let nominal = registry.register_nominal_counter(&REQUESTS, &VARIANT);
+    nominal.record(&REQUESTS, &VARIANT, 4u64);
+
+    let expected = [
+        "# TYPE idle_time_seconds counter",
+        "# UNIT idle_time_seconds seconds",
+        "idle_time_seconds_total 1.5",
+        "# TYPE is_enabled counter",
+        "is_enabled_total{value=\"false\"} 1",
+        "is_enabled_total{value=\"true\"} 2",
+        "# TYPE request counter",
+        "request_total 3",
+        "request_total{variant=\"IDLE_TIMEOUT\"} 4",
+        "# EOF",
+        "",
+    ]
+    .join("\n");
+
+    assert_eq!(registry.encode(), expected);
+}
+
+#[test]
+fn gauges() {
+    let registry = Registry::new();
+
+    let cwnd = registry.register_gauge(&CWND);
+    cwnd.record(&CWND, 1200u64);
+    cwnd.record(&CWND, 2400u64);
+
+    let expected = [
+        "# TYPE congestion_window_bytes gauge",
+        "# UNIT congestion_window_bytes bytes",
+        "congestion_window_bytes 2400.0",
+        "# EOF",
+        "",
+    ]
+    .join("\n");
+
+    assert_eq!(registry.encode(), expected);
+}
+
+#[test]
+fn histograms() {
+    let registry = Registry::new();
+
+    let handshake = registry.register_timer(&HANDSHAKE);
+    handshake.record(&HANDSHAKE, Duration::from_millis(3));
+    handshake.record(&HANDSHAKE, Duration::from_millis(20));
+    handshake.record(&HANDSHAKE, Duration::from_secs(120));
+
+    let encoded = registry.encode();
+    let lines: Vec<_> = encoded.lines().collect();
+
+    assert_eq!(lines[0], "# TYPE handshake_latency_seconds histogram");
+    assert_eq!(lines[1], "# UNIT handshake_latency_seconds seconds");
+    assert!(lines.contains(&"handshake_latency_seconds_bucket{le=\"0.0025\"} 0"));
+    assert!(lines.contains(&"handshake_latency_seconds_bucket{le=\"0.005\"} 1"));
+    assert!(lines.contains(&"handshake_latency_seconds_bucket{le=\"0.025\"} 2"));
+    assert!(lines.contains(&"handshake_latency_seconds_bucket{le=\"60.0\"} 2"));
+    assert!(lines.contains(&"handshake_latency_seconds_bucket{le=\"+Inf\"} 3"));
+    assert!(lines.contains(&"handshake_latency_seconds_count 3"));
+    assert!(lines.contains(&"handshake_latency_seconds_sum 120.023"));
+    assert_eq!(lines.last(), Some(&"# EOF"));
+}
+
+#[test]
+fn labels() {
+    let registry = Registry::new();
+    let client = registry
+        .with_endpoint_type(endpoint::Type::Client)
+        .with_label("app", "say \"hi\"");
+    let server = registry.with_endpoint_type(endpoint::Type::Server);
+
+    client.register_counter(&REQUESTS).record(&REQUESTS, 1u64);
+    server.register_counter(&REQUESTS).record(&REQUESTS, 2u64);
+    // registering the same metric again should return the existing series
+    server.register_counter(&REQUESTS).record(&REQUESTS, 3u64);
+
+    server.register_nominal_timer(&HANDSHAKE, &VARIANT).record(
+        &HANDSHAKE,
+        &VARIANT,
+        Duration::from_secs(1),
+    );
+
+    let encoded = registry.encode();
+    let lines: Vec<_> = encoded.lines().collect();
+
+    // each family should only be described once
+    assert_eq!(
+        lines
+            .iter()
+            .filter(|l| l.starts_with("# TYPE request "))
+            .count(),
+        1
+    );
+    assert!(lines.contains(&"request_total{endpoint_type=\"client\",app=\"say \\\"hi\\\"\"} 1"));
+    assert!(lines.contains(&"request_total{endpoint_type=\"server\"} 5"));
+    assert!(lines.contains(
+        &"handshake_latency_seconds_bucket{endpoint_type=\"server\",variant=\"IDLE_TIMEOUT\",le=\"1.0\"} 1"
+    ));
+}
+
+#[test]
+#[should_panic]
+fn reserved_label() {
+    let _ = Registry::new().with_label("variant", "foo");
+}
+
+#[test]
+fn subscriber() {
+    let registry = Registry::new().with_endpoint_type(endpoint::Type::Server);
+    let _subscriber = crate::event::metrics::aggregate::Subscriber::new(registry.clone());
+
+    let encoded = registry.encode();
+    assert!(encoded.contains("# TYPE packet_sent counter\n"));
+    assert!(encoded.contains("# TYPE packet_sent_bytes counter\n"));
+    assert!(encoded.contains("# TYPE packet_sent_histogram_bytes histogram\n"));
+    assert!(encoded.ends_with("# EOF\n"));
+
+    // family names must be unique
+    let families: Vec<_> = encoded
+        .lines()
+        .filter(|line| line.starts_with("# TYPE "))
+        .collect();
+    let unique: std::collections::BTreeSet<_> = families.iter().collect();
+    assert_eq!(families.len(), unique.len());
+}
@@ -0,0 +1,162 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::ExplicitCongestionNotification;
+use core::fmt;
+
+#[cfg(any(test, feature = "generator"))]
+use bolero_generator::prelude::*;
+
+//= https://www.rfc-editor.org/rfc/rfc2474#section-3
+//# A replacement header field, called the DS field, is defined, which is
+//# intended to supersede the existing definitions of the IPv4 TOS octet
+//# [RFC791] and the IPv6 Traffic Class octet [IPv6].
+//#
+//# Six bits of the DS field are used as a codepoint (DSCP) to select the
+//# PHB a packet experiences at each node.
+
+/// Differentiated Services Code Point
+///
+/// The DSCP occupies the upper 6 bits of the IPv4 TOS and IPv6 Traffic Class fields. The lower
+/// 2 bits carry the [`ExplicitCongestionNotification`] markings.
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(any(test, feature = "generator"), derive(TypeGenerator))]
+pub struct Dscp(#[cfg_attr(any(test, feature = "generator"), generator(0..=Self::MAX))] u8);
+
+macro_rules! code_points {
+    ($($(#[doc = $doc:literal])* $name:ident = $value:literal),* $(,)?) => {
+        impl Dscp {
+            $(
+                $(#[doc = $doc])*
+                pub const $name: Self = Self($value);
+            )*
+        }
+    };
+}
+
+code_points!(
+    /// Default forwarding (best effort)
+    DEFAULT = 0,
+    /// Class selector 1 - commonly used for scavenger traffic
+    CS1 = 8,
+    /// Class selector 2
+    CS2 = 16,
+    /// Class selector 3
+    CS3 = 24,
+    /// Class selector 4
+    CS4 = 32,
+    /// Class selector 5
+    CS5 = 40,
+    /// Class selector 6 - network control
+    CS6 = 48,
+    /// Class selector 7
+    CS7 = 56,
+    /// Assured forwarding class 1, low drop precedence
+    AF11 = 10,
+    /// Assured forwarding class 1, medium drop precedence
+    AF12 = 12,
+    /// Assured forwarding class 1, high drop precedence
+    AF13 = 14,
+    /// Assured forwarding class 2, low drop precedence
+    AF21 = 18,
+    /// Assured forwarding class 2, medium drop precedence
+    AF22 = 20,
+    /// Assured forwarding class 2, high drop precedence
+    AF23 = 22,
+    /// Assured forwarding class 3, low drop precedence
+    AF31 = 26,
+    /// Assured forwarding class 3, medium drop precedence
+    AF32 = 28,
+    /// Assured forwarding class 3, high drop precedence
+    AF33 = 30,
+    /// Assured forwarding class 4, low drop precedence
+    AF41 = 34,
+    /// Assured forwarding class 4, medium drop precedence
+    AF42 = 36,
+    /// Assured forwarding class 4, high drop precedence
+    AF43 = 38,
+    /// Expedited forwarding - low latency, low loss traffic
+    EF = 46,
+    /// Lower effort - see [RFC 8622](https://www.rfc-editor.org/rfc/rfc8622)
+    LE = 1,
+);
+
+impl Dscp {
+    /// The largest code point that fits in the 6 bit field
+    pub const MAX: u8 = 0b11_1111;
+
+    /// Creates a code point, returning `None` if the value doesn't fit in 6 bits
+    #[inline]
+    pub const fn new(value: u8) -> Option<Self> {
+        if value > Self::MAX {
+            return None;
+        }
+        Some(Self(value))
+    }
+
+    /// Reads the code point from an IPv4 TOS or IPv6 Traffic Class field
+    #[inline]
+    pub const fn from_traffic_class(value: u8) -> Self {
+        Self(value >> 2)
+    }
+
+    #[inline]
+    pub const fn as_u8(self) -> u8 {
+        self.0
+    }
+
+    /// Combines the code point with the ECN markings into a single IPv4 TOS or IPv6 Traffic
+    /// Class value
+    #[inline]
+    pub const fn traffic_class(self, ecn: ExplicitCongestionNotification) -> u8 {
+        (self.0 << 2) | ecn as u8
+    }
+}
+
+impl fmt::Debug for Dscp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Dscp").field(&self.0).finish()
+    }
+}
+
+impl TryFrom<u8> for Dscp {
+    type Error = u8;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::new(value).ok_or(value)
+    }
+}
+
+impl From<Dscp> for u8 {
+    #[inline]
+    fn from(value: Dscp) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn traffic_class_round_trip() {
+        bolero::check!()
+            .with_type::<(Dscp, u8)>()
+            .cloned()
+            .for_each(|(dscp, ecn)| {
+                let ecn = ExplicitCongestionNotification::new(ecn);
+                let value = dscp.traffic_class(ecn);
+                assert_eq!(Dscp::from_traffic_class(value), dscp);
+                assert_eq!(ExplicitCongestionNotification::new(value), ecn);
+            });
+    }
+
+    #[test]
+    fn new_test() {
+        assert_eq!(Dscp::new(46), Some(Dscp::EF));
+        assert_eq!(Dscp::new(Dscp::MAX).map(u8::from), Some(63));
+        assert_eq!(Dscp::new(64), None);
+        assert_eq!(Dscp::default(), Dscp::DEFAULT);
+    }
+}
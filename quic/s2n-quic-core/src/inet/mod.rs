@@ -6,6 +6,7 @@ mod macros;
 
 pub mod checksum;
 pub mod datagram;
+pub mod dscp;
 pub mod ecn;
 pub mod ethernet;
 pub mod ip;
@@ -15,6 +16,7 @@ pub mod udp;
 pub mod unspecified;
 
 pub use datagram::{AncillaryData, DatagramInfo};
+pub use dscp::Dscp;
 pub use ecn::*;
 pub use ip::*;
 pub use ipv4::{IpV4Address, SocketAddressV4};
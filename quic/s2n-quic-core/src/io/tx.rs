@@ -1,7 +1,12 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{event, inet::ExplicitCongestionNotification, path};
+use crate::{
+    event,
+    inet::{Dscp, ExplicitCongestionNotification},
+    path,
+    time::Timestamp,
+};
 use core::{
     task::{Context, Poll},
     time::Duration,
@@ -99,6 +104,16 @@ pub trait Queue {
         // default as no-op
     }
 
+    /// Returns how far into the future messages can be scheduled with [`Message::delay`]
+    ///
+    /// Queues that support pacing hold on to delayed messages until they are due, which allows
+    /// the sender to push messages ahead of their departure time rather than waking up for each
+    /// one. This is only queried if [`Self::SUPPORTS_PACING`] is set.
+    #[inline]
+    fn pacing_horizon(&self) -> Duration {
+        Duration::ZERO
+    }
+
     /// Returns the number of remaining datagrams that can be transmitted
     fn capacity(&self) -> usize;
 
@@ -141,11 +156,30 @@ pub trait Message {
     /// Returns the ECN markings for the message
     fn ecn(&mut self) -> ExplicitCongestionNotification;
 
+    /// Returns the DSCP markings for the message
+    ///
+    /// The markings are combined with the [`Message::ecn`] bits in the IPv4 TOS or IPv6 Traffic
+    /// Class field.
+    #[inline]
+    fn dscp(&mut self) -> Dscp {
+        Dscp::DEFAULT
+    }
+
     /// Returns the Duration for which the message will be delayed.
     ///
     /// This is used in scenarios where packets need to be paced.
     fn delay(&mut self) -> Duration;
 
+    /// Returns the time at which the message should depart, as measured by the endpoint's clock
+    ///
+    /// This is only set if the message is written ahead of its departure time. Unlike
+    /// [`Message::delay`], the value doesn't depend on when the queue processes the message, so
+    /// IO providers that offload pacing should prefer it.
+    #[inline]
+    fn departure_time(&mut self) -> Option<Timestamp> {
+        None
+    }
+
     /// Returns the IPv6 flow label for the message
     fn ipv6_flow_label(&mut self) -> u32;
 
@@ -278,6 +312,7 @@ mod tests {
 
         assert_eq!(*message.path_handle(), tuple);
         assert_eq!(message.ecn(), Default::default());
+        assert_eq!(message.dscp(), Dscp::DEFAULT);
         assert_eq!(message.delay(), Default::default());
         assert_eq!(message.ipv6_flow_label(), 0);
         assert_eq!(
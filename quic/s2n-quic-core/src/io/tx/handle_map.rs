@@ -1,7 +1,13 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{event, inet::ExplicitCongestionNotification, io::tx, path};
+use crate::{
+    event,
+    inet::{Dscp, ExplicitCongestionNotification},
+    io::tx,
+    path,
+    time::Timestamp,
+};
 use core::{
     marker::PhantomData,
     task::{Context, Poll},
@@ -104,6 +110,11 @@ where
         self.tx.push(message)
     }
 
+    #[inline]
+    fn pacing_horizon(&self) -> Duration {
+        self.tx.pacing_horizon()
+    }
+
     #[inline]
     fn capacity(&self) -> usize {
         self.tx.capacity()
@@ -138,11 +149,21 @@ where
         self.inner.ecn()
     }
 
+    #[inline]
+    fn dscp(&mut self) -> Dscp {
+        self.inner.dscp()
+    }
+
     #[inline]
     fn delay(&mut self) -> Duration {
         self.inner.delay()
     }
 
+    #[inline]
+    fn departure_time(&mut self) -> Option<Timestamp> {
+        self.inner.departure_time()
+    }
+
     #[inline]
     fn ipv6_flow_label(&mut self) -> u32 {
         self.inner.ipv6_flow_label()
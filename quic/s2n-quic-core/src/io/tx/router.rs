@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{event, io::tx, path};
-use core::task::{Context, Poll};
+use core::{
+    task::{Context, Poll},
+    time::Duration,
+};
 
 /// Defines how to route a message between two different channels
 pub trait Router {
@@ -154,6 +157,12 @@ where
         self.router.route(message, self.a, self.b)
     }
 
+    #[inline]
+    fn pacing_horizon(&self) -> Duration {
+        // take the minimum of the horizons, since we don't know where the next message will go
+        self.a.pacing_horizon().min(self.b.pacing_horizon())
+    }
+
     #[inline]
     fn capacity(&self) -> usize {
         // take the minimum of the channel capacity, since we don't know where the next message
@@ -565,6 +565,11 @@ impl CongestionController for BbrCongestionController {
     fn send_quantum(&self) -> Option<usize> {
         Some(self.pacer.send_quantum())
     }
+
+    #[inline]
+    fn pacing_rate(&self) -> Option<Bandwidth> {
+        Some(self.pacer.pacing_rate())
+    }
 }
 
 impl BbrCongestionController {
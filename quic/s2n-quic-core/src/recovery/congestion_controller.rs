@@ -243,6 +243,13 @@ pub trait CongestionController: 'static + Clone + Send + Debug + private::Sealed
     fn send_quantum(&self) -> Option<usize> {
         None
     }
+
+    /// The rate at which packets are being paced
+    ///
+    /// If the value is `None`, the congestion controller is not pacing packets.
+    fn pacing_rate(&self) -> Option<Bandwidth> {
+        None
+    }
 }
 
 // Prevent implementation of the `CongestionController` trait if the
@@ -6,6 +6,7 @@ use crate::{
     event::builder::SlowStartExitCause,
     random,
     recovery::{
+        bandwidth::Bandwidth,
         congestion_controller::{self, CongestionController, Publisher},
         cubic::{FastRetransmission::*, State::*},
         hybrid_slow_start::HybridSlowStart,
@@ -493,6 +494,11 @@ impl CongestionController for CubicCongestionController {
     fn earliest_departure_time(&self) -> Option<Timestamp> {
         self.pacer.earliest_departure_time()
     }
+
+    #[inline]
+    fn pacing_rate(&self) -> Option<Bandwidth> {
+        self.pacer.pacing_rate()
+    }
 }
 
 impl CubicCongestionController {
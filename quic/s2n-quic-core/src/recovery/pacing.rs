@@ -39,6 +39,8 @@ pub struct Pacer {
     capacity: Counter<u32, Saturating>,
     // The time the next packet should be transmitted
     next_packet_departure_time: Option<Timestamp>,
+    // The rate used to compute the most recent departure time
+    pacing_rate: Option<Bandwidth>,
 }
 
 impl Pacer {
@@ -55,6 +57,7 @@ impl Pacer {
         publisher: &mut Pub,
     ) {
         if rtt_estimator.smoothed_rtt() < MINIMUM_PACING_RTT {
+            self.pacing_rate = None;
             return;
         }
 
@@ -69,6 +72,11 @@ impl Pacer {
                 );
                 self.next_packet_departure_time =
                     Some((next_packet_departure_time + interval).max(now));
+                self.pacing_rate = Some(Self::rate(
+                    rtt_estimator.smoothed_rtt(),
+                    congestion_window,
+                    slow_start,
+                ));
             } else {
                 self.next_packet_departure_time = Some(now + INITIAL_INTERVAL);
             }
@@ -85,6 +93,13 @@ impl Pacer {
         self.next_packet_departure_time
     }
 
+    /// Returns the rate at which packets are currently being paced
+    ///
+    /// This is `None` if packets are not being paced.
+    pub fn pacing_rate(&self) -> Option<Bandwidth> {
+        self.pacing_rate
+    }
+
     // Recalculate the interval between bursts of paced packets
     #[inline]
     fn interval<Pub: Publisher>(
@@ -96,6 +111,21 @@ impl Pacer {
     ) -> Duration {
         debug_assert_ne!(congestion_window, 0);
 
+        let n = if slow_start { SLOW_START_N } else { N };
+        let pacing_rate = Self::rate(rtt, congestion_window, slow_start);
+
+        // `MAX_BURST_PACKETS` is incorporated into the formula since we are trying to spread
+        // bursts of packets evenly over time.
+        let packet_size = MAX_BURST_PACKETS * max_datagram_size as u32;
+
+        publisher.on_pacing_rate_updated(pacing_rate, packet_size, n);
+
+        packet_size as u64 / pacing_rate
+    }
+
+    // Calculate the rate at which packets are paced
+    #[inline]
+    fn rate(rtt: Duration, congestion_window: u32, slow_start: bool) -> Bandwidth {
         let n = if slow_start { SLOW_START_N } else { N };
 
         //= https://www.rfc-editor.org/rfc/rfc9002#section-7.7
@@ -106,15 +136,7 @@ impl Pacer {
         //# where congestion_window is in bytes:
         //#
         //# rate = N * congestion_window / smoothed_rtt
-        let pacing_rate = Bandwidth::new(congestion_window as u64, rtt) * n;
-
-        // `MAX_BURST_PACKETS` is incorporated into the formula since we are trying to spread
-        // bursts of packets evenly over time.
-        let packet_size = MAX_BURST_PACKETS * max_datagram_size as u32;
-
-        publisher.on_pacing_rate_updated(pacing_rate, packet_size, n);
-
-        packet_size as u64 / pacing_rate
+        Bandwidth::new(congestion_window as u64, rtt) * n
     }
 }
 
@@ -124,7 +124,10 @@ fn encode_ipv4<M: Message<Handle = path::Tuple>>(
 
     buffer.write_zerocopy(|header: &mut ipv4::Header| {
         header.vihl_mut().set_version(4).set_header_len(5);
-        header.tos_mut().set_dscp(0).set_ecn(message.ecn());
+        header
+            .tos_mut()
+            .set_dscp(message.dscp().as_u8())
+            .set_ecn(message.ecn());
         header
             .flag_fragment_mut()
             .set_reserved(false)
@@ -197,7 +200,7 @@ fn encode_ipv6<M: Message<Handle = path::Tuple>>(
         header
             .vtcfl_mut()
             .set_version(6)
-            .set_dscp(0)
+            .set_dscp(message.dscp().as_u8())
             .set_ecn(message.ecn())
             .set_flow_label(message.ipv6_flow_label());
         header.payload_len_mut().set(payload_len);
@@ -340,7 +343,10 @@ struct PayloadOutcome {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{inet::ExplicitCongestionNotification, path::Handle};
+    use crate::{
+        inet::{Dscp, ExplicitCongestionNotification},
+        path::Handle,
+    };
     use bolero::{check, generator::*};
     use s2n_codec::DecoderBufferMut;
 
@@ -348,6 +354,7 @@ mod tests {
     pub struct Message {
         path: path::Tuple,
         ecn: ExplicitCongestionNotification,
+        dscp: Dscp,
         ipv4_id: u16,
         ipv4_checksum: bool,
         ipv6_flow_label: u32,
@@ -365,6 +372,10 @@ mod tests {
             self.ecn
         }
 
+        fn dscp(&mut self) -> Dscp {
+            self.dscp
+        }
+
         fn delay(&mut self) -> core::time::Duration {
             Default::default()
         }
@@ -402,6 +413,14 @@ mod tests {
                 return;
             }
 
+            // the traffic class is in the first two bytes after the ethernet header
+            let traffic_class = if buffer[14] >> 4 == 4 {
+                buffer[15]
+            } else {
+                (buffer[14] << 4) | (buffer[15] >> 4)
+            };
+            assert_eq!(Dscp::from_traffic_class(traffic_class), message.dscp);
+
             let (mut header, payload) =
                 crate::xdp::decoder::decode_packet(DecoderBufferMut::new(&mut buffer))
                     .unwrap()
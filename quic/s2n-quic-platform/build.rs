@@ -14,6 +14,10 @@ enum Feature {
     GenericReceiveOffload,
     PacketInfo,
     TypeOfService,
+    RxQueueOverflow,
+    SocketMemoryInfo,
+    TransmitTime,
+    IoUring,
 }
 
 impl Feature {
@@ -27,6 +31,10 @@ impl Feature {
             GenericReceiveOffload => "gro",
             PacketInfo => "pktinfo",
             TypeOfService => "tos",
+            RxQueueOverflow => "rxq_ovfl",
+            SocketMemoryInfo => "meminfo",
+            TransmitTime => "txtime",
+            IoUring => "io_uring",
         }
     }
 }
@@ -43,7 +51,7 @@ impl From<&str> for Feature {
     }
 }
 
-const ALL_FEATURES: [Feature; 8] = [
+const ALL_FEATURES: [Feature; 12] = [
     ControlMessage,
     SocketMessage,
     SocketMultiMessage,
@@ -52,6 +60,10 @@ const ALL_FEATURES: [Feature; 8] = [
     GenericReceiveOffload,
     PacketInfo,
     TypeOfService,
+    RxQueueOverflow,
+    SocketMemoryInfo,
+    TransmitTime,
+    IoUring,
 ];
 
 fn main() -> Result<(), Error> {
@@ -95,6 +107,14 @@ fn main() -> Result<(), Error> {
             features.insert(GenericReceiveOffload);
             features.insert(PacketInfo);
             features.insert(TypeOfService);
+            features.insert(RxQueueOverflow);
+            features.insert(SocketMemoryInfo);
+            features.insert(TransmitTime);
+
+            // miri can't execute the io_uring syscalls
+            if !is_miri {
+                features.insert(IoUring);
+            }
         }
         "macos" => {
             // miri doesn't support the way we detect syscall support so override it
@@ -119,6 +139,7 @@ fn main() -> Result<(), Error> {
         println!("cargo::rustc-check-cfg=cfg(s2n_quic_platform_{name})");
     }
 
+    println!("cargo::rustc-check-cfg=cfg(s2n_quic_platform_xdp_trace)");
     println!("cargo::rustc-check-cfg=cfg(fuzz, kani, kani_slow)");
 
     Ok(())
@@ -142,6 +163,9 @@ impl Features {
             GenericReceiveOffload,
             PacketInfo,
             TypeOfService,
+            RxQueueOverflow,
+            TransmitTime,
+            IoUring,
         ]
         .contains(&feature)
             && !self.supports(ControlMessage)
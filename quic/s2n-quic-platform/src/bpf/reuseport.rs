@@ -0,0 +1,140 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Programs for steering QUIC packets between the sockets in a `SO_REUSEPORT` group
+
+use super::cbpf::*;
+use alloc::vec::Vec;
+
+/// The offset of the destination connection ID in a short header packet
+const SHORT_HEADER_DCID_OFFSET: u32 = 1;
+
+/// The `Header Form` bit, which is set for long header packets
+const LONG_HEADER_BIT: u32 = 0x80;
+
+/// Returning an index outside of the group makes the kernel fall back to selecting
+/// a socket by hashing the packet's 4-tuple
+const HASH_FALLBACK: u32 = u32::MAX;
+
+/// Builds a program which steers packets to a socket based on their destination connection ID
+///
+/// Short header packets are routed to the socket at index `dcid[offset] % sockets` in the
+/// group. Connection IDs need to be generated such that this byte identifies the socket that
+/// owns the connection, which keeps migrated and NAT-rebound packets on the same socket.
+///
+/// Long header packets are routed by the kernel's 4-tuple hash, since the client picks the
+/// destination connection ID of its first Initial packet. Endpoints aren't allowed to migrate
+/// before the handshake is confirmed, so the rest of the handshake arrives on the same socket.
+///
+/// The kernel orders sockets in a group by the time they joined it so they should be bound in
+/// the order of their index.
+///
+/// # Panics
+///
+/// Panics if `sockets` is 0.
+pub fn connection_id_router(offset: u8, sockets: u8) -> Vec<Instruction> {
+    assert!(sockets > 0, "at least one socket is required");
+
+    alloc::vec![
+        // load the first byte of the packet
+        ldb(abs(0)),
+        // IF:
+        // the packet has a long header
+        jset(LONG_HEADER_BIT, 3, 0),
+        // ELSE:
+        // load the byte in the destination connection ID which identifies the socket
+        ldb(abs(SHORT_HEADER_DCID_OFFSET + offset as u32)),
+        // map the byte to a socket index
+        rem(sockets as u32),
+        // return the socket index
+        ret_a(),
+        // THEN:
+        // let the kernel pick a socket with the 4-tuple hash
+        ret(HASH_FALLBACK),
+    ]
+}
+
+#[cfg(all(test, not(miri)))]
+mod tests {
+    use super::*;
+    use crate::syscall;
+    use core::time::Duration;
+    use std::{io, net::UdpSocket};
+
+    #[test]
+    fn snapshot_test() {
+        let program = connection_id_router(0, 4);
+        insta::assert_snapshot!(Program::new(&program));
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_group_test() {
+        connection_id_router(0, 0);
+    }
+
+    fn bind_group(count: u8) -> io::Result<Vec<UdpSocket>> {
+        let mut sockets: Vec<UdpSocket> = Vec::new();
+        let mut addr: std::net::SocketAddr = "127.0.0.1:0".parse().unwrap();
+
+        for _ in 0..count {
+            let socket: UdpSocket = syscall::bind_udp(addr, false, true, false)?.into();
+            addr = socket.local_addr()?;
+            socket.set_read_timeout(Some(Duration::from_millis(100)))?;
+            sockets.push(socket);
+        }
+
+        let program = connection_id_router(0, count);
+        Program::new(&program).attach(&sockets[0])?;
+
+        Ok(sockets)
+    }
+
+    #[test]
+    fn routing_test() {
+        let sockets = match bind_group(2) {
+            Ok(sockets) => sockets,
+            Err(err)
+                if [
+                    io::ErrorKind::PermissionDenied,
+                    io::ErrorKind::AddrNotAvailable,
+                ]
+                .contains(&err.kind()) =>
+            {
+                eprintln!("skipping test due to insufficient permissions");
+                return;
+            }
+            Err(err) => panic!("{err}"),
+        };
+
+        let addr = sockets[0].local_addr().unwrap();
+        let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+        for idx in 0u8..20 {
+            // a short header packet followed by the destination connection ID
+            let packet = [0b0100_0000, idx, 1, 2, 3];
+            client.send_to(&packet, addr).unwrap();
+
+            let socket = &sockets[idx as usize % 2];
+            let mut buffer = [0; 32];
+            let (len, _) = socket.recv_from(&mut buffer).unwrap();
+            assert_eq!(&buffer[..len], &packet);
+        }
+
+        // long header packets from the same 4-tuple all arrive at the same socket
+        for _ in 0..10 {
+            let packet = [0b1100_0000, 0, 0, 0, 1];
+            client.send_to(&packet, addr).unwrap();
+        }
+
+        let mut counts = [0; 2];
+        for (socket, count) in sockets.iter().zip(&mut counts) {
+            let mut buffer = [0; 32];
+            while socket.recv_from(&mut buffer).is_ok() {
+                *count += 1;
+            }
+        }
+        counts.sort();
+        assert_eq!(counts, [0, 10]);
+    }
+}
@@ -9,8 +9,13 @@ pub mod gso;
 pub mod pktinfo;
 pub mod pktinfo_v4;
 pub mod pktinfo_v6;
+pub mod rxq_ovfl;
 pub mod tos;
 pub mod tos_v4;
 pub mod tos_v6;
+pub mod txtime;
+pub mod uring;
 
 pub use gso::Gso;
+pub use txtime::TxTime;
+pub use uring::IoUring;
@@ -0,0 +1,200 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::c_int;
+use core::time::Duration;
+use s2n_quic_core::time::{Clock, Timestamp};
+
+/// How far ahead of their departure time packets can be handed to the kernel
+///
+/// Packets written ahead of time are held by the qdisc until they are due. This is kept small to
+/// limit how much is committed to the kernel before the congestion controller can react.
+pub const PACING_HORIZON: Duration = Duration::from_millis(2);
+
+#[cfg(s2n_quic_platform_txtime)]
+mod txtime_enabled {
+    use super::*;
+    use libc::{SCM_TXTIME, SOL_SOCKET, SO_TXTIME};
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+
+    pub const LEVEL: Option<c_int> = Some(SOL_SOCKET as _);
+    pub const TYPE: Option<c_int> = Some(SCM_TXTIME as _);
+    pub const SOCKOPT: Option<(c_int, c_int)> = Some((SOL_SOCKET as _, SO_TXTIME as _));
+    pub const CMSG_SPACE: usize = crate::message::cmsg::size_of_cmsg::<super::Cmsg>();
+
+    #[inline]
+    pub const fn is_match(level: c_int, ty: c_int) -> bool {
+        level == SOL_SOCKET as c_int && ty == SCM_TXTIME as c_int
+    }
+
+    /// Returns the current `CLOCK_MONOTONIC` time, which the kernel uses for departure times
+    #[inline]
+    pub fn monotonic_now() -> Duration {
+        let mut now = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // Safety: the timespec is valid for the duration of the call
+        unsafe {
+            libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut now);
+        }
+
+        Duration::new(now.tv_sec as _, now.tv_nsec as _)
+    }
+
+    /// Tracks if the kernel is pacing transmissions on the socket
+    #[derive(Clone, Debug)]
+    pub struct TxTime {
+        enabled: Arc<AtomicBool>,
+        /// A timestamp of the endpoint's clock and the `CLOCK_MONOTONIC` time it corresponds to
+        reference: Option<(Timestamp, Duration)>,
+    }
+
+    impl Default for TxTime {
+        fn default() -> Self {
+            Self::new(false)
+        }
+    }
+
+    impl TxTime {
+        #[inline]
+        pub fn new(enabled: bool) -> Self {
+            Self {
+                enabled: Arc::new(AtomicBool::new(enabled)),
+                reference: None,
+            }
+        }
+
+        /// Maps the endpoint's clock to `CLOCK_MONOTONIC`
+        ///
+        /// The mapping is sampled once so every departure time is derived from the endpoint's
+        /// timestamps rather than the time the message happens to be written.
+        #[inline]
+        pub fn with_clock<C: Clock + ?Sized>(mut self, clock: &C) -> Self {
+            let timestamp = clock.get_time();
+            self.reference = Some((timestamp, monotonic_now()));
+            self
+        }
+
+        /// Returns the `CLOCK_MONOTONIC` time, in nanoseconds, for a departure time measured by
+        /// the endpoint's clock
+        ///
+        /// Returns `None` if the clock hasn't been mapped with [`Self::with_clock`].
+        #[inline]
+        pub fn departure_time(&self, departure_time: Timestamp) -> Option<super::Cmsg> {
+            let (reference, monotonic) = self.reference?;
+            let offset = departure_time.saturating_duration_since(reference);
+            Some((monotonic + offset).as_nanos() as _)
+        }
+
+        #[inline]
+        pub fn is_enabled(&self) -> bool {
+            self.enabled.load(Ordering::Relaxed)
+        }
+
+        #[inline]
+        pub fn disable(&self) {
+            self.enabled.store(false, Ordering::Relaxed);
+        }
+
+        /// Returns how far ahead of their departure time packets can be written
+        #[inline]
+        pub fn pacing_horizon(&self) -> Duration {
+            if self.is_enabled() {
+                PACING_HORIZON
+            } else {
+                Duration::ZERO
+            }
+        }
+
+        /// Disables pacing offload if the kernel rejected the transmit time
+        ///
+        /// Returns the previous state if the error was handled.
+        #[inline]
+        pub fn handle_socket_error(&self, error: &std::io::Error) -> Option<bool> {
+            let raw_error = error.raw_os_error()?;
+            s2n_quic_core::ensure!(raw_error == libc::EINVAL, None);
+            let prev = self.enabled.swap(false, Ordering::Relaxed);
+            Some(prev)
+        }
+    }
+}
+
+#[cfg(any(not(s2n_quic_platform_txtime), test))]
+mod txtime_disabled {
+    #![cfg_attr(test, allow(dead_code))]
+    use super::*;
+
+    pub const LEVEL: Option<c_int> = None;
+    pub const TYPE: Option<c_int> = None;
+    pub const SOCKOPT: Option<(c_int, c_int)> = None;
+    pub const CMSG_SPACE: usize = 0;
+
+    #[inline]
+    pub const fn is_match(level: c_int, ty: c_int) -> bool {
+        let _ = level;
+        let _ = ty;
+        false
+    }
+
+    #[derive(Clone, Default, Debug)]
+    pub struct TxTime(());
+
+    impl TxTime {
+        #[inline]
+        pub fn new(enabled: bool) -> Self {
+            let _ = enabled;
+            Self(())
+        }
+
+        #[inline]
+        pub fn with_clock<C: Clock + ?Sized>(self, clock: &C) -> Self {
+            let _ = clock;
+            self
+        }
+
+        #[inline]
+        pub fn departure_time(&self, departure_time: Timestamp) -> Option<super::Cmsg> {
+            let _ = departure_time;
+            None
+        }
+
+        #[inline]
+        pub fn is_enabled(&self) -> bool {
+            false
+        }
+
+        #[inline]
+        #[allow(dead_code)] // this may or may not be used on certain platforms
+        pub fn disable(&self) {
+            // it's already disabled
+        }
+
+        #[inline]
+        pub fn pacing_horizon(&self) -> Duration {
+            Duration::ZERO
+        }
+
+        #[inline(always)]
+        pub fn handle_socket_error(&self, error: &std::io::Error) -> Option<bool> {
+            let _ = error;
+            None
+        }
+    }
+}
+
+mod txtime_impl {
+    #[cfg(not(s2n_quic_platform_txtime))]
+    pub use super::txtime_disabled::*;
+    #[cfg(s2n_quic_platform_txtime)]
+    pub use super::txtime_enabled::*;
+}
+
+pub use txtime_impl::*;
+
+/// The departure time of the packet in nanoseconds
+pub type Cmsg = u64;
+pub const IS_SUPPORTED: bool = cfg!(s2n_quic_platform_txtime);
@@ -0,0 +1,218 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Detects support for driving sockets with [io_uring](https://man7.org/linux/man-pages/man7/io_uring.7.html)
+//!
+//! Similar to GSO, the build script determines if the target can support io_uring and the
+//! remaining checks happen at runtime. The running kernel may be older than required or have
+//! io_uring disabled entirely (e.g. with the `kernel.io_uring_disabled` sysctl or a seccomp
+//! filter), in which case callers should fall back to the readiness-based socket tasks.
+
+#[cfg(all(feature = "io-uring", s2n_quic_platform_io_uring))]
+mod uring_enabled {
+    use io_uring::{opcode, IoUring as Ring, Probe};
+    use std::{
+        ffi::CStr,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, OnceLock,
+        },
+    };
+
+    #[derive(Clone, Debug)]
+    pub struct IoUring {
+        zero_copy: Arc<AtomicBool>,
+        fixed_buffers: Arc<AtomicBool>,
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    struct Support {
+        zero_copy: bool,
+        fixed_buffers: bool,
+    }
+
+    impl IoUring {
+        /// Probes the running kernel for the operations used by the io_uring socket tasks
+        ///
+        /// Returns `None` if io_uring can't be used.
+        #[inline]
+        pub fn probe() -> Option<Self> {
+            // the kernel capabilities don't change while the process is running so only probe once
+            static SUPPORT: OnceLock<Option<Support>> = OnceLock::new();
+
+            let support = (*SUPPORT.get_or_init(probe_kernel))?;
+
+            Some(Self {
+                zero_copy: Arc::new(AtomicBool::new(support.zero_copy)),
+                fixed_buffers: Arc::new(AtomicBool::new(support.fixed_buffers)),
+            })
+        }
+
+        /// Returns `true` if messages should be transmitted with `IORING_OP_SENDMSG_ZC`
+        #[inline]
+        pub fn is_zero_copy_enabled(&self) -> bool {
+            self.zero_copy.load(Ordering::Relaxed)
+        }
+
+        #[inline]
+        pub fn disable_zero_copy(&self) {
+            self.zero_copy.store(false, Ordering::Relaxed);
+        }
+
+        /// Disables zero copy transmission if the error indicates the socket doesn't support it
+        ///
+        /// Returns `true` if the message should be retried without zero copy.
+        #[inline]
+        pub fn handle_zero_copy_error(&self, error: &std::io::Error) -> bool {
+            let Some(raw_error) = error.raw_os_error() else {
+                return false;
+            };
+            s2n_quic_core::ensure!(raw_error == libc::EOPNOTSUPP, false);
+            // every message that failed is retried, not just the first one to notice
+            self.zero_copy.store(false, Ordering::Relaxed);
+            true
+        }
+
+        /// Returns `true` if zero copy messages should be sent from buffers registered with
+        /// `IORING_REGISTER_BUFFERS`
+        #[inline]
+        pub fn is_fixed_buffers_enabled(&self) -> bool {
+            self.fixed_buffers.load(Ordering::Relaxed)
+        }
+
+        /// Disables fixed buffers if the error indicates the kernel rejected them
+        ///
+        /// Returns `true` if the message should be retried without fixed buffers.
+        #[inline]
+        pub fn handle_fixed_buffer_error(&self, error: &std::io::Error) -> bool {
+            let Some(raw_error) = error.raw_os_error() else {
+                return false;
+            };
+            s2n_quic_core::ensure!(matches!(raw_error, libc::EINVAL | libc::EFAULT), false);
+            self.fixed_buffers.store(false, Ordering::Relaxed);
+            true
+        }
+    }
+
+    /// The first kernel version which supports multishot `IORING_OP_RECVMSG`
+    const MULTISHOT_RECVMSG: (u32, u32) = (6, 0);
+
+    /// The first kernel version which supports fixed buffers with `IORING_OP_SENDMSG_ZC`
+    const SENDMSG_ZC_FIXED_BUFFERS: (u32, u32) = (6, 15);
+
+    /// Returns the supported features if the kernel supports all of the required operations
+    fn probe_kernel() -> Option<Support> {
+        // Multishot recvmsg and fixed buffers for sendmsg are flags on existing opcodes so the
+        // opcode probe can't detect them. Older kernels reject unknown flags so the version is
+        // checked instead.
+        let version = kernel_version()?;
+        s2n_quic_core::ensure!(version >= MULTISHOT_RECVMSG, None);
+
+        let ring = Ring::new(2).ok()?;
+
+        let mut probe = Probe::new();
+        ring.submitter().register_probe(&mut probe).ok()?;
+
+        let required = [opcode::RecvMsg::CODE, opcode::SendMsg::CODE];
+
+        if !required.iter().all(|code| probe.is_supported(*code)) {
+            return None;
+        }
+
+        let zero_copy = probe.is_supported(opcode::SendMsgZc::CODE);
+
+        Some(Support {
+            zero_copy,
+            fixed_buffers: zero_copy && version >= SENDMSG_ZC_FIXED_BUFFERS,
+        })
+    }
+
+    /// Returns the `(major, minor)` version of the running kernel
+    fn kernel_version() -> Option<(u32, u32)> {
+        // Safety: `utsname` is valid when zeroed
+        let mut name: libc::utsname = unsafe { core::mem::zeroed() };
+        // Safety: `name` is a valid pointer to a `utsname`
+        s2n_quic_core::ensure!(unsafe { libc::uname(&mut name) } == 0, None);
+        // Safety: the kernel null terminates the release
+        let release = unsafe { CStr::from_ptr(name.release.as_ptr()) };
+        parse_version(release.to_str().ok()?)
+    }
+
+    /// Parses the `(major, minor)` version from a release, e.g. `6.8.0-1017-aws`
+    fn parse_version(release: &str) -> Option<(u32, u32)> {
+        let mut parts = release.split(|c: char| !c.is_ascii_digit());
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        Some((major, minor))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn version_test() {
+            assert_eq!(parse_version("6.8.0-1017-aws"), Some((6, 8)));
+            assert_eq!(parse_version("6.15.2"), Some((6, 15)));
+            assert_eq!(
+                parse_version("5.10.220-209.869.amzn2.x86_64"),
+                Some((5, 10))
+            );
+            assert_eq!(parse_version("6"), None);
+            assert_eq!(parse_version(""), None);
+        }
+    }
+}
+
+#[cfg(any(not(all(feature = "io-uring", s2n_quic_platform_io_uring)), test))]
+mod uring_disabled {
+    #![cfg_attr(test, allow(dead_code))]
+
+    #[derive(Clone, Debug)]
+    pub struct IoUring(());
+
+    impl IoUring {
+        #[inline]
+        pub fn probe() -> Option<Self> {
+            None
+        }
+
+        #[inline]
+        pub fn is_zero_copy_enabled(&self) -> bool {
+            false
+        }
+
+        #[inline]
+        pub fn disable_zero_copy(&self) {
+            // it's already disabled
+        }
+
+        #[inline(always)]
+        pub fn handle_zero_copy_error(&self, error: &std::io::Error) -> bool {
+            let _ = error;
+            false
+        }
+
+        #[inline]
+        pub fn is_fixed_buffers_enabled(&self) -> bool {
+            false
+        }
+
+        #[inline(always)]
+        pub fn handle_fixed_buffer_error(&self, error: &std::io::Error) -> bool {
+            let _ = error;
+            false
+        }
+    }
+}
+
+mod uring_impl {
+    #[cfg(not(all(feature = "io-uring", s2n_quic_platform_io_uring)))]
+    pub use super::uring_disabled::*;
+    #[cfg(all(feature = "io-uring", s2n_quic_platform_io_uring))]
+    pub use super::uring_enabled::*;
+}
+
+pub use uring_impl::*;
+
+pub const IS_SUPPORTED: bool = cfg!(all(feature = "io-uring", s2n_quic_platform_io_uring));
@@ -4,6 +4,9 @@
 #[cfg(feature = "tokio")]
 pub mod tokio;
 
+#[cfg(feature = "std")]
+pub mod record;
+
 #[cfg(any(test, feature = "io-testing"))]
 pub mod testing;
 
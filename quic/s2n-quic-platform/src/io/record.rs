@@ -0,0 +1,814 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Records the datagrams sent and received by an endpoint
+//!
+//! The [`Rx`] and [`Tx`] channels wrap the channels of an IO provider and write each datagram,
+//! along with the time it was observed and its ECN markings, to a [`Recorder`]. The resulting
+//! [`Recording`] can then be fed back into an endpoint with the `testing::replay` IO provider.
+//!
+//! # Format
+//!
+//! A recording starts with the `s2nqrec` magic bytes, followed by a version byte. Each datagram
+//! is then encoded as:
+//!
+//! ```text
+//! Datagram {
+//!   Direction (8),
+//!   Time Delta (varint),
+//!   ECN (8),
+//!   Local Address (..),
+//!   Remote Address (..),
+//!   Payload Length (varint),
+//!   Payload (..),
+//! }
+//!
+//! Address {
+//!   Family (8) = 4 | 6,
+//!   IP (32 | 128),
+//!   Port (16),
+//! }
+//! ```
+//!
+//! The time delta is the number of microseconds since the previous datagram, encoded as an
+//! unsigned LEB128 integer.
+
+use core::{
+    task::{Context, Poll},
+    time::Duration,
+};
+use s2n_quic_core::{
+    event,
+    inet::{datagram, Dscp, ExplicitCongestionNotification, SocketAddress},
+    io::{rx, tx},
+    path::Handle as _,
+};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    net::{IpAddr, SocketAddr},
+    path::Path,
+    sync::{mpsc, Arc, Mutex},
+    time::Instant,
+};
+
+const MAGIC: &[u8] = b"s2nqrec";
+const VERSION: u8 = 1;
+
+/// The direction of a recorded datagram, relative to the endpoint
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Rx,
+    Tx,
+}
+
+/// A single recorded datagram
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Datagram {
+    pub direction: Direction,
+    /// The amount of time since the recording started
+    pub time: Duration,
+    pub ecn: ExplicitCongestionNotification,
+    pub local_address: SocketAddress,
+    pub remote_address: SocketAddress,
+    pub payload: Vec<u8>,
+}
+
+/// Writes datagrams to a recording
+///
+/// The recorder is cheaply cloneable so it can be shared between the RX and TX channels.
+///
+/// Datagrams are encoded into an in-memory buffer which is handed off to a background thread
+/// once it reaches [`FLUSH_SIZE`] bytes or [`FLUSH_INTERVAL`] has elapsed. This keeps file IO
+/// off of the endpoint task. Any remaining datagrams are written when the last clone of the
+/// recorder is dropped.
+#[derive(Clone)]
+pub struct Recorder(Arc<Mutex<Writer>>);
+
+impl core::fmt::Debug for Recorder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Recorder").finish()
+    }
+}
+
+/// The number of buffered bytes after which the datagrams are handed to the writer thread
+const FLUSH_SIZE: usize = 64 * 1024;
+
+/// The maximum amount of time datagrams are buffered before being handed to the writer thread
+const FLUSH_INTERVAL: Duration = Duration::from_millis(100);
+
+enum Command {
+    Write(Vec<u8>),
+    Flush(mpsc::SyncSender<io::Result<()>>),
+}
+
+struct Writer {
+    start: Instant,
+    prev: Duration,
+    buffer: Vec<u8>,
+    last_flush: Instant,
+    sender: Option<mpsc::Sender<Command>>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Writer {
+    /// Hands the buffered datagrams to the writer thread
+    fn send(&mut self) {
+        self.last_flush = Instant::now();
+
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let buffer = core::mem::take(&mut self.buffer);
+        if let Some(sender) = self.sender.as_ref() {
+            // recording is best-effort so it never interferes with the endpoint
+            let _ = sender.send(Command::Write(buffer));
+        }
+    }
+
+    fn is_flush_needed(&self) -> bool {
+        self.buffer.len() >= FLUSH_SIZE
+            || (!self.buffer.is_empty() && self.last_flush.elapsed() >= FLUSH_INTERVAL)
+    }
+}
+
+impl Drop for Writer {
+    fn drop(&mut self) {
+        self.send();
+
+        // closing the channel shuts down the writer thread once it has written everything
+        drop(self.sender.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Recorder {
+    /// Creates a recording at the given path, truncating any existing file
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::create(path)?;
+        Self::new(BufWriter::new(file))
+    }
+
+    /// Creates a recorder that writes to the provided output
+    pub fn new<W: 'static + Write + Send>(mut out: W) -> io::Result<Self> {
+        out.write_all(MAGIC)?;
+        out.write_all(&[VERSION])?;
+        out.flush()?;
+
+        let (sender, receiver) = mpsc::channel::<Command>();
+
+        let thread = std::thread::Builder::new()
+            .name("s2n-quic-recorder".into())
+            .spawn(move || {
+                for command in receiver {
+                    match command {
+                        Command::Write(buffer) => {
+                            // flush after each chunk so the recording survives the process
+                            // being terminated
+                            let _ = out.write_all(&buffer).and_then(|_| out.flush());
+                        }
+                        Command::Flush(ack) => {
+                            let _ = ack.send(out.flush());
+                        }
+                    }
+                }
+            })?;
+
+        let now = Instant::now();
+
+        Ok(Self(Arc::new(Mutex::new(Writer {
+            start: now,
+            prev: Duration::ZERO,
+            buffer: vec![],
+            last_flush: now,
+            sender: Some(sender),
+            thread: Some(thread),
+        }))))
+    }
+
+    /// Records a single datagram at the current time
+    pub fn record(
+        &self,
+        direction: Direction,
+        ecn: ExplicitCongestionNotification,
+        local_address: SocketAddress,
+        remote_address: SocketAddress,
+        payload: &[u8],
+    ) {
+        let Ok(mut writer) = self.0.lock() else {
+            return;
+        };
+
+        let writer = &mut *writer;
+        let now = writer.start.elapsed();
+        let delta = now.saturating_sub(writer.prev);
+        writer.prev = now;
+
+        let buffer = &mut writer.buffer;
+        buffer.push(match direction {
+            Direction::Rx => 0,
+            Direction::Tx => 1,
+        });
+        encode_varint(buffer, delta.as_micros() as u64);
+        buffer.push(ecn as u8);
+        encode_address(buffer, local_address);
+        encode_address(buffer, remote_address);
+        encode_varint(buffer, payload.len() as u64);
+        buffer.extend_from_slice(payload);
+
+        if writer.is_flush_needed() {
+            writer.send();
+        }
+    }
+
+    /// Hands the buffered datagrams to the writer thread if the size or time threshold has been
+    /// reached
+    ///
+    /// This never blocks on file IO.
+    pub fn poll_flush(&self) {
+        if let Ok(mut writer) = self.0.lock() {
+            if writer.is_flush_needed() {
+                writer.send();
+            }
+        }
+    }
+
+    /// Writes all of the buffered datagrams to the output and waits for them to be flushed
+    ///
+    /// This blocks on file IO so it should not be called from the endpoint task.
+    pub fn flush(&self) -> io::Result<()> {
+        let (ack, result) = mpsc::sync_channel(1);
+
+        {
+            let Ok(mut writer) = self.0.lock() else {
+                return Ok(());
+            };
+            writer.send();
+            let Some(sender) = writer.sender.as_ref() else {
+                return Ok(());
+            };
+            if sender.send(Command::Flush(ack)).is_err() {
+                return Err(io::Error::other("recorder thread has stopped"));
+            }
+        }
+
+        result
+            .recv()
+            .unwrap_or_else(|_| Err(io::Error::other("recorder thread has stopped")))
+    }
+}
+
+/// A sequence of datagrams read from a recording
+#[derive(Clone, Debug, Default)]
+pub struct Recording {
+    datagrams: Vec<Datagram>,
+}
+
+impl Recording {
+    /// Reads the recording at the given path
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        Self::decode(&bytes)
+    }
+
+    /// Decodes a recording from bytes
+    ///
+    /// A datagram that was only partially written, which can happen if the process was
+    /// terminated while recording, is ignored.
+    pub fn decode(bytes: &[u8]) -> io::Result<Self> {
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+        let bytes = bytes
+            .strip_prefix(MAGIC)
+            .ok_or_else(|| invalid("missing recording header"))?;
+        let (version, mut bytes) = bytes
+            .split_first()
+            .ok_or_else(|| invalid("missing recording version"))?;
+        if *version != VERSION {
+            return Err(invalid("unsupported recording version"));
+        }
+
+        let mut datagrams = vec![];
+        let mut time = Duration::ZERO;
+
+        while !bytes.is_empty() {
+            let Some((datagram, remaining)) = decode_datagram(bytes, &mut time) else {
+                break;
+            };
+            datagrams.push(datagram);
+            bytes = remaining;
+        }
+
+        Ok(Self { datagrams })
+    }
+
+    /// Returns all of the recorded datagrams
+    pub fn datagrams(&self) -> &[Datagram] {
+        &self.datagrams
+    }
+
+    /// Returns the datagrams that were received by the endpoint
+    pub fn rx(&self) -> impl Iterator<Item = &Datagram> {
+        self.datagrams
+            .iter()
+            .filter(|datagram| datagram.direction == Direction::Rx)
+    }
+
+    /// Returns the datagrams that were transmitted by the endpoint
+    pub fn tx(&self) -> impl Iterator<Item = &Datagram> {
+        self.datagrams
+            .iter()
+            .filter(|datagram| datagram.direction == Direction::Tx)
+    }
+}
+
+impl From<Vec<Datagram>> for Recording {
+    fn from(datagrams: Vec<Datagram>) -> Self {
+        Self { datagrams }
+    }
+}
+
+impl IntoIterator for Recording {
+    type Item = Datagram;
+    type IntoIter = std::vec::IntoIter<Datagram>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.datagrams.into_iter()
+    }
+}
+
+fn encode_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buffer.push(byte);
+            return;
+        }
+        buffer.push(byte | 0x80);
+    }
+}
+
+fn decode_varint(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value = 0u64;
+    for (idx, byte) in bytes.iter().enumerate().take(10) {
+        value |= ((byte & 0x7f) as u64) << (idx * 7);
+        if byte & 0x80 == 0 {
+            return Some((value, &bytes[idx + 1..]));
+        }
+    }
+    None
+}
+
+fn encode_address(buffer: &mut Vec<u8>, address: SocketAddress) {
+    let address: SocketAddr = address.into();
+    match address.ip() {
+        IpAddr::V4(ip) => {
+            buffer.push(4);
+            buffer.extend_from_slice(&ip.octets());
+        }
+        IpAddr::V6(ip) => {
+            buffer.push(6);
+            buffer.extend_from_slice(&ip.octets());
+        }
+    }
+    buffer.extend_from_slice(&address.port().to_be_bytes());
+}
+
+fn decode_address(bytes: &[u8]) -> Option<(SocketAddress, &[u8])> {
+    let (family, bytes) = bytes.split_first()?;
+    let (ip, bytes): (IpAddr, _) = match family {
+        4 => {
+            let (ip, bytes) = bytes.split_at_checked(4)?;
+            (<[u8; 4]>::try_from(ip).ok()?.into(), bytes)
+        }
+        6 => {
+            let (ip, bytes) = bytes.split_at_checked(16)?;
+            (<[u8; 16]>::try_from(ip).ok()?.into(), bytes)
+        }
+        _ => return None,
+    };
+    let (port, bytes) = bytes.split_at_checked(2)?;
+    let port = u16::from_be_bytes(port.try_into().ok()?);
+    Some((SocketAddr::new(ip, port).into(), bytes))
+}
+
+fn decode_datagram<'a>(bytes: &'a [u8], time: &mut Duration) -> Option<(Datagram, &'a [u8])> {
+    let (direction, bytes) = bytes.split_first()?;
+    let direction = match direction {
+        0 => Direction::Rx,
+        1 => Direction::Tx,
+        _ => return None,
+    };
+    let (delta, bytes) = decode_varint(bytes)?;
+    let (ecn, bytes) = bytes.split_first()?;
+    let (local_address, bytes) = decode_address(bytes)?;
+    let (remote_address, bytes) = decode_address(bytes)?;
+    let (len, bytes) = decode_varint(bytes)?;
+    let (payload, bytes) = bytes.split_at_checked(len.try_into().ok()?)?;
+
+    *time += Duration::from_micros(delta);
+
+    let datagram = Datagram {
+        direction,
+        time: *time,
+        ecn: ExplicitCongestionNotification::new(*ecn),
+        local_address,
+        remote_address,
+        payload: payload.to_vec(),
+    };
+
+    Some((datagram, bytes))
+}
+
+/// Records all of the datagrams received on the inner channel
+pub struct Rx<R> {
+    inner: R,
+    recorder: Recorder,
+}
+
+impl<R> Rx<R> {
+    pub fn new(inner: R, recorder: Recorder) -> Self {
+        Self { inner, recorder }
+    }
+}
+
+impl<R> rx::Rx for Rx<R>
+where
+    R: rx::Rx,
+    R::Queue: 'static,
+{
+    type PathHandle = R::PathHandle;
+    type Queue = RxQueue<'static, R::Queue>;
+    type Error = R::Error;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    #[inline]
+    fn queue<F: FnOnce(&mut Self::Queue)>(&mut self, f: F) {
+        let recorder = &self.recorder;
+        self.inner.queue(|inner| {
+            let (inner, recorder): (&'static mut _, &'static _) = unsafe {
+                // Safety: As noted in the [transmute examples](https://doc.rust-lang.org/std/mem/fn.transmute.html#examples)
+                // it can be used to temporarily extend the lifetime of a reference. In this case, we
+                // don't want to use GATs until the MSRV is >=1.65.0, which means `Self::Queue` is not
+                // allowed to take generic lifetimes.
+                //
+                // We are left with using a `'static` lifetime here and encapsulating it in a private
+                // field. The `Self::Queue` struct is then borrowed for the lifetime of the `F`
+                // function. This will prevent the value from escaping beyond the lifetime of `&mut
+                // self`.
+                //
+                // See https://play.rust-lang.org/?version=stable&mode=debug&edition=2021&gist=9a32abe85c666f36fb2ec86496cc41b4
+                //
+                // Once https://github.com/aws/s2n-quic/issues/1742 is resolved this code can go away
+                (
+                    core::mem::transmute::<&mut R::Queue, &mut R::Queue>(inner),
+                    core::mem::transmute::<&Recorder, &Recorder>(recorder),
+                )
+            };
+
+            let mut queue = RxQueue { inner, recorder };
+            f(&mut queue);
+        });
+
+        // hand off the datagrams to the writer thread periodically so they're persisted in case
+        // the process is terminated
+        self.recorder.poll_flush();
+    }
+
+    #[inline]
+    fn handle_error<E: event::EndpointPublisher>(self, error: Self::Error, event: &mut E) {
+        self.inner.handle_error(error, event)
+    }
+}
+
+pub struct RxQueue<'a, Q> {
+    inner: &'a mut Q,
+    recorder: &'a Recorder,
+}
+
+impl<Q: rx::Queue> rx::Queue for RxQueue<'_, Q> {
+    type Handle = Q::Handle;
+
+    #[inline]
+    fn for_each<F: FnMut(datagram::Header<Self::Handle>, &mut [u8])>(&mut self, mut on_packet: F) {
+        let recorder = self.recorder;
+        self.inner.for_each(|header, payload| {
+            // record the payload before the endpoint decrypts it in place
+            recorder.record(
+                Direction::Rx,
+                header.ecn,
+                header.path.local_address().0,
+                header.path.remote_address().0,
+                payload,
+            );
+            on_packet(header, payload)
+        })
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+/// Records all of the datagrams transmitted on the inner channel
+pub struct Tx<T> {
+    inner: T,
+    recorder: Recorder,
+    payload: Vec<u8>,
+}
+
+impl<T> Tx<T> {
+    pub fn new(inner: T, recorder: Recorder) -> Self {
+        Self {
+            inner,
+            recorder,
+            payload: vec![],
+        }
+    }
+}
+
+impl<T> tx::Tx for Tx<T>
+where
+    T: tx::Tx,
+    T::Queue: 'static,
+{
+    type PathHandle = T::PathHandle;
+    type Queue = TxQueue<'static, T::Queue>;
+    type Error = T::Error;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    #[inline]
+    fn queue<F: FnOnce(&mut Self::Queue)>(&mut self, f: F) {
+        let recorder = &self.recorder;
+        let payload = &mut self.payload;
+        self.inner.queue(|inner| {
+            let (inner, recorder, payload): (&'static mut _, &'static _, &'static mut _) = unsafe {
+                // Safety: See the `Rx` implementation above
+                (
+                    core::mem::transmute::<&mut T::Queue, &mut T::Queue>(inner),
+                    core::mem::transmute::<&Recorder, &Recorder>(recorder),
+                    core::mem::transmute::<&mut Vec<u8>, &mut Vec<u8>>(payload),
+                )
+            };
+
+            let mut queue = TxQueue {
+                inner,
+                recorder,
+                payload,
+            };
+            f(&mut queue);
+        });
+
+        self.recorder.poll_flush();
+    }
+
+    #[inline]
+    fn handle_error<E: event::EndpointPublisher>(self, error: Self::Error, event: &mut E) {
+        self.inner.handle_error(error, event)
+    }
+}
+
+pub struct TxQueue<'a, Q> {
+    inner: &'a mut Q,
+    recorder: &'a Recorder,
+    payload: &'a mut Vec<u8>,
+}
+
+impl<Q: tx::Queue> tx::Queue for TxQueue<'_, Q> {
+    type Handle = Q::Handle;
+
+    const SUPPORTS_ECN: bool = Q::SUPPORTS_ECN;
+    const SUPPORTS_PACING: bool = Q::SUPPORTS_PACING;
+    const SUPPORTS_FLOW_LABELS: bool = Q::SUPPORTS_FLOW_LABELS;
+
+    #[inline]
+    fn push<M: tx::Message<Handle = Self::Handle>>(
+        &mut self,
+        message: M,
+    ) -> Result<tx::Outcome, tx::Error> {
+        let handle = *message.path_handle();
+        let mut ecn = ExplicitCongestionNotification::default();
+        self.payload.clear();
+
+        let message = RecordMessage {
+            inner: message,
+            ecn: &mut ecn,
+            payload: self.payload,
+        };
+
+        let outcome = self.inner.push(message)?;
+
+        self.recorder.record(
+            Direction::Tx,
+            ecn,
+            handle.local_address().0,
+            handle.remote_address().0,
+            self.payload,
+        );
+
+        Ok(outcome)
+    }
+
+    #[inline]
+    fn flush(&mut self) {
+        self.inner.flush()
+    }
+
+    #[inline]
+    fn pacing_horizon(&self) -> Duration {
+        self.inner.pacing_horizon()
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+}
+
+/// Captures the ECN markings and payload of a message as it is written to the inner queue
+struct RecordMessage<'a, M> {
+    inner: M,
+    ecn: &'a mut ExplicitCongestionNotification,
+    payload: &'a mut Vec<u8>,
+}
+
+impl<M: tx::Message> tx::Message for RecordMessage<'_, M> {
+    type Handle = M::Handle;
+
+    #[inline]
+    fn path_handle(&self) -> &Self::Handle {
+        self.inner.path_handle()
+    }
+
+    #[inline]
+    fn ecn(&mut self) -> ExplicitCongestionNotification {
+        let ecn = self.inner.ecn();
+        *self.ecn = ecn;
+        ecn
+    }
+
+    #[inline]
+    fn dscp(&mut self) -> Dscp {
+        self.inner.dscp()
+    }
+
+    #[inline]
+    fn delay(&mut self) -> Duration {
+        self.inner.delay()
+    }
+
+    #[inline]
+    fn departure_time(&mut self) -> Option<s2n_quic_core::time::Timestamp> {
+        self.inner.departure_time()
+    }
+
+    #[inline]
+    fn ipv6_flow_label(&mut self) -> u32 {
+        self.inner.ipv6_flow_label()
+    }
+
+    #[inline]
+    fn can_gso(&self, segment_len: usize, segment_count: usize) -> bool {
+        self.inner.can_gso(segment_len, segment_count)
+    }
+
+    #[inline]
+    fn write_payload(
+        &mut self,
+        buffer: tx::PayloadBuffer,
+        gso_offset: usize,
+    ) -> Result<usize, tx::Error> {
+        let buffer = unsafe {
+            // Safety: the buffer is only used to read back what the inner message wrote
+            buffer.into_mut_slice()
+        };
+        let len = self
+            .inner
+            .write_payload(tx::PayloadBuffer::new(buffer), gso_offset)?;
+        self.payload.extend_from_slice(&buffer[..len]);
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::inet::IpV4Address;
+
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let out = Shared::default();
+        let recorder = Recorder::new(out.clone()).unwrap();
+
+        let local_address: SocketAddress = IpV4Address::new([127, 0, 0, 1]).with_port(443).into();
+        let remote_address: SocketAddress =
+            SocketAddr::from(([0xfe80, 0, 0, 0, 0, 0, 0, 1], 1234)).into();
+
+        recorder.record(
+            Direction::Rx,
+            ExplicitCongestionNotification::Ect0,
+            local_address,
+            remote_address,
+            &[1, 2, 3],
+        );
+        recorder.record(
+            Direction::Tx,
+            ExplicitCongestionNotification::NotEct,
+            local_address,
+            remote_address,
+            &[4; 300],
+        );
+
+        // nothing is written until the buffer is handed to the writer thread
+        assert_eq!(out.0.lock().unwrap().len(), MAGIC.len() + 1);
+
+        recorder.flush().unwrap();
+
+        let mut bytes = out.0.lock().unwrap().clone();
+
+        // a partially written datagram at the end of the file is ignored
+        bytes.extend_from_slice(&[0, 1]);
+
+        let recording = Recording::decode(&bytes).unwrap();
+        let datagrams = recording.datagrams();
+        assert_eq!(datagrams.len(), 2);
+
+        assert_eq!(datagrams[0].direction, Direction::Rx);
+        assert_eq!(datagrams[0].ecn, ExplicitCongestionNotification::Ect0);
+        assert_eq!(datagrams[0].local_address, local_address);
+        assert_eq!(datagrams[0].remote_address, remote_address);
+        assert_eq!(datagrams[0].payload, [1, 2, 3]);
+
+        assert_eq!(datagrams[1].direction, Direction::Tx);
+        assert_eq!(datagrams[1].payload, [4; 300]);
+        assert!(datagrams[0].time <= datagrams[1].time);
+
+        assert!(Recording::decode(b"pcap").is_err());
+    }
+
+    #[test]
+    fn drop_test() {
+        let out = Shared::default();
+        let recorder = Recorder::new(out.clone()).unwrap();
+        let address: SocketAddress = IpV4Address::new([127, 0, 0, 1]).with_port(443).into();
+
+        // fill the buffer past the size threshold so it's handed off to the writer thread
+        let payload = [1; 1200];
+        let count = FLUSH_SIZE / payload.len() + 1;
+        for _ in 0..count {
+            let ecn = ExplicitCongestionNotification::NotEct;
+            recorder.record(Direction::Rx, ecn, address, address, &payload);
+        }
+        recorder.record(
+            Direction::Tx,
+            ExplicitCongestionNotification::NotEct,
+            address,
+            address,
+            &[2],
+        );
+
+        // dropping the recorder writes the remaining datagrams
+        drop(recorder);
+
+        let bytes = out.0.lock().unwrap().clone();
+        let recording = Recording::decode(&bytes).unwrap();
+        assert_eq!(recording.rx().count(), count);
+        assert_eq!(recording.tx().count(), 1);
+    }
+
+    #[test]
+    fn varint_test() {
+        for value in [0, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+            let mut buffer = vec![];
+            encode_varint(&mut buffer, value);
+            assert_eq!(decode_varint(&buffer), Some((value, &[][..])));
+        }
+    }
+}
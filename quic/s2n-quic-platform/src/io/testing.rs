@@ -9,14 +9,19 @@ use s2n_quic_core::{
 type Error = std::io::Error;
 type Result<T = (), E = Error> = core::result::Result<T, E>;
 
+mod aqm;
 pub mod message;
 mod model;
 pub mod network;
+mod pcap;
+pub mod replay;
 mod socket;
 pub mod time;
 
+pub use aqm::Aqm;
 pub use model::{Model, TxRecorder};
 pub use network::{Network, PathHandle};
+pub use pcap::Pcap;
 pub use socket::Socket;
 pub use time::now;
 
@@ -212,6 +217,11 @@ pub struct Handle {
 }
 
 impl Handle {
+    /// Writes all of the packets that are transmitted on the network to a pcapng file
+    pub fn capture(&self, pcap: Pcap) {
+        self.buffers.set_capture(pcap);
+    }
+
     pub fn builder(&self) -> Builder {
         Builder {
             handle: self.clone(),
@@ -334,6 +344,7 @@ impl Io {
         let (stats_sender, stats_recv) = crate::socket::stats::channel();
 
         let socket = buffers.register(handle, mtu_config.max_mtu());
+        buffers.set_endpoint_type(handle, E::ENDPOINT_TYPE);
         let tx = socket.tx_task(
             mtu_config.max_mtu(),
             queue_send_buffer_size,
@@ -0,0 +1,236 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Active queue management algorithms for the network [`Model`](super::Model)
+
+use core::time::Duration;
+use s2n_quic_core::time::Timestamp;
+
+/// The active queue management algorithm applied to the network queue
+///
+/// The queue length is the number of inflight packets, and the time a packet spends in the
+/// queue is the delay added by the `inflight_delay` settings. When the algorithm signals
+/// congestion, ECN-capable packets are marked with CE and all other packets are dropped.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Aqm {
+    /// Packets are only dropped when the queue is full
+    #[default]
+    None,
+    /// Random Early Detection
+    ///
+    /// Packets are signaled with a probability that grows linearly from `0` at
+    /// `min_threshold` to `max_probability` at `max_threshold`. Every packet is signaled once
+    /// the queue reaches `max_threshold`. The instantaneous queue length is used rather than
+    /// an average.
+    Red {
+        min_threshold: u64,
+        max_threshold: u64,
+        max_probability: f64,
+    },
+    /// Controlled Delay
+    ///
+    /// Packets are signaled once the queueing delay has stayed above `target` for at least
+    /// `interval`, with the signaling rate increasing until the delay drops below `target`.
+    CoDel {
+        target: Duration,
+        interval: Duration,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum Verdict {
+    Pass,
+    Signal,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct Controller {
+    config: Aqm,
+    codel: CoDel,
+}
+
+impl Controller {
+    pub fn config(&self) -> Aqm {
+        self.config
+    }
+
+    pub fn set_config(&mut self, config: Aqm) {
+        self.config = config;
+        self.codel = Default::default();
+    }
+
+    /// Called for each packet entering the queue
+    ///
+    /// `random` produces a uniformly distributed value in `0.0..1.0` and is only called when a
+    /// probabilistic decision is needed.
+    pub fn on_enqueue<R: FnOnce() -> f64>(
+        &mut self,
+        now: Timestamp,
+        queue_len: u64,
+        queue_delay: Duration,
+        random: R,
+    ) -> Verdict {
+        match self.config {
+            Aqm::None => Verdict::Pass,
+            Aqm::Red {
+                min_threshold,
+                max_threshold,
+                max_probability,
+            } => {
+                if queue_len < min_threshold {
+                    return Verdict::Pass;
+                }
+
+                if queue_len >= max_threshold {
+                    return Verdict::Signal;
+                }
+
+                let range = (max_threshold - min_threshold) as f64;
+                let probability = max_probability * (queue_len - min_threshold) as f64 / range;
+
+                if random() < probability {
+                    Verdict::Signal
+                } else {
+                    Verdict::Pass
+                }
+            }
+            Aqm::CoDel { target, interval } => {
+                self.codel
+                    .on_enqueue(now, queue_len, queue_delay, target, interval)
+            }
+        }
+    }
+}
+
+/// State for the CoDel control law
+///
+/// Since the model knows the queueing delay of a packet when it enters the queue, the
+/// algorithm is applied on enqueue rather than dequeue.
+#[derive(Debug, Default)]
+struct CoDel {
+    first_above_time: Option<Timestamp>,
+    drop_next: Option<Timestamp>,
+    is_dropping: bool,
+    count: u32,
+}
+
+impl CoDel {
+    fn on_enqueue(
+        &mut self,
+        now: Timestamp,
+        queue_len: u64,
+        queue_delay: Duration,
+        target: Duration,
+        interval: Duration,
+    ) -> Verdict {
+        if queue_delay < target || queue_len == 0 {
+            self.first_above_time = None;
+            self.is_dropping = false;
+            return Verdict::Pass;
+        }
+
+        match self.first_above_time {
+            None => {
+                self.first_above_time = Some(now + interval);
+                return Verdict::Pass;
+            }
+            Some(first_above_time) if now < first_above_time => return Verdict::Pass,
+            Some(_) => {}
+        }
+
+        if !self.is_dropping {
+            self.is_dropping = true;
+
+            // start from the previous rate if we were recently dropping
+            let was_recent = self
+                .drop_next
+                .is_some_and(|drop_next| now.saturating_duration_since(drop_next) < interval * 16);
+            self.count = if self.count > 2 && was_recent {
+                self.count - 2
+            } else {
+                1
+            };
+            self.drop_next = Some(control_law(now, interval, self.count));
+            return Verdict::Signal;
+        }
+
+        match self.drop_next {
+            Some(drop_next) if now >= drop_next => {
+                self.count += 1;
+                self.drop_next = Some(control_law(drop_next, interval, self.count));
+                Verdict::Signal
+            }
+            _ => Verdict::Pass,
+        }
+    }
+}
+
+fn control_law(t: Timestamp, interval: Duration, count: u32) -> Timestamp {
+    t + interval.div_f64((count as f64).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::time::clock::testing as time;
+
+    #[test]
+    fn red_test() {
+        let mut controller = Controller::default();
+        controller.set_config(Aqm::Red {
+            min_threshold: 10,
+            max_threshold: 20,
+            max_probability: 0.5,
+        });
+
+        let now = time::now();
+        let delay = Duration::ZERO;
+
+        assert_eq!(controller.on_enqueue(now, 9, delay, || 0.0), Verdict::Pass);
+        // halfway between the thresholds has a probability of 0.25
+        assert_eq!(
+            controller.on_enqueue(now, 15, delay, || 0.2),
+            Verdict::Signal
+        );
+        assert_eq!(controller.on_enqueue(now, 15, delay, || 0.3), Verdict::Pass);
+        assert_eq!(
+            controller.on_enqueue(now, 20, delay, || 1.0),
+            Verdict::Signal
+        );
+    }
+
+    #[test]
+    fn codel_test() {
+        let mut controller = Controller::default();
+        let target = Duration::from_millis(5);
+        let interval = Duration::from_millis(100);
+        controller.set_config(Aqm::CoDel { target, interval });
+
+        let mut now = time::now();
+        let high = Duration::from_millis(10);
+        let step = Duration::from_millis(10);
+
+        // the delay needs to stay above the target for an interval
+        let mut signals = vec![];
+        for _ in 0..30 {
+            signals.push(controller.on_enqueue(now, 10, high, || 0.0) == Verdict::Signal);
+            now += step;
+        }
+
+        // nothing is signaled for the first interval
+        assert!(signals[..10].iter().all(|signal| !signal));
+        assert!(signals[10]);
+
+        // the signaling rate increases while the delay is above the target
+        let count = signals.iter().filter(|signal| **signal).count();
+        assert!(count >= 3, "{signals:?}");
+
+        // dropping below the target stops signaling
+        assert_eq!(
+            controller.on_enqueue(now, 10, Duration::from_millis(1), || 0.0),
+            Verdict::Pass
+        );
+        now += step;
+        assert_eq!(controller.on_enqueue(now, 10, high, || 0.0), Verdict::Pass);
+    }
+}
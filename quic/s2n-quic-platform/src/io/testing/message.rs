@@ -4,7 +4,7 @@
 use crate::message::{self, Message as MessageTrait};
 use core::alloc::Layout;
 use s2n_quic_core::{
-    inet::{datagram, ExplicitCongestionNotification},
+    inet::{datagram, Dscp, ExplicitCongestionNotification},
     io::tx,
     path,
 };
@@ -16,6 +16,7 @@ use s2n_quic_core::{
 pub struct Message {
     handle: Handle,
     ecn: ExplicitCongestionNotification,
+    dscp: Dscp,
     payload_ptr: *mut u8,
     payload_len: usize,
 }
@@ -41,6 +42,11 @@ impl Message {
         &mut self.ecn
     }
 
+    #[inline]
+    pub(crate) fn dscp(&self) -> Dscp {
+        self.dscp
+    }
+
     #[inline]
     pub(crate) fn payload(&self) -> &[u8] {
         unsafe { core::slice::from_raw_parts(self.payload_ptr, self.payload_len) }
@@ -55,6 +61,7 @@ impl MessageTrait for Message {
     const SUPPORTS_GSO: bool = false;
     const SUPPORTS_ECN: bool = true;
     const SUPPORTS_FLOW_LABELS: bool = false;
+    const SUPPORTS_TXTIME: bool = false;
 
     #[inline]
     fn alloc(entries: u32, payload_len: u32, offset: usize) -> message::Storage {
@@ -94,7 +101,7 @@ impl MessageTrait for Message {
         let path = self.handle;
         let header = datagram::Header {
             path,
-            ecn: Default::default(),
+            ecn: self.ecn,
         };
         let payload = self.payload_mut();
 
@@ -123,6 +130,8 @@ impl MessageTrait for Message {
         }
 
         self.handle = *message.path_handle();
+        self.ecn = message.ecn();
+        self.dscp = message.dscp();
 
         Ok(len)
     }
@@ -2,15 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::{
+    aqm::{self, Aqm},
     network::{Buffers, Network, Packet},
     rand::Any,
 };
 use core::time::Duration;
-use s2n_quic_core::{havoc, path::MaxMtu};
+use s2n_quic_core::{havoc, inet::ExplicitCongestionNotification, path::MaxMtu, time::Timestamp};
 use std::{
     borrow::Cow,
     sync::{
-        atomic::{AtomicU16, AtomicU64, Ordering},
+        atomic::{AtomicBool, AtomicU16, AtomicU64, Ordering},
         Arc, Mutex,
     },
 };
@@ -183,6 +184,147 @@ impl Model {
             .store(value, Ordering::SeqCst);
         self
     }
+
+    fn reorder_rate(&self) -> u64 {
+        self.0.reorder_rate.load(Ordering::SeqCst)
+    }
+
+    /// The odds a packet will be held back by the `reorder_delay`
+    ///
+    /// Packets sent after a held back packet will overtake it, as long as they are sent within
+    /// the `reorder_delay`. Each packet will make an independent decision with odds of
+    /// `0.0..1.0`, with `0.0` having no chance and `1.0` occurring with each packet.
+    pub fn set_reorder_rate(&self, value: f64) -> &Self {
+        let value = rate_to_u64(value);
+        self.0.reorder_rate.store(value, Ordering::SeqCst);
+        self
+    }
+
+    pub fn reorder_delay(&self) -> Duration {
+        Duration::from_micros(self.0.reorder_delay.load(Ordering::SeqCst))
+    }
+
+    /// The additional delay for packets selected by the `reorder_rate`
+    ///
+    /// This controls the size of the reordering window.
+    pub fn set_reorder_delay(&self, value: Duration) -> &Self {
+        self.0
+            .reorder_delay
+            .store(value.as_micros() as _, Ordering::SeqCst);
+        self
+    }
+
+    fn duplicate_rate(&self) -> u64 {
+        self.0.duplicate_rate.load(Ordering::SeqCst)
+    }
+
+    /// The odds a packet will be duplicated by the network
+    ///
+    /// Unlike the `retransmit_rate`, duplicates are made after the packet has been admitted to
+    /// the network, so the copies share the fate of the original with respect to drops and
+    /// corruption. Each packet will make an independent decision with odds of `0.0..1.0`, with
+    /// `0.0` having no chance and `1.0` occurring with each packet.
+    pub fn set_duplicate_rate(&self, value: f64) -> &Self {
+        let value = rate_to_u64(value);
+        self.0.duplicate_rate.store(value, Ordering::SeqCst);
+        self
+    }
+
+    pub fn ce_threshold(&self) -> u64 {
+        self.0.ce_threshold.load(Ordering::SeqCst)
+    }
+
+    /// Sets the number of inflight packets at which ECN-capable packets are marked with CE
+    ///
+    /// Packets which are not ECN-capable are unaffected.
+    pub fn set_ce_threshold(&self, value: u64) -> &Self {
+        self.0.ce_threshold.store(value, Ordering::SeqCst);
+        self
+    }
+
+    pub fn uplink_delay(&self) -> Duration {
+        Duration::from_micros(self.0.uplink_delay.load(Ordering::SeqCst))
+    }
+
+    /// The additional delay for packets sent to a server endpoint
+    pub fn set_uplink_delay(&self, value: Duration) -> &Self {
+        self.0
+            .uplink_delay
+            .store(value.as_micros() as _, Ordering::SeqCst);
+        self
+    }
+
+    pub fn downlink_delay(&self) -> Duration {
+        Duration::from_micros(self.0.downlink_delay.load(Ordering::SeqCst))
+    }
+
+    /// The additional delay for packets sent to a client endpoint
+    pub fn set_downlink_delay(&self, value: Duration) -> &Self {
+        self.0
+            .downlink_delay
+            .store(value.as_micros() as _, Ordering::SeqCst);
+        self
+    }
+
+    fn uplink_drop_rate(&self) -> u64 {
+        self.0.uplink_drop_rate.load(Ordering::SeqCst)
+    }
+
+    /// The odds a packet sent to a server endpoint will be dropped
+    ///
+    /// This is applied in addition to the `drop_rate`.
+    pub fn set_uplink_drop_rate(&self, value: f64) -> &Self {
+        let value = rate_to_u64(value);
+        self.0.uplink_drop_rate.store(value, Ordering::SeqCst);
+        self
+    }
+
+    fn downlink_drop_rate(&self) -> u64 {
+        self.0.downlink_drop_rate.load(Ordering::SeqCst)
+    }
+
+    /// The odds a packet sent to a client endpoint will be dropped
+    ///
+    /// This is applied in addition to the `drop_rate`.
+    pub fn set_downlink_drop_rate(&self, value: f64) -> &Self {
+        let value = rate_to_u64(value);
+        self.0.downlink_drop_rate.store(value, Ordering::SeqCst);
+        self
+    }
+
+    pub fn nat_rebind_interval(&self) -> Duration {
+        Duration::from_micros(self.0.nat_rebind_interval.load(Ordering::SeqCst))
+    }
+
+    /// The amount of time between NAT rebindings of the client endpoints
+    ///
+    /// Each rebinding moves all of the clients to a new port on the same IP. Setting this value
+    /// to `0` disables rebinding.
+    pub fn set_nat_rebind_interval(&self, value: Duration) -> &Self {
+        self.0
+            .nat_rebind_interval
+            .store(value.as_micros() as _, Ordering::SeqCst);
+        *self.0.next_nat_rebind.lock().unwrap() = None;
+        self
+    }
+
+    /// Moves all of the clients to a new port on the same IP the next time the network executes
+    ///
+    /// Packets that are still in flight to the previous addresses are dropped.
+    pub fn rebind_clients(&self) -> &Self {
+        self.0.pending_rebind.store(true, Ordering::SeqCst);
+        self
+    }
+
+    pub fn aqm(&self) -> Aqm {
+        self.0.aqm.lock().unwrap().config()
+    }
+
+    /// Sets the active queue management algorithm for the network
+    pub fn set_aqm(&self, value: Aqm) -> &Self {
+        self.0.aqm.lock().unwrap().set_config(value);
+        self
+    }
 }
 
 fn rate_to_u64(rate: f64) -> u64 {
@@ -204,6 +346,18 @@ struct State {
     inflight_delay: AtomicU64,
     inflight_delay_threshold: AtomicU64,
     current_inflight: AtomicU64,
+    reorder_rate: AtomicU64,
+    reorder_delay: AtomicU64,
+    duplicate_rate: AtomicU64,
+    ce_threshold: AtomicU64,
+    uplink_delay: AtomicU64,
+    downlink_delay: AtomicU64,
+    uplink_drop_rate: AtomicU64,
+    downlink_drop_rate: AtomicU64,
+    nat_rebind_interval: AtomicU64,
+    next_nat_rebind: Mutex<Option<Timestamp>>,
+    pending_rebind: AtomicBool,
+    aqm: Mutex<aqm::Controller>,
 }
 
 impl Default for State {
@@ -221,10 +375,72 @@ impl Default for State {
             inflight_delay: AtomicU64::new(0),
             inflight_delay_threshold: AtomicU64::new(u64::MAX),
             current_inflight: AtomicU64::new(0),
+            reorder_rate: AtomicU64::new(0),
+            reorder_delay: AtomicU64::new(0),
+            duplicate_rate: AtomicU64::new(0),
+            ce_threshold: AtomicU64::new(u64::MAX),
+            uplink_delay: AtomicU64::new(0),
+            downlink_delay: AtomicU64::new(0),
+            uplink_drop_rate: AtomicU64::new(0),
+            downlink_drop_rate: AtomicU64::new(0),
+            nat_rebind_interval: AtomicU64::new(0),
+            next_nat_rebind: Mutex::new(None),
+            pending_rebind: AtomicBool::new(false),
+            aqm: Default::default(),
         }
     }
 }
 
+impl Model {
+    /// Rebinds the client endpoints if requested or the NAT rebinding interval has elapsed
+    ///
+    /// Rebinding is only observable when packets are sent so this is checked as the network
+    /// executes, rather than arming a timer.
+    fn on_nat_rebind(&self, now: Timestamp, buffers: &Buffers) {
+        if self.0.pending_rebind.swap(false, Ordering::SeqCst) {
+            debug!("model::nat_rebind");
+            buffers.rebind_clients();
+        }
+
+        let interval = self.nat_rebind_interval();
+        if interval.is_zero() {
+            return;
+        }
+
+        let mut next_rebind = self.0.next_nat_rebind.lock().unwrap();
+        match *next_rebind {
+            Some(target) if target <= now => {
+                debug!("model::nat_rebind");
+                buffers.rebind_clients();
+                *next_rebind = Some(now + interval);
+            }
+            Some(_) => {}
+            None => *next_rebind = Some(now + interval),
+        }
+    }
+
+    /// Spawns a task that will push the packet onto the receiver queue at the transmit time
+    fn deliver(&self, buffers: &Buffers, now: Timestamp, packet: Packet, transmit_time: Timestamp) {
+        let model = self.clone();
+        model.0.current_inflight.fetch_add(1, Ordering::SeqCst);
+
+        let buffers = buffers.clone();
+
+        super::spawn(async move {
+            // if the packet isn't scheduled to transmit immediately, wait until the computed
+            // time
+            if now != transmit_time {
+                super::time::delay_until(transmit_time).await;
+            }
+
+            buffers.rx(*packet.path.local_address, |queue| {
+                model.0.current_inflight.fetch_sub(1, Ordering::SeqCst);
+                queue.enqueue(packet);
+            });
+        });
+    }
+}
+
 impl Network for Model {
     fn execute(&mut self, buffers: &Buffers) -> usize {
         let jitter = self.jitter();
@@ -236,8 +452,31 @@ impl Network for Model {
         let max_udp_payload = self.max_udp_payload() as usize;
         let inflight_delay = self.inflight_delay();
         let inflight_delay_threshold = self.inflight_delay_threshold();
+        let reorder_rate = self.reorder_rate();
+        let reorder_delay = self.reorder_delay();
+        let duplicate_rate = self.duplicate_rate();
+        let ce_threshold = self.ce_threshold();
+        let uplink_delay = self.uplink_delay();
+        let downlink_delay = self.downlink_delay();
+        let uplink_drop_rate = self.uplink_drop_rate();
+        let downlink_drop_rate = self.downlink_drop_rate();
+        let has_aqm = self.aqm() != Aqm::None;
 
         let now = super::time::now();
+
+        self.on_nat_rebind(now, buffers);
+
+        // the direction of a packet is only needed if the links are asymmetric
+        let is_asymmetric = !uplink_delay.is_zero()
+            || !downlink_delay.is_zero()
+            || uplink_drop_rate > 0
+            || downlink_drop_rate > 0;
+        let servers = if is_asymmetric {
+            buffers.server_addresses()
+        } else {
+            vec![]
+        };
+
         let mut transmit_time = now + self.delay();
         let transmit_time = &mut transmit_time;
 
@@ -256,7 +495,8 @@ impl Network for Model {
 
             // drop packets that exceed the maximum number of inflight packets for the network
             let max_inflight = self.max_inflight();
-            if self.inflight() >= max_inflight {
+            let current_inflight = self.inflight();
+            if current_inflight >= max_inflight {
                 debug!("model::drop::inflight max_inflight={}", max_inflight);
                 return 0;
             }
@@ -267,8 +507,52 @@ impl Network for Model {
                 return 0;
             }
 
+            // the packet hasn't been switched yet so the remote address is the receiver
+            let is_uplink = servers.contains(&packet.path.remote_address.0);
+            let (link_delay, link_drop_rate) = if is_uplink {
+                (uplink_delay, uplink_drop_rate)
+            } else {
+                (downlink_delay, downlink_drop_rate)
+            };
+
+            if gen_rate(link_drop_rate) {
+                debug!("model::drop::link is_uplink={is_uplink}");
+                return 0;
+            }
+
+            // scale the inflight delay by the number above the delay threshold
+            let queue_delay = current_inflight
+                .checked_sub(inflight_delay_threshold)
+                .map_or(Duration::ZERO, |mul| inflight_delay * mul as u32);
+
+            let mut is_congested = current_inflight >= ce_threshold;
+
+            if has_aqm {
+                let verdict = self.0.aqm.lock().unwrap().on_enqueue(
+                    now,
+                    current_inflight,
+                    queue_delay,
+                    || super::rand::produce::<u64>().any() as f64 / u64::MAX as f64,
+                );
+
+                if verdict == aqm::Verdict::Signal {
+                    // packets that can't carry a congestion signal are dropped instead
+                    if !packet.ecn.using_ecn() {
+                        debug!("model::drop::aqm");
+                        return 0;
+                    }
+
+                    is_congested = true;
+                }
+            }
+
             let mut packet = packet.into_owned();
 
+            if is_congested && packet.ecn.using_ecn() {
+                debug!("model::ecn::ce inflight={current_inflight}");
+                packet.ecn = ExplicitCongestionNotification::Ce;
+            }
+
             if !packet.payload.is_empty() && gen_rate(corrupt_rate) {
                 use havoc::Strategy as _;
 
@@ -290,38 +574,33 @@ impl Network for Model {
             }
 
             // copy the transmit time for this packet
-            let mut transmit_time = *transmit_time;
+            let mut transmit_time = *transmit_time + link_delay + queue_delay;
 
             if !network_jitter.is_zero() {
                 transmit_time += gen_jitter(network_jitter);
             }
 
-            let model = self.clone();
-            let current_inflight = model.0.current_inflight.fetch_add(1, Ordering::SeqCst);
-
-            // scale the inflight delay by the number above the delay threshold
-            if let Some(mul) = current_inflight.checked_sub(inflight_delay_threshold) {
-                transmit_time += inflight_delay * mul as u32;
+            // hold the packet back so the following packets overtake it
+            if gen_rate(reorder_rate) {
+                debug!("model::reorder delay={reorder_delay:?}");
+                transmit_time += reorder_delay;
             }
 
             // reverse the addresses so the dst/src are correct for the receiver
             packet.switch();
 
-            let buffers = buffers.clone();
+            if gen_rate(duplicate_rate) {
+                debug!("model::duplicate");
 
-            // spawn a task that will push the packet onto the receiver queue at the transit time
-            super::spawn(async move {
-                // if the packet isn't scheduled to transmit immediately, wait until the computed
-                // time
-                if now != transmit_time {
-                    super::time::delay_until(transmit_time).await;
+                let mut transmit_time = transmit_time;
+                if !network_jitter.is_zero() {
+                    transmit_time += gen_jitter(network_jitter);
                 }
 
-                buffers.rx(*packet.path.local_address, |queue| {
-                    model.0.current_inflight.fetch_sub(1, Ordering::SeqCst);
-                    queue.enqueue(packet);
-                });
-            });
+                self.deliver(buffers, now, packet.clone(), transmit_time);
+            }
+
+            self.deliver(buffers, now, packet, transmit_time);
 
             1
         };
@@ -4,7 +4,8 @@
 use crate::message::Message as _;
 use core::task::{Context, Waker};
 use s2n_quic_core::{
-    inet::{ExplicitCongestionNotification, SocketAddress},
+    endpoint,
+    inet::{Dscp, ExplicitCongestionNotification, SocketAddress},
     path::{LocalAddress, MaxMtu, Tuple},
 };
 use std::{
@@ -110,6 +111,56 @@ impl Buffers {
         }
     }
 
+    /// Records the type of endpoint which is bound to the address
+    pub fn set_endpoint_type(&self, handle: SocketAddress, endpoint_type: endpoint::Type) {
+        if let Ok(mut lock) = self.inner.lock() {
+            if let Some(host) = lock.addr_to_host.get(&handle).copied() {
+                lock.endpoint_types.insert(host, endpoint_type);
+            }
+        }
+    }
+
+    /// Returns the addresses of all of the server endpoints on the network
+    pub fn server_addresses(&self) -> Vec<SocketAddress> {
+        let mut addresses = vec![];
+
+        if let Ok(lock) = self.inner.lock() {
+            for (host, endpoint_type) in lock.endpoint_types.iter() {
+                if endpoint_type.is_server() {
+                    if let Some(addrs) = lock.host_to_addr.get(host) {
+                        addresses.extend(addrs.iter().copied());
+                    }
+                }
+            }
+        }
+
+        addresses
+    }
+
+    /// Moves every client endpoint to a new port on its current IP, similar to a NAT
+    /// expiring its mappings
+    pub fn rebind_clients(&self) {
+        let mut hosts: Vec<_> = if let Ok(lock) = self.inner.lock() {
+            lock.endpoint_types
+                .iter()
+                .filter(|(_, endpoint_type)| endpoint_type.is_client())
+                .map(|(host, _)| *host)
+                .collect()
+        } else {
+            return;
+        };
+
+        // sort the hosts so the assigned ports are deterministic
+        hosts.sort_unstable_by_key(|host| host.0);
+
+        for host in hosts {
+            if let Ok(mut addr) = self.lookup_addr(host) {
+                addr.set_port(self.next_port.fetch_add(1, Ordering::SeqCst));
+                self.rebind(host, addr);
+            }
+        }
+    }
+
     pub fn tx<F: FnOnce(&mut Queue)>(&self, handle: SocketAddress, f: F) {
         if let Ok(mut lock) = self.inner.lock() {
             let lock = &mut *lock;
@@ -184,6 +235,13 @@ impl Buffers {
         }
     }
 
+    /// Captures all of the packets that are transmitted on the network
+    pub fn set_capture(&self, capture: super::Pcap) {
+        if let Ok(mut lock) = self.inner.lock() {
+            lock.capture = Some(capture);
+        }
+    }
+
     pub fn drain_pending_transmissions<F: FnMut(Packet) -> Result<(), ()>>(&self, mut f: F) {
         let mut lock = if let Ok(lock) = self.inner.lock() {
             lock
@@ -191,9 +249,16 @@ impl Buffers {
             return;
         };
 
+        let lock = &mut *lock;
         let mut queues = vec![];
         let mut to_remove = vec![];
 
+        // only query the clock if the packets are being captured
+        let mut capture = lock.capture.as_mut().map(|capture| {
+            let now = unsafe { super::time::now().as_duration() };
+            (capture, now)
+        });
+
         // find all of the queues with at least one packet to transmit
         for (host, queue) in lock.tx.iter_mut() {
             if queue.packets.is_empty() {
@@ -212,6 +277,10 @@ impl Buffers {
                 // transmit a single packet at a time per queue so they are fairly
                 // transmitted
                 if let Some(packet) = queue.packets.pop_front() {
+                    if let Some((capture, now)) = capture.as_mut() {
+                        capture.on_packet(*now, &packet);
+                    }
+
                     let result = f(packet);
                     has_result = true;
 
@@ -287,8 +356,10 @@ struct State {
     next_host: u64,
     addr_to_host: HashMap<SocketAddress, HostId>,
     host_to_addr: HashMap<HostId, Vec<SocketAddress>>,
+    endpoint_types: HashMap<HostId, endpoint::Type>,
     tx: HashMap<HostId, Queue>,
     rx: HashMap<HostId, Queue>,
+    capture: Option<super::Pcap>,
 }
 
 impl Default for State {
@@ -298,8 +369,10 @@ impl Default for State {
             next_host: 0,
             addr_to_host: Default::default(),
             host_to_addr: Default::default(),
+            endpoint_types: Default::default(),
             tx: Default::default(),
             rx: Default::default(),
+            capture: None,
         }
     }
 }
@@ -319,6 +392,7 @@ impl State {
         }
 
         self.rx.remove(&host);
+        self.endpoint_types.remove(&host);
 
         if let Some(addrs) = self.host_to_addr.remove(&host) {
             for addr in addrs {
@@ -401,8 +475,14 @@ impl Queue {
         for msg in accepted {
             let path = *msg.handle();
             let ecn = msg.ecn();
+            let dscp = msg.dscp();
             let payload = msg.payload().to_vec();
-            let packet = Packet { path, ecn, payload };
+            let packet = Packet {
+                path,
+                ecn,
+                dscp,
+                payload,
+            };
             self.send_packet(packet);
         }
 
@@ -526,6 +606,7 @@ impl Queue {
 pub struct Packet {
     pub path: Tuple,
     pub ecn: ExplicitCongestionNotification,
+    pub dscp: Dscp,
     pub payload: Vec<u8>,
 }
 
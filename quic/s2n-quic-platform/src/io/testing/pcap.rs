@@ -0,0 +1,301 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Captures the packets crossing the testing network to a [pcapng] file
+//!
+//! Each packet is written with synthetic Ethernet, IP and UDP headers using the simulated
+//! addresses. If a TLS key log is configured, its contents are embedded in a Decryption Secrets
+//! Block so tools like Wireshark can decrypt the QUIC packets without any extra configuration.
+//!
+//! [pcapng]: https://www.ietf.org/archive/id/draft-ietf-opsawg-pcapng-03.html
+
+use super::network::Packet;
+use core::{fmt, time::Duration};
+use s2n_codec::{Encoder as _, EncoderBuffer};
+use s2n_quic_core::{
+    inet::{Dscp, ExplicitCongestionNotification},
+    io::tx,
+    xdp::{encoder, path},
+};
+use std::{fs, io, path::PathBuf};
+
+const SECTION_HEADER_BLOCK: u32 = 0x0A0D_0D0A;
+const INTERFACE_DESCRIPTION_BLOCK: u32 = 0x0000_0001;
+const ENHANCED_PACKET_BLOCK: u32 = 0x0000_0006;
+const DECRYPTION_SECRETS_BLOCK: u32 = 0x0000_000A;
+
+const BYTE_ORDER_MAGIC: u32 = 0x1A2B_3C4D;
+const LINKTYPE_ETHERNET: u16 = 1;
+/// The secrets type for the [NSS Key Log format](https://firefox-source-docs.mozilla.org/security/nss/legacy/key_log_format/index.html)
+const TLS_KEY_LOG: u32 = 0x544c_534b;
+
+/// Space reserved for the Ethernet, IPv6 and UDP headers in front of the payload
+const HEADER_CAPACITY: usize = 128;
+
+/// Writes all of the transmitted packets to a pcapng file
+///
+/// The file is written once the network is dropped, which allows the key log to be read after
+/// all of the connections have completed their handshakes.
+pub struct Pcap {
+    path: PathBuf,
+    keylog: Option<PathBuf>,
+    packets: Vec<u8>,
+    encoder: encoder::State,
+    frame: Vec<u8>,
+}
+
+impl fmt::Debug for Pcap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pcap")
+            .field("path", &self.path)
+            .field("keylog", &self.keylog)
+            .field("packets_len", &self.packets.len())
+            .finish()
+    }
+}
+
+impl Pcap {
+    /// Creates a capture that will be written to `path`
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            keylog: None,
+            packets: vec![],
+            encoder: Default::default(),
+            frame: vec![],
+        }
+    }
+
+    /// Embeds the TLS secrets from the key log at `path` in the capture
+    ///
+    /// This is usually the value of the `SSLKEYLOGFILE` environment variable.
+    pub fn with_keylog<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.keylog = Some(path.into());
+        self
+    }
+
+    /// Records a packet that was transmitted at `now`
+    pub(crate) fn on_packet(&mut self, now: Duration, packet: &Packet) {
+        let path = path::Tuple {
+            remote_address: packet.path.remote_address.into(),
+            local_address: packet.path.local_address.into(),
+        };
+        let mut message = Message { path, packet };
+
+        self.frame.clear();
+        self.frame.resize(packet.payload.len() + HEADER_CAPACITY, 0);
+        let mut buffer = EncoderBuffer::new(&mut self.frame);
+
+        // empty payloads can't be encoded but they also don't carry anything worth capturing
+        if encoder::encode_packet(&mut buffer, &mut message, &mut self.encoder).is_err() {
+            return;
+        }
+
+        let len = buffer.len();
+        let frame = &self.frame[..len];
+        let timestamp = now.as_micros() as u64;
+
+        write_block(&mut self.packets, ENHANCED_PACKET_BLOCK, |out| {
+            // interface id
+            out.extend_from_slice(&0u32.to_le_bytes());
+            out.extend_from_slice(&((timestamp >> 32) as u32).to_le_bytes());
+            out.extend_from_slice(&(timestamp as u32).to_le_bytes());
+            // captured length
+            out.extend_from_slice(&(len as u32).to_le_bytes());
+            // original length
+            out.extend_from_slice(&(len as u32).to_le_bytes());
+            out.extend_from_slice(frame);
+        });
+    }
+
+    fn write(&self) -> io::Result<()> {
+        let mut out = vec![];
+
+        write_block(&mut out, SECTION_HEADER_BLOCK, |out| {
+            out.extend_from_slice(&BYTE_ORDER_MAGIC.to_le_bytes());
+            // major version
+            out.extend_from_slice(&1u16.to_le_bytes());
+            // minor version
+            out.extend_from_slice(&0u16.to_le_bytes());
+            // the section length isn't known up front
+            out.extend_from_slice(&(-1i64).to_le_bytes());
+        });
+
+        // the secrets need to come before the packets so they can be decrypted in a single pass
+        if let Some(keylog) = self.keylog.as_ref() {
+            let secrets = fs::read(keylog)?;
+            if !secrets.is_empty() {
+                write_block(&mut out, DECRYPTION_SECRETS_BLOCK, |out| {
+                    out.extend_from_slice(&TLS_KEY_LOG.to_le_bytes());
+                    out.extend_from_slice(&(secrets.len() as u32).to_le_bytes());
+                    out.extend_from_slice(&secrets);
+                });
+            }
+        }
+
+        write_block(&mut out, INTERFACE_DESCRIPTION_BLOCK, |out| {
+            out.extend_from_slice(&LINKTYPE_ETHERNET.to_le_bytes());
+            // reserved
+            out.extend_from_slice(&0u16.to_le_bytes());
+            // snap length - 0 means unlimited
+            out.extend_from_slice(&0u32.to_le_bytes());
+        });
+
+        out.extend_from_slice(&self.packets);
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&self.path, out)
+    }
+}
+
+impl Drop for Pcap {
+    fn drop(&mut self) {
+        if let Err(err) = self.write() {
+            tracing::warn!("could not write pcap to {}: {err}", self.path.display());
+        }
+    }
+}
+
+/// Adapts a captured packet to the XDP encoder, including its traffic class markings
+struct Message<'a> {
+    path: path::Tuple,
+    packet: &'a Packet,
+}
+
+impl tx::Message for Message<'_> {
+    type Handle = path::Tuple;
+
+    #[inline]
+    fn path_handle(&self) -> &Self::Handle {
+        &self.path
+    }
+
+    #[inline]
+    fn ecn(&mut self) -> ExplicitCongestionNotification {
+        self.packet.ecn
+    }
+
+    #[inline]
+    fn dscp(&mut self) -> Dscp {
+        self.packet.dscp
+    }
+
+    #[inline]
+    fn delay(&mut self) -> Duration {
+        Duration::ZERO
+    }
+
+    #[inline]
+    fn ipv6_flow_label(&mut self) -> u32 {
+        0
+    }
+
+    #[inline]
+    fn can_gso(&self, _segment_len: usize, _segment_count: usize) -> bool {
+        false
+    }
+
+    #[inline]
+    fn write_payload(
+        &mut self,
+        mut buffer: tx::PayloadBuffer,
+        _gso_offset: usize,
+    ) -> Result<usize, tx::Error> {
+        buffer.write(&self.packet.payload)
+    }
+}
+
+/// Writes a single block, including the type, length and padding
+fn write_block<F: FnOnce(&mut Vec<u8>)>(out: &mut Vec<u8>, block_type: u32, f: F) {
+    let start = out.len();
+    out.extend_from_slice(&block_type.to_le_bytes());
+    // reserve space for the length
+    out.extend_from_slice(&0u32.to_le_bytes());
+
+    f(out);
+
+    // the block body is padded to 32 bits
+    while out.len() % 4 != 0 {
+        out.push(0);
+    }
+
+    let len = (out.len() - start + 4) as u32;
+    out[start + 4..start + 8].copy_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(&len.to_le_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::{
+        inet::{IpV4Address, SocketAddress},
+        path::Tuple,
+    };
+
+    #[test]
+    fn capture_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("capture.pcapng");
+        let keylog = dir.path().join("keylog.txt");
+        fs::write(&keylog, "CLIENT_RANDOM 00 00\n").unwrap();
+
+        let local_address: SocketAddress = IpV4Address::new([1, 0, 0, 1]).with_port(49152).into();
+        let remote_address: SocketAddress = IpV4Address::new([1, 0, 0, 2]).with_port(443).into();
+        let packet = Packet {
+            path: Tuple {
+                local_address: local_address.into(),
+                remote_address: remote_address.into(),
+            },
+            ecn: ExplicitCongestionNotification::Ect0,
+            dscp: Dscp::EF,
+            payload: vec![1, 2, 3],
+        };
+
+        let mut pcap = Pcap::new(&path).with_keylog(&keylog);
+        pcap.on_packet(Duration::from_secs(1), &packet);
+        drop(pcap);
+
+        let out = fs::read(&path).unwrap();
+
+        let mut blocks = vec![];
+        let mut offset = 0;
+        while offset < out.len() {
+            let block_type = u32::from_le_bytes(out[offset..offset + 4].try_into().unwrap());
+            let len = u32::from_le_bytes(out[offset + 4..offset + 8].try_into().unwrap()) as usize;
+            assert_eq!(len % 4, 0);
+            let trailer =
+                u32::from_le_bytes(out[offset + len - 4..offset + len].try_into().unwrap());
+            assert_eq!(len, trailer as usize);
+            blocks.push((block_type, &out[offset + 8..offset + len - 4]));
+            offset += len;
+        }
+
+        let types: Vec<_> = blocks.iter().map(|(block_type, _)| *block_type).collect();
+        assert_eq!(
+            types,
+            [
+                SECTION_HEADER_BLOCK,
+                DECRYPTION_SECRETS_BLOCK,
+                INTERFACE_DESCRIPTION_BLOCK,
+                ENHANCED_PACKET_BLOCK
+            ]
+        );
+
+        let secrets = blocks[1].1;
+        assert_eq!(&secrets[8..28], b"CLIENT_RANDOM 00 00\n");
+
+        // ethernet (14) + ipv4 (20) + udp (8) + payload (3)
+        let packet = blocks[3].1;
+        let captured_len = u32::from_le_bytes(packet[12..16].try_into().unwrap());
+        assert_eq!(captured_len, 45);
+        let frame = &packet[20..20 + 45];
+        // EF and ECT(0) are set in the IPv4 TOS field
+        assert_eq!(frame[15], (46 << 2) | 0b10);
+        // source and destination ports
+        assert_eq!(&frame[34..38], &[0xc0, 0x00, 0x01, 0xbb]);
+        assert_eq!(&frame[42..], &[1, 2, 3]);
+    }
+}
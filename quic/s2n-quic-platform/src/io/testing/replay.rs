@@ -0,0 +1,301 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Replays a [`Recording`] into an endpoint
+//!
+//! The received datagrams are delivered to the endpoint at the same offsets, relative to the
+//! start of the endpoint, as they were recorded. Because the endpoint runs on the testing
+//! executor and clock, each replay is deterministic and can be stepped through under a debugger.
+//!
+//! Note that only the received datagrams are replayed. If the endpoint chooses different
+//! connection IDs or keys than the original endpoint, which is the case for any endpoint using
+//! real randomness, it won't be able to process packets past the Initial packets from the peer.
+
+use super::{executor, network::PathHandle, primary, time, Handle, Result as IoResult};
+use crate::io::record::{Datagram, Direction, Recording};
+use core::{
+    task::{Context, Poll},
+    time::Duration,
+};
+use s2n_quic_core::{
+    endpoint::Endpoint,
+    event,
+    inet::{datagram, SocketAddress},
+    io::{event_loop::EventLoop, rx, tx},
+    path::{Handle as _, Tuple},
+    time::{clock::Timer as _, Timestamp},
+};
+use std::{
+    collections::VecDeque,
+    io,
+    sync::{Arc, Mutex},
+};
+
+/// The amount of time the replay keeps running after the last recorded datagram, so the
+/// endpoint has a chance to respond to it
+const GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+/// An IO provider that feeds the received datagrams from a recording into an endpoint
+pub struct Io {
+    handle: Handle,
+    recording: Recording,
+    transmissions: Arc<Mutex<Vec<Datagram>>>,
+}
+
+impl Io {
+    pub fn new(handle: &Handle, recording: Recording) -> Self {
+        Self {
+            handle: handle.clone(),
+            recording,
+            transmissions: Default::default(),
+        }
+    }
+
+    /// Returns the datagrams that the endpoint transmitted during the replay
+    ///
+    /// These can be compared to [`Recording::tx`] to find where the endpoint diverged from the
+    /// original.
+    pub fn transmissions(&self) -> Arc<Mutex<Vec<Datagram>>> {
+        self.transmissions.clone()
+    }
+
+    pub fn start<E: Endpoint<PathHandle = PathHandle>>(
+        self,
+        mut endpoint: E,
+    ) -> IoResult<(executor::JoinHandle<()>, SocketAddress)> {
+        let Self {
+            handle,
+            recording,
+            transmissions,
+        } = self;
+
+        let local_addr = recording
+            .rx()
+            .next()
+            .map(|datagram| datagram.local_address)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "the recording doesn't contain any received datagrams",
+                )
+            })?;
+
+        let end = recording
+            .datagrams()
+            .last()
+            .map_or(Duration::ZERO, |datagram| datagram.time);
+
+        let start = time::now();
+
+        // keep the simulation running until all of the datagrams have been replayed
+        primary::spawn(async move {
+            time::delay_until(start + end + GRACE_PERIOD).await;
+        });
+
+        let datagrams = recording
+            .into_iter()
+            .filter(|datagram| datagram.direction == Direction::Rx)
+            .collect();
+
+        let rx = Rx {
+            start,
+            datagrams,
+            timer: Default::default(),
+        };
+
+        let tx = Tx {
+            start,
+            transmissions,
+        };
+
+        let (_stats_sender, stats) = crate::socket::stats::channel();
+
+        endpoint.set_mtu_config(Default::default());
+
+        let event_loop = EventLoop {
+            endpoint,
+            clock: time::Clock::default(),
+            rx,
+            tx,
+            cooldown: Default::default(),
+            stats,
+        };
+
+        let join = handle.executor.spawn(event_loop.start(local_addr));
+        Ok((join, local_addr))
+    }
+}
+
+struct Rx {
+    start: Timestamp,
+    datagrams: VecDeque<Datagram>,
+    timer: time::Timer,
+}
+
+impl rx::Rx for Rx {
+    type PathHandle = PathHandle;
+    type Queue = RxQueue<'static>;
+    type Error = ();
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context) -> Poll<Result<(), Self::Error>> {
+        let Some(datagram) = self.datagrams.front() else {
+            // the recording has been fully replayed
+            return Poll::Pending;
+        };
+
+        let deadline = self.start + datagram.time;
+        if deadline <= time::now() {
+            return Poll::Ready(Ok(()));
+        }
+
+        self.timer.update(deadline);
+        self.timer.poll_ready(cx).map(Ok)
+    }
+
+    #[inline]
+    fn queue<F: FnOnce(&mut Self::Queue)>(&mut self, f: F) {
+        let datagrams: &'static mut _ = unsafe {
+            // Safety: As noted in the [transmute examples](https://doc.rust-lang.org/std/mem/fn.transmute.html#examples)
+            // it can be used to temporarily extend the lifetime of a reference. In this case, we
+            // don't want to use GATs until the MSRV is >=1.65.0, which means `Self::Queue` is not
+            // allowed to take generic lifetimes.
+            //
+            // We are left with using a `'static` lifetime here and encapsulating it in a private
+            // field. The `Self::Queue` struct is then borrowed for the lifetime of the `F`
+            // function. This will prevent the value from escaping beyond the lifetime of `&mut
+            // self`.
+            //
+            // See https://play.rust-lang.org/?version=stable&mode=debug&edition=2021&gist=9a32abe85c666f36fb2ec86496cc41b4
+            //
+            // Once https://github.com/aws/s2n-quic/issues/1742 is resolved this code can go away
+            core::mem::transmute::<&mut VecDeque<Datagram>, &mut VecDeque<Datagram>>(
+                &mut self.datagrams,
+            )
+        };
+
+        let now = time::now().saturating_duration_since(self.start);
+        let mut queue = RxQueue { datagrams, now };
+        f(&mut queue);
+    }
+
+    #[inline]
+    fn handle_error<E: event::EndpointPublisher>(self, _error: Self::Error, _event: &mut E) {
+        // nothing to do
+    }
+}
+
+struct RxQueue<'a> {
+    datagrams: &'a mut VecDeque<Datagram>,
+    now: Duration,
+}
+
+impl rx::Queue for RxQueue<'_> {
+    type Handle = PathHandle;
+
+    #[inline]
+    fn for_each<F: FnMut(datagram::Header<Self::Handle>, &mut [u8])>(&mut self, mut on_packet: F) {
+        while self
+            .datagrams
+            .front()
+            .is_some_and(|datagram| datagram.time <= self.now)
+        {
+            let Some(mut datagram) = self.datagrams.pop_front() else {
+                break;
+            };
+
+            let header = datagram::Header {
+                path: Tuple {
+                    local_address: datagram.local_address.into(),
+                    remote_address: datagram.remote_address.into(),
+                },
+                ecn: datagram.ecn,
+            };
+
+            on_packet(header, &mut datagram.payload);
+        }
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        !self
+            .datagrams
+            .front()
+            .is_some_and(|datagram| datagram.time <= self.now)
+    }
+}
+
+struct Tx {
+    start: Timestamp,
+    transmissions: Arc<Mutex<Vec<Datagram>>>,
+}
+
+impl tx::Tx for Tx {
+    type PathHandle = PathHandle;
+    type Queue = TxQueue;
+    type Error = ();
+
+    #[inline]
+    fn poll_ready(&mut self, _cx: &mut Context) -> Poll<Result<(), Self::Error>> {
+        // the TX queue never fills up so it never needs to notify the endpoint
+        Poll::Pending
+    }
+
+    #[inline]
+    fn queue<F: FnOnce(&mut Self::Queue)>(&mut self, f: F) {
+        let mut queue = TxQueue {
+            now: time::now().saturating_duration_since(self.start),
+            transmissions: self.transmissions.clone(),
+        };
+        f(&mut queue);
+    }
+
+    #[inline]
+    fn handle_error<E: event::EndpointPublisher>(self, _error: Self::Error, _event: &mut E) {
+        // nothing to do
+    }
+}
+
+struct TxQueue {
+    now: Duration,
+    transmissions: Arc<Mutex<Vec<Datagram>>>,
+}
+
+impl tx::Queue for TxQueue {
+    type Handle = PathHandle;
+
+    const SUPPORTS_ECN: bool = true;
+
+    #[inline]
+    fn push<M: tx::Message<Handle = Self::Handle>>(
+        &mut self,
+        mut message: M,
+    ) -> Result<tx::Outcome, tx::Error> {
+        let path = *message.path_handle();
+        let ecn = message.ecn();
+
+        let mut payload = vec![0; u16::MAX as usize];
+        let len = message.write_payload(tx::PayloadBuffer::new(&mut payload), 0)?;
+        payload.truncate(len);
+
+        let datagram = Datagram {
+            direction: Direction::Tx,
+            time: self.now,
+            ecn,
+            local_address: path.local_address().0,
+            remote_address: path.remote_address().0,
+            payload,
+        };
+
+        if let Ok(mut transmissions) = self.transmissions.lock() {
+            transmissions.push(datagram);
+        }
+
+        Ok(tx::Outcome { len, index: 0 })
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        usize::MAX
+    }
+}
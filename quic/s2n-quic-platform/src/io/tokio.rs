@@ -1,13 +1,13 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{features::gso, message::default as message, socket, syscall};
+use crate::{features::gso, io::record, message::default as message, socket, syscall};
 use s2n_quic_core::{
     endpoint::Endpoint,
     event::{self, EndpointPublisher as _},
     inet::{self, SocketAddress},
-    io::event_loop::EventLoop,
-    path::{mtu, MaxMtu},
+    io::{event_loop::EventLoop, rx::RxExt as _, tx::TxExt as _},
+    path::{self, mtu, MaxMtu},
     task::cooldown::Cooldown,
     time::Clock as ClockTrait,
 };
@@ -42,8 +42,27 @@ impl Io {
 
     pub fn start<E: Endpoint<PathHandle = PathHandle>>(
         self,
-        mut endpoint: E,
+        endpoint: E,
     ) -> io::Result<(tokio::task::JoinHandle<()>, SocketAddress)> {
+        self.start_with_handle_map(endpoint, |handle| handle, |handle| *handle)
+    }
+
+    /// Starts the IO provider for an endpoint that uses a different path handle type
+    ///
+    /// Handles of received datagrams are converted with `rx_map` and handles of transmitted
+    /// datagrams with `tx_map`.
+    pub(crate) fn start_with_handle_map<E, H, RxMap, TxMap>(
+        self,
+        mut endpoint: E,
+        rx_map: RxMap,
+        tx_map: TxMap,
+    ) -> io::Result<(tokio::task::JoinHandle<()>, SocketAddress)>
+    where
+        E: Endpoint<PathHandle = H>,
+        H: path::Handle,
+        RxMap: 'static + Send + Fn(PathHandle) -> H,
+        TxMap: 'static + Send + Fn(&H) -> PathHandle,
+    {
         let Builder {
             handle,
             rx_socket,
@@ -57,9 +76,12 @@ impl Io {
             mtu_config_builder,
             max_segments,
             gro_enabled,
+            pacing_offload,
             reuse_address,
             reuse_port,
             only_v6,
+            io_uring,
+            recorder,
         } = self.builder;
 
         let clock = Clock::default();
@@ -170,7 +192,40 @@ impl Io {
             },
         });
 
-        let (stats_sender, stats_recv) = crate::socket::stats::channel();
+        // Configure the socket to hold packets until their departure time
+        let txtime_enabled = pacing_offload && syscall::configure_txtime(&tx_socket);
+
+        publisher.on_platform_feature_configured(event::builder::PlatformFeatureConfigured {
+            configuration: event::builder::PlatformFeatureConfiguration::PacingOffload {
+                enabled: txtime_enabled,
+            },
+        });
+
+        // Configure the socket to report datagrams dropped due to a full receive buffer
+        let rxq_ovfl_enabled = syscall::configure_rxq_ovfl(&rx_socket);
+
+        publisher.on_platform_feature_configured(event::builder::PlatformFeatureConfigured {
+            configuration: event::builder::PlatformFeatureConfiguration::RxQueueOverflow {
+                enabled: rxq_ovfl_enabled,
+            },
+        });
+
+        // Fall back to the default socket tasks if the kernel can't support io_uring
+        #[cfg(all(feature = "io-uring", s2n_quic_platform_io_uring))]
+        let io_uring = if io_uring {
+            crate::features::IoUring::probe()
+        } else {
+            None
+        };
+        #[cfg(not(all(feature = "io-uring", s2n_quic_platform_io_uring)))]
+        let _ = io_uring;
+
+        let (stats_sender, mut stats_recv) = crate::socket::stats::channel();
+
+        // Periodically report the memory usage of the socket, if the platform supports it
+        if syscall::socket_meminfo(&rx_socket).is_some() {
+            stats_recv.sample_socket_memory(rx_socket.try_clone()?);
+        }
 
         let rx = {
             // if GRO is enabled, then we need to provide the syscall with the maximum size buffer
@@ -199,27 +254,37 @@ impl Io {
             // complete
             let rx_cooldown = cooldown("RX");
 
+            let spawn_rx = |rx_socket: socket2::Socket, producer, rx_cooldown| {
+                #[cfg(all(feature = "io-uring", s2n_quic_platform_io_uring))]
+                if io_uring.is_some() {
+                    return handle.spawn(task::uring::rx(
+                        rx_socket,
+                        producer,
+                        payload_len,
+                        rx_cooldown,
+                        stats_sender.clone(),
+                    ));
+                }
+
+                handle.spawn(task::rx(
+                    rx_socket,
+                    producer,
+                    rx_cooldown,
+                    stats_sender.clone(),
+                ))
+            };
+
             for idx in 0usize..rx_socket_count {
                 let (producer, consumer) = socket::ring::pair(entries, payload_len);
                 consumers.push(consumer);
 
                 // spawn a task that actually reads from the socket into the ring buffer
                 if idx + 1 == rx_socket_count {
-                    handle.spawn(task::rx(
-                        rx_socket,
-                        producer,
-                        rx_cooldown,
-                        stats_sender.clone(),
-                    ));
+                    spawn_rx(rx_socket, producer, rx_cooldown);
                     break;
                 } else {
                     let rx_socket = rx_socket.try_clone()?;
-                    handle.spawn(task::rx(
-                        rx_socket,
-                        producer,
-                        rx_cooldown.clone(),
-                        stats_sender.clone(),
-                    ));
+                    spawn_rx(rx_socket, producer, rx_cooldown.clone());
                 }
             }
 
@@ -231,6 +296,7 @@ impl Io {
 
         let tx = {
             let gso = crate::features::Gso::from(max_segments);
+            let txtime = crate::features::TxTime::new(txtime_enabled).with_clock(&clock);
 
             // compute the payload size for each message from the number of GSO segments we can
             // fill
@@ -256,50 +322,76 @@ impl Io {
             // complete
             let tx_cooldown = cooldown("TX");
 
-            for idx in 0usize..tx_socket_count {
-                let (producer, consumer) = socket::ring::pair(entries, payload_len);
-                producers.push(producer);
-
-                // spawn a task that actually flushes the ring buffer to the socket
-                if idx + 1 == tx_socket_count {
-                    handle.spawn(task::tx(
+            let spawn_tx = |tx_socket: socket2::Socket, consumer, tx_cooldown| {
+                #[cfg(all(feature = "io-uring", s2n_quic_platform_io_uring))]
+                if let Some(io_uring) = io_uring.clone() {
+                    return handle.spawn(task::uring::tx(
                         tx_socket,
                         consumer,
                         gso.clone(),
+                        txtime.clone(),
+                        io_uring,
                         tx_cooldown,
                         stats_sender.clone(),
                     ));
+                }
+
+                handle.spawn(task::tx(
+                    tx_socket,
+                    consumer,
+                    gso.clone(),
+                    txtime.clone(),
+                    tx_cooldown,
+                    stats_sender.clone(),
+                ))
+            };
+
+            for idx in 0usize..tx_socket_count {
+                let (producer, consumer) = socket::ring::pair(entries, payload_len);
+                producers.push(producer);
+
+                // spawn a task that actually flushes the ring buffer to the socket
+                if idx + 1 == tx_socket_count {
+                    spawn_tx(tx_socket, consumer, tx_cooldown);
                     break;
                 } else {
                     let tx_socket = tx_socket.try_clone()?;
-                    handle.spawn(task::tx(
-                        tx_socket,
-                        consumer,
-                        gso.clone(),
-                        tx_cooldown.clone(),
-                        stats_sender.clone(),
-                    ));
+                    spawn_tx(tx_socket, consumer, tx_cooldown.clone());
                 }
             }
 
             // construct the TX side for the endpoint event loop
-            socket::io::tx::Tx::new(producers, gso, mtu_config.max_mtu())
+            socket::io::tx::Tx::new(producers, gso, mtu_config.max_mtu()).with_txtime(txtime)
         };
 
         // Notify the endpoint of the MTU that we chose
         endpoint.set_mtu_config(mtu_config);
 
-        let task = handle.spawn(
-            EventLoop {
-                endpoint,
-                clock,
-                rx,
-                tx,
-                cooldown: cooldown("ENDPOINT"),
-                stats: stats_recv,
-            }
-            .start(rx_addr.into()),
-        );
+        let task = if let Some(recorder) = recorder {
+            handle.spawn(
+                EventLoop {
+                    endpoint,
+                    clock,
+                    rx: record::Rx::new(rx, recorder.clone()).with_handle_map(rx_map),
+                    tx: record::Tx::new(tx, recorder).with_handle_map(tx_map),
+                    cooldown: cooldown("ENDPOINT"),
+                    stats: stats_recv,
+                }
+                .start(rx_addr.into()),
+            )
+        } else {
+            handle.spawn(
+                EventLoop {
+                    endpoint,
+                    clock,
+                    rx: rx.with_handle_map(rx_map),
+                    tx: tx.with_handle_map(tx_map),
+                    cooldown: cooldown("ENDPOINT"),
+                    stats: stats_recv,
+                }
+                .start(rx_addr.into()),
+            )
+        };
 
         drop(guard);
 
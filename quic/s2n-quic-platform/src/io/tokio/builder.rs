@@ -17,9 +17,12 @@ pub struct Builder {
     pub(super) mtu_config_builder: mtu::Builder,
     pub(super) max_segments: gso::MaxSegments,
     pub(super) gro_enabled: Option<bool>,
+    pub(super) pacing_offload: bool,
     pub(super) reuse_address: bool,
     pub(super) reuse_port: bool,
     pub(super) only_v6: bool,
+    pub(super) io_uring: bool,
+    pub(super) recorder: Option<record::Recorder>,
 }
 
 impl Builder {
@@ -219,6 +222,25 @@ impl Builder {
         }
     }
 
+    /// Offloads pacing of transmissions to the kernel (default: disabled)
+    ///
+    /// When enabled, each packet is sent with the departure time chosen by the congestion
+    /// controller's pacer (SO_TXTIME) instead of waiting for it with a timer, which is cheaper and
+    /// more precise at high rates. The departure times are only enforced if the interface uses
+    /// the `fq` qdisc; otherwise packets are sent immediately, so only enable this if the host is
+    /// configured accordingly. If the kernel rejects the departure times, the endpoint falls back
+    /// to pacing with timers.
+    pub fn with_pacing_offload(mut self, enabled: bool) -> io::Result<Self> {
+        if enabled && !crate::features::txtime::IS_SUPPORTED {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "pacing offload is not supported on the current platform",
+            ));
+        }
+        self.pacing_offload = enabled;
+        Ok(self)
+    }
+
     /// Enables the address reuse (SO_REUSEADDR) socket option
     pub fn with_reuse_address(mut self, enabled: bool) -> io::Result<Self> {
         self.reuse_address = enabled;
@@ -250,6 +272,38 @@ impl Builder {
         Ok(self)
     }
 
+    /// Drives the sockets with io_uring instead of readiness-based syscalls
+    ///
+    /// This reduces the number of syscalls at high packet rates by using multishot receives and
+    /// batched, zero-copy transmissions. If the running kernel doesn't support the required
+    /// io_uring operations, the default socket tasks will be used instead.
+    pub fn with_io_uring(mut self, enabled: bool) -> io::Result<Self> {
+        if enabled && !crate::features::uring::IS_SUPPORTED {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "io_uring is not supported on the current platform",
+            ));
+        }
+        self.io_uring = enabled;
+        Ok(self)
+    }
+
+    /// Records every datagram that the endpoint sends and receives to a file at `path`
+    ///
+    /// The recording includes the payloads, ECN markings and the time each datagram was
+    /// observed. The payloads are written as-is so the recording should be handled with the same
+    /// care as any other packet capture.
+    ///
+    /// The received datagrams can be fed back into an endpoint with the `testing::replay` IO
+    /// provider. The recording doesn't capture the endpoint's randomness or secrets, so the
+    /// replayed endpoint picks different connection IDs and TLS key shares than the original and
+    /// can't process any packets past the peer's first Initial. Replays are mostly useful for
+    /// reproducing issues in datagram parsing and Initial packet handling.
+    pub fn with_recording<P: AsRef<std::path::Path>>(mut self, path: P) -> io::Result<Self> {
+        self.recorder = Some(record::Recorder::create(path)?);
+        Ok(self)
+    }
+
     pub fn build(self) -> io::Result<Io> {
         Ok(Io { builder: self })
     }
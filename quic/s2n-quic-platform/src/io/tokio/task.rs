@@ -7,6 +7,8 @@
 mod simple;
 #[cfg(unix)]
 mod unix;
+#[cfg(all(feature = "io-uring", s2n_quic_platform_io_uring))]
+pub mod uring;
 
 cfg_if::cfg_if! {
     if #[cfg(s2n_quic_platform_socket_mmsg)] {
@@ -24,7 +26,7 @@ macro_rules! libc_msg {
         mod $message {
             use super::unix;
             use crate::{
-                features::Gso,
+                features::{Gso, TxTime},
                 message::$message::Message,
                 socket::{ring, stats},
             };
@@ -43,10 +45,11 @@ macro_rules! libc_msg {
                 socket: S,
                 consumer: ring::Consumer<Message>,
                 gso: Gso,
+                txtime: TxTime,
                 cooldown: Cooldown,
                 stats: stats::Sender,
             ) -> std::io::Result<()> {
-                unix::tx(socket, consumer, gso, cooldown, stats).await
+                unix::tx(socket, consumer, gso, txtime, cooldown, stats).await
             }
         }
     };
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    features::Gso,
+    features::{Gso, TxTime},
     message::{simple::Message, Message as _},
     socket::{
         ring, stats, task,
@@ -36,6 +36,7 @@ pub async fn tx<S: Into<std::net::UdpSocket>>(
     socket: S,
     consumer: ring::Consumer<Message>,
     gso: Gso,
+    txtime: TxTime,
     cooldown: Cooldown,
     stats: stats::Sender,
 ) -> io::Result<()> {
@@ -43,7 +44,9 @@ pub async fn tx<S: Into<std::net::UdpSocket>>(
     socket.set_nonblocking(true).unwrap();
 
     let socket = UdpSocket::from_std(socket).unwrap();
-    let result = task::Sender::new(consumer, socket, gso, cooldown, stats).await;
+    let result = task::Sender::new(consumer, socket, gso, cooldown, stats)
+        .with_txtime(txtime)
+        .await;
     if let Some(err) = result {
         Err(err)
     } else {
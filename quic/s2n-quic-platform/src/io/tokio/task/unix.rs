@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    features::Gso,
+    features::{Gso, TxTime},
     socket::{
         ring, stats,
         task::{rx, tx},
@@ -36,6 +36,7 @@ pub async fn tx<S: Into<std::net::UdpSocket>, M: UnixMessage + Unpin>(
     socket: S,
     consumer: ring::Consumer<M>,
     gso: Gso,
+    txtime: TxTime,
     cooldown: Cooldown,
     stats: stats::Sender,
 ) -> io::Result<()> {
@@ -43,7 +44,9 @@ pub async fn tx<S: Into<std::net::UdpSocket>, M: UnixMessage + Unpin>(
     socket.set_nonblocking(true).unwrap();
 
     let socket = AsyncFd::new(socket).unwrap();
-    let result = tx::Sender::new(consumer, socket, gso, cooldown, stats).await;
+    let result = tx::Sender::new(consumer, socket, gso, cooldown, stats)
+        .with_txtime(txtime)
+        .await;
     if let Some(err) = result {
         Err(err)
     } else {
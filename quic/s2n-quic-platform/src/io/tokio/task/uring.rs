@@ -0,0 +1,755 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Socket tasks backed by [io_uring](https://man7.org/linux/man-pages/man7/io_uring.7.html)
+//!
+//! The RX task arms a single multishot `recvmsg` which selects buffers from a provided buffer ring
+//! registered with the kernel. Each completion is copied into the next free message in the RX
+//! ring, which means a burst of packets costs a single `io_uring_enter` rather than a syscall per
+//! batch. The kernel doesn't support fixed buffers for `recvmsg`, so the provided buffer ring takes
+//! their place on the RX side.
+//!
+//! The TX task submits a `sendmsg` for each message in the TX ring, pointing the kernel directly at
+//! the message headers, including any GSO control messages. If the kernel supports it, messages
+//! are transmitted with `IORING_OP_SENDMSG_ZC`, in which case the message isn't released back to
+//! the ring until the kernel notifies it is no longer referencing the payload. On Linux 6.15 and
+//! later, the TX ring's memory is registered with `IORING_REGISTER_BUFFERS` and zero copy sends
+//! use it as a fixed buffer, which avoids pinning the payload pages for every message.
+
+use crate::{
+    features::{Gso, IoUring as Features, TxTime},
+    message::{cmsg, Message as MessageTrait},
+    socket::{
+        ring, stats,
+        task::{rx, tx},
+    },
+    syscall::SocketEvents as _,
+};
+use core::{
+    alloc::Layout,
+    ptr::NonNull,
+    sync::atomic::{AtomicU16, Ordering},
+    task::{Context, Poll},
+};
+use io_uring::{cqueue, opcode, squeue, types, IoUring};
+use s2n_quic_core::task::cooldown::Cooldown;
+use std::{
+    collections::VecDeque,
+    io,
+    net::UdpSocket,
+    os::unix::io::{AsRawFd, RawFd},
+};
+use tokio::io::unix::AsyncFd;
+
+/// The buffer group id for the RX provided buffer ring
+const BUFFER_GROUP: u16 = 0;
+/// The user data for the multishot recvmsg operation
+const RECV_USER_DATA: u64 = 0;
+/// The user data for cancelling the multishot recvmsg operation
+const CANCEL_USER_DATA: u64 = 1;
+/// The maximum number of buffers in a provided buffer ring
+const MAX_BUFFERS: u32 = 1 << 15;
+/// The maximum number of entries in the TX submission queue
+const MAX_TX_ENTRIES: u32 = 4096;
+/// The size of the `io_uring_recvmsg_out` header at the beginning of each received buffer
+const RECVMSG_OUT_LEN: usize = core::mem::size_of::<[u32; 4]>();
+/// `IORING_RECVSEND_FIXED_BUF`, which isn't exposed by the `io-uring` crate for `SendMsgZc`
+const RECVSEND_FIXED_BUF: u16 = 1 << 2;
+
+/// A message that can be passed to the kernel as a [`libc::msghdr`]
+pub trait Message: MessageTrait {
+    fn msghdr_mut(&mut self) -> &mut libc::msghdr;
+}
+
+impl Message for libc::msghdr {
+    #[inline]
+    fn msghdr_mut(&mut self) -> &mut libc::msghdr {
+        self
+    }
+}
+
+impl Message for libc::mmsghdr {
+    #[inline]
+    fn msghdr_mut(&mut self) -> &mut libc::msghdr {
+        &mut self.msg_hdr
+    }
+}
+
+pub async fn rx<S: Into<UdpSocket>, M: Message + Unpin>(
+    socket: S,
+    producer: ring::Producer<M>,
+    payload_len: u32,
+    cooldown: Cooldown,
+    stats: stats::Sender,
+) -> io::Result<()> {
+    let socket = socket.into();
+    // io_uring waits for the socket to be ready internally so we don't need non-blocking mode
+    socket.set_nonblocking(false)?;
+
+    let buffers = producer.capacity().clamp(1, MAX_BUFFERS);
+    let socket = Rx::new(socket, buffers, payload_len)?;
+    let result = rx::Receiver::new(producer, socket, cooldown, stats).await;
+    if let Some(err) = result {
+        Err(err)
+    } else {
+        Ok(())
+    }
+}
+
+pub async fn tx<S: Into<UdpSocket>, M: Message + Unpin>(
+    socket: S,
+    consumer: ring::Consumer<M>,
+    gso: Gso,
+    txtime: TxTime,
+    features: Features,
+    cooldown: Cooldown,
+    stats: stats::Sender,
+) -> io::Result<()> {
+    let socket = socket.into();
+    // io_uring waits for the socket to be ready internally so we don't need non-blocking mode
+    socket.set_nonblocking(false)?;
+
+    let entries = consumer.capacity().clamp(1, MAX_TX_ENTRIES);
+    let socket = Tx::new(socket, entries, features, consumer.region())?;
+    let result = tx::Sender::new(consumer, socket, gso, cooldown, stats)
+        .with_txtime(txtime)
+        .await;
+    if let Some(err) = result {
+        Err(err)
+    } else {
+        Ok(())
+    }
+}
+
+/// Registers the task waker with the io_uring file descriptor
+///
+/// Returns `true` if completions are available and the caller should try again.
+#[inline]
+fn poll_completions(ring: &mut AsyncFd<IoUring>, cx: &mut Context) -> io::Result<bool> {
+    // * First iteration we need to clear the readiness since we drained the completion queue.
+    // * Second iteration we need to register the waker, assuming the readiness was cleared.
+    //   * If we got a `Ready` anyway, then have the caller try again.
+    for i in 0..2 {
+        match ring.poll_read_ready(cx) {
+            Poll::Ready(guard) => {
+                let mut guard = guard?;
+                if i == 0 {
+                    guard.clear_ready();
+                } else {
+                    return Ok(true);
+                }
+            }
+            Poll::Pending => break,
+        }
+    }
+
+    // a completion could have been posted after we drained the queue but before the readiness was
+    // cleared so check one last time
+    Ok(!ring.get_mut().completion().is_empty())
+}
+
+/// Returns `true` if the submission error is transient and the submission can be retried later
+#[inline]
+fn is_transient(error: &io::Error) -> bool {
+    matches!(
+        error.raw_os_error(),
+        Some(libc::EAGAIN | libc::EBUSY | libc::EINTR)
+    )
+}
+
+struct Rx {
+    // NOTE: the ring needs to be dropped before the buffers and socket it references
+    ring: AsyncFd<IoUring>,
+    buffers: BufRing,
+    /// The message passed to the multishot recvmsg
+    ///
+    /// Only the `msg_namelen` and `msg_controllen` fields are used by the kernel to determine the
+    /// layout of each received buffer.
+    msghdr: Box<libc::msghdr>,
+    socket: UdpSocket,
+    is_armed: bool,
+}
+
+/// Safety: the msghdr doesn't point to any memory and is only read by the kernel
+unsafe impl Send for Rx {}
+/// Safety: the msghdr doesn't point to any memory and is only read by the kernel
+unsafe impl Sync for Rx {}
+
+impl Rx {
+    fn new(socket: UdpSocket, buffers: u32, payload_len: u32) -> io::Result<Self> {
+        let ring = IoUring::builder().setup_cqsize(buffers * 2).build(4)?;
+
+        // Safety: `msghdr` is valid when zeroed
+        let mut msghdr: Box<libc::msghdr> = Box::new(unsafe { core::mem::zeroed() });
+        msghdr.msg_namelen = core::mem::size_of::<libc::sockaddr_in6>() as _;
+        msghdr.msg_controllen = cmsg::MAX_LEN as _;
+
+        // each buffer holds an `io_uring_recvmsg_out` header followed by the name, control
+        // messages, and payload
+        let buffer_len = RECVMSG_OUT_LEN
+            + msghdr.msg_namelen as usize
+            + msghdr.msg_controllen
+            + payload_len as usize;
+
+        let buffers = BufRing::new(buffers as u16, buffer_len);
+
+        // Safety: the buffer ring is unregistered when the io_uring is dropped, which happens
+        //         before the buffers are freed
+        unsafe {
+            ring.submitter().register_buf_ring_with_flags(
+                buffers.entries.as_ptr() as u64,
+                buffers.len,
+                BUFFER_GROUP,
+                0,
+            )?;
+        }
+
+        let ring = AsyncFd::new(ring)?;
+
+        Ok(Self {
+            ring,
+            buffers,
+            msghdr,
+            socket,
+            is_armed: false,
+        })
+    }
+
+    #[inline]
+    fn arm(&mut self) -> io::Result<()> {
+        let sqe = opcode::RecvMsgMulti::new(
+            types::Fd(self.socket.as_raw_fd()),
+            &*self.msghdr,
+            BUFFER_GROUP,
+        )
+        .build()
+        .user_data(RECV_USER_DATA);
+
+        let ring = self.ring.get_mut();
+
+        // Safety: the msghdr and buffers outlive the operation
+        unsafe {
+            ring.submission()
+                .push(&sqe)
+                .map_err(|_| io::Error::other("io_uring submission queue is full"))?;
+        }
+
+        match ring.submit() {
+            Ok(_) => {}
+            // the entry is still in the submission queue so it will be submitted on the next call
+            Err(error) if is_transient(&error) => {}
+            Err(error) => return Err(error),
+        }
+
+        self.is_armed = true;
+
+        Ok(())
+    }
+}
+
+impl<M: Message> rx::Socket<M> for Rx {
+    type Error = io::Error;
+
+    #[inline]
+    fn recv(
+        &mut self,
+        cx: &mut Context,
+        entries: &mut [M],
+        events: &mut rx::Events,
+        stats: &stats::Sender,
+    ) -> io::Result<()> {
+        if !self.is_armed {
+            self.arm()?;
+        }
+
+        let mut count = 0;
+
+        {
+            let mut completion = self.ring.get_mut().completion();
+
+            while count < entries.len() {
+                let Some(cqe) = completion.next() else {
+                    break;
+                };
+
+                debug_assert_eq!(cqe.user_data(), RECV_USER_DATA);
+
+                let flags = cqe.flags();
+
+                // the multishot operation was terminated so it needs to be armed again
+                if !cqueue::more(flags) {
+                    self.is_armed = false;
+                }
+
+                let result = cqe.result();
+
+                if result < 0 {
+                    let error = io::Error::from_raw_os_error(-result);
+                    stats
+                        .recv()
+                        .on_operation_result(&Err::<(), _>(error), |_| 0);
+
+                    match -result {
+                        // the kernel doesn't support multishot recvmsg or the socket type
+                        libc::EINVAL | libc::EOPNOTSUPP => {
+                            return Err(io::Error::from_raw_os_error(-result));
+                        }
+                        // all other errors, including running out of buffers, just require
+                        // re-arming the operation
+                        _ => continue,
+                    }
+                }
+
+                let Some(buffer_id) = cqueue::buffer_select(flags) else {
+                    continue;
+                };
+
+                let buffer = self.buffers.get(buffer_id, result as usize);
+
+                if let Ok(out) = types::RecvMsgOut::parse(buffer, &self.msghdr) {
+                    copy_message(&out, &mut entries[count]);
+                    count += 1;
+                }
+
+                self.buffers.recycle(buffer_id);
+            }
+        }
+
+        self.buffers.publish();
+
+        if count > 0 {
+            stats.recv().on_operation_ready(count);
+            // the drop counter is cumulative so only the last message needs to be checked
+            crate::syscall::on_rx_queue_overflow(entries[count - 1].msghdr_mut(), stats);
+            let _ = events.on_complete(count);
+            return Ok(());
+        }
+
+        // try to arm the operation again before blocking on completions
+        if !self.is_armed {
+            return Ok(());
+        }
+
+        events.blocked();
+        stats.recv().on_operation_pending();
+
+        if poll_completions(&mut self.ring, cx)? {
+            events.take_blocked();
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Rx {
+    fn drop(&mut self) {
+        if !self.is_armed {
+            return;
+        }
+
+        // make a best effort to cancel the operation before the buffers are freed
+        let sqe = opcode::AsyncCancel::new(RECV_USER_DATA)
+            .build()
+            .user_data(CANCEL_USER_DATA);
+
+        let ring = self.ring.get_mut();
+
+        // Safety: the cancellation doesn't reference any memory
+        if unsafe { ring.submission().push(&sqe) }.is_ok() {
+            let _ = ring.submit_and_wait(1);
+        }
+    }
+}
+
+/// Copies a multishot recvmsg result into a message in the RX ring
+#[inline]
+fn copy_message<M: Message>(out: &types::RecvMsgOut, entry: &mut M) {
+    let payload = out.payload_data();
+    let len = payload.len().min(entry.payload_len());
+
+    // Safety: the message was reset by the consumer so it has the full payload capacity
+    unsafe {
+        core::ptr::copy_nonoverlapping(payload.as_ptr(), entry.payload_ptr_mut(), len);
+        entry.set_payload_len(len);
+    }
+
+    let msghdr = entry.msghdr_mut();
+
+    let name = out.name_data();
+    let control = out.control_data();
+
+    debug_assert!(name.len() <= core::mem::size_of::<libc::sockaddr_in6>());
+    debug_assert!(control.len() <= cmsg::MAX_LEN);
+
+    // Safety: the kernel was told the name and control lengths of the messages in the ring so the
+    //         data always fits
+    unsafe {
+        core::ptr::copy_nonoverlapping(name.as_ptr(), msghdr.msg_name as *mut u8, name.len());
+        core::ptr::copy_nonoverlapping(
+            control.as_ptr(),
+            msghdr.msg_control as *mut u8,
+            control.len(),
+        );
+    }
+
+    msghdr.msg_namelen = name.len() as _;
+    msghdr.msg_controllen = control.len() as _;
+    msghdr.msg_flags = out.flags() as _;
+}
+
+/// A ring of buffers provided to the kernel for multishot receives
+struct BufRing {
+    entries: NonNull<types::BufRingEntry>,
+    layout: Layout,
+    data: Box<[u8]>,
+    buffer_len: usize,
+    len: u16,
+    tail: u16,
+}
+
+/// Safety: the buffer ring is only accessed through `&mut self`
+unsafe impl Send for BufRing {}
+/// Safety: the buffer ring is only accessed through `&mut self`
+unsafe impl Sync for BufRing {}
+
+impl BufRing {
+    fn new(len: u16, buffer_len: usize) -> Self {
+        debug_assert!(len.is_power_of_two());
+
+        // the ring needs to be page aligned
+        let layout = Layout::array::<types::BufRingEntry>(len as usize)
+            .and_then(|layout| layout.align_to(4096))
+            .expect("invalid buffer ring layout");
+
+        // Safety: the layout has a non-zero size and the entries are valid when zeroed
+        let entries = unsafe { std::alloc::alloc_zeroed(layout) } as *mut types::BufRingEntry;
+        let entries =
+            NonNull::new(entries).unwrap_or_else(|| std::alloc::handle_alloc_error(layout));
+
+        let data = vec![0u8; len as usize * buffer_len].into_boxed_slice();
+
+        let mut ring = Self {
+            entries,
+            layout,
+            data,
+            buffer_len,
+            len,
+            tail: 0,
+        };
+
+        for buffer_id in 0..len {
+            ring.recycle(buffer_id);
+        }
+        ring.publish();
+
+        ring
+    }
+
+    #[inline]
+    fn get(&self, buffer_id: u16, len: usize) -> &[u8] {
+        let start = buffer_id as usize * self.buffer_len;
+        let len = len.min(self.buffer_len);
+        &self.data[start..start + len]
+    }
+
+    /// Hands a buffer back to the kernel
+    ///
+    /// The buffer isn't visible to the kernel until [`Self::publish`] is called.
+    #[inline]
+    fn recycle(&mut self, buffer_id: u16) {
+        let index = self.tail & (self.len - 1);
+        let addr = self.data[buffer_id as usize * self.buffer_len..].as_mut_ptr();
+
+        // Safety: the index is masked by the ring length
+        let entry = unsafe { &mut *self.entries.as_ptr().add(index as usize) };
+        entry.set_addr(addr as u64);
+        entry.set_len(self.buffer_len as _);
+        entry.set_bid(buffer_id);
+
+        self.tail = self.tail.wrapping_add(1);
+    }
+
+    /// Publishes all of the recycled buffers to the kernel
+    #[inline]
+    fn publish(&self) {
+        // Safety: the tail overlaps the reserved field of the first entry and needs to be updated
+        //         atomically since the kernel concurrently reads it
+        unsafe {
+            let tail = types::BufRingEntry::tail(self.entries.as_ptr()) as *const AtomicU16;
+            (*tail).store(self.tail, Ordering::Release);
+        }
+    }
+}
+
+impl Drop for BufRing {
+    fn drop(&mut self) {
+        // Safety: the entries were allocated with `self.layout`
+        unsafe { std::alloc::dealloc(self.entries.as_ptr() as *mut u8, self.layout) }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Slot {
+    /// The message was submitted and is waiting for a result
+    Submitted { zero_copy: bool, fixed: bool },
+    /// The message was sent with zero copy and the kernel is still referencing the payload
+    Notify { result: i32 },
+    /// The message has completed with the given result
+    Complete { result: i32 },
+}
+
+struct Tx {
+    ring: AsyncFd<IoUring>,
+    socket: UdpSocket,
+    features: Features,
+    /// Set if the TX ring's memory was registered with the kernel
+    has_fixed_buffers: bool,
+    /// The sequence number of the first message passed to `send`
+    head: u64,
+    /// The state of each submitted message, starting at `head`
+    slots: VecDeque<Slot>,
+}
+
+impl Tx {
+    fn new(
+        socket: UdpSocket,
+        entries: u32,
+        features: Features,
+        (region, region_len): (*mut u8, usize),
+    ) -> io::Result<Self> {
+        // zero copy sends post two completions per message, which the default completion queue
+        // size of twice the submission queue can hold
+        let ring = IoUring::new(entries.next_power_of_two())?;
+
+        let mut has_fixed_buffers = false;
+        if features.is_fixed_buffers_enabled() {
+            let iovec = libc::iovec {
+                iov_base: region as *mut _,
+                iov_len: region_len,
+            };
+
+            // `SendMsgZc` leaves `buf_index` zeroed so the ring has to be the only registered
+            // buffer.
+            //
+            // Safety: the kernel pins the pages for as long as the buffer is registered so they
+            //         stay valid even if the ring is freed first
+            //
+            // Registration can fail if the memory lock limit is too low, in which case the payload
+            // pages are pinned for each send instead.
+            has_fixed_buffers = unsafe { ring.submitter().register_buffers(&[iovec]) }.is_ok();
+        }
+
+        let ring = AsyncFd::new(ring)?;
+
+        Ok(Self {
+            ring,
+            socket,
+            features,
+            has_fixed_buffers,
+            head: 0,
+            slots: VecDeque::with_capacity(entries as _),
+        })
+    }
+
+    #[inline]
+    fn sqe(
+        fd: RawFd,
+        msghdr: &libc::msghdr,
+        zero_copy: bool,
+        fixed: bool,
+        seq: u64,
+    ) -> squeue::Entry {
+        let fd = types::Fd(fd);
+        let sqe = if zero_copy {
+            // the payloads are all within the registered TX ring so they can be imported from
+            // the fixed buffer rather than being pinned for each message
+            let ioprio = if fixed { RECVSEND_FIXED_BUF } else { 0 };
+            opcode::SendMsgZc::new(fd, msghdr).ioprio(ioprio).build()
+        } else {
+            opcode::SendMsg::new(fd, msghdr).build()
+        };
+        sqe.user_data(seq)
+    }
+
+    /// Returns `true` if a zero copy message should be sent from the registered buffer
+    #[inline]
+    fn is_fixed(&self, zero_copy: bool) -> bool {
+        zero_copy && self.has_fixed_buffers && self.features.is_fixed_buffers_enabled()
+    }
+
+    /// Submits any messages that are not already in flight
+    #[inline]
+    fn submit<M: Message>(&mut self, entries: &mut [M]) -> io::Result<()> {
+        let start = self.slots.len();
+
+        if start >= entries.len() {
+            return Ok(());
+        }
+
+        let fd = self.socket.as_raw_fd();
+        let zero_copy = self.features.is_zero_copy_enabled();
+        let fixed = self.is_fixed(zero_copy);
+        let ring = self.ring.get_mut();
+
+        for (offset, entry) in entries.iter_mut().enumerate().skip(start) {
+            let seq = self.head + offset as u64;
+            let sqe = Self::sqe(fd, entry.msghdr_mut(), zero_copy, fixed, seq);
+
+            // Safety: the message isn't released back to the ring until the kernel is done with it
+            while unsafe { ring.submission().push(&sqe) }.is_err() {
+                // the submission queue is full so flush it to the kernel
+                match ring.submit() {
+                    Ok(_) => {}
+                    Err(error) if is_transient(&error) => return Ok(()),
+                    Err(error) => return Err(error),
+                }
+            }
+
+            self.slots.push_back(Slot::Submitted { zero_copy, fixed });
+        }
+
+        match ring.submit() {
+            Ok(_) => Ok(()),
+            // the entries are still in the submission queue so they will be submitted on the next
+            // call
+            Err(error) if is_transient(&error) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Processes the completion queue, resubmitting any messages that need to be retried
+    #[inline]
+    fn reap<M: Message>(&mut self, entries: &mut [M], stats: &stats::Sender) -> io::Result<()> {
+        let fd = self.socket.as_raw_fd();
+        let (submitter, mut submission, completion) = self.ring.get_mut().split();
+        let mut needs_submit = false;
+
+        for cqe in completion {
+            let Some(index) = cqe.user_data().checked_sub(self.head) else {
+                debug_assert!(false, "completion for a released message");
+                continue;
+            };
+            let index = index as usize;
+            let Some(slot) = self.slots.get_mut(index) else {
+                debug_assert!(false, "completion for an unknown message");
+                continue;
+            };
+
+            let flags = cqe.flags();
+            let result = cqe.result();
+
+            // the kernel is done referencing the payload of a zero copy send
+            if cqueue::notif(flags) {
+                if let Slot::Notify { result } = *slot {
+                    *slot = Slot::Complete { result };
+                }
+                continue;
+            }
+
+            let Slot::Submitted { zero_copy, fixed } = *slot else {
+                debug_assert!(false, "unexpected completion for {slot:?}");
+                continue;
+            };
+
+            let retry = match -result {
+                libc::EAGAIN | libc::EINTR => Some((zero_copy, fixed)),
+                error if error > 0 => {
+                    let error = io::Error::from_raw_os_error(error);
+                    if fixed && self.features.handle_fixed_buffer_error(&error) {
+                        Some((zero_copy, false))
+                    } else if zero_copy && self.features.handle_zero_copy_error(&error) {
+                        Some((false, false))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            };
+
+            if let Some((zero_copy, fixed)) = retry {
+                let msghdr = entries[index].msghdr_mut();
+                let sqe = Self::sqe(fd, msghdr, zero_copy, fixed, cqe.user_data());
+                // Safety: the message isn't released back to the ring until the kernel is done
+                //         with it
+                while unsafe { submission.push(&sqe) }.is_err() {
+                    submission.sync();
+                    submitter.submit()?;
+                    submission.sync();
+                }
+                needs_submit = true;
+                *slot = Slot::Submitted { zero_copy, fixed };
+                continue;
+            }
+
+            let res = if result < 0 {
+                Err(io::Error::from_raw_os_error(-result))
+            } else {
+                Ok(result)
+            };
+            stats.send().on_operation_result(&res, |_| 1);
+
+            // zero copy sends post a notification once the payload is no longer referenced
+            *slot = if cqueue::more(flags) {
+                Slot::Notify { result }
+            } else {
+                Slot::Complete { result }
+            };
+        }
+
+        if needs_submit {
+            submission.sync();
+            match submitter.submit() {
+                Ok(_) => {}
+                Err(error) if is_transient(&error) => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<M: Message> tx::Socket<M> for Tx {
+    type Error = io::Error;
+
+    #[inline]
+    fn send(
+        &mut self,
+        cx: &mut Context,
+        entries: &mut [M],
+        events: &mut tx::Events,
+        stats: &stats::Sender,
+    ) -> io::Result<()> {
+        self.submit(entries)?;
+        self.reap(entries, stats)?;
+
+        // release the prefix of messages that have completed
+        let mut count = 0;
+        while let Some(Slot::Complete { result }) = self.slots.front().copied() {
+            self.slots.pop_front();
+            count += 1;
+
+            // the transient errors are retried when reaping so everything here is counted as sent
+            let _ = if result < 0 {
+                events.on_error(io::Error::from_raw_os_error(-result))
+            } else {
+                events.on_complete(1)
+            };
+        }
+        self.head += count;
+
+        if count > 0 {
+            return Ok(());
+        }
+
+        // all of the messages are in flight so wait for completions
+        events.blocked();
+        stats.send().on_operation_pending();
+
+        if poll_completions(&mut self.ring, cx)? {
+            events.take_blocked();
+        }
+
+        Ok(())
+    }
+}
@@ -141,8 +141,9 @@ impl<const IS_SERVER: bool> Endpoint for TestEndpoint<IS_SERVER> {
 async fn runtime<A: ToSocketAddrs>(
     receive_addr: A,
     send_addr: Option<A>,
+    io_uring: bool,
 ) -> io::Result<(super::Io, SocketAddress)> {
-    let mut io_builder = Io::builder();
+    let mut io_builder = Io::builder().with_io_uring(io_uring)?;
 
     let rx_socket = syscall::bind_udp(receive_addr, false, false, false)?;
     rx_socket.set_nonblocking(true)?;
@@ -180,8 +181,25 @@ async fn test<A: ToSocketAddrs>(
     client_rx_addr: A,
     client_tx_addr: Option<A>,
 ) -> io::Result<()> {
-    let (server_io, server_addr) = runtime(server_rx_addr, server_tx_addr).await?;
-    let (client_io, client_addr) = runtime(client_rx_addr, client_tx_addr).await?;
+    test_with_io_uring(
+        false,
+        server_rx_addr,
+        server_tx_addr,
+        client_rx_addr,
+        client_tx_addr,
+    )
+    .await
+}
+
+async fn test_with_io_uring<A: ToSocketAddrs>(
+    io_uring: bool,
+    server_rx_addr: A,
+    server_tx_addr: Option<A>,
+    client_rx_addr: A,
+    client_tx_addr: Option<A>,
+) -> io::Result<()> {
+    let (server_io, server_addr) = runtime(server_rx_addr, server_tx_addr, io_uring).await?;
+    let (client_io, client_addr) = runtime(client_rx_addr, client_tx_addr, io_uring).await?;
 
     let server_endpoint = {
         let mut handle = PathHandle::from_remote_address(client_addr.into());
@@ -208,6 +226,64 @@ async fn test<A: ToSocketAddrs>(
     Ok(())
 }
 
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn recording_test() -> io::Result<()> {
+    use crate::io::record::{Direction, Recording};
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("server.rec");
+
+    let (server_io, server_addr) = runtime(IPV4_LOCALHOST, None, false).await?;
+    let server_io = Io {
+        builder: server_io.builder.with_recording(&path)?,
+    };
+    let (client_io, client_addr) = runtime(IPV4_LOCALHOST, None, false).await?;
+
+    let server_endpoint = {
+        let mut handle = PathHandle::from_remote_address(client_addr.into());
+        handle.local_address = server_addr.into();
+        TestEndpoint::<true>::new(handle)
+    };
+
+    let client_endpoint = {
+        let mut handle = PathHandle::from_remote_address(server_addr.into());
+        handle.local_address = client_addr.into();
+        TestEndpoint::<false>::new(handle)
+    };
+
+    let (server_task, _) = server_io.start(server_endpoint)?;
+    let (client_task, _) = client_io.start(client_endpoint)?;
+
+    tokio::time::timeout(core::time::Duration::from_secs(60), client_task).await??;
+
+    server_task.abort();
+    let _ = server_task.await;
+
+    let recording = Recording::open(&path)?;
+
+    let mut received = BTreeMap::new();
+    for datagram in recording.rx() {
+        assert_eq!(datagram.direction, Direction::Rx);
+        assert_eq!(datagram.remote_address, client_addr);
+        let id = u32::from_be_bytes(datagram.payload[..].try_into().unwrap());
+        received.insert(id, ());
+    }
+    // the client sent all of its messages to the server
+    assert_eq!(received.len(), 30);
+
+    // the server echoed at least one of them
+    assert!(recording.tx().count() > 0);
+
+    let mut prev = Duration::ZERO;
+    for datagram in recording.datagrams() {
+        assert!(prev <= datagram.time);
+        prev = datagram.time;
+    }
+
+    Ok(())
+}
+
 static IPV4_LOCALHOST: &str = "127.0.0.1:0";
 static IPV6_LOCALHOST: &str = "[::1]:0";
 
@@ -263,6 +339,42 @@ async fn ipv6_two_socket_test() -> io::Result<()> {
     }
 }
 
+#[cfg(all(feature = "io-uring", s2n_quic_platform_io_uring))]
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn ipv4_io_uring_test() -> io::Result<()> {
+    test_with_io_uring(true, IPV4_LOCALHOST, None, IPV4_LOCALHOST, None).await
+}
+
+#[cfg(all(feature = "io-uring", s2n_quic_platform_io_uring))]
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn ipv4_two_socket_io_uring_test() -> io::Result<()> {
+    test_with_io_uring(
+        true,
+        IPV4_LOCALHOST,
+        Some(IPV4_LOCALHOST),
+        IPV4_LOCALHOST,
+        Some(IPV4_LOCALHOST),
+    )
+    .await
+}
+
+#[cfg(all(feature = "io-uring", s2n_quic_platform_io_uring))]
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn ipv6_io_uring_test() -> io::Result<()> {
+    let result = test_with_io_uring(true, IPV6_LOCALHOST, None, IPV6_LOCALHOST, None).await;
+
+    match result {
+        Err(err) if err.kind() == io::ErrorKind::AddrNotAvailable => {
+            eprintln!("The current environment does not support IPv6; skipping");
+            Ok(())
+        }
+        other => other,
+    }
+}
+
 #[cfg(unix)]
 #[tokio::test]
 #[cfg_attr(miri, ignore)]
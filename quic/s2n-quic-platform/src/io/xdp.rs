@@ -10,34 +10,44 @@ pub use s2n_quic_core::{
     sync::{spsc, worker},
     xdp::path::Tuple as PathHandle,
 };
-pub use s2n_quic_xdp::*;
 use std::io::ErrorKind;
 
-// export the encoder configuration for writing packets
-pub mod encoder {
-    pub use s2n_quic_core::xdp::encoder::State as Config;
-}
-
-// export socket types and helpers
-pub mod socket {
-    pub use crate::socket::stats;
-    pub use s2n_quic_xdp::socket::*;
+type Result<T = (), E = std::io::Error> = core::result::Result<T, E>;
 
-    /// Binds a UDP socket to a particular interface and socket address
-    pub fn bind_udp(
-        interface: &::std::ffi::CStr,
-        addr: ::std::net::SocketAddr,
-    ) -> ::std::io::Result<::std::net::UdpSocket> {
-        let only_v6 = false;
-        let socket = crate::syscall::udp_socket(addr, only_v6)?;
-
-        // associate the socket with a single interface
-        crate::syscall::bind_to_interface(&socket, interface)?;
+/// Emits a log line if the `s2n_quic_platform_xdp_trace` cfg option is enabled. Otherwise, the
+/// trace is a no-op.
+macro_rules! trace {
+    ($($fmt:tt)*) => {{
+        if cfg!(s2n_quic_platform_xdp_trace) {
+            let args = format!($($fmt)*);
+            println!("{}:{}: {}", module_path!(), line!(), args);
+        }
+    }}
+}
 
-        socket.bind(&addr.into())?;
+/// Low-level bindings to various linux userspace APIs
+mod bindings;
+
+/// Default BPF programs to direct QUIC traffic
+pub mod bpf;
+/// Primitive types for AF-XDP kernel APIs
+pub mod if_xdp;
+/// Implementations of the IO traits from [`s2n_quic_core::io`]
+pub mod io;
+/// Helpers for creating mmap'd regions
+pub mod mmap;
+/// Structures for tracking ring cursors and synchronizing with the kernel
+pub mod ring;
+/// Structure for opening and reference counting an AF-XDP socket, along with UDP socket helpers
+pub mod socket;
+/// Helpers for making API calls to AF-XDP sockets
+pub mod syscall;
+/// A shared region of memory for holding frame (packet) data
+pub mod umem;
 
-        Ok(socket.into())
-    }
+// export the encoder configuration for writing packets
+pub mod encoder {
+    pub use s2n_quic_core::xdp::encoder::State as Config;
 }
 
 pub mod tx {
@@ -58,6 +68,9 @@ pub mod tx {
         let gso = crate::features::Gso::default();
         gso.disable();
 
+        // Initial packets aren't paced so the kernel doesn't need to hold them
+        let txtime = crate::features::TxTime::default();
+
         // compute the payload size for each message from the MaxMtu
         let payload_len = {
             let max_mtu: u16 = max_mtu.into();
@@ -81,7 +94,8 @@ pub mod tx {
 
         // spawn a task that actually flushes the ring buffer to the socket
         let cooldown = s2n_quic_core::task::cooldown::Cooldown::default();
-        let task = crate::io::tokio::task::tx(socket, consumer, gso.clone(), cooldown, stats);
+        let task =
+            crate::io::tokio::task::tx(socket, consumer, gso.clone(), txtime, cooldown, stats);
 
         // construct the TX side for the endpoint event loop
         let io = crate::socket::io::tx::Tx::new(producers, gso, max_mtu);
@@ -102,6 +116,16 @@ impl From<PathHandle> for crate::message::msg::Handle {
     }
 }
 
+impl From<crate::message::msg::Handle> for PathHandle {
+    #[inline]
+    fn from(handle: crate::message::msg::Handle) -> Self {
+        PathHandle {
+            remote_address: handle.remote_address.into(),
+            local_address: handle.local_address.into(),
+        }
+    }
+}
+
 impl From<&PathHandle> for crate::message::msg::Handle {
     #[inline]
     fn from(handle: &PathHandle) -> Self {
@@ -115,6 +139,7 @@ impl From<&PathHandle> for crate::message::msg::Handle {
 }
 
 mod builder;
+pub mod interface;
 pub use builder::Builder;
 
 pub struct Provider<Rx, Tx> {
@@ -123,6 +148,7 @@ pub struct Provider<Rx, Tx> {
     mtu_config_builder: mtu::Builder,
     stats: stats::Receiver,
     handle: Option<tokio::runtime::Handle>,
+    local_addr: SocketAddress,
 }
 
 impl Provider<(), ()> {
@@ -147,6 +173,7 @@ where
             mtu_config_builder,
             handle,
             stats,
+            local_addr,
         } = self;
 
         let mtu_config = mtu_config_builder
@@ -169,8 +196,6 @@ where
             stats,
         };
 
-        let local_addr = SocketAddress::default();
-
         // spawn the event loop on to the tokio handle
         let task = if let Some(handle) = handle {
             handle.spawn(event_loop.start(local_addr))
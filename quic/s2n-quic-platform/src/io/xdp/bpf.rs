@@ -8,13 +8,13 @@ pub static DEFAULT_PROGRAM: &[u8] = {
     #[cfg(target_endian = "little")]
     let prog = include_bytes_aligned!(concat!(
         env!("CARGO_MANIFEST_DIR"),
-        "/src/bpf/s2n-quic-xdp-bpfel.ebpf"
+        "/src/io/xdp/bpf/s2n-quic-xdp-bpfel.ebpf"
     ));
 
     #[cfg(target_endian = "big")]
     let prog = include_bytes_aligned!(concat!(
         env!("CARGO_MANIFEST_DIR"),
-        "/src/bpf/s2n-quic-xdp-bpfeb.ebpf"
+        "/src/io/xdp/bpf/s2n-quic-xdp-bpfeb.ebpf"
     ));
 
     prog
@@ -25,13 +25,13 @@ pub static DEFAULT_PROGRAM_TRACE: &[u8] = {
     #[cfg(target_endian = "little")]
     let prog = include_bytes_aligned!(concat!(
         env!("CARGO_MANIFEST_DIR"),
-        "/src/bpf/s2n-quic-xdp-bpfel-trace.ebpf"
+        "/src/io/xdp/bpf/s2n-quic-xdp-bpfel-trace.ebpf"
     ));
 
     #[cfg(target_endian = "big")]
     let prog = include_bytes_aligned!(concat!(
         env!("CARGO_MANIFEST_DIR"),
-        "/src/bpf/s2n-quic-xdp-bpfeb-trace.ebpf"
+        "/src/io/xdp/bpf/s2n-quic-xdp-bpfeb-trace.ebpf"
     ));
 
     prog
@@ -1,13 +1,13 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+use super::umem::DEFAULT_FRAME_SIZE;
 use crate::socket::stats;
 use core::mem::size_of;
 use s2n_quic_core::{
-    inet::{ethernet, ipv4, udp},
+    inet::{ethernet, ipv4, udp, SocketAddress},
     path::{mtu, MtuError},
 };
-use s2n_quic_xdp::umem::DEFAULT_FRAME_SIZE;
 use tokio::runtime::Handle;
 
 /// Calculate how much a packet will need for fixed-size headers
@@ -22,6 +22,7 @@ pub struct Builder<Rx = (), Tx = ()> {
     stats: Option<stats::Receiver>,
     mtu_config_builder: mtu::Builder,
     handle: Option<Handle>,
+    local_addr: SocketAddress,
 }
 
 impl Default for Builder<(), ()> {
@@ -34,6 +35,7 @@ impl Default for Builder<(), ()> {
                 .with_max_mtu(DEFAULT_FRAME_SIZE as u16 - MIN_FRAME_OVERHEAD)
                 .unwrap(),
             handle: None,
+            local_addr: SocketAddress::default(),
         }
     }
 }
@@ -50,6 +52,12 @@ impl<Rx, Tx> Builder<Rx, Tx> {
         self
     }
 
+    /// Sets the local address that is reported to the endpoint when the provider starts
+    pub fn with_local_address(mut self, local_addr: SocketAddress) -> Self {
+        self.local_addr = local_addr;
+        self
+    }
+
     /// Sets the UMEM frame size for the provider
     pub fn with_frame_size(mut self, frame_size: u16) -> Result<Self, MtuError> {
         self.mtu_config_builder = self
@@ -68,6 +76,7 @@ impl<Rx, Tx> Builder<Rx, Tx> {
             handle,
             stats,
             mtu_config_builder,
+            local_addr,
             ..
         } = self;
         Builder {
@@ -76,6 +85,7 @@ impl<Rx, Tx> Builder<Rx, Tx> {
             handle,
             stats,
             mtu_config_builder,
+            local_addr,
         }
     }
 
@@ -89,6 +99,7 @@ impl<Rx, Tx> Builder<Rx, Tx> {
             handle,
             stats,
             mtu_config_builder,
+            local_addr,
             ..
         } = self;
         Builder {
@@ -97,6 +108,7 @@ impl<Rx, Tx> Builder<Rx, Tx> {
             handle,
             stats,
             mtu_config_builder,
+            local_addr,
         }
     }
 }
@@ -113,6 +125,7 @@ where
             stats,
             handle,
             mtu_config_builder,
+            local_addr,
         } = self;
 
         let stats = stats.unwrap_or_else(|| {
@@ -126,6 +139,7 @@ where
             handle,
             stats,
             mtu_config_builder,
+            local_addr,
         }
     }
 }
@@ -3,7 +3,7 @@
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::Result;
+use crate::io::xdp::Result;
 use bitflags::bitflags;
 use core::mem::size_of;
 use std::{ffi::CStr, os::unix::io::AsRawFd};
@@ -84,7 +84,7 @@ impl Address {
     /// If the device does not exist, then an error is returned.
     #[inline]
     pub fn set_if_name(&mut self, name: &CStr) -> Result<&mut Self> {
-        self.ifindex = crate::syscall::if_nametoindex(name)?;
+        self.ifindex = crate::io::xdp::syscall::if_nametoindex(name)?;
         Ok(self)
     }
 
@@ -0,0 +1,466 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! An AF_XDP IO provider that is configured from a network interface name
+//!
+//! On start, the provider loads the default BPF program on to the interface, registers the bound
+//! UDP port in the port map and opens an AF_XDP socket on each of the interface's queues. If any of
+//! these steps fail, the provider falls back to the tokio provider on a standard UDP socket.
+
+use super::{bpf, encoder, if_xdp, io as xdp_io, ring, socket, syscall, tx, umem, PathHandle};
+use crate::{io::tokio as tokio_io, socket::stats};
+use aya::{
+    maps::{HashMap, XskMap},
+    programs, Ebpf,
+};
+use s2n_quic_core::{
+    endpoint::Endpoint,
+    event::{self, EndpointPublisher as _},
+    inet::SocketAddress,
+    io::tx::TxExt as _,
+    time::Clock as _,
+};
+use std::{ffi::CString, io, os::unix::io::AsRawFd, sync::Arc};
+use tokio::{io::unix::AsyncFd, runtime::Handle};
+use xdp_io::rx::Driver as _;
+
+/// The mode in which the BPF program is attached to the interface
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Automatically selects a mode based on the capabilities of the NIC
+    #[default]
+    Auto,
+    /// Uses the software SKB (socket buffer) mode, which usually requires no NIC support
+    Skb,
+    /// Integrates with XDP directly in the kernel driver
+    Driver,
+    /// Integrates with XDP directly in the NIC hardware
+    Hardware,
+}
+
+impl From<Mode> for programs::xdp::XdpFlags {
+    #[inline]
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Auto => Self::default(),
+            Mode::Skb => Self::SKB_MODE,
+            Mode::Driver => Self::DRV_MODE,
+            Mode::Hardware => Self::HW_MODE,
+        }
+    }
+}
+
+#[derive(Debug)]
+#[must_use = "Builders do nothing without calling `build`"]
+pub struct Builder {
+    handle: Option<Handle>,
+    interface: Option<String>,
+    recv_addr: Option<std::net::SocketAddr>,
+    frame_size: u32,
+    rx_queue_len: u32,
+    tx_queue_len: u32,
+    mode: Mode,
+    fallback: bool,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            handle: None,
+            interface: None,
+            recv_addr: None,
+            frame_size: umem::DEFAULT_FRAME_SIZE,
+            // Default values come from https://elixir.bootlin.com/linux/v6.3.9/source/tools/testing/selftests/bpf/xsk.h#L185
+            rx_queue_len: 2048,
+            tx_queue_len: 2048,
+            mode: Mode::Auto,
+            fallback: true,
+        }
+    }
+}
+
+impl Builder {
+    /// Sets the tokio runtime handle for the provider
+    pub fn with_handle(mut self, handle: Handle) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the name of the network interface the provider attaches to
+    pub fn with_interface(mut self, interface: &str) -> io::Result<Self> {
+        // the name needs to fit in `ifreq.ifr_name`, including the null terminator
+        if interface.is_empty() || interface.len() >= libc::IFNAMSIZ || interface.contains('\0') {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid interface name",
+            ));
+        }
+        self.interface = Some(interface.to_owned());
+        Ok(self)
+    }
+
+    /// Sets the local address for the provider to listen on
+    ///
+    /// If the port is `0`, the operating system selects an available port.
+    pub fn with_receive_address(mut self, addr: std::net::SocketAddr) -> io::Result<Self> {
+        self.recv_addr = Some(addr);
+        Ok(self)
+    }
+
+    /// Sets the size of each UMEM frame
+    ///
+    /// The size must be a power of two between 2048 and 4096 and limits the maximum MTU of the
+    /// endpoint.
+    pub fn with_frame_size(mut self, frame_size: u32) -> io::Result<Self> {
+        if !frame_size.is_power_of_two() || !(2048..=4096).contains(&frame_size) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frame size must be a power of two between 2048 and 4096",
+            ));
+        }
+        self.frame_size = frame_size;
+        Ok(self)
+    }
+
+    /// Sets the number of descriptors in the RX ring for each queue
+    pub fn with_rx_queue_len(mut self, len: u32) -> io::Result<Self> {
+        self.rx_queue_len = ring_len(len)?;
+        Ok(self)
+    }
+
+    /// Sets the number of descriptors in the TX ring for each queue
+    pub fn with_tx_queue_len(mut self, len: u32) -> io::Result<Self> {
+        self.tx_queue_len = ring_len(len)?;
+        Ok(self)
+    }
+
+    /// Sets the mode in which the BPF program is attached to the interface
+    pub fn with_mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Enables or disables falling back to the tokio provider if AF_XDP can't be configured
+    ///
+    /// The fallback is enabled by default. When disabled, starting the provider fails instead.
+    pub fn with_fallback(mut self, enabled: bool) -> Self {
+        self.fallback = enabled;
+        self
+    }
+
+    pub fn build(self) -> io::Result<Provider> {
+        if self.interface.is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing interface",
+            ));
+        }
+
+        if self.recv_addr.is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing bind address",
+            ));
+        }
+
+        Ok(Provider { builder: self })
+    }
+}
+
+/// Validates the length of a ring, which is required to be a power of two
+fn ring_len(len: u32) -> io::Result<u32> {
+    if len.is_power_of_two() {
+        Ok(len)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "queue length must be a power of two",
+        ))
+    }
+}
+
+#[derive(Debug)]
+pub struct Provider {
+    builder: Builder,
+}
+
+impl Provider {
+    /// Creates a builder to construct an interface-based XDP provider
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    pub fn start<E: Endpoint<PathHandle = PathHandle>>(
+        self,
+        mut endpoint: E,
+    ) -> io::Result<(tokio::task::JoinHandle<()>, SocketAddress)> {
+        let builder = self.builder;
+        let interface = builder.interface.clone().expect("checked in build");
+        let recv_addr = builder.recv_addr.expect("checked in build");
+
+        // try to use the tokio runtime handle if provided, otherwise try to use the implicit tokio
+        // runtime in the current scope of the application.
+        let handle = if let Some(handle) = builder.handle.clone() {
+            handle
+        } else {
+            Handle::try_current().map_err(io::Error::other)?
+        };
+
+        let guard = handle.enter();
+
+        // The UDP socket reserves the port and resolves it if one wasn't specified. It's also used
+        // to send packets to peers with unknown addresses and by the fallback provider.
+        let udp_socket = socket::bind_udp(&CString::new(interface.as_str())?, recv_addr)?;
+        let local_addr = udp_socket.local_addr()?;
+
+        let result = match Xdp::open(&builder, &interface, local_addr.port()) {
+            Ok(xdp) => {
+                publish_xdp_configured(&mut endpoint, None);
+                xdp.start(endpoint, &builder, &handle, udp_socket, local_addr)
+            }
+            Err(error) if builder.fallback => {
+                publish_xdp_configured(&mut endpoint, Some(&error));
+
+                let mut io = tokio_io::Io::builder().with_rx_socket(udp_socket)?;
+                if let Some(handle) = builder.handle {
+                    io = io.with_handle(handle);
+                }
+
+                io.build()?.start_with_handle_map(
+                    endpoint,
+                    |handle| handle.into(),
+                    |handle: &PathHandle| handle.into(),
+                )
+            }
+            Err(error) => Err(error),
+        };
+
+        drop(guard);
+
+        result
+    }
+}
+
+/// Publishes the AF_XDP configuration along with the error that caused a fallback, if any
+fn publish_xdp_configured<E: Endpoint>(endpoint: &mut E, error: Option<&io::Error>) {
+    let clock = tokio_io::Clock::default();
+
+    let mut publisher = event::EndpointPublisherSubscriber::new(
+        event::builder::EndpointMeta {
+            endpoint_type: E::ENDPOINT_TYPE,
+            timestamp: clock.get_time(),
+        },
+        None,
+        endpoint.subscriber(),
+    );
+
+    if let Some(error) = error {
+        let reason = error.to_string();
+        publisher.on_platform_xdp_fallback(event::builder::PlatformXdpFallback {
+            reason: &reason,
+            errno: error.raw_os_error(),
+        });
+    }
+
+    publisher.on_platform_feature_configured(event::builder::PlatformFeatureConfigured {
+        configuration: event::builder::PlatformFeatureConfiguration::Xdp {
+            enabled: error.is_none(),
+        },
+    });
+}
+
+type RxDriver = xdp_io::rx::WithCooldown<Arc<AsyncFd<socket::Fd>>>;
+
+/// The AF_XDP resources for an interface
+struct Xdp {
+    umem: umem::Umem,
+    rx: Vec<xdp_io::rx::Channel<RxDriver>>,
+    tx: Vec<xdp_io::tx::Channel<xdp_io::tx::BusyPoll>>,
+    bpf: Ebpf,
+}
+
+impl Xdp {
+    fn open(builder: &Builder, interface: &str, port: u16) -> io::Result<Self> {
+        let frame_size = builder.frame_size;
+        let rx_queue_len = builder.rx_queue_len;
+        let tx_queue_len = builder.tx_queue_len;
+        let fill_ring_len = rx_queue_len * 2;
+        let completion_ring_len = tx_queue_len;
+
+        let max_queues = syscall::max_queues(interface);
+        let frame_count = (rx_queue_len + tx_queue_len)
+            .checked_mul(max_queues)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "UMEM is too large"))?;
+
+        let umem = umem::Builder {
+            frame_count,
+            frame_size,
+            ..Default::default()
+        }
+        .build()?;
+
+        let mut address = if_xdp::Address {
+            flags: if_xdp::XdpFlags::USE_NEED_WAKEUP,
+            ..Default::default()
+        };
+        address.set_if_name(&CString::new(interface)?)?;
+
+        let mut shared_umem_fd = None;
+        let mut rx = vec![];
+        let mut tx = vec![];
+        let mut rx_fds = vec![];
+
+        let mut desc = umem.frames();
+
+        // open a socket for each of the queues on the interface
+        for queue_id in 0..max_queues {
+            let socket = socket::Fd::open()?;
+
+            // all of the sockets share the UMEM attached to the first one
+            if let Some(fd) = shared_umem_fd {
+                address.set_shared_umem(&fd);
+            } else {
+                socket.attach_umem(&umem)?;
+                shared_umem_fd = Some(socket.as_raw_fd());
+            }
+
+            address.queue_id = queue_id;
+
+            // file descriptors can only be registered once so wrap it in an Arc
+            let async_fd = Arc::new(AsyncFd::new(socket.clone())?);
+
+            let offsets = syscall::offsets(&socket)?;
+
+            {
+                let mut fill = ring::Fill::new(socket.clone(), &offsets, fill_ring_len)?;
+                let ring = ring::Rx::new(socket.clone(), &offsets, rx_queue_len)?;
+
+                // give the kernel frames to receive packets into
+                fill.init((&mut desc).take(rx_queue_len as _));
+
+                rx.push(xdp_io::rx::Channel {
+                    rx: ring,
+                    fill,
+                    driver: async_fd.with_cooldown(tokio_io::cooldown("RX")),
+                });
+            }
+
+            {
+                let mut completion =
+                    ring::Completion::new(socket.clone(), &offsets, completion_ring_len)?;
+                let ring = ring::Tx::new(socket.clone(), &offsets, tx_queue_len)?;
+
+                // the completion ring holds the frames that are free for transmission
+                completion.init((&mut desc).take(tx_queue_len as _));
+
+                tx.push(xdp_io::tx::Channel {
+                    tx: ring,
+                    completion,
+                    driver: xdp_io::tx::BusyPoll,
+                });
+            }
+
+            syscall::bind(&socket, &mut address)?;
+
+            rx_fds.push((queue_id, socket));
+        }
+
+        debug_assert_eq!(desc.count(), 0, "descriptors have been leaked");
+
+        let bpf = load_bpf(interface, builder.mode, port, &rx_fds)?;
+
+        Ok(Self { umem, rx, tx, bpf })
+    }
+
+    fn start<E: Endpoint<PathHandle = PathHandle>>(
+        self,
+        endpoint: E,
+        builder: &Builder,
+        handle: &Handle,
+        udp_socket: std::net::UdpSocket,
+        local_addr: std::net::SocketAddr,
+    ) -> io::Result<(tokio::task::JoinHandle<()>, SocketAddress)> {
+        let Self { umem, rx, tx, bpf } = self;
+
+        let (stats_sender, stats_recv) = stats::channel();
+
+        let rx = xdp_io::rx::Rx::new(rx, umem.clone());
+
+        let tx = {
+            let tx = xdp_io::tx::Tx::new(tx, umem, encoder::Config::default());
+
+            let (udp_tx, udp_task) = tx::channel(udp_socket, stats_sender);
+            handle.spawn(udp_task);
+
+            // send packets to peers with unresolved addresses over the UDP socket so the operating
+            // system can resolve them
+            let udp_tx = udp_tx.with_handle_map(|handle: &PathHandle| handle.into());
+            tx.with_router(xdp_io::router::Router::default(), udp_tx)
+        };
+
+        let (task, local_addr) = super::Provider::builder()
+            .with_rx(rx)
+            .with_tx(tx)
+            .with_frame_size(builder.frame_size as _)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, format!("{err}")))?
+            .with_stats(stats_recv)
+            .with_handle(handle.clone())
+            .with_local_address(local_addr.into())
+            .build()
+            .start(endpoint)?;
+
+        // keep the BPF program attached to the interface while the endpoint is running
+        let task = handle.spawn(async move {
+            let _ = task.await;
+            drop(bpf);
+        });
+
+        Ok((task, local_addr))
+    }
+}
+
+/// Attaches the default BPF program to the interface and directs the port to the sockets
+fn load_bpf(
+    interface: &str,
+    mode: Mode,
+    port: u16,
+    rx_fds: &[(u32, socket::Fd)],
+) -> io::Result<Ebpf> {
+    let mut bpf = Ebpf::load(bpf::DEFAULT_PROGRAM).map_err(io::Error::other)?;
+
+    let program: &mut programs::Xdp = bpf
+        .program_mut(bpf::PROGRAM_NAME)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "missing BPF program"))?
+        .try_into()
+        .map_err(io::Error::other)?;
+    program.load().map_err(io::Error::other)?;
+    program
+        .attach(interface, mode.into())
+        .map_err(io::Error::other)?;
+
+    let mut ports: HashMap<_, u16, u8> = bpf
+        .map_mut(bpf::PORT_MAP_NAME)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "missing port map"))?
+        .try_into()
+        .map_err(io::Error::other)?;
+
+    // the BPF program only needs a non-zero value for the port
+    ports.insert(port, 1, 0).map_err(io::Error::other)?;
+
+    let mut sockets: XskMap<_> = bpf
+        .map_mut(bpf::XSK_MAP_NAME)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "missing socket map"))?
+        .try_into()
+        .map_err(io::Error::other)?;
+
+    for (queue_id, socket) in rx_fds {
+        sockets
+            .set(*queue_id, socket.as_raw_fd(), 0)
+            .map_err(io::Error::other)?;
+    }
+
+    Ok(bpf)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,253 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use core::task::{Context, Poll};
+use s2n_quic_core::{
+    endpoint::{self, CloseError},
+    inet::ExplicitCongestionNotification,
+    io::rx,
+    path::mtu,
+    time::{Clock, Timestamp},
+};
+use std::{net::UdpSocket, time::Duration};
+
+/// An endpoint that echoes every datagram back to its sender
+#[derive(Default)]
+struct EchoEndpoint {
+    pending: Vec<(PathHandle, Vec<u8>)>,
+    subscriber: Subscriber,
+}
+
+/// Records the reason the provider fell back to UDP sockets
+#[derive(Clone, Debug, Default)]
+struct Subscriber {
+    fallback_reason: Arc<std::sync::Mutex<Option<String>>>,
+}
+
+impl event::Subscriber for Subscriber {
+    type ConnectionContext = ();
+
+    fn create_connection_context(
+        &mut self,
+        _meta: &event::api::ConnectionMeta,
+        _info: &event::api::ConnectionInfo,
+    ) -> Self::ConnectionContext {
+    }
+
+    fn on_platform_xdp_fallback(
+        &mut self,
+        _meta: &event::api::EndpointMeta,
+        event: &event::api::PlatformXdpFallback,
+    ) {
+        *self.fallback_reason.lock().unwrap() = Some(event.reason.to_string());
+    }
+}
+
+impl Endpoint for EchoEndpoint {
+    type PathHandle = PathHandle;
+    type Subscriber = Subscriber;
+
+    const ENDPOINT_TYPE: endpoint::Type = endpoint::Type::Server;
+
+    fn transmit<Tx: tx::Queue<Handle = PathHandle>, C: Clock>(
+        &mut self,
+        queue: &mut Tx,
+        _clock: &C,
+    ) {
+        while let Some((handle, payload)) = self.pending.pop() {
+            let msg = (
+                handle,
+                ExplicitCongestionNotification::default(),
+                &payload[..],
+            );
+            if queue.push(msg).is_err() {
+                // no more capacity
+                self.pending.push((handle, payload));
+                return;
+            }
+        }
+    }
+
+    fn receive<Rx: rx::Queue<Handle = PathHandle>, C: Clock>(
+        &mut self,
+        queue: &mut Rx,
+        _clock: &C,
+    ) {
+        queue.for_each(|header, payload| {
+            self.pending.push((header.path, payload.to_vec()));
+        });
+    }
+
+    fn poll_wakeups<C: Clock>(
+        &mut self,
+        _cx: &mut Context<'_>,
+        _clock: &C,
+    ) -> Poll<Result<usize, CloseError>> {
+        Poll::Pending
+    }
+
+    fn timeout(&self) -> Option<Timestamp> {
+        None
+    }
+
+    fn set_mtu_config(&mut self, _mtu_config: mtu::Config) {
+        // noop
+    }
+
+    fn subscriber(&mut self) -> &mut Self::Subscriber {
+        &mut self.subscriber
+    }
+}
+
+/// Sends datagrams to the server and waits for each of them to be echoed back
+fn echo_client(server_addr: std::net::SocketAddr) -> io::Result<()> {
+    let socket = UdpSocket::bind(("0.0.0.0", 0))?;
+    socket.set_read_timeout(Some(Duration::from_millis(100)))?;
+
+    'messages: for id in 0u32..10 {
+        let payload = id.to_be_bytes();
+
+        // the first datagrams can be lost while the interface is being configured so retry
+        for _ in 0..50 {
+            socket.send_to(&payload, server_addr)?;
+
+            let mut buffer = [0u8; 16];
+            while let Ok((len, addr)) = socket.recv_from(&mut buffer) {
+                if addr == server_addr && buffer[..len] == payload {
+                    continue 'messages;
+                }
+            }
+        }
+
+        return Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("message {id} was not echoed"),
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn builder_test() {
+    assert!(Provider::builder().build().is_err());
+    assert!(Provider::builder()
+        .with_interface("lo")
+        .unwrap()
+        .build()
+        .is_err());
+
+    for interface in ["", "an-interface-name-that-is-too-long", "l\0o"] {
+        assert!(Provider::builder().with_interface(interface).is_err());
+    }
+
+    for frame_size in [0, 1024, 3000, 8192] {
+        assert!(Provider::builder().with_frame_size(frame_size).is_err());
+    }
+
+    for len in [0, 1000] {
+        assert!(Provider::builder().with_rx_queue_len(len).is_err());
+        assert!(Provider::builder().with_tx_queue_len(len).is_err());
+    }
+
+    assert!(Provider::builder()
+        .with_interface("lo")
+        .unwrap()
+        .with_receive_address("127.0.0.1:0".parse().unwrap())
+        .unwrap()
+        .with_frame_size(2048)
+        .unwrap()
+        .with_rx_queue_len(1024)
+        .unwrap()
+        .with_tx_queue_len(1024)
+        .unwrap()
+        .build()
+        .is_ok());
+}
+
+fn loopback_provider(fallback: bool) -> Provider {
+    Provider::builder()
+        .with_interface("lo")
+        .unwrap()
+        .with_receive_address("127.0.0.1:0".parse().unwrap())
+        .unwrap()
+        // the loopback device can't offload XDP programs to hardware so opening AF_XDP always fails
+        .with_mode(Mode::Hardware)
+        .with_fallback(fallback)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn fallback_test() -> io::Result<()> {
+    let endpoint = EchoEndpoint::default();
+    let fallback_reason = endpoint.subscriber.fallback_reason.clone();
+
+    let (task, addr) = loopback_provider(true).start(endpoint)?;
+    let addr: std::net::SocketAddr = addr.into();
+    assert_ne!(addr.port(), 0);
+
+    // the reason AF_XDP wasn't used should be reported
+    let fallback_reason = fallback_reason.lock().unwrap().take();
+    assert!(fallback_reason.is_some_and(|reason| !reason.is_empty()));
+
+    tokio::task::spawn_blocking(move || echo_client(addr)).await??;
+
+    task.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn fallback_disabled_test() {
+    assert!(loopback_provider(false)
+        .start(EchoEndpoint::default())
+        .is_err());
+}
+
+/// Echoes datagrams over AF_XDP on one end of a veth pair
+///
+/// The test requires `CAP_NET_ADMIN`, `CAP_NET_RAW` and `CAP_BPF` along with a veth pair that
+/// has each end in a different network namespace. `tools/xdp/veth-test.sh` sets up the
+/// namespaces and runs the test inside of them.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(miri, ignore)]
+async fn veth_test() -> io::Result<()> {
+    let (Ok(interface), Ok(address), Ok(peer_netns)) = (
+        std::env::var("S2N_QUIC_XDP_VETH_INTERFACE"),
+        std::env::var("S2N_QUIC_XDP_VETH_ADDRESS"),
+        std::env::var("S2N_QUIC_XDP_VETH_PEER_NETNS"),
+    ) else {
+        eprintln!("WARNING: veth pair is not configured; skipping `veth_test`");
+        return Ok(());
+    };
+
+    let address: std::net::IpAddr = address.parse().map_err(io::Error::other)?;
+
+    let (task, addr) = Provider::builder()
+        .with_interface(&interface)?
+        .with_receive_address((address, 0).into())?
+        .with_fallback(false)
+        .build()?
+        .start(EchoEndpoint::default())?;
+    let addr: std::net::SocketAddr = addr.into();
+
+    // run the client from the other end of the pair
+    let client = std::thread::spawn(move || {
+        let netns = std::fs::File::open(format!("/run/netns/{peer_netns}"))?;
+        // Safety: the file descriptor is valid for the duration of the call
+        if unsafe { libc::setns(netns.as_raw_fd(), libc::CLONE_NEWNET) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        echo_client(addr)
+    });
+
+    tokio::task::spawn_blocking(move || client.join().expect("client panicked")).await??;
+
+    task.abort();
+
+    Ok(())
+}
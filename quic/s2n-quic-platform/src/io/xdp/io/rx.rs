@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{if_xdp::RxTxDescriptor, ring, umem::Umem};
+use crate::io::xdp::{if_xdp::RxTxDescriptor, ring, umem::Umem};
 use core::task::{Context, Poll};
 use s2n_codec::DecoderBufferMut;
 use s2n_quic_core::{
@@ -14,7 +14,6 @@ use s2n_quic_core::{
     xdp::{decoder, path},
 };
 
-#[cfg(feature = "tokio")]
 mod tokio_impl;
 
 /// An interface to handle any errors that happen on the RX IO provider
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::Driver;
-use crate::{ring, socket, syscall};
+use crate::io::xdp::{ring, socket, syscall};
 use core::task::{Context, Poll};
 
 type Fd = tokio::io::unix::AsyncFd<socket::Fd>;
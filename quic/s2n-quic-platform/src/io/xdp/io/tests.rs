@@ -5,7 +5,7 @@ use super::{
     rx::{self, Rx},
     tx::{self, Tx},
 };
-use crate::{if_xdp::RingFlags, ring, umem::Umem};
+use crate::io::xdp::{if_xdp::RingFlags, ring, umem::Umem};
 use core::{mem::size_of, time::Duration};
 use rand::Rng;
 use s2n_quic_core::{
@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{ring, syscall, umem::Umem};
+use crate::io::xdp::{ring, syscall, umem::Umem};
 use core::task::{Context, Poll};
 use s2n_codec::{Encoder as _, EncoderBuffer};
 use s2n_quic_core::{
@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{
+use crate::io::xdp::{
     if_xdp::{MmapOffsets, RingFlags, RingOffsetV2, RxTxDescriptor, UmemDescriptor},
     mmap::{self, Mmap},
     socket, syscall,
@@ -270,7 +270,7 @@ macro_rules! impl_consumer {
         }
 
         #[cfg(test)]
-        pub fn set_flags(&mut self, flags: crate::if_xdp::RingFlags) {
+        pub fn set_flags(&mut self, flags: crate::io::xdp::if_xdp::RingFlags) {
             self.0.set_flags(flags);
         }
     };
@@ -362,7 +362,7 @@ impl Completion {
 #[cfg(test)]
 pub mod testing {
     use super::*;
-    use crate::{if_xdp, socket::Fd};
+    use crate::io::xdp::{if_xdp, socket::Fd};
 
     fn offsets() -> if_xdp::RingOffsetV2 {
         if_xdp::RingOffsetV2 {
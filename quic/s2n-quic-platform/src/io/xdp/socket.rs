@@ -1,7 +1,8 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{syscall, Result};
+use crate::io::xdp::{syscall, Result};
+pub use crate::socket::stats;
 use core::fmt;
 use std::{
     os::unix::io::{AsRawFd, RawFd},
@@ -29,7 +30,7 @@ impl Fd {
         Ok(Self(fd))
     }
 
-    pub fn attach_umem(&self, umem: &crate::umem::Umem) -> Result<()> {
+    pub fn attach_umem(&self, umem: &crate::io::xdp::umem::Umem) -> Result<()> {
         umem.attach(self)?;
         // TODO store the umem
         Ok(())
@@ -61,3 +62,19 @@ impl Drop for Inner {
         }
     }
 }
+
+/// Binds a UDP socket to a particular interface and socket address
+pub fn bind_udp(
+    interface: &::std::ffi::CStr,
+    addr: ::std::net::SocketAddr,
+) -> ::std::io::Result<::std::net::UdpSocket> {
+    let only_v6 = false;
+    let socket = crate::syscall::udp_socket(addr, only_v6)?;
+
+    // associate the socket with a single interface
+    crate::syscall::bind_to_interface(&socket, interface)?;
+
+    socket.bind(&addr.into())?;
+
+    Ok(socket.into())
+}
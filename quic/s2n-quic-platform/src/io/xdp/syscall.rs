@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{
+use crate::io::xdp::{
     if_xdp::{Address, MmapOffsets, RingOffsetV1, SocketOptions, Statistics, UmemReg},
     Result,
 };
@@ -285,10 +285,10 @@ fn ethtool_queues(ifname: &str) -> Option<u32> {
 
     let fd = unsafe { libc!(socket(libc::AF_LOCAL, libc::SOCK_DGRAM, 0)).ok()? };
     // close the FD on drop
-    let fd = crate::socket::Fd::from_raw(fd);
+    let fd = crate::io::xdp::socket::Fd::from_raw(fd);
 
-    let mut channels = unsafe { core::mem::zeroed::<crate::bindings::ethtool_channels>() };
-    channels.cmd = crate::bindings::ETHTOOL_GCHANNELS;
+    let mut channels = unsafe { core::mem::zeroed::<crate::io::xdp::bindings::ethtool_channels>() };
+    channels.cmd = crate::io::xdp::bindings::ETHTOOL_GCHANNELS;
 
     let mut ifreq = unsafe { core::mem::zeroed::<libc::ifreq>() };
     ifreq.ifr_ifru.ifru_data = (&mut channels) as *mut _ as *mut _;
@@ -377,7 +377,7 @@ fn set_xdp_option<Fd: AsRawFd, T: Sized>(fd: &Fd, opt: SocketOptions, value: &T)
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::mmap::Mmap;
+    use crate::io::xdp::mmap::Mmap;
     use core::ffi::CStr;
 
     #[test]
@@ -407,7 +407,7 @@ mod tests {
             return;
         };
         // close the FD on drop
-        let _owned_fd = crate::socket::Fd::from_raw(fd);
+        let _owned_fd = crate::io::xdp::socket::Fd::from_raw(fd);
 
         // the ring sizes need to be a power of 2
         let ring_size = 32u32;
@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{
+use crate::io::xdp::{
     if_xdp::{RxTxDescriptor, UmemDescriptor, UmemFlags, UmemReg},
     mmap::{self, Mmap},
     syscall, Result,
@@ -50,6 +50,12 @@ impl Storage {
         self.ptr.as_ptr()
     }
 
+    /// Returns the size of the allocation in bytes
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.layout.size()
+    }
+
     /// Asserts that the pointer is in bounds of the allocation
     #[inline]
     pub fn check_bounds<T: Sized>(&self, ptr: *mut T) {
@@ -80,6 +86,7 @@ pub trait Message: 'static + Copy {
     const SUPPORTS_GSO: bool;
     const SUPPORTS_ECN: bool;
     const SUPPORTS_FLOW_LABELS: bool;
+    const SUPPORTS_TXTIME: bool;
 
     /// Allocates `entries` messages, each with `payload_len` bytes
     fn alloc(entries: u32, payload_len: u32, offset: usize) -> Storage;
@@ -115,6 +122,13 @@ pub trait Message: 'static + Copy {
         panic!("cannot use GSO on the current platform");
     }
 
+    /// Sets the time at which the kernel should transmit the message
+    ///
+    /// The departure time is the `CLOCK_MONOTONIC` time in nanoseconds.
+    fn set_departure_time(&mut self, _departure_time: crate::features::txtime::Cmsg) {
+        panic!("cannot use SO_TXTIME on the current platform");
+    }
+
     /// Resets the message for future use
     ///
     /// # Safety
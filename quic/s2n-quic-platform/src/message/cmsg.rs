@@ -52,7 +52,10 @@ pub const MAX_LEN: usize = {
     // TODO remove this once we split the `reset` traits into TX and RX types
     let padding = size_of::<cmsghdr>();
 
-    tos_size + segment_offload_size + pktinfo_size + padding
+    let rxq_ovfl_size = features::rxq_ovfl::CMSG_SPACE;
+    let txtime_size = features::txtime::CMSG_SPACE;
+
+    tos_size + segment_offload_size + pktinfo_size + rxq_ovfl_size + txtime_size + padding
 };
 
 #[cfg(test)]
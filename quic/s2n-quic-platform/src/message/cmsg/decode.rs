@@ -88,6 +88,9 @@ unsafe fn collect_item(data: &mut AncillaryData, cmsg: &cmsghdr, value: &[u8]) {
         (level, ty) if features::gso::is_match(level, ty) => {
             // ignore GSO settings when reading
         }
+        (level, ty) if features::rxq_ovfl::is_match(level, ty) => {
+            // the drop counter is reported to the socket stats when the message is received
+        }
         (level, ty) if features::gro::is_match(level, ty) => {
             if let Some(segment_size) = value_from_bytes::<features::gro::Cmsg>(value) {
                 data.segment_size = segment_size as _;
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::features;
-use s2n_quic_core::inet::{ExplicitCongestionNotification, SocketAddress};
+use s2n_quic_core::inet::{Dscp, ExplicitCongestionNotification, SocketAddress};
 
 #[derive(Clone, Copy, Debug)]
 pub struct Error;
@@ -26,8 +26,21 @@ pub trait Encoder {
         ecn: ExplicitCongestionNotification,
         remote_address: &SocketAddress,
     ) -> Result<usize, Error> {
+        self.encode_traffic_class(ecn, Dscp::DEFAULT, remote_address)
+    }
+
+    /// Encodes ECN and DSCP markings into the cmsg encoder
+    #[inline]
+    fn encode_traffic_class(
+        &mut self,
+        ecn: ExplicitCongestionNotification,
+        dscp: Dscp,
+        remote_address: &SocketAddress,
+    ) -> Result<usize, Error> {
+        let traffic_class = dscp.traffic_class(ecn);
+
         // no need to encode for the default case
-        if ecn == ExplicitCongestionNotification::NotEct {
+        if traffic_class == 0 {
             return Ok(0);
         }
 
@@ -35,12 +48,12 @@ pub trait Encoder {
         match remote_address.unmap() {
             SocketAddress::IpV4(_) => {
                 if let (Some(level), Some(ty)) = (features::tos_v4::LEVEL, features::tos_v4::TYPE) {
-                    return self.encode_cmsg(level, ty, ecn as u8 as features::tos_v4::Cmsg);
+                    return self.encode_cmsg(level, ty, traffic_class as features::tos_v4::Cmsg);
                 }
             }
             SocketAddress::IpV6(_) => {
                 if let (Some(level), Some(ty)) = (features::tos_v6::LEVEL, features::tos_v6::TYPE) {
-                    return self.encode_cmsg(level, ty, ecn as u8 as features::tos_v6::Cmsg);
+                    return self.encode_cmsg(level, ty, traffic_class as features::tos_v6::Cmsg);
                 }
             }
         }
@@ -59,6 +72,18 @@ pub trait Encoder {
         }
     }
 
+    /// Encodes the departure time of a message into the cmsg encoder
+    ///
+    /// The departure time is the `CLOCK_MONOTONIC` time in nanoseconds.
+    #[inline]
+    fn encode_txtime(&mut self, departure_time: features::txtime::Cmsg) -> Result<usize, Error> {
+        if let (Some(level), Some(ty)) = (features::txtime::LEVEL, features::txtime::TYPE) {
+            self.encode_cmsg(level, ty, departure_time)
+        } else {
+            panic!("platform does not support SO_TXTIME");
+        }
+    }
+
     #[inline]
     fn encode_local_address(&mut self, address: &SocketAddress) -> Result<usize, Error> {
         use s2n_quic_core::inet::Unspecified;
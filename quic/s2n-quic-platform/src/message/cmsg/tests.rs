@@ -121,3 +121,91 @@ type Ops = s2n_quic_core::testing::InlineVec<Op, 8>;
 fn round_trip_test() {
     check!().with_type::<Ops>().for_each(|ops| round_trip(ops));
 }
+
+/// Ensures the DSCP markings are combined with the ECN markings in the TOS control message
+#[test]
+fn traffic_class_test() {
+    use s2n_quic_core::inet::{
+        Dscp, ExplicitCongestionNotification, IpV4Address, IpV6Address, SocketAddress,
+    };
+
+    let v4: SocketAddress = IpV4Address::new([127, 0, 0, 1]).with_port(443).into();
+    let v6: SocketAddress = IpV6Address::new([0; 16]).with_port(443).into();
+
+    for (remote_address, level, ty) in [
+        (v4, features::tos_v4::LEVEL, features::tos_v4::TYPE),
+        (v6, features::tos_v6::LEVEL, features::tos_v6::TYPE),
+    ] {
+        let (Some(level), Some(ty)) = (level, ty) else {
+            continue;
+        };
+
+        // nothing is written when neither marking is set
+        let mut storage = Storage::<32>::default();
+        let mut encoder = storage.encoder();
+        encoder
+            .encode_traffic_class(Default::default(), Dscp::DEFAULT, &remote_address)
+            .unwrap();
+        assert!(encoder.iter().next().is_none());
+
+        let mut storage = Storage::<32>::default();
+        let mut encoder = storage.encoder();
+        encoder
+            .encode_traffic_class(
+                ExplicitCongestionNotification::Ect0,
+                Dscp::EF,
+                &remote_address,
+            )
+            .unwrap();
+
+        let (cmsghdr, value) = encoder.iter().next().unwrap();
+        assert_eq!(cmsghdr.cmsg_level, level);
+        assert_eq!(cmsghdr.cmsg_type, ty);
+
+        let value = match value.len() {
+            1 => value[0],
+            4 => u32::from_ne_bytes(value.try_into().unwrap()) as u8,
+            len => panic!("unexpected value length {len}"),
+        };
+        assert_eq!(value, (46 << 2) | 0b10);
+    }
+}
+
+/// Ensures departure times from the endpoint's clock are encoded relative to the monotonic clock
+#[test]
+#[cfg(s2n_quic_platform_txtime)]
+fn txtime_test() {
+    use core::time::Duration;
+    use s2n_quic_core::time::clock::testing as time;
+
+    let before = features::txtime::monotonic_now();
+    let start = time::now();
+    let txtime = features::TxTime::new(true).with_clock(&start);
+
+    // the departure time only depends on the endpoint's timestamp and not when it's mapped
+    let departure_time = txtime
+        .departure_time(start + Duration::from_millis(1))
+        .unwrap();
+    std::thread::sleep(Duration::from_millis(2));
+    assert_eq!(
+        txtime.departure_time(start + Duration::from_millis(1)),
+        Some(departure_time)
+    );
+
+    let mut storage = Storage::<32>::default();
+    let mut encoder = storage.encoder();
+    encoder.encode_txtime(departure_time).unwrap();
+
+    let (cmsghdr, value) = encoder.iter().next().unwrap();
+    assert!(features::txtime::is_match(
+        cmsghdr.cmsg_level,
+        cmsghdr.cmsg_type
+    ));
+
+    let value = unsafe { decode::value_from_bytes::<features::txtime::Cmsg>(value).unwrap() };
+    assert_eq!(value, departure_time);
+    assert!(value as u128 >= (before + Duration::from_millis(1)).as_nanos());
+    assert!(
+        value as u128 <= (features::txtime::monotonic_now() + Duration::from_millis(1)).as_nanos()
+    );
+}
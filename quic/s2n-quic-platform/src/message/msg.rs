@@ -34,6 +34,7 @@ impl MessageTrait for msghdr {
     const SUPPORTS_GSO: bool = features::gso::IS_SUPPORTED;
     const SUPPORTS_ECN: bool = features::tos::IS_SUPPORTED;
     const SUPPORTS_FLOW_LABELS: bool = true;
+    const SUPPORTS_TXTIME: bool = features::txtime::IS_SUPPORTED;
 
     #[inline]
     fn alloc(entries: u32, payload_len: u32, offset: usize) -> super::Storage {
@@ -61,6 +62,11 @@ impl MessageTrait for msghdr {
         self.cmsg_encoder().encode_gso(size as _).unwrap();
     }
 
+    #[inline]
+    fn set_departure_time(&mut self, departure_time: features::txtime::Cmsg) {
+        self.cmsg_encoder().encode_txtime(departure_time).unwrap();
+    }
+
     #[inline]
     unsafe fn reset(&mut self, mtu: usize) {
         // reset the payload
@@ -191,7 +197,7 @@ impl MessageTrait for msghdr {
         let handle = *message.path_handle();
         handle.update_msg_hdr(self);
         self.cmsg_encoder()
-            .encode_ecn(message.ecn(), &handle.remote_address.0)
+            .encode_traffic_class(message.ecn(), message.dscp(), &handle.remote_address.0)
             .unwrap();
 
         Ok(len)
@@ -41,6 +41,7 @@ impl MessageTrait for Message {
     const SUPPORTS_GSO: bool = false;
     const SUPPORTS_ECN: bool = false;
     const SUPPORTS_FLOW_LABELS: bool = false;
+    const SUPPORTS_TXTIME: bool = false;
 
     #[inline]
     fn alloc(entries: u32, payload_len: u32, offset: usize) -> super::Storage {
@@ -1,11 +1,18 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{features::Gso, message::Message, socket::ring::Producer};
-use core::task::{Context, Poll};
+use crate::{
+    features::{self, Gso, TxTime},
+    message::Message,
+    socket::ring::Producer,
+};
+use core::{
+    task::{Context, Poll},
+    time::Duration,
+};
 use s2n_quic_core::{
     event,
-    inet::ExplicitCongestionNotification,
+    inet::{Dscp, ExplicitCongestionNotification},
     io::tx,
     path::{Handle as _, MaxMtu},
     task::waker,
@@ -15,6 +22,7 @@ use s2n_quic_core::{
 pub struct Tx<T: Message> {
     channels: Vec<Producer<T>>,
     gso: Gso,
+    txtime: TxTime,
     max_mtu: usize,
     is_full: bool,
 }
@@ -25,10 +33,18 @@ impl<T: Message> Tx<T> {
         Self {
             channels,
             gso,
+            txtime: TxTime::default(),
             max_mtu: max_mtu.into(),
             is_full: true,
         }
     }
+
+    /// Offloads pacing of messages to the socket tasks, if enabled
+    #[inline]
+    pub fn with_txtime(mut self, txtime: TxTime) -> Self {
+        self.txtime = txtime;
+        self
+    }
 }
 
 impl<T: Message> tx::Tx for Tx<T> {
@@ -123,6 +139,13 @@ impl<T: Message> tx::Tx for Tx<T> {
         //       so we do need to query it each iteration.
         let max_segments = this.gso.max_segments();
 
+        // NOTE: like GSO, pacing offload can be disabled by the TX task at any point
+        let pacing_horizon = if T::SUPPORTS_TXTIME {
+            this.txtime.pacing_horizon()
+        } else {
+            Duration::ZERO
+        };
+
         let mut queue = TxQueue {
             channels: &mut this.channels,
             channel_index,
@@ -130,6 +153,8 @@ impl<T: Message> tx::Tx for Tx<T> {
             pending_release: 0,
             gso_segment: None,
             max_segments,
+            pacing_horizon,
+            txtime: &this.txtime,
             max_mtu: this.max_mtu,
             capacity,
             is_full: &mut this.is_full,
@@ -159,6 +184,14 @@ pub struct GsoSegment<Handle> {
     /// This is used to determine if future messages should be included in this payload or need a
     /// separate packet.
     ecn: ExplicitCongestionNotification,
+    /// The value of the DSCP markings for the current GSO segment being written.
+    ///
+    /// Like the ECN markings, these apply to the entire payload.
+    dscp: Dscp,
+    /// The departure time of the current GSO segment being written.
+    ///
+    /// The kernel transmits all of the segments at the same departure time.
+    departure_time: Option<features::txtime::Cmsg>,
     /// The number of segments that have been written
     count: usize,
     /// The size of each segment.
@@ -187,6 +220,10 @@ pub struct TxQueue<'a, T: Message> {
     gso_segment: Option<GsoSegment<T::Handle>>,
     /// The maximum number of GSO segments that can be written
     max_segments: usize,
+    /// How far ahead of their departure time messages can be written
+    pacing_horizon: Duration,
+    /// Maps the departure times of messages to the kernel's clock
+    txtime: &'a TxTime,
     /// The maximum MTU for any given packet
     max_mtu: usize,
     /// The maximum number of packets that can be sent in the current iteration
@@ -197,6 +234,18 @@ pub struct TxQueue<'a, T: Message> {
 }
 
 impl<T: Message> TxQueue<'_, T> {
+    /// Returns the departure time of the message on the kernel's clock, if it should be delayed
+    #[inline]
+    fn departure_time<M: tx::Message>(&self, message: &mut M) -> Option<features::txtime::Cmsg> {
+        // messages are only written ahead of their departure time if pacing is offloaded
+        if self.pacing_horizon.is_zero() {
+            return None;
+        }
+
+        let departure_time = message.departure_time()?;
+        self.txtime.departure_time(departure_time)
+    }
+
     /// Tries to send a message as a GSO segment
     ///
     /// Returns the Err(Message) if it was not able to. Otherwise, the index of the GSO'd message is returned.
@@ -211,6 +260,7 @@ impl<T: Message> TxQueue<'_, T> {
         }
 
         let max_segments = self.max_segments;
+        let departure_time = self.departure_time(&mut message);
 
         let (prev_message, gso) = if let Some(gso) = self.gso_message() {
             gso
@@ -227,7 +277,9 @@ impl<T: Message> TxQueue<'_, T> {
         // GSO payload as the previous message
         let can_gso = message.can_gso(gso.size, gso.count)
             && message.path_handle().strict_eq(&gso.handle)
-            && message.ecn() == gso.ecn;
+            && message.ecn() == gso.ecn
+            && message.dscp() == gso.dscp
+            && departure_time == gso.departure_time;
 
         // if we can't use GSO then flush the current message
         if !can_gso {
@@ -386,6 +438,7 @@ impl<T: Message> tx::Queue for TxQueue<'_, T> {
     type Handle = T::Handle;
 
     const SUPPORTS_ECN: bool = T::SUPPORTS_ECN;
+    const SUPPORTS_PACING: bool = T::SUPPORTS_TXTIME;
     const SUPPORTS_FLOW_LABELS: bool = T::SUPPORTS_FLOW_LABELS;
 
     #[inline]
@@ -399,6 +452,9 @@ impl<T: Message> tx::Queue for TxQueue<'_, T> {
             Err(message) => message,
         };
 
+        // query the departure time before borrowing the entry
+        let departure_time = self.departure_time(&mut message);
+
         // find the next free entry, if any
         let entry = loop {
             let channel = self
@@ -424,17 +480,25 @@ impl<T: Message> tx::Queue for TxQueue<'_, T> {
         // query the values that we use for GSO before we write the message to the entry
         let handle = *message.path_handle();
         let ecn = message.ecn();
+        let dscp = message.dscp();
         let can_gso = message.can_gso(self.max_mtu, 0);
 
         // write the message to the entry
         let payload_len = entry.tx_write(message)?;
 
+        // the kernel holds the message until the departure time if pacing offload is enabled
+        if let Some(departure_time) = departure_time {
+            entry.set_departure_time(departure_time);
+        }
+
         // if GSO is supported and we are allowed to have additional segments, store the GSO state
         // for another potential message to be written later
         if T::SUPPORTS_GSO && self.max_segments > 1 && can_gso {
             self.gso_segment = Some(GsoSegment {
                 handle,
                 ecn,
+                dscp,
+                departure_time,
                 count: 1,
                 size: payload_len,
             });
@@ -458,6 +522,11 @@ impl<T: Message> tx::Queue for TxQueue<'_, T> {
         self.flush_gso();
     }
 
+    #[inline]
+    fn pacing_horizon(&self) -> Duration {
+        self.pacing_horizon
+    }
+
     #[inline]
     fn capacity(&self) -> usize {
         self.capacity
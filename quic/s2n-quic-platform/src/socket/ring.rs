@@ -196,6 +196,23 @@ impl<T: Message> Consumer<T> {
     pub fn is_open(&self) -> bool {
         self.wakers.is_open()
     }
+
+    /// Returns the number of messages in the ring
+    #[inline]
+    #[allow(dead_code)] // this is only used by some socket implementations
+    pub fn capacity(&self) -> u32 {
+        self.cursor.capacity()
+    }
+
+    /// Returns the start and length of the memory holding the messages and their payloads
+    ///
+    /// The region can be registered with the kernel so it doesn't need to be mapped for each
+    /// operation. It stays valid for as long as either half of the ring is alive.
+    #[inline]
+    #[allow(dead_code)] // this is only used by some socket implementations
+    pub fn region(&self) -> (*mut u8, usize) {
+        (self.storage.as_ptr(), self.storage.size())
+    }
 }
 
 /// A producer ring for messages
@@ -353,6 +370,13 @@ impl<T: Message> Producer<T> {
         self.wakers.is_open()
     }
 
+    /// Returns the number of messages in the ring
+    #[inline]
+    #[allow(dead_code)] // this is only used by some socket implementations
+    pub fn capacity(&self) -> u32 {
+        self.cursor.capacity()
+    }
+
     /// Replicates messages from the primary to secondary memory regions
     #[inline]
     unsafe fn replicate(&self, primary: *mut T, secondary: *mut T, len: usize) {
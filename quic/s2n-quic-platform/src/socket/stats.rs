@@ -1,10 +1,12 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::syscall;
 use core::{
     fmt,
-    sync::atomic::{AtomicU64, Ordering},
+    sync::atomic::{AtomicU32, AtomicU64, Ordering},
     task::Poll,
+    time::Duration,
 };
 use s2n_quic_core::{
     event::{self, EndpointPublisher},
@@ -15,9 +17,12 @@ use std::{
     ffi::c_int,
     io,
     sync::{Arc, Mutex},
+    time::Instant,
 };
 
 const ERROR_QUEUE_CAP: usize = 256;
+/// How often the memory usage of the socket is sampled
+const SOCKET_MEMORY_INTERVAL: Duration = Duration::from_secs(1);
 type Error = c_int;
 
 pub fn channel() -> (Sender, Receiver) {
@@ -28,6 +33,7 @@ pub fn channel() -> (Sender, Receiver) {
     let recv = Receiver {
         state,
         pending_errors: VecDeque::with_capacity(ERROR_QUEUE_CAP),
+        socket_memory: None,
     };
 
     (sender, recv)
@@ -52,11 +58,54 @@ impl Sender {
     pub fn recv(&self) -> &Stats {
         &self.0.recv
     }
+
+    /// Records the receive queue overflow counter reported by the kernel
+    ///
+    /// The counter is the total number of datagrams dropped on the socket so only the increase
+    /// since the last observed value is reported. Since multiple tasks can read from the same
+    /// socket, older values of the counter are ignored.
+    #[inline]
+    pub fn on_rx_queue_overflow(&self, counter: u32) {
+        let res =
+            self.0
+                .rx_queue_counter
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |prev| {
+                    // the kernel counter is allowed to wrap so compare the distance between the two
+                    let dropped = counter.wrapping_sub(prev);
+                    (dropped > 0 && dropped <= u32::MAX / 2).then_some(counter)
+                });
+
+        if let Ok(prev) = res {
+            let dropped = counter.wrapping_sub(prev);
+            self.0
+                .rx_queue_dropped
+                .fetch_add(dropped as _, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+impl Sender {
+    /// Takes the number of dropped datagrams that haven't been published yet
+    pub(crate) fn rx_queue_dropped(&self) -> u64 {
+        self.0.rx_queue_dropped.swap(0, Ordering::Relaxed)
+    }
 }
 
 pub struct Receiver {
     state: Arc<State>,
     pending_errors: VecDeque<Error>,
+    socket_memory: Option<SocketMemory>,
+}
+
+impl Receiver {
+    /// Periodically publishes the memory usage of the given socket
+    pub fn sample_socket_memory(&mut self, socket: socket2::Socket) {
+        self.socket_memory = Some(SocketMemory {
+            socket,
+            next_sample: Instant::now(),
+        });
+    }
 }
 
 impl fmt::Debug for Receiver {
@@ -84,6 +133,17 @@ impl event_loop::Stats for Receiver {
             },
             |publisher, metrics| publisher.on_platform_rx(metrics.into()),
         );
+
+        let dropped = self.state.rx_queue_dropped.swap(0, Ordering::Relaxed);
+        let dropped = dropped.try_into().unwrap_or(usize::MAX);
+        if dropped > 0 {
+            publisher
+                .on_platform_rx_queue_overflow(event::builder::PlatformRxQueueOverflow { dropped });
+        }
+
+        if let Some(socket_memory) = self.socket_memory.as_mut() {
+            socket_memory.publish(publisher);
+        }
     }
 }
 
@@ -91,6 +151,36 @@ impl event_loop::Stats for Receiver {
 struct State {
     send: Stats,
     recv: Stats,
+    /// The most recent value of the kernel's receive queue drop counter
+    rx_queue_counter: AtomicU32,
+    /// The number of datagrams dropped by the kernel that haven't been published yet
+    rx_queue_dropped: AtomicU64,
+}
+
+struct SocketMemory {
+    socket: socket2::Socket,
+    next_sample: Instant,
+}
+
+impl SocketMemory {
+    #[inline]
+    fn publish<P: EndpointPublisher>(&mut self, publisher: &mut P) {
+        let now = Instant::now();
+        if now < self.next_sample {
+            return;
+        }
+        self.next_sample = now + SOCKET_MEMORY_INTERVAL;
+
+        if let Some(info) = syscall::socket_meminfo(&self.socket) {
+            publisher.on_platform_socket_memory(event::builder::PlatformSocketMemory {
+                rx_allocated: info.rx_allocated as _,
+                rx_capacity: info.rx_capacity as _,
+                tx_allocated: info.tx_allocated as _,
+                tx_capacity: info.tx_capacity as _,
+                dropped: info.dropped as _,
+            });
+        }
+    }
 }
 
 pub struct Stats {
@@ -282,3 +372,43 @@ impl From<Metrics> for event::builder::PlatformTx {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rx_queue_overflow_test() {
+        let (sender, _recv) = channel();
+
+        sender.on_rx_queue_overflow(0);
+        assert_eq!(sender.rx_queue_dropped(), 0);
+
+        sender.on_rx_queue_overflow(3);
+        assert_eq!(sender.rx_queue_dropped(), 3);
+
+        // clones share the same socket so the counter shouldn't be counted twice
+        let clone = sender.clone();
+        clone.on_rx_queue_overflow(3);
+        assert_eq!(sender.rx_queue_dropped(), 0);
+        clone.on_rx_queue_overflow(5);
+        assert_eq!(sender.rx_queue_dropped(), 2);
+
+        // stale values from another task are ignored
+        sender.on_rx_queue_overflow(4);
+        assert_eq!(sender.rx_queue_dropped(), 0);
+    }
+
+    #[test]
+    fn rx_queue_overflow_wrapping_test() {
+        let (sender, _recv) = channel();
+
+        let step = u32::MAX / 4;
+        let mut counter = 0u32;
+        for _ in 0..8 {
+            counter = counter.wrapping_add(step);
+            sender.on_rx_queue_overflow(counter);
+            assert_eq!(sender.rx_queue_dropped(), step as u64);
+        }
+    }
+}
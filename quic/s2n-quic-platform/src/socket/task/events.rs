@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::features::Gso;
+use crate::features::{Gso, TxTime};
 use core::ops::ControlFlow;
 
 #[derive(Debug)]
@@ -9,6 +9,7 @@ pub struct TxEvents {
     count: usize,
     is_blocked: bool,
     gso: Gso,
+    txtime: TxTime,
 }
 
 impl TxEvents {
@@ -18,9 +19,17 @@ impl TxEvents {
             count: 0,
             is_blocked: false,
             gso,
+            txtime: TxTime::default(),
         }
     }
 
+    /// Sets the pacing offload settings to notify of socket errors
+    #[inline]
+    pub fn with_txtime(mut self, txtime: TxTime) -> Self {
+        self.txtime = txtime;
+        self
+    }
+
     /// Returns if the task is blocked
     #[inline]
     pub fn is_blocked(&self) -> bool {
@@ -73,6 +82,8 @@ impl crate::syscall::SocketEvents for TxEvents {
                 // let the GSO settings know there was an error on the socket, which may indicate
                 // it needs to be disabled
                 let _ = self.gso.handle_socket_error(&error);
+                // the same goes for pacing offload, in case the kernel rejected the departure time
+                let _ = self.txtime.handle_socket_error(&error);
 
                 // ignore all other errors and just consider the packet sent
                 self.count += 1;
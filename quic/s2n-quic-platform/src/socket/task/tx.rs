@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    features::Gso,
+    features::{Gso, TxTime},
     message::Message,
     socket::{ring::Consumer, stats, task::events},
 };
@@ -62,6 +62,13 @@ where
         }
     }
 
+    /// Disables pacing offload if the socket rejects the departure times of messages
+    #[inline]
+    pub fn with_txtime(mut self, txtime: TxTime) -> Self {
+        self.events = self.events.with_txtime(txtime);
+        self
+    }
+
     #[inline]
     fn poll_ring(&mut self, watermark: u32, cx: &mut Context) -> Poll<Result<(), ()>> {
         loop {
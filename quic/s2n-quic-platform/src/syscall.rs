@@ -208,6 +208,132 @@ pub fn configure_tos(rx_socket: &Socket) -> bool {
     success
 }
 
+/// Configures the socket to report the number of datagrams the kernel dropped because the receive
+/// buffer was full
+pub fn configure_rxq_ovfl(rx_socket: &Socket) -> bool {
+    let mut success = false;
+
+    #[cfg(unix)]
+    if let Some((level, ty)) = crate::features::rxq_ovfl::SOCKOPT {
+        use std::os::unix::io::AsRawFd;
+        let enabled: libc::c_int = 1;
+
+        success |= libc!(setsockopt(
+            rx_socket.as_raw_fd(),
+            level as _,
+            ty as _,
+            &enabled as *const _ as _,
+            core::mem::size_of_val(&enabled) as _
+        ))
+        .is_ok();
+    }
+
+    success
+}
+
+/// Configures the socket to hold messages until the departure time attached to them
+///
+/// The departure times are relative to `CLOCK_MONOTONIC`, which is what the fq qdisc requires.
+pub fn configure_txtime(tx_socket: &Socket) -> bool {
+    #[cfg(s2n_quic_platform_txtime)]
+    if let Some((level, ty)) = crate::features::txtime::SOCKOPT {
+        use std::os::unix::io::AsRawFd;
+        let config = libc::sock_txtime {
+            clockid: libc::CLOCK_MONOTONIC,
+            flags: 0,
+        };
+
+        return libc!(setsockopt(
+            tx_socket.as_raw_fd(),
+            level as _,
+            ty as _,
+            &config as *const _ as _,
+            core::mem::size_of_val(&config) as _
+        ))
+        .is_ok();
+    }
+
+    let _ = tx_socket;
+    false
+}
+
+/// Reports the receive queue overflow counter attached to a received message
+///
+/// The kernel attaches the total number of datagrams dropped on the socket to each message, so
+/// only the most recently received message needs to be inspected.
+#[cfg(s2n_quic_platform_cmsg)]
+#[inline]
+pub fn on_rx_queue_overflow(msghdr: &libc::msghdr, stats: &stats::Sender) {
+    use crate::{features::rxq_ovfl, message::cmsg};
+
+    if !rxq_ovfl::IS_SUPPORTED {
+        return;
+    }
+
+    let iter = unsafe {
+        // SAFETY: the msghdr was filled in by the kernel
+        cmsg::decode::Iter::from_msghdr(msghdr)
+    };
+
+    for (cmsghdr, value) in iter {
+        if rxq_ovfl::is_match(cmsghdr.cmsg_level, cmsghdr.cmsg_type) {
+            if let Some(counter) = rxq_ovfl::decode(value) {
+                stats.on_rx_queue_overflow(counter);
+            }
+            return;
+        }
+    }
+}
+
+/// The memory usage of a socket, as reported by `SO_MEMINFO`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MemInfo {
+    pub rx_allocated: u32,
+    pub rx_capacity: u32,
+    pub tx_allocated: u32,
+    pub tx_capacity: u32,
+    pub dropped: u32,
+}
+
+/// Queries the memory usage of the socket
+#[cfg(s2n_quic_platform_meminfo)]
+pub fn socket_meminfo(socket: &Socket) -> Option<MemInfo> {
+    use std::os::unix::io::AsRawFd;
+
+    // SK_MEMINFO_VARS isn't exported by libc but newer kernels may append values so leave some
+    // extra room
+    let mut values = [0u32; 16];
+    let mut len = core::mem::size_of_val(&values) as libc::socklen_t;
+
+    libc!(getsockopt(
+        socket.as_raw_fd(),
+        libc::SOL_SOCKET,
+        libc::SO_MEMINFO,
+        values.as_mut_ptr() as *mut _,
+        &mut len,
+    ))
+    .ok()?;
+
+    let get = |idx: libc::c_int| values.get(idx as usize).copied().unwrap_or(0);
+
+    Some(MemInfo {
+        rx_allocated: get(libc::SK_MEMINFO_RMEM_ALLOC),
+        rx_capacity: get(libc::SK_MEMINFO_RCVBUF),
+        tx_allocated: get(libc::SK_MEMINFO_WMEM_ALLOC),
+        tx_capacity: get(libc::SK_MEMINFO_SNDBUF),
+        dropped: get(libc::SK_MEMINFO_DROPS),
+    })
+}
+
+/// Queries the memory usage of the socket
+///
+/// Always returns `None` since the platform doesn't support `SO_MEMINFO`.
+#[cfg(not(s2n_quic_platform_meminfo))]
+pub fn socket_meminfo(socket: &Socket) -> Option<MemInfo> {
+    let _ = socket;
+    None
+}
+
 /// Configures the socket to return local address and interface information as part of the
 /// ancillary data
 pub fn configure_pktinfo(rx_socket: &Socket) -> bool {
@@ -265,3 +391,134 @@ pub fn configure_gro(rx_socket: &Socket) -> bool {
 
     success
 }
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg(all(s2n_quic_platform_rxq_ovfl, s2n_quic_platform_meminfo))]
+    fn rx_queue_overflow_test() {
+        use crate::features::rxq_ovfl;
+
+        let rx_socket = bind_udp("127.0.0.1:0", false, false, false).unwrap();
+        rx_socket.set_recv_buffer_size(1).unwrap();
+        assert!(configure_rxq_ovfl(&rx_socket));
+
+        let addr = rx_socket.local_addr().unwrap().as_socket().unwrap();
+        let tx_socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+
+        // overflow the receive buffer
+        for _ in 0..64 {
+            tx_socket.send_to(&[0; 1200], addr).unwrap();
+        }
+
+        let meminfo = socket_meminfo(&rx_socket).unwrap();
+        assert!(meminfo.rx_capacity > 0);
+        assert!(meminfo.dropped > 0);
+
+        let mut payload = [0u8; 1500];
+        // use a u64 array to align the buffer to cmsghdr
+        let mut control = [0u64; 8];
+        assert!(core::mem::size_of_val(&control) >= rxq_ovfl::CMSG_SPACE);
+
+        let mut recv = || {
+            use std::os::unix::io::AsRawFd;
+
+            let mut iov = libc::iovec {
+                iov_base: payload.as_mut_ptr() as _,
+                iov_len: payload.len(),
+            };
+            let mut msghdr: libc::msghdr = unsafe { core::mem::zeroed() };
+            msghdr.msg_iov = &mut iov;
+            msghdr.msg_iovlen = 1;
+            msghdr.msg_control = control.as_mut_ptr() as _;
+            msghdr.msg_controllen = core::mem::size_of_val(&control) as _;
+
+            libc!(recvmsg(
+                rx_socket.as_raw_fd(),
+                &mut msghdr,
+                libc::MSG_DONTWAIT
+            ))
+            .ok()
+            .map(|_| msghdr)
+        };
+
+        // the kernel records the drop counter when the datagram is queued so drain the datagrams
+        // that made it in before the buffer overflowed
+        while recv().is_some() {}
+
+        tx_socket.send_to(&[0; 1200], addr).unwrap();
+        let msghdr = recv().unwrap();
+
+        let (sender, _recv) = stats::channel();
+        on_rx_queue_overflow(&msghdr, &sender);
+        assert_eq!(sender.rx_queue_dropped(), meminfo.dropped as u64);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg(s2n_quic_platform_txtime)]
+    fn txtime_test() {
+        use crate::{
+            features::TxTime,
+            message::cmsg::{self, Encoder as _},
+        };
+        use core::time::Duration;
+        use std::os::unix::io::AsRawFd;
+
+        let rx_socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        rx_socket
+            .set_read_timeout(Some(Duration::from_secs(1)))
+            .unwrap();
+        let addr: socket2::SockAddr = rx_socket.local_addr().unwrap().into();
+
+        let send = |tx_socket: &Socket| {
+            let mut payload = [1u8; 32];
+            let mut iov = libc::iovec {
+                iov_base: payload.as_mut_ptr() as _,
+                iov_len: payload.len(),
+            };
+
+            let mut control = cmsg::Storage::<{ cmsg::MAX_LEN }>::default();
+            let mut encoder = control.encoder();
+            let departure_time =
+                crate::features::txtime::monotonic_now() + Duration::from_micros(10);
+            encoder
+                .encode_txtime(departure_time.as_nanos() as _)
+                .unwrap();
+            let control_len = encoder.len();
+
+            let mut msghdr: libc::msghdr = unsafe { core::mem::zeroed() };
+            msghdr.msg_name = addr.as_ptr() as *mut _;
+            msghdr.msg_namelen = addr.len();
+            msghdr.msg_iov = &mut iov;
+            msghdr.msg_iovlen = 1;
+            msghdr.msg_control = control.as_mut_ptr() as _;
+            msghdr.msg_controllen = control_len as _;
+
+            libc!(sendmsg(tx_socket.as_raw_fd(), &msghdr, 0))
+        };
+
+        // the departure time is accepted once the socket is configured
+        let tx_socket = bind_udp("127.0.0.1:0", false, false, false).unwrap();
+        assert!(configure_txtime(&tx_socket));
+        send(&tx_socket).unwrap();
+        let mut payload = [0u8; 64];
+        assert_eq!(rx_socket.recv(&mut payload).unwrap(), 32);
+
+        // otherwise the kernel rejects it and the offload is disabled
+        let tx_socket = bind_udp("127.0.0.1:0", false, false, false).unwrap();
+        let error = send(&tx_socket).unwrap_err();
+        let txtime = TxTime::new(true);
+        assert_eq!(
+            txtime.pacing_horizon(),
+            crate::features::txtime::PACING_HORIZON
+        );
+        assert_eq!(txtime.handle_socket_error(&error), Some(true));
+        assert!(!txtime.is_enabled());
+        assert_eq!(txtime.pacing_horizon(), Duration::ZERO);
+    }
+}
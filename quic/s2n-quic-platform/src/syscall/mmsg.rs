@@ -162,6 +162,17 @@ pub fn recv<Sock: AsRawFd, E: SocketEvents>(
 
     stats.recv().on_operation_result(&res, |count| *count as _);
 
+    // the drop counter is cumulative so only the last message needs to be checked
+    #[cfg(s2n_quic_platform_cmsg)]
+    if let Ok(count) = res {
+        if let Some(packet) = (count as usize)
+            .checked_sub(1)
+            .and_then(|idx| packets.get(idx))
+        {
+            super::on_rx_queue_overflow(&packet.msg_hdr, stats);
+        }
+    }
+
     let _ = match res {
         Ok(count) => events.on_complete(count as _),
         Err(error) => events.on_error(error),
@@ -172,6 +172,10 @@ pub fn recv<'a, Sock: AsRawFd, P: IntoIterator<Item = &'a mut msghdr>, E: Socket
                 unsafe {
                     msg.set_payload_len(payload_len.min(u16::MAX as _).max(0) as _);
                 }
+
+                #[cfg(s2n_quic_platform_cmsg)]
+                super::on_rx_queue_overflow(msg, stats);
+
                 events.on_complete(1)
             }
             Err(err) => events.on_error(err),
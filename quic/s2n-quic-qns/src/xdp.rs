@@ -201,7 +201,7 @@ impl Xdp {
     }
 
     fn bpf_task(&self, port: u16, rx_fds: Vec<(u32, socket::Fd)>) -> Result<()> {
-        // load the default BPF program from s2n-quic-xdp
+        // load the default BPF program that ships with s2n-quic-platform
         let mut bpf = if self.bpf_trace {
             let mut bpf = Ebpf::load(bpf::DEFAULT_PROGRAM_TRACE)?;
 
@@ -1,12 +1,17 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{certificate, cipher_suite::default_crypto_provider, session::Session, Error};
+use crate::{
+    certificate, cipher_suite::default_crypto_provider, raw_public_key, session::Session, Error,
+};
 use core::convert::TryFrom;
 use rustls::{ClientConfig, ConfigBuilder, WantsVerifier};
 use s2n_codec::EncoderValue;
 use s2n_quic_core::{application::ServerName, crypto::tls};
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
+
+/// The maximum number of configurations derived from per-connection options which are cached
+const MAX_DERIVED_CONFIGS: usize = 64;
 
 /// Create a QUIC client specific [rustls::ConfigBuilder].
 ///
@@ -20,6 +25,13 @@ fn default_config_builder() -> Result<ConfigBuilder<ClientConfig, WantsVerifier>
 #[derive(Clone)]
 pub struct Client {
     config: Arc<ClientConfig>,
+    /// Configurations derived from per-connection options
+    ///
+    /// Connections tend to reuse the same options, so each distinct set is only applied once
+    /// and the resulting config is shared between the connections.
+    derived_configs: HashMap<tls::ClientOptions, Arc<ClientConfig>>,
+    resumption: bool,
+    raw_public_keys: bool,
 }
 
 impl Client {
@@ -32,14 +44,29 @@ impl Client {
     /// - QUIC compliant ciphersuites
     #[deprecated = "client and server builders should be used instead"]
     pub fn new(config: ClientConfig) -> Self {
-        Self {
-            config: Arc::new(config),
-        }
+        Self::from(Arc::new(config))
     }
 
     pub fn builder() -> Builder {
         Builder::new()
     }
+
+    /// Applies the per-connection `options` to the client's config
+    fn derive_config(&self, options: &tls::ClientOptions) -> Result<ClientConfig, tls::Error> {
+        let mut config = (*self.config).clone();
+
+        if let Some(protocols) = options.application_protocols.as_ref() {
+            config.alpn_protocols = protocols.iter().map(|p| p.to_vec()).collect();
+        }
+
+        if let Some(identity) = options.identity.as_ref() {
+            let identity = ClientIdentity::new(&config, identity)
+                .map_err(|_| tls::Error::INTERNAL_ERROR.with_reason("invalid client identity"))?;
+            config.client_auth_cert_resolver = Arc::new(identity);
+        }
+
+        Ok(config)
+    }
 }
 
 impl Default for Client {
@@ -61,7 +88,13 @@ impl From<ClientConfig> for Client {
 // TODO this should be removed after removing deprecated re-exports
 impl From<Arc<ClientConfig>> for Client {
     fn from(config: Arc<ClientConfig>) -> Self {
-        Self { config }
+        // rustls enables resumption by default
+        Self {
+            config,
+            derived_configs: HashMap::new(),
+            resumption: true,
+            raw_public_keys: false,
+        }
     }
 }
 
@@ -96,6 +129,47 @@ impl tls::Endpoint for Client {
         .expect("could not create rustls client session");
 
         Session::new(session.into(), Some(server_name))
+            .with_session_tickets(self.resumption)
+            .with_peer_raw_public_key(self.raw_public_keys)
+    }
+
+    fn new_client_session_with_options<Params: EncoderValue>(
+        &mut self,
+        transport_parameters: &Params,
+        server_name: ServerName,
+        options: &tls::ClientOptions,
+    ) -> Result<Self::Session, tls::Error> {
+        if options.is_empty() {
+            return Ok(self.new_client_session(transport_parameters, server_name));
+        }
+
+        if options.session_ticket.is_some() {
+            // rustls only resumes sessions from its own session store
+            return Err(tls::Error::INTERNAL_ERROR
+                .with_reason("rustls does not support resuming from a session ticket"));
+        }
+
+        let config = match self.derived_configs.get(options) {
+            Some(config) => config.clone(),
+            None => {
+                let config = Arc::new(self.derive_config(options)?);
+
+                if self.derived_configs.len() >= MAX_DERIVED_CONFIGS {
+                    self.derived_configs.clear();
+                }
+                self.derived_configs.insert(options.clone(), config.clone());
+
+                config
+            }
+        };
+
+        let mut client = Self {
+            config,
+            derived_configs: HashMap::new(),
+            resumption: self.resumption,
+            raw_public_keys: self.raw_public_keys,
+        };
+        Ok(client.new_client_session(transport_parameters, server_name))
     }
 
     fn max_tag_length(&self) -> usize {
@@ -107,6 +181,10 @@ pub struct Builder {
     cert_store: rustls::RootCertStore,
     application_protocols: Vec<Vec<u8>>,
     key_log: Option<Arc<dyn rustls::KeyLog>>,
+    identity: Option<Identity>,
+    raw_public_key_verifier: Option<Arc<raw_public_key::Verifier>>,
+    resumption: Option<rustls::client::Resumption>,
+    resumption_enabled: bool,
 }
 
 impl Default for Builder {
@@ -121,6 +199,10 @@ impl Builder {
             cert_store: rustls::RootCertStore::empty(),
             application_protocols: vec![b"h3".to_vec()],
             key_log: None,
+            identity: None,
+            raw_public_key_verifier: None,
+            resumption: None,
+            resumption_enabled: true,
         }
     }
 
@@ -138,6 +220,77 @@ impl Builder {
         Ok(self)
     }
 
+    /// Sets the certificate chain and private key presented to servers which require client
+    /// authentication (mutual TLS)
+    pub fn with_client_identity<
+        C: certificate::IntoCertificate,
+        PK: certificate::IntoPrivateKey,
+    >(
+        mut self,
+        certificate: C,
+        private_key: PK,
+    ) -> Result<Self, Error> {
+        let certificate = certificate.into_certificate()?;
+        let private_key = private_key.into_private_key()?;
+        self.identity = Some(Identity::Certificate(certificate, private_key));
+        Ok(self)
+    }
+
+    /// Sets the private key whose [raw public key](https://www.rfc-editor.org/rfc/rfc7250) is
+    /// presented to servers which require client authentication
+    ///
+    /// This replaces any identity set with [`Self::with_client_identity`].
+    pub fn with_client_raw_public_key<PK: certificate::IntoPrivateKey>(
+        mut self,
+        private_key: PK,
+    ) -> Result<Self, Error> {
+        let private_key = private_key.into_private_key()?;
+        let key = raw_public_key::certified_key(private_key)?;
+        self.identity = Some(Identity::RawPublicKey(Arc::new(key)));
+        Ok(self)
+    }
+
+    /// Authenticates servers with a [raw public key](https://www.rfc-editor.org/rfc/rfc7250)
+    /// rather than a certificate chain
+    ///
+    /// The verifier is called with the DER-encoded SubjectPublicKeyInfo presented by the server
+    /// and returns `true` if the key is trusted. The trusted certificates are not used when
+    /// this is set.
+    pub fn with_raw_public_key_verifier<F>(mut self, verifier: F) -> Result<Self, Error>
+    where
+        F: 'static + Fn(&[u8]) -> bool + Send + Sync,
+    {
+        self.raw_public_key_verifier = Some(Arc::new(raw_public_key::Verifier::new(verifier)?));
+        Ok(self)
+    }
+
+    /// Sets the number of sessions cached in memory for resumption (default: 256)
+    ///
+    /// Sessions are cached per server name. Setting the size to `0` disables session resumption.
+    pub fn with_session_cache_size(mut self, size: usize) -> Result<Self, Error> {
+        self.resumption = Some(if size == 0 {
+            rustls::client::Resumption::disabled()
+        } else {
+            rustls::client::Resumption::in_memory_sessions(size)
+        });
+        self.resumption_enabled = size > 0;
+        Ok(self)
+    }
+
+    /// Sets the storage used to cache sessions for resumption
+    ///
+    /// This can be used to share sessions between multiple clients or persist them across
+    /// restarts.
+    #[deprecated = "client and server builders should be used instead"]
+    pub fn with_session_store(
+        mut self,
+        store: Arc<dyn rustls::client::ClientSessionStore>,
+    ) -> Result<Self, Error> {
+        self.resumption = Some(rustls::client::Resumption::store(store));
+        self.resumption_enabled = true;
+        Ok(self)
+    }
+
     pub fn with_max_cert_chain_depth(self, len: u16) -> Result<Self, Error> {
         // TODO is there a way to configure this?
         let _ = len;
@@ -158,27 +311,92 @@ impl Builder {
     }
 
     pub fn build(self) -> Result<Client, Error> {
-        // TODO load system root store?
-        if self.cert_store.is_empty() {
-            //= https://www.rfc-editor.org/rfc/rfc9001#section-4.4
-            //# A client MUST authenticate the identity of the server.
-            return Err(
-                rustls::Error::General("missing trusted root certificate(s)".to_string()).into(),
-            );
-        }
+        let raw_public_keys = self.raw_public_key_verifier.is_some();
+
+        let builder = if let Some(verifier) = self.raw_public_key_verifier {
+            default_config_builder()?
+                .dangerous()
+                .with_custom_certificate_verifier(verifier)
+        } else {
+            // TODO load system root store?
+            if self.cert_store.is_empty() {
+                //= https://www.rfc-editor.org/rfc/rfc9001#section-4.4
+                //# A client MUST authenticate the identity of the server.
+                return Err(rustls::Error::General(
+                    "missing trusted root certificate(s)".to_string(),
+                )
+                .into());
+            }
+
+            default_config_builder()?.with_root_certificates(self.cert_store)
+        };
 
-        let mut config = default_config_builder()?
-            .with_root_certificates(self.cert_store)
-            .with_no_client_auth();
+        let mut config = match self.identity {
+            Some(Identity::Certificate(certificate, private_key)) => {
+                builder.with_client_auth_cert(certificate.0, private_key.0)?
+            }
+            Some(Identity::RawPublicKey(key)) => builder.with_client_cert_resolver(Arc::new(
+                rustls::client::AlwaysResolvesClientRawPublicKeys::new(key),
+            )),
+            None => builder.with_no_client_auth(),
+        };
 
         config.max_fragment_size = None;
         config.alpn_protocols = self.application_protocols;
 
+        if let Some(resumption) = self.resumption {
+            config.resumption = resumption;
+        }
+
         if let Some(key_log) = self.key_log {
             config.key_log = key_log;
         }
 
-        #[allow(deprecated)]
-        Ok(Client::new(config))
+        Ok(Client {
+            config: Arc::new(config),
+            derived_configs: HashMap::new(),
+            resumption: self.resumption_enabled,
+            raw_public_keys,
+        })
+    }
+}
+
+/// The identity presented to servers which require client authentication
+enum Identity {
+    Certificate(certificate::Certificate, certificate::PrivateKey),
+    RawPublicKey(Arc<rustls::sign::CertifiedKey>),
+}
+
+/// Presents the same certificate to every server that requests client authentication
+#[derive(Debug)]
+struct ClientIdentity(Arc<rustls::sign::CertifiedKey>);
+
+impl ClientIdentity {
+    fn new(config: &ClientConfig, identity: &tls::ClientIdentity) -> Result<Self, Error> {
+        use certificate::{IntoCertificate as _, IntoPrivateKey as _};
+
+        let chain = core::str::from_utf8(&identity.certificate_chain)?.into_certificate()?;
+        let key = core::str::from_utf8(&identity.private_key)?.into_private_key()?;
+        let key = config
+            .crypto_provider()
+            .key_provider
+            .load_private_key(key.0)?;
+        Ok(Self(Arc::new(rustls::sign::CertifiedKey::new(
+            chain.0, key,
+        ))))
+    }
+}
+
+impl rustls::client::ResolvesClientCert for ClientIdentity {
+    fn resolve(
+        &self,
+        _root_hint_subjects: &[&[u8]],
+        _sigschemes: &[rustls::SignatureScheme],
+    ) -> Option<Arc<rustls::sign::CertifiedKey>> {
+        Some(self.0.clone())
+    }
+
+    fn has_certs(&self) -> bool {
+        true
     }
 }
@@ -25,10 +25,12 @@ type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
 
 mod cipher_suite;
 mod error;
+mod raw_public_key;
 mod session;
 
 pub mod certificate;
 pub mod client;
+pub mod reload;
 pub mod server;
 
 pub use client::Client;
@@ -114,4 +116,318 @@ mod tests {
 
         pair.finish();
     }
+
+    #[test]
+    fn client_server_mtls_test() {
+        let mut client = client::Builder::new()
+            .with_certificate(MTLS_CA_CERT)
+            .unwrap()
+            .with_client_identity(MTLS_CLIENT_CERT, MTLS_CLIENT_KEY)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut server = server::Builder::new()
+            .with_certificate(MTLS_SERVER_CERT, MTLS_SERVER_KEY)
+            .unwrap()
+            .with_client_authentication()
+            .unwrap()
+            .with_trusted_certificate(MTLS_CA_CERT)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut pair = tls::testing::Pair::new(&mut server, &mut client, "localhost".into());
+
+        while pair.is_handshaking() {
+            pair.poll(None).unwrap();
+        }
+
+        pair.finish();
+    }
+
+    #[test]
+    fn client_server_mtls_missing_identity_test() {
+        let mut client = client::Builder::new()
+            .with_certificate(MTLS_CA_CERT)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut server = server::Builder::new()
+            .with_certificate(MTLS_SERVER_CERT, MTLS_SERVER_KEY)
+            .unwrap()
+            .with_client_authentication()
+            .unwrap()
+            .with_trusted_certificate(MTLS_CA_CERT)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut pair = tls::testing::Pair::new(&mut server, &mut client, "localhost".into());
+
+        // the server rejects the handshake since the client didn't present a certificate
+        let error = loop {
+            if let Err(error) = pair.poll(None) {
+                break error;
+            }
+        };
+        assert!(error.try_into_tls_error().is_some(), "{error:?}");
+    }
+
+    #[test]
+    fn client_authentication_requires_trusted_certificate_test() {
+        assert!(server::Builder::new()
+            .with_certificate(MTLS_SERVER_CERT, MTLS_SERVER_KEY)
+            .unwrap()
+            .with_client_authentication()
+            .unwrap()
+            .build()
+            .is_err());
+    }
+
+    /// Returns the SubjectPublicKeyInfo for the private key
+    fn public_key(private_key: &str) -> Vec<u8> {
+        use certificate::IntoPrivateKey as _;
+        let key = raw_public_key::certified_key(private_key.into_private_key().unwrap()).unwrap();
+        key.cert[0].to_vec()
+    }
+
+    #[test]
+    fn client_server_raw_public_key_test() {
+        let server_key = public_key(KEY_PEM);
+        let client_key = public_key(MTLS_CLIENT_KEY);
+
+        let mut client = client::Builder::new()
+            .with_raw_public_key_verifier({
+                let server_key = server_key.clone();
+                move |key| key == server_key
+            })
+            .unwrap()
+            .with_client_raw_public_key(MTLS_CLIENT_KEY)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut server = server::Builder::new()
+            .with_raw_public_key(KEY_PEM)
+            .unwrap()
+            .with_client_raw_public_key_verifier({
+                let client_key = client_key.clone();
+                move |key| key == client_key
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut pair = tls::testing::Pair::new(&mut server, &mut client, "localhost".into());
+
+        while pair.is_handshaking() {
+            pair.poll(None).unwrap();
+        }
+
+        pair.finish();
+
+        assert_eq!(
+            pair.client.context.peer_public_key.as_deref(),
+            Some(&server_key[..])
+        );
+        assert_eq!(
+            pair.server.context.peer_public_key.as_deref(),
+            Some(&client_key[..])
+        );
+    }
+
+    #[test]
+    fn client_server_untrusted_raw_public_key_test() {
+        let server_key = public_key(UNTRUSTED_KEY_PEM);
+
+        let mut client = client::Builder::new()
+            .with_raw_public_key_verifier(move |key| key == server_key)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut server = server::Builder::new()
+            .with_raw_public_key(KEY_PEM)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut pair = tls::testing::Pair::new(&mut server, &mut client, "localhost".into());
+
+        // the client rejects the handshake since it doesn't trust the server's key
+        let error = loop {
+            if let Err(error) = pair.poll(None) {
+                break error;
+            }
+        };
+        assert!(error.try_into_tls_error().is_some(), "{error:?}");
+        assert!(pair.client.context.peer_public_key.is_none());
+    }
+
+    #[test]
+    fn client_server_resumption_test() {
+        use ::rustls::client::{ClientSessionMemoryCache, ClientSessionStore};
+        use core::sync::atomic::{AtomicUsize, Ordering};
+        use s2n_quic_core::crypto::tls::Session as _;
+        use std::sync::Arc;
+
+        /// Counts the number of sessions which were resumed
+        #[derive(Debug)]
+        struct Store {
+            cache: ClientSessionMemoryCache,
+            resumed: AtomicUsize,
+        }
+
+        impl ClientSessionStore for Store {
+            fn set_kx_hint(
+                &self,
+                server_name: ::rustls::pki_types::ServerName<'static>,
+                group: ::rustls::NamedGroup,
+            ) {
+                self.cache.set_kx_hint(server_name, group)
+            }
+
+            fn kx_hint(
+                &self,
+                server_name: &::rustls::pki_types::ServerName<'_>,
+            ) -> Option<::rustls::NamedGroup> {
+                self.cache.kx_hint(server_name)
+            }
+
+            fn set_tls12_session(
+                &self,
+                server_name: ::rustls::pki_types::ServerName<'static>,
+                value: ::rustls::client::Tls12ClientSessionValue,
+            ) {
+                self.cache.set_tls12_session(server_name, value)
+            }
+
+            fn tls12_session(
+                &self,
+                server_name: &::rustls::pki_types::ServerName<'_>,
+            ) -> Option<::rustls::client::Tls12ClientSessionValue> {
+                self.cache.tls12_session(server_name)
+            }
+
+            fn remove_tls12_session(&self, server_name: &::rustls::pki_types::ServerName<'static>) {
+                self.cache.remove_tls12_session(server_name)
+            }
+
+            fn insert_tls13_ticket(
+                &self,
+                server_name: ::rustls::pki_types::ServerName<'static>,
+                value: ::rustls::client::Tls13ClientSessionValue,
+            ) {
+                self.cache.insert_tls13_ticket(server_name, value)
+            }
+
+            fn take_tls13_ticket(
+                &self,
+                server_name: &::rustls::pki_types::ServerName<'static>,
+            ) -> Option<::rustls::client::Tls13ClientSessionValue> {
+                let ticket = self.cache.take_tls13_ticket(server_name);
+                if ticket.is_some() {
+                    self.resumed.fetch_add(1, Ordering::Relaxed);
+                }
+                ticket
+            }
+        }
+
+        for session_tickets in [false, true] {
+            let store = Arc::new(Store {
+                cache: ClientSessionMemoryCache::new(4),
+                resumed: AtomicUsize::new(0),
+            });
+
+            #[allow(deprecated)]
+            let mut client = client::Builder::new()
+                .with_certificate(CERT_PEM)
+                .unwrap()
+                .with_session_store(store.clone())
+                .unwrap()
+                .build()
+                .unwrap();
+
+            let mut server = server::Builder::new()
+                .with_certificate(CERT_PEM, KEY_PEM)
+                .unwrap()
+                .with_session_tickets(session_tickets)
+                .unwrap()
+                .build()
+                .unwrap();
+
+            for _ in 0..2 {
+                let mut pair =
+                    tls::testing::Pair::new(&mut server, &mut client, "localhost".into());
+
+                while pair.is_handshaking() {
+                    pair.poll(None).unwrap();
+                }
+
+                pair.finish();
+
+                // the server sends session tickets once the handshake is complete
+                pair.client.context.transfer(&mut pair.server.context);
+                assert!(!pair.client.session.should_discard_session());
+                pair.client
+                    .session
+                    .process_post_handshake_message(&mut pair.client.context)
+                    .unwrap();
+                assert!(pair.client.session.should_discard_session());
+            }
+
+            assert_eq!(store.resumed.load(Ordering::Relaxed), 1);
+        }
+    }
+
+    #[test]
+    fn reload_test() {
+        let dir =
+            std::env::temp_dir().join(format!("s2n-quic-rustls-reload-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let cert = dir.join("cert.pem");
+        let key = dir.join("key.pem");
+        std::fs::write(&cert, CERT_PEM).unwrap();
+        std::fs::write(&key, KEY_PEM).unwrap();
+
+        let files = reload::Files::new(&cert, &key).without_watching();
+        let reloader = reload::Reloader::server(files, Ok).unwrap();
+        let mut server = Server::from(reloader.clone());
+
+        let handshake = |server: &mut Server| {
+            let mut client = client::Builder::new()
+                .with_certificate(CERT_PEM)
+                .unwrap()
+                .build()
+                .unwrap();
+            let mut pair = tls::testing::Pair::new(server, &mut client, "localhost".into());
+            while pair.is_handshaking() {
+                pair.poll(None)?;
+            }
+            pair.finish();
+            Ok::<_, s2n_quic_core::transport::Error>(())
+        };
+
+        handshake(&mut server).unwrap();
+
+        // the client doesn't trust the new certificate
+        std::fs::write(&cert, UNTRUSTED_CERT_PEM).unwrap();
+        std::fs::write(&key, UNTRUSTED_KEY_PEM).unwrap();
+        reloader.reload().unwrap();
+        assert!(handshake(&mut server).is_err());
+
+        // an invalid certificate keeps the previous config
+        std::fs::write(&cert, "invalid").unwrap();
+        assert!(reloader.reload().is_err());
+        assert!(handshake(&mut server).is_err());
+
+        std::fs::write(&cert, CERT_PEM).unwrap();
+        std::fs::write(&key, KEY_PEM).unwrap();
+        reloader.reload().unwrap();
+        handshake(&mut server).unwrap();
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
@@ -0,0 +1,169 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Support for authenticating peers with [raw public keys](https://www.rfc-editor.org/rfc/rfc7250)
+//! rather than certificate chains
+
+use crate::{certificate, cipher_suite::default_crypto_provider, Error};
+use core::fmt;
+use rustls::{
+    client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+    crypto::{aws_lc_rs, WebPkiSupportedAlgorithms},
+    pki_types::{CertificateDer, ServerName, SubjectPublicKeyInfoDer, UnixTime},
+    server::danger::{ClientCertVerified, ClientCertVerifier},
+    sign::CertifiedKey,
+    CertificateError, DigitallySignedStruct, DistinguishedName, SignatureScheme,
+};
+
+type Callback = dyn Fn(&[u8]) -> bool + Send + Sync;
+
+/// Creates a key which presents its public key to the peer instead of a certificate
+pub(crate) fn certified_key(private_key: certificate::PrivateKey) -> Result<CertifiedKey, Error> {
+    let key = aws_lc_rs::sign::any_supported_type(&private_key.0)
+        .map_err(|_| rustls::Error::General("invalid private key".into()))?;
+
+    // with raw public keys, the certificate message carries the SubjectPublicKeyInfo
+    let public_key = key
+        .public_key()
+        .ok_or_else(|| rustls::Error::General("could not encode the public key".into()))?;
+    let public_key = CertificateDer::from(public_key.as_ref().to_vec());
+
+    Ok(CertifiedKey::new(vec![public_key], key))
+}
+
+/// Verifies the peer's raw public key with an application-provided callback
+pub(crate) struct Verifier {
+    callback: Box<Callback>,
+    algorithms: WebPkiSupportedAlgorithms,
+}
+
+impl fmt::Debug for Verifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Verifier").finish_non_exhaustive()
+    }
+}
+
+impl Verifier {
+    pub(crate) fn new<F>(callback: F) -> Result<Self, Error>
+    where
+        F: 'static + Fn(&[u8]) -> bool + Send + Sync,
+    {
+        Ok(Self {
+            callback: Box::new(callback),
+            algorithms: default_crypto_provider()?.signature_verification_algorithms,
+        })
+    }
+
+    fn verify(&self, public_key: &CertificateDer) -> Result<(), rustls::Error> {
+        if (self.callback)(public_key.as_ref()) {
+            Ok(())
+        } else {
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::ApplicationVerificationFailure,
+            ))
+        }
+    }
+
+    fn verify_signature(
+        &self,
+        message: &[u8],
+        public_key: &CertificateDer,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        let public_key = SubjectPublicKeyInfoDer::from(public_key.as_ref());
+        rustls::crypto::verify_tls13_signature_with_raw_key(
+            message,
+            &public_key,
+            dss,
+            &self.algorithms,
+        )
+    }
+}
+
+impl ServerCertVerifier for Verifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer,
+        _intermediates: &[CertificateDer],
+        _server_name: &ServerName,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        self.verify(end_entity)?;
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        _message: &[u8],
+        _cert: &CertificateDer,
+        _dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        // QUIC only supports TLS 1.3
+        Err(rustls::Error::PeerIncompatible(
+            rustls::PeerIncompatible::Tls13RequiredForQuic,
+        ))
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.verify_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.algorithms.supported_schemes()
+    }
+
+    fn requires_raw_public_keys(&self) -> bool {
+        true
+    }
+}
+
+impl ClientCertVerifier for Verifier {
+    fn root_hint_subjects(&self) -> &[DistinguishedName] {
+        &[]
+    }
+
+    fn verify_client_cert(
+        &self,
+        end_entity: &CertificateDer,
+        _intermediates: &[CertificateDer],
+        _now: UnixTime,
+    ) -> Result<ClientCertVerified, rustls::Error> {
+        self.verify(end_entity)?;
+        Ok(ClientCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        _message: &[u8],
+        _cert: &CertificateDer,
+        _dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        // QUIC only supports TLS 1.3
+        Err(rustls::Error::PeerIncompatible(
+            rustls::PeerIncompatible::Tls13RequiredForQuic,
+        ))
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.verify_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.algorithms.supported_schemes()
+    }
+
+    fn requires_raw_public_keys(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reloads server certificates from PEM files without restarting the endpoint
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! use s2n_quic_rustls::{reload, Server};
+//!
+//! let files = reload::Files::new("cert.pem", "key.pem");
+//! let reloader = reload::Reloader::server(files, |builder| {
+//!     builder.with_application_protocols(["h3"].iter())
+//! })?;
+//! let server = Server::from(reloader.clone());
+//!
+//! // new connections use the updated certificate once the reload succeeds
+//! reloader.reload()?;
+//! # let _ = server;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{server, Server};
+use s2n_quic_core::{crypto::tls::reload, event::EndpointPublisher};
+use std::sync::Arc;
+
+pub use reload::{Error, Files, Pem};
+
+/// Reloads the configuration of a [`Server`](crate::Server) from [`Files`]
+///
+/// Cloning the reloader returns a handle to the same configuration, which can be used to reload
+/// it after it has been passed to a server.
+#[derive(Clone, Debug)]
+pub struct Reloader(reload::Reloader<Server>);
+
+impl Reloader {
+    /// Creates a reloader for server configurations
+    ///
+    /// `configure` is called with a new [`server::Builder`] each time the files are loaded. The
+    /// certificate and private key are then added to it, along with the trusted certificate, if
+    /// one was provided.
+    pub fn server<F>(files: Files, configure: F) -> Result<Self, Error>
+    where
+        F: 'static + Fn(server::Builder) -> Result<server::Builder, Error> + Send + Sync,
+    {
+        let reloader = reload::Reloader::new(files, move |pem| {
+            let mut builder = configure(server::Builder::new())?
+                .with_certificate(pem.certificate.as_str(), pem.private_key.as_str())?;
+
+            if let Some(trusted_certificate) = pem.trusted_certificate.as_deref() {
+                builder = builder.with_trusted_certificate(trusted_certificate)?;
+            }
+
+            builder.build()
+        })?;
+
+        Ok(Self(reloader))
+    }
+
+    /// Reloads the files and replaces the configuration used for new connections
+    ///
+    /// If the files can't be loaded, the previous configuration is kept and the error is
+    /// returned.
+    pub fn reload(&self) -> Result<(), Error> {
+        self.0.reload()
+    }
+
+    pub(crate) fn current(&self) -> Arc<Server> {
+        self.0.config()
+    }
+
+    pub(crate) fn publish_events(&self, publisher: &mut dyn EndpointPublisher) {
+        self.0.publish_events(publisher)
+    }
+}
@@ -1,7 +1,10 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{certificate, cipher_suite::default_crypto_provider, session::Session, Error};
+use crate::{
+    certificate, cipher_suite::default_crypto_provider, raw_public_key, reload, session::Session,
+    Error,
+};
 use rustls::{crypto::aws_lc_rs, ConfigBuilder, ServerConfig, WantsVerifier};
 use s2n_codec::EncoderValue;
 use s2n_quic_core::{application::ServerName, crypto::tls};
@@ -19,6 +22,8 @@ fn default_config_builder() -> Result<ConfigBuilder<ServerConfig, WantsVerifier>
 #[derive(Clone)]
 pub struct Server {
     config: Arc<ServerConfig>,
+    reloader: Option<reload::Reloader>,
+    raw_public_keys: bool,
 }
 
 impl Server {
@@ -31,9 +36,7 @@ impl Server {
     /// - QUIC compliant ciphersuites
     #[deprecated = "client and server builders should be used instead"]
     pub fn new(config: ServerConfig) -> Self {
-        Self {
-            config: Arc::new(config),
-        }
+        Self::from(Arc::new(config))
     }
 
     pub fn builder() -> Builder {
@@ -59,7 +62,22 @@ impl From<ServerConfig> for Server {
 // TODO this should be removed after removing deprecated re-exports
 impl From<Arc<ServerConfig>> for Server {
     fn from(config: Arc<ServerConfig>) -> Self {
-        Self { config }
+        Self {
+            config,
+            reloader: None,
+            raw_public_keys: false,
+        }
+    }
+}
+
+impl From<reload::Reloader> for Server {
+    fn from(reloader: reload::Reloader) -> Self {
+        let server = reloader.current();
+        Self {
+            config: server.config.clone(),
+            reloader: Some(reloader),
+            raw_public_keys: server.raw_public_keys,
+        }
     }
 }
 
@@ -74,6 +92,13 @@ impl tls::Endpoint for Server {
         //# Endpoints MUST send the quic_transport_parameters extension;
         let transport_parameters = transport_parameters.encode_to_vec();
 
+        // pick up the latest configuration for each new connection
+        if let Some(reloader) = self.reloader.as_ref() {
+            let server = reloader.current();
+            self.config = server.config.clone();
+            self.raw_public_keys = server.raw_public_keys;
+        }
+
         let session = rustls::quic::ServerConnection::new(
             self.config.clone(),
             crate::QUIC_VERSION,
@@ -81,7 +106,7 @@ impl tls::Endpoint for Server {
         )
         .expect("could not create rustls server session");
 
-        Session::new(session.into(), None)
+        Session::new(session.into(), None).with_peer_raw_public_key(self.raw_public_keys)
     }
 
     fn new_client_session<Params: EncoderValue>(
@@ -92,6 +117,15 @@ impl tls::Endpoint for Server {
         panic!("cannot create a client session from a server config");
     }
 
+    fn publish_events<Pub: s2n_quic_core::event::EndpointPublisher>(
+        &mut self,
+        publisher: &mut Pub,
+    ) {
+        if let Some(reloader) = self.reloader.as_ref() {
+            reloader.publish_events(publisher);
+        }
+    }
+
     fn max_tag_length(&self) -> usize {
         s2n_quic_crypto::MAX_TAG_LEN
     }
@@ -102,6 +136,12 @@ pub struct Builder {
     application_protocols: Vec<Vec<u8>>,
     key_log: Option<Arc<dyn rustls::KeyLog>>,
     prefer_server_cipher_suite_order: bool,
+    client_authentication: bool,
+    trust_store: rustls::RootCertStore,
+    client_cert_verifier: Option<Arc<dyn rustls::server::danger::ClientCertVerifier>>,
+    client_raw_public_keys: bool,
+    session_storage: Option<Arc<dyn rustls::server::StoresServerSessions>>,
+    ticketer: Option<Arc<dyn rustls::server::ProducesTickets>>,
 }
 
 impl Default for Builder {
@@ -117,6 +157,12 @@ impl Builder {
             application_protocols: vec![b"h3".to_vec()],
             key_log: None,
             prefer_server_cipher_suite_order: true,
+            client_authentication: false,
+            trust_store: rustls::RootCertStore::empty(),
+            client_cert_verifier: None,
+            client_raw_public_keys: false,
+            session_storage: None,
+            ticketer: None,
         }
     }
 
@@ -133,6 +179,21 @@ impl Builder {
         Ok(self)
     }
 
+    /// Presents the [raw public key](https://www.rfc-editor.org/rfc/rfc7250) of the private key
+    /// to clients rather than a certificate chain
+    ///
+    /// Clients must be configured to authenticate the server with a raw public key.
+    pub fn with_raw_public_key<PK: certificate::IntoPrivateKey>(
+        mut self,
+        private_key: PK,
+    ) -> Result<Self, Error> {
+        let private_key = private_key.into_private_key()?;
+        let key = raw_public_key::certified_key(private_key)?;
+        let resolver = rustls::server::AlwaysResolvesServerRawPublicKeys::new(Arc::new(key));
+        self.cert_resolver = Some(Arc::new(resolver));
+        Ok(self)
+    }
+
     #[deprecated = "client and server builders should be used instead"]
     pub fn with_cert_resolver(
         mut self,
@@ -162,8 +223,128 @@ impl Builder {
         Ok(self)
     }
 
+    /// Adds a certificate authority which is trusted to issue client certificates
+    pub fn with_trusted_certificate<C: certificate::IntoCertificate>(
+        mut self,
+        certificate: C,
+    ) -> Result<Self, Error> {
+        let certificates = certificate.into_certificate()?;
+        let root_certificate = certificates.0.first().ok_or_else(|| {
+            rustls::Error::General("Certificate chain needs to have at least one entry".to_string())
+        })?;
+        self.trust_store
+            .add(root_certificate.to_owned())
+            .map_err(|err| rustls::Error::General(err.to_string()))?;
+        Ok(self)
+    }
+
+    /// Configures this server instance to require client authentication (mutual TLS).
+    ///
+    /// Client certificates are verified against the certificates added with
+    /// [`Self::with_trusted_certificate`].
+    pub fn with_client_authentication(mut self) -> Result<Self, Error> {
+        self.client_authentication = true;
+        Ok(self)
+    }
+
+    /// Sets the verifier for certificates presented by clients
+    ///
+    /// This takes precedence over [`Self::with_client_authentication`].
+    #[deprecated = "client and server builders should be used instead"]
+    pub fn with_client_cert_verifier(
+        mut self,
+        verifier: Arc<dyn rustls::server::danger::ClientCertVerifier>,
+    ) -> Result<Self, Error> {
+        self.client_cert_verifier = Some(verifier);
+        self.client_raw_public_keys = false;
+        Ok(self)
+    }
+
+    /// Requires clients to authenticate with a
+    /// [raw public key](https://www.rfc-editor.org/rfc/rfc7250) rather than a certificate chain
+    ///
+    /// The verifier is called with the DER-encoded SubjectPublicKeyInfo presented by the client
+    /// and returns `true` if the key is trusted. This takes precedence over
+    /// [`Self::with_client_authentication`].
+    pub fn with_client_raw_public_key_verifier<F>(mut self, verifier: F) -> Result<Self, Error>
+    where
+        F: 'static + Fn(&[u8]) -> bool + Send + Sync,
+    {
+        self.client_cert_verifier = Some(Arc::new(raw_public_key::Verifier::new(verifier)?));
+        self.client_raw_public_keys = true;
+        Ok(self)
+    }
+
+    /// Sets the number of sessions cached in memory for stateful resumption (default: 256)
+    ///
+    /// Setting the size to `0` disables stateful resumption.
+    pub fn with_session_cache_size(mut self, size: usize) -> Result<Self, Error> {
+        let storage: Arc<dyn rustls::server::StoresServerSessions> = if size == 0 {
+            Arc::new(rustls::server::NoServerSessionStorage {})
+        } else {
+            rustls::server::ServerSessionMemoryCache::new(size)
+        };
+        self.session_storage = Some(storage);
+        Ok(self)
+    }
+
+    /// Sets the storage used to cache sessions for stateful resumption
+    #[deprecated = "client and server builders should be used instead"]
+    pub fn with_session_storage(
+        mut self,
+        storage: Arc<dyn rustls::server::StoresServerSessions>,
+    ) -> Result<Self, Error> {
+        self.session_storage = Some(storage);
+        Ok(self)
+    }
+
+    /// If enabled, sessions are resumed from encrypted session tickets rather than the
+    /// session cache (default: false)
+    ///
+    /// The ticket keys are randomly generated and rotated every 6 hours.
+    pub fn with_session_tickets(mut self, enabled: bool) -> Result<Self, Error> {
+        self.ticketer = if enabled {
+            Some(aws_lc_rs::Ticketer::new()?)
+        } else {
+            None
+        };
+        Ok(self)
+    }
+
+    /// Sets the ticketer used to issue session tickets for stateless resumption
+    ///
+    /// This can be used to share ticket keys between multiple servers.
+    #[deprecated = "client and server builders should be used instead"]
+    pub fn with_ticketer(
+        mut self,
+        ticketer: Arc<dyn rustls::server::ProducesTickets>,
+    ) -> Result<Self, Error> {
+        self.ticketer = Some(ticketer);
+        Ok(self)
+    }
+
     pub fn build(self) -> Result<Server, Error> {
-        let builder = default_config_builder()?.with_no_client_auth();
+        let builder = default_config_builder()?;
+
+        let builder = if let Some(verifier) = self.client_cert_verifier {
+            builder.with_client_cert_verifier(verifier)
+        } else if self.client_authentication {
+            if self.trust_store.is_empty() {
+                return Err(rustls::Error::General(
+                    "missing trusted certificate(s) for client authentication".to_string(),
+                )
+                .into());
+            }
+
+            let verifier = rustls::server::WebPkiClientVerifier::builder_with_provider(
+                Arc::new(self.trust_store),
+                default_crypto_provider()?.into(),
+            )
+            .build()?;
+            builder.with_client_cert_verifier(verifier)
+        } else {
+            builder.with_no_client_auth()
+        };
 
         let mut config = if let Some(cert_resolver) = self.cert_resolver {
             builder.with_cert_resolver(cert_resolver)
@@ -182,8 +363,19 @@ impl Builder {
             config.key_log = key_log;
         }
 
-        #[allow(deprecated)]
-        Ok(Server::new(config))
+        if let Some(session_storage) = self.session_storage {
+            config.session_storage = session_storage;
+        }
+
+        if let Some(ticketer) = self.ticketer {
+            config.ticketer = ticketer;
+        }
+
+        Ok(Server {
+            config: Arc::new(config),
+            reloader: None,
+            raw_public_keys: self.client_raw_public_keys,
+        })
     }
 }
 
@@ -195,11 +387,23 @@ impl AlwaysResolvesChain {
         chain: certificate::Certificate,
         priv_key: certificate::PrivateKey,
     ) -> Result<Self, rustls::Error> {
+        if chain.0.is_empty() {
+            return Err(rustls::Error::General("missing certificate".into()));
+        }
+
         let key = aws_lc_rs::sign::any_supported_type(&priv_key.0)
             .map_err(|_| rustls::Error::General("invalid private key".into()))?;
-        Ok(Self(Arc::new(rustls::sign::CertifiedKey::new(
-            chain.0, key,
-        ))))
+        let key = rustls::sign::CertifiedKey::new(chain.0, key);
+
+        // reject a private key which doesn't belong to the certificate, rather than failing
+        // every handshake
+        if let Err(err @ rustls::Error::InconsistentKeys(rustls::InconsistentKeys::KeyMismatch)) =
+            key.keys_match()
+        {
+            return Err(err);
+        }
+
+        Ok(Self(Arc::new(key)))
     }
 }
 
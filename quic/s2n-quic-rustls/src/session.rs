@@ -25,7 +25,11 @@ pub struct Session {
     emitted_server_name: bool,
     emitted_application_protocol: bool,
     emitted_key_exchange_group: bool,
+    emitted_peer_public_key: bool,
     server_name: Option<ServerName>,
+    session_tickets_enabled: bool,
+    received_ticket: bool,
+    peer_raw_public_key: bool,
 }
 
 impl tls::TlsSession for Session {
@@ -95,10 +99,30 @@ impl Session {
             emitted_server_name: false,
             emitted_application_protocol: false,
             emitted_key_exchange_group: false,
+            emitted_peer_public_key: false,
             server_name,
+            session_tickets_enabled: false,
+            received_ticket: false,
+            peer_raw_public_key: false,
         }
     }
 
+    /// Keeps the session after the handshake so that session tickets can be received
+    ///
+    /// This should only be enabled for clients which can resume sessions.
+    pub fn with_session_tickets(mut self, enabled: bool) -> Self {
+        self.session_tickets_enabled = enabled;
+        self
+    }
+
+    /// Reports the peer's public key to the application once it has been verified
+    ///
+    /// This should be enabled when the peer authenticates with a raw public key.
+    pub fn with_peer_raw_public_key(mut self, enabled: bool) -> Self {
+        self.peer_raw_public_key = enabled;
+        self
+    }
+
     fn receive(&mut self, crypto_data: &[u8]) -> Result<(), transport::Error> {
         self.connection
             .read_hs(crypto_data)
@@ -211,6 +235,7 @@ impl Session {
             // attempt to emit server_name and application_protocol events prior to completing the
             // handshake
             self.emit_events(context)?;
+            self.emit_peer_public_key(context)?;
 
             // the handshake is complete!
             if !self.emitted_handshake_complete {
@@ -219,6 +244,9 @@ impl Session {
                 context.on_tls_exporter_ready(self)?;
             }
 
+            // servers send session tickets after reading the client's Finished message
+            self.send_post_handshake_messages(context);
+
             self.emitted_handshake_complete = true;
         }
 
@@ -229,6 +257,24 @@ impl Session {
         }
     }
 
+    /// Sends any messages produced after the handshake is complete, such as session tickets
+    fn send_post_handshake_messages<C: tls::Context<Self>>(&mut self, context: &mut C) {
+        while context.can_send_application() {
+            let mut transmission_buffer = vec![];
+            let key_change = self.connection.write_hs(&mut transmission_buffer);
+            debug_assert!(
+                key_change.is_none(),
+                "keys should not change after the handshake"
+            );
+
+            if transmission_buffer.is_empty() {
+                break;
+            }
+
+            context.send_application(transmission_buffer.into());
+        }
+    }
+
     fn poll_impl<C: tls::Context<Self>>(
         &mut self,
         context: &mut C,
@@ -361,6 +407,35 @@ impl Session {
 
         Ok(())
     }
+
+    /// Reports the peer's raw public key
+    ///
+    /// rustls exposes the key as soon as the Certificate message is read, so this must only be
+    /// called once the handshake is complete and the peer has proven it holds the private key
+    /// with CertificateVerify.
+    fn emit_peer_public_key<C: tls::Context<Self>>(
+        &mut self,
+        context: &mut C,
+    ) -> Result<(), transport::Error> {
+        debug_assert!(!self.connection.is_handshaking());
+
+        if !self.peer_raw_public_key || self.emitted_peer_public_key {
+            return Ok(());
+        }
+
+        // the certificate entry holds the SubjectPublicKeyInfo with raw public keys
+        if let Some(public_key) = self
+            .connection
+            .peer_certificates()
+            .and_then(|certificates| certificates.first())
+        {
+            let public_key = Bytes::copy_from_slice(public_key.as_ref());
+            context.on_peer_public_key(public_key)?;
+            self.emitted_peer_public_key = true;
+        }
+
+        Ok(())
+    }
 }
 
 impl crypto::CryptoSuite for Session {
@@ -386,6 +461,28 @@ impl tls::Session for Session {
         self.emit_events(context)?;
         result
     }
+
+    fn process_post_handshake_message<C: tls::Context<Self>>(
+        &mut self,
+        context: &mut C,
+    ) -> Result<(), transport::Error> {
+        while let Some(crypto_data) = context.receive_application(None) {
+            self.receive(&crypto_data)?;
+            self.received_ticket = true;
+        }
+
+        Ok(())
+    }
+
+    fn should_discard_session(&self) -> bool {
+        // Only clients which can resume sessions need to receive session tickets
+        if !self.session_tickets_enabled {
+            return true;
+        }
+
+        // Discard the session once a ticket is received
+        self.received_ticket
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
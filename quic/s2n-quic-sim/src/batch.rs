@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    run::Config as Sim,
+    run::{Config as Sim, Timeline},
     stats::{Filter, Query},
     Result,
 };
@@ -63,7 +63,13 @@ struct Plan {
 
     sim: Sim,
 
+    #[serde(flatten)]
+    timeline: Timeline,
+
     report: BTreeMap<String, Report>,
+
+    #[serde(skip)]
+    path: PathBuf,
 }
 
 impl Plan {
@@ -82,12 +88,18 @@ impl Plan {
         let db = out.join("db.proto");
 
         if !skip_run || !db.exists() {
-            let status = Command::new(command)
-                .arg("run")
+            let mut cmd = Command::new(command);
+
+            cmd.arg("run")
                 .arg("--progress")
                 .args(self.sim.args())
-                .stdout(fs::File::create(&db)?)
-                .status()?;
+                .stdout(fs::File::create(&db)?);
+
+            if !self.timeline.is_empty() {
+                cmd.arg("--timeline").arg(&self.path);
+            }
+
+            let status = cmd.status()?;
 
             if !status.success() {
                 return Err(anyhow!("run did not succeed"));
@@ -110,6 +122,7 @@ impl FromStr for Plan {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let file = fs::read_to_string(s)?;
         let mut plan: Self = toml::from_str(&file).map_err(io::Error::other)?;
+        plan.path = PathBuf::from(s);
 
         if plan.name.is_none() {
             plan.name = Some(
@@ -23,7 +23,7 @@ pub struct Query {
     #[structopt(long, short)]
     filter: Vec<stats::Filter>,
 
-    #[structopt(long, short, possible_values = &*stats::QUERY_NAMES)]
+    #[structopt(long, short)]
     query: Vec<stats::Query>,
 
     #[structopt(long)]
@@ -59,7 +59,7 @@ impl Query {
             } else {
                 None
             };
-            let queries = queries.iter().map(|q| q.name);
+            let queries = queries.iter().map(|q| q.to_string());
             if emit(seed, queries).is_err() {
                 return Ok(());
             }
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    stats::{self, Connection, Filter, Parameters, Query, Stats, QUERY_NAMES},
+    stats::{self, Connection, Filter, Parameters, Query, Stats},
     Result,
 };
 use serde_json::json;
@@ -18,13 +18,13 @@ pub struct Report {
     #[structopt(long, short)]
     filter: Vec<Filter>,
 
-    #[structopt(long, short, possible_values = &*QUERY_NAMES)]
+    #[structopt(long, short)]
     x: Query,
 
     #[structopt(long, default_value = "100")]
     x_width: u32,
 
-    #[structopt(long, short, possible_values = &*QUERY_NAMES)]
+    #[structopt(long, short)]
     y: Query,
 
     #[structopt(long, default_value = "100")]
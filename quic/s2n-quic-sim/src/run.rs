@@ -4,7 +4,8 @@
 use crate::{stats, Result};
 use indicatif::{ParallelProgressIterator, ProgressBar};
 use rayon::prelude::*;
-use s2n_quic::provider::io::testing::{test_seed, Model};
+use s2n_quic::provider::io::testing::{test_seed, Aqm, Model};
+use std::path::PathBuf;
 use structopt::StructOpt;
 
 mod config;
@@ -14,7 +15,12 @@ mod endpoint;
 mod events;
 
 mod range;
-use range::CliRange;
+use range::{CliChoice, CliRange};
+
+mod timeline;
+pub use timeline::Timeline;
+
+mod workload;
 
 #[derive(Debug, StructOpt)]
 pub struct Run {
@@ -26,6 +32,10 @@ pub struct Run {
 
     #[structopt(long)]
     progress: bool,
+
+    /// Reads the `[[timeline]]` phases from the specified plan
+    #[structopt(long)]
+    timeline: Option<PathBuf>,
 }
 
 impl core::ops::Deref for Run {
@@ -42,6 +52,12 @@ impl Run {
         assert_ne!(self.clients.start, 0);
         assert_ne!(self.connections.start, 0);
 
+        let timeline = if let Some(path) = self.timeline.as_ref() {
+            Timeline::load(path)?
+        } else {
+            Timeline::default()
+        };
+
         let test = |seed: u64| {
             let network = Model::default();
 
@@ -49,27 +65,41 @@ impl Run {
                 let server_len = self.servers.gen();
                 let client_len = self.clients.gen();
 
-                let events = self.gen_network(seed, server_len, client_len, &network);
+                let params = workload::Params {
+                    workload: self.workload.gen(),
+                    congestion_controller: self.congestion_controller.gen(),
+                    streams: self.streams,
+                    stream_data: self.stream_data,
+                    request_size: self.request_size,
+                    response_size: self.response_size,
+                    datagrams: self.datagrams,
+                    datagram_size: self.datagram_size,
+                    datagram_interval: self.datagram_interval,
+                    idle_duration: self.idle_duration,
+                };
+
+                let phases = timeline.gen();
+
+                let events = stats::Parameters {
+                    seed,
+                    servers: server_len,
+                    clients: client_len,
+                    workload: params.workload.id(),
+                    congestion_controller: params.congestion_controller.id(),
+                    phases: phases.iter().map(stats::Phase::from).collect(),
+                    ..Default::default()
+                };
+                let events = self.gen_network(events, &network, phases);
 
                 let mut servers = vec![];
                 for _ in 0..server_len {
-                    servers.push(endpoint::server(handle, events.clone())?);
+                    servers.push(endpoint::server(handle, events.clone(), &params)?);
                 }
 
                 for _ in 0..client_len {
                     let count = self.connections.gen() as usize;
                     let delay = self.connect_delay;
-                    let streams = self.streams;
-                    let stream_data = self.stream_data;
-                    endpoint::client(
-                        handle,
-                        events.clone(),
-                        &servers,
-                        count,
-                        delay,
-                        streams,
-                        stream_data,
-                    )?;
+                    endpoint::client(handle, events.clone(), &servers, count, delay, &params)?;
                 }
 
                 Ok(())
@@ -79,10 +109,14 @@ impl Run {
 
         if self.seed.is_empty() {
             events::dump(|stdout| {
-                stats::Setup {
-                    args: self.config.args(),
+                let mut args = self.config.args();
+
+                if let Some(path) = self.timeline.as_ref() {
+                    args.push("--timeline".to_owned());
+                    args.push(path.display().to_string());
                 }
-                .write(stdout)
+
+                stats::Setup { args }.write(stdout)
             });
 
             let pb = if self.progress {
@@ -123,14 +157,12 @@ impl Run {
         Ok(())
     }
 
-    fn gen_network(&self, seed: u64, servers: u32, clients: u32, model: &Model) -> events::Events {
-        let mut events = stats::Parameters {
-            seed,
-            servers,
-            clients,
-            ..Default::default()
-        };
-
+    fn gen_network(
+        &self,
+        mut events: stats::Parameters,
+        model: &Model,
+        phases: Vec<timeline::Scheduled>,
+    ) -> events::Events {
         macro_rules! param {
             ($name:ident, $set:ident, gen_duration) => {{
                 let value = self.$name.gen_duration();
@@ -152,6 +184,14 @@ impl Run {
         param!(inflight_delay, set_inflight_delay, gen_duration);
         param!(retransmit_rate, set_retransmit_rate, gen * 100.0);
         param!(max_udp_payload, set_max_udp_payload, gen as _);
+        param!(reorder_rate, set_reorder_rate, gen * 100.0);
+        param!(reorder_delay, set_reorder_delay, gen_duration);
+        param!(duplicate_rate, set_duplicate_rate, gen * 100.0);
+        param!(uplink_delay, set_uplink_delay, gen_duration);
+        param!(downlink_delay, set_downlink_delay, gen_duration);
+        param!(uplink_drop_rate, set_uplink_drop_rate, gen * 100.0);
+        param!(downlink_drop_rate, set_downlink_drop_rate, gen * 100.0);
+        param!(nat_rebind_interval, set_nat_rebind_interval, gen_duration);
 
         macro_rules! zero_param {
             ($name:ident, $set:ident) => {
@@ -168,6 +208,30 @@ impl Run {
         zero_param!(transmit_rate, set_transmit_rate);
         zero_param!(max_inflight, set_max_inflight);
         zero_param!(inflight_delay_threshold, set_inflight_delay_threshold);
+        zero_param!(ce_threshold, set_ce_threshold);
+
+        // RED takes precedence over CoDel if both are configured
+        if self.red_max_threshold.end > 0 {
+            let min_threshold = self.red_min_threshold.gen();
+            let max_threshold = self.red_max_threshold.gen().max(min_threshold + 1);
+            let max_probability = self.red_max_probability.gen();
+            model.set_aqm(Aqm::Red {
+                min_threshold,
+                max_threshold,
+                max_probability,
+            });
+            events.red_min_threshold = min_threshold;
+            events.red_max_threshold = max_threshold;
+            events.red_max_probability = max_probability * 100.0;
+        } else if !self.codel_target.end.is_zero() {
+            let target = self.codel_target.gen_duration();
+            let interval = self.codel_interval.gen_duration();
+            model.set_aqm(Aqm::CoDel { target, interval });
+            events.codel_target = Some(target.into());
+            events.codel_interval = Some(interval.into());
+        }
+
+        timeline::spawn_phases(model.clone(), events.clone(), phases);
 
         events.into()
     }
@@ -1,7 +1,10 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use super::CliRange;
+use super::{
+    workload::{CongestionController, Workload},
+    CliChoice, CliRange,
+};
 use jiff::SignedDuration;
 use serde::Deserialize;
 use structopt::StructOpt;
@@ -91,6 +94,62 @@ config!(
         #[default = "0"]
         inflight_delay_threshold: CliRange<u64>,
 
+        #[name = "reorder_rate"]
+        #[default = "0.0"]
+        reorder_rate: CliRange<f64>,
+
+        #[name = "reorder_delay"]
+        #[default = "0ms"]
+        reorder_delay: CliRange<SignedDuration>,
+
+        #[name = "duplicate_rate"]
+        #[default = "0.0"]
+        duplicate_rate: CliRange<f64>,
+
+        #[name = "ce_threshold"]
+        #[default = "0"]
+        ce_threshold: CliRange<u64>,
+
+        #[name = "uplink_delay"]
+        #[default = "0ms"]
+        uplink_delay: CliRange<SignedDuration>,
+
+        #[name = "downlink_delay"]
+        #[default = "0ms"]
+        downlink_delay: CliRange<SignedDuration>,
+
+        #[name = "uplink_drop_rate"]
+        #[default = "0.0"]
+        uplink_drop_rate: CliRange<f64>,
+
+        #[name = "downlink_drop_rate"]
+        #[default = "0.0"]
+        downlink_drop_rate: CliRange<f64>,
+
+        #[name = "nat_rebind_interval"]
+        #[default = "0ms"]
+        nat_rebind_interval: CliRange<SignedDuration>,
+
+        #[name = "red_min_threshold"]
+        #[default = "0"]
+        red_min_threshold: CliRange<u64>,
+
+        #[name = "red_max_threshold"]
+        #[default = "0"]
+        red_max_threshold: CliRange<u64>,
+
+        #[name = "red_max_probability"]
+        #[default = "0.1"]
+        red_max_probability: CliRange<f64>,
+
+        #[name = "codel_target"]
+        #[default = "0ms"]
+        codel_target: CliRange<SignedDuration>,
+
+        #[name = "codel_interval"]
+        #[default = "100ms"]
+        codel_interval: CliRange<SignedDuration>,
+
         #[name = "clients"]
         #[default = "1"]
         clients: CliRange<u32>,
@@ -115,6 +174,38 @@ config!(
         #[default = "4096"]
         stream_data: CliRange<u64>,
 
+        #[name = "workload"]
+        #[default = "bulk"]
+        workload: CliChoice<Workload>,
+
+        #[name = "congestion_controller"]
+        #[default = "cubic"]
+        congestion_controller: CliChoice<CongestionController>,
+
+        #[name = "request_size"]
+        #[default = "100"]
+        request_size: CliRange<u64>,
+
+        #[name = "response_size"]
+        #[default = "1000"]
+        response_size: CliRange<u64>,
+
+        #[name = "datagrams"]
+        #[default = "100"]
+        datagrams: CliRange<u32>,
+
+        #[name = "datagram_size"]
+        #[default = "100"]
+        datagram_size: CliRange<u64>,
+
+        #[name = "datagram_interval"]
+        #[default = "10ms"]
+        datagram_interval: CliRange<SignedDuration>,
+
+        #[name = "idle_duration"]
+        #[default = "60s"]
+        idle_duration: CliRange<SignedDuration>,
+
         #[name = "iterations"]
         #[default = "10000"]
         iterations: u64,
@@ -1,38 +1,80 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use super::{events, CliRange};
+use super::{
+    events,
+    workload::{CongestionController, Params, Workload},
+    CliRange,
+};
 use s2n_quic::{
     client::Connect,
     provider::{
+        congestion_controller::{Bbr, Cubic},
+        datagram,
         event::tracing::Subscriber as Tracing,
-        io::testing::{primary, rand, spawn, time, Handle, Result},
+        io::testing::{primary, rand, spawn, time, tls_client, tls_server, Handle, Result},
     },
     Client, Server,
 };
-use s2n_quic_core::{crypto::tls::testing::certificates, stream::testing::Data};
+use s2n_quic_core::crypto::tls::testing::certificates;
 use std::net::SocketAddr;
 
-pub fn server(handle: &Handle, events: events::Events) -> Result<SocketAddr> {
-    let mut server = Server::builder()
+/// Starts the endpoint with the configured congestion controller
+macro_rules! start {
+    ($builder:expr, $params:expr) => {{
+        let builder = $builder;
+        let params: &Params = $params;
+        match params.congestion_controller {
+            CongestionController::Cubic => {
+                with_datagrams!(
+                    builder.with_congestion_controller(Cubic::default())?,
+                    params
+                )
+            }
+            CongestionController::Bbr => {
+                with_datagrams!(builder.with_congestion_controller(Bbr::default())?, params)
+            }
+        }
+    }};
+}
+
+/// Enables datagrams if the workload requires them
+///
+/// Datagram support changes the transport parameters so it's only enabled when needed.
+macro_rules! with_datagrams {
+    ($builder:expr, $params:expr) => {
+        if $params.workload == Workload::Datagram {
+            $builder.with_datagram(datagram_endpoint())?.start()?
+        } else {
+            $builder.start()?
+        }
+    };
+}
+
+fn datagram_endpoint() -> datagram::default::Endpoint {
+    datagram::default::Endpoint::builder()
+        .with_send_capacity(1024)
+        .unwrap()
+        .with_recv_capacity(1024)
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+pub fn server(handle: &Handle, events: events::Events, params: &Params) -> Result<SocketAddr> {
+    let builder = Server::builder()
         .with_io(handle.builder().build().unwrap())?
-        .with_tls((certificates::CERT_PEM, certificates::KEY_PEM))?
-        .with_event((events, Tracing::default()))?
-        .start()?;
+        .with_tls(tls_server(certificates::CERT_PEM, certificates::KEY_PEM)?)?
+        .with_event((events, Tracing::default()))?;
+    let mut server: Server = start!(builder, params);
     let server_addr = server.local_addr()?;
 
-    // accept connections and echo back
+    let params = *params;
+
+    // accept connections and respond according to the workload
     spawn(async move {
-        while let Some(mut connection) = server.accept().await {
-            primary::spawn(async move {
-                while let Ok(Some(mut stream)) = connection.accept_bidirectional_stream().await {
-                    primary::spawn(async move {
-                        while let Ok(Some(chunk)) = stream.receive().await {
-                            let _ = chunk;
-                        }
-                    });
-                }
-            });
+        while let Some(connection) = server.accept().await {
+            primary::spawn(params.server(connection));
         }
     });
 
@@ -45,14 +87,13 @@ pub fn client(
     servers: &[SocketAddr],
     count: usize,
     delay: CliRange<jiff::SignedDuration>,
-    streams: CliRange<u32>,
-    stream_data: CliRange<u64>,
+    params: &Params,
 ) -> Result {
-    let client = Client::builder()
+    let builder = Client::builder()
         .with_io(handle.builder().build().unwrap())?
-        .with_tls(certificates::CERT_PEM)?
-        .with_event((events, Tracing::default()))?
-        .start()?;
+        .with_tls(tls_client(certificates::CERT_PEM)?)?
+        .with_event((events, Tracing::default()))?;
+    let client: Client = start!(builder, params);
 
     let mut total_delay = core::time::Duration::ZERO;
 
@@ -62,6 +103,7 @@ pub fn client(
         // pick a random server to connect to
         let server_addr = *rand::pick(servers);
         let delay = total_delay;
+        let params = *params;
 
         let client = client.clone();
         primary::spawn(async move {
@@ -70,35 +112,9 @@ pub fn client(
             }
 
             let connect = Connect::new(server_addr).with_server_name("localhost");
-            let mut connection = client.connect(connect).await?;
-
-            for _ in 0..streams.gen() {
-                let stream = connection.open_bidirectional_stream().await?;
-                primary::spawn(async move {
-                    let (mut recv, mut send) = stream.split();
-
-                    let mut send_data = Data::new(stream_data.gen());
-
-                    let mut recv_data = send_data;
-                    primary::spawn(async move {
-                        while let Some(chunk) = recv.receive().await? {
-                            recv_data.receive(&[chunk]);
-                        }
-
-                        <s2n_quic::stream::Result<()>>::Ok(())
-                    });
-
-                    while let Some(chunk) = send_data.send_one(usize::MAX) {
-                        send.send(chunk).await?;
-                    }
-
-                    <s2n_quic::stream::Result<()>>::Ok(())
-                })
-                .await
-                .unwrap()?;
-            }
+            let connection = client.connect(connect).await?;
 
-            <s2n_quic::stream::Result<()>>::Ok(())
+            params.client(connection).await
         });
     }
 
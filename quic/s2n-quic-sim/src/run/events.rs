@@ -1,13 +1,13 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::stats::{Connection, Parameters};
+use crate::stats::{Connection, Parameters, PhaseCounts};
 use core::time::Duration;
 use once_cell::sync::Lazy;
 use s2n_quic::{
     connection,
     provider::{
-        event,
+        event::{self, Timestamp},
         io::testing::{primary, time},
     },
 };
@@ -71,6 +71,27 @@ impl<T: Default> OrDefault<T> for Option<T> {
     }
 }
 
+impl Events {
+    /// Updates the counts for each phase that is currently active
+    #[inline]
+    fn on_phase<F: Fn(&mut PhaseCounts)>(&self, conn: &mut Connection, f: F) {
+        let now = now();
+        for (phase, counts) in self.params.phases.iter().zip(&mut conn.phases) {
+            if phase.contains(now) {
+                f(counts);
+            }
+        }
+    }
+
+    #[inline]
+    fn on_phase_progress(&self, conn: &mut Connection, timestamp: Timestamp, bytes: usize) {
+        let now = timestamp.duration_since_start();
+        for (phase, counts) in self.params.phases.iter().zip(&mut conn.phases) {
+            counts.stream_progress(phase, now, bytes);
+        }
+    }
+}
+
 impl event::Subscriber for Events {
     type ConnectionContext = PrimaryContext<DumpOnDrop<Connection>>;
 
@@ -85,6 +106,7 @@ impl event::Subscriber for Events {
         let mut conn = Connection {
             seed,
             start_time: Some(now().into()),
+            phases: vec![Default::default(); self.params.phases.len()],
             ..Default::default()
         };
 
@@ -132,6 +154,7 @@ impl event::Subscriber for Events {
         event: &event::events::PacketLost,
     ) {
         context.loss.or_default().inc_packet(&event.packet_header);
+        self.on_phase(context, |counts| counts.lost_packets += 1);
     }
 
     #[inline]
@@ -142,6 +165,7 @@ impl event::Subscriber for Events {
         _event: &event::events::Congestion,
     ) {
         context.congestion += 1;
+        self.on_phase(context, |counts| counts.congestion += 1);
     }
 
     #[inline]
@@ -219,6 +243,7 @@ impl event::Subscriber for Events {
             .rx
             .or_default()
             .stream_progress(meta.timestamp, event.bytes);
+        self.on_phase_progress(context, meta.timestamp, event.bytes);
     }
 
     #[inline]
@@ -232,6 +257,7 @@ impl event::Subscriber for Events {
             .tx
             .or_default()
             .stream_progress(meta.timestamp, event.bytes);
+        self.on_phase_progress(context, meta.timestamp, event.bytes);
     }
 
     #[inline]
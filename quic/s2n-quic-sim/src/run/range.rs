@@ -143,3 +143,94 @@ where
         v.parse().map_err(E::custom)
     }
 }
+
+/// A set of values, one of which is picked for each simulation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CliChoice<T>(Vec<T>);
+
+impl<T: fmt::Display> fmt::Display for CliChoice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, value) in self.0.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(",")?;
+            }
+            value.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Copy> CliChoice<T> {
+    pub fn gen(&self) -> T {
+        // avoid consuming randomness if there's only a single option
+        if let [value] = self.0.as_slice() {
+            return *value;
+        }
+
+        *rand::pick(&self.0)
+    }
+}
+
+impl<T: FromStr> FromStr for CliChoice<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s
+            .split(',')
+            .map(|value| value.trim().parse())
+            .collect::<Result<_, _>>()?;
+        Ok(Self(values))
+    }
+}
+
+impl<'de, T> Deserialize<'de> for CliChoice<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: core::fmt::Display,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ChoiceVisitor::<T>(PhantomData))
+    }
+}
+
+struct ChoiceVisitor<T>(PhantomData<T>);
+
+impl<'de, T> serde::de::Visitor<'de> for ChoiceVisitor<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: core::fmt::Display,
+{
+    type Value = CliChoice<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a list of values or individual value")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let mut values = vec![];
+        while let Some(value) = seq.next_element::<String>()? {
+            values.push(value.parse().map_err(A::Error::custom)?);
+        }
+
+        if values.is_empty() {
+            return Err(A::Error::custom("expected at least one value"));
+        }
+
+        Ok(CliChoice(values))
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::CliRange;
+use crate::{stats, Result};
+use core::time::Duration;
+use jiff::SignedDuration;
+use s2n_quic::provider::io::testing::{spawn, time, Model};
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+/// The `[[timeline]]` section of a plan
+///
+/// All other sections of the plan are ignored so the plan file can be passed as-is.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Timeline {
+    #[serde(default)]
+    timeline: Vec<Phase>,
+}
+
+impl Timeline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let file = fs::read_to_string(path)?;
+        let timeline = toml::from_str(&file)?;
+        Ok(timeline)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timeline.is_empty()
+    }
+
+    /// Generates the concrete start/end times and network values for each phase
+    pub fn gen(&self) -> Vec<Scheduled> {
+        self.timeline.iter().map(Phase::gen).collect()
+    }
+}
+
+macro_rules! phase {
+    ($($kind:ident $name:ident => $set:ident),* $(,)?) => {
+        /// A period of the simulation in which the network parameters are overridden
+        #[derive(Clone, Debug, Deserialize)]
+        #[serde(deny_unknown_fields)]
+        pub struct Phase {
+            /// When the phase starts, relative to the start of the simulation
+            start: CliRange<SignedDuration>,
+            /// How long the phase lasts
+            ///
+            /// If not specified, the phase lasts until the end of the simulation.
+            #[serde(default)]
+            duration: Option<CliRange<SignedDuration>>,
+            /// Moves all of the clients to new ports when the phase starts, which blackholes
+            /// the current paths and forces the connections to migrate
+            #[serde(default)]
+            rebind: bool,
+            $(
+                #[serde(default)]
+                $name: Option<CliRange<phase!(@config $kind)>>,
+            )*
+        }
+
+        #[derive(Clone, Copy, Debug, Default)]
+        struct Overrides {
+            $(
+                $name: Option<phase!(@value $kind)>,
+            )*
+        }
+
+        impl Phase {
+            fn gen(&self) -> Scheduled {
+                let start = self.start.gen_duration();
+                let end = self.duration.map(|duration| start + duration.gen_duration());
+
+                let overrides = Overrides {
+                    $(
+                        $name: self.$name.map(|v| phase!(@gen $kind v)),
+                    )*
+                };
+
+                Scheduled {
+                    start,
+                    end,
+                    rebind: self.rebind,
+                    overrides,
+                }
+            }
+        }
+
+        /// Applies the base parameters to the model, followed by the overrides of each active
+        /// phase in the order they were declared
+        fn apply(model: &Model, base: &stats::Parameters, active: &[&Scheduled]) {
+            $(
+                let value = active
+                    .iter()
+                    .rev()
+                    .find_map(|phase| phase.overrides.$name)
+                    .unwrap_or_else(|| phase!(@base $kind base.$name));
+                model.$set(value);
+            )*
+        }
+    };
+    (@config rate) => { f64 };
+    (@config duration) => { SignedDuration };
+    (@config count) => { u64 };
+    (@value rate) => { f64 };
+    (@value duration) => { Duration };
+    (@value count) => { u64 };
+    (@gen rate $v:ident) => { $v.gen() };
+    (@gen duration $v:ident) => { $v.gen_duration() };
+    (@gen count $v:ident) => { $v.gen() };
+    (@base rate $v:expr) => { $v / 100.0 };
+    (@base duration $v:expr) => { $v.unwrap_or_default().as_duration() };
+    (@base count $v:expr) => { $v };
+}
+
+phase!(
+    rate drop_rate => set_drop_rate,
+    rate corrupt_rate => set_corrupt_rate,
+    duration delay => set_delay,
+    duration jitter => set_jitter,
+    duration network_jitter => set_network_jitter,
+    duration inflight_delay => set_inflight_delay,
+    count transmit_rate => set_transmit_rate,
+    count max_inflight => set_max_inflight,
+    rate reorder_rate => set_reorder_rate,
+    rate duplicate_rate => set_duplicate_rate,
+    duration uplink_delay => set_uplink_delay,
+    duration downlink_delay => set_downlink_delay,
+    rate uplink_drop_rate => set_uplink_drop_rate,
+    rate downlink_drop_rate => set_downlink_drop_rate,
+);
+
+/// A phase with concrete values for a single simulation
+#[derive(Clone, Copy, Debug)]
+pub struct Scheduled {
+    start: Duration,
+    end: Option<Duration>,
+    rebind: bool,
+    overrides: Overrides,
+}
+
+impl Scheduled {
+    fn is_active(&self, now: Duration) -> bool {
+        self.start <= now && self.end.is_none_or(|end| now < end)
+    }
+}
+
+impl From<&Scheduled> for stats::Phase {
+    fn from(phase: &Scheduled) -> Self {
+        Self {
+            start: Some(phase.start.into()),
+            end: phase.end.map(|end| end.into()),
+        }
+    }
+}
+
+/// Spawns a task that updates the model at each phase boundary
+///
+/// `base` holds the values that were generated for the simulation and is restored once a phase
+/// ends.
+pub fn spawn_phases(model: Model, base: stats::Parameters, phases: Vec<Scheduled>) {
+    if phases.is_empty() {
+        return;
+    }
+
+    let mut boundaries: Vec<_> = phases
+        .iter()
+        .flat_map(|phase| core::iter::once(phase.start).chain(phase.end))
+        .collect();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    spawn(async move {
+        for boundary in boundaries {
+            let now = unsafe { time::now().as_duration() };
+            if let Some(delay) = boundary.checked_sub(now) {
+                time::delay(delay).await;
+            }
+
+            let active: Vec<_> = phases
+                .iter()
+                .filter(|phase| phase.is_active(boundary))
+                .collect();
+
+            apply(&model, &base, &active);
+
+            if phases
+                .iter()
+                .any(|phase| phase.rebind && phase.start == boundary)
+            {
+                model.rebind_clients();
+            }
+        }
+    });
+}
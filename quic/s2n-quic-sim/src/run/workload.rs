@@ -0,0 +1,293 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::CliRange;
+use crate::stats;
+use bytes::Bytes;
+use core::{fmt, str::FromStr, task::Poll};
+use jiff::SignedDuration;
+use s2n_quic::{
+    connection::Connection,
+    provider::{
+        datagram::default::{Receiver, Sender},
+        io::testing::{primary, spawn, time},
+    },
+    stream::{self, BidirectionalStream},
+};
+use s2n_quic_core::stream::testing::Data;
+
+/// The application behavior of each connection
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Workload {
+    /// Sends `stream_data` bytes on each of the `streams`, one after another
+    Bulk,
+    /// Sends `streams` sequential requests of `request_size` bytes, each of which is answered
+    /// with `response_size` bytes
+    Rpc,
+    /// Sends `stream_data` bytes on each of the `streams`, all at once
+    Concurrent,
+    /// Sends `datagrams` unreliable datagrams of `datagram_size` bytes every `datagram_interval`
+    Datagram,
+    /// Keeps the connection alive while idle for `idle_duration` before sending `stream_data`
+    /// bytes on a single stream
+    Idle,
+}
+
+impl Workload {
+    pub fn id(self) -> u32 {
+        match self {
+            Self::Bulk => 0,
+            Self::Rpc => 1,
+            Self::Concurrent => 2,
+            Self::Datagram => 3,
+            Self::Idle => 4,
+        }
+    }
+}
+
+impl fmt::Display for Workload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        stats::WORKLOADS[self.id() as usize].fmt(f)
+    }
+}
+
+impl FromStr for Workload {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match stats::WORKLOADS.iter().position(|name| *name == s) {
+            Some(0) => Self::Bulk,
+            Some(1) => Self::Rpc,
+            Some(2) => Self::Concurrent,
+            Some(3) => Self::Datagram,
+            Some(4) => Self::Idle,
+            _ => return Err(anyhow::anyhow!("invalid workload: {s:?}")),
+        })
+    }
+}
+
+/// The congestion controller used by both endpoints
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CongestionController {
+    Cubic,
+    Bbr,
+}
+
+impl CongestionController {
+    pub fn id(self) -> u32 {
+        match self {
+            Self::Cubic => 0,
+            Self::Bbr => 1,
+        }
+    }
+}
+
+impl fmt::Display for CongestionController {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        stats::CONGESTION_CONTROLLERS[self.id() as usize].fmt(f)
+    }
+}
+
+impl FromStr for CongestionController {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(
+            match stats::CONGESTION_CONTROLLERS
+                .iter()
+                .position(|name| *name == s)
+            {
+                Some(0) => Self::Cubic,
+                Some(1) => Self::Bbr,
+                _ => return Err(anyhow::anyhow!("invalid congestion controller: {s:?}")),
+            },
+        )
+    }
+}
+
+/// The workload for a single simulation, along with the ranges used to generate the values for
+/// each connection
+#[derive(Clone, Copy, Debug)]
+pub struct Params {
+    pub workload: Workload,
+    pub congestion_controller: CongestionController,
+    pub streams: CliRange<u32>,
+    pub stream_data: CliRange<u64>,
+    pub request_size: CliRange<u64>,
+    pub response_size: CliRange<u64>,
+    pub datagrams: CliRange<u32>,
+    pub datagram_size: CliRange<u64>,
+    pub datagram_interval: CliRange<SignedDuration>,
+    pub idle_duration: CliRange<SignedDuration>,
+}
+
+impl Params {
+    pub async fn client(self, mut connection: Connection) -> stream::Result<()> {
+        match self.workload {
+            Workload::Bulk => {
+                for _ in 0..self.streams.gen() {
+                    let stream = connection.open_bidirectional_stream().await?;
+                    let len = self.stream_data.gen();
+                    primary::spawn(send_and_drain(stream, len)).await.unwrap()?;
+                }
+            }
+            Workload::Rpc => {
+                for _ in 0..self.streams.gen() {
+                    let stream = connection.open_bidirectional_stream().await?;
+                    let request_size = self.request_size.gen();
+                    let response_size = self.response_size.gen();
+                    primary::spawn(request(stream, request_size, response_size))
+                        .await
+                        .unwrap()?;
+                }
+            }
+            Workload::Concurrent => {
+                let mut streams = vec![];
+                for _ in 0..self.streams.gen() {
+                    let stream = connection.open_bidirectional_stream().await?;
+                    let len = self.stream_data.gen();
+                    streams.push(primary::spawn(send_and_drain(stream, len)));
+                }
+
+                for stream in streams {
+                    stream.await.unwrap()?;
+                }
+            }
+            Workload::Datagram => {
+                for _ in 0..self.datagrams.gen() {
+                    let len = self.datagram_size.gen() as usize;
+                    let datagram = Bytes::from(vec![0; len]);
+
+                    // the datagram is dropped if the send queue is full, same as a lossy network
+                    let _ = connection
+                        .datagram_mut(|sender: &mut Sender| sender.send_datagram(datagram));
+
+                    let interval = self.datagram_interval.gen_duration();
+                    if !interval.is_zero() {
+                        time::delay(interval).await;
+                    }
+                }
+            }
+            Workload::Idle => {
+                connection.keep_alive(true)?;
+
+                let idle_duration = self.idle_duration.gen_duration();
+                if !idle_duration.is_zero() {
+                    time::delay(idle_duration).await;
+                }
+
+                let stream = connection.open_bidirectional_stream().await?;
+                let len = self.stream_data.gen();
+                primary::spawn(send_and_drain(stream, len)).await.unwrap()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn server(self, mut connection: Connection) {
+        let workload = self.workload;
+
+        if workload == Workload::Datagram {
+            let handle = connection.handle();
+            spawn(async move {
+                loop {
+                    let res = core::future::poll_fn(|cx| {
+                        match handle.datagram_mut(|recv: &mut Receiver| recv.poll_recv_datagram(cx))
+                        {
+                            Ok(poll) => poll.map(|res| res.is_ok()),
+                            Err(_) => Poll::Ready(false),
+                        }
+                    })
+                    .await;
+
+                    if !res {
+                        break;
+                    }
+                }
+            });
+        }
+
+        while let Ok(Some(mut stream)) = connection.accept_bidirectional_stream().await {
+            primary::spawn(async move {
+                if workload == Workload::Rpc {
+                    let _ = respond(stream).await;
+                } else {
+                    while let Ok(Some(chunk)) = stream.receive().await {
+                        let _ = chunk;
+                    }
+                }
+            });
+        }
+    }
+}
+
+async fn send_and_drain(stream: BidirectionalStream, len: u64) -> stream::Result<()> {
+    let (mut recv, mut send) = stream.split();
+
+    let mut send_data = Data::new(len);
+
+    let mut recv_data = send_data;
+    primary::spawn(async move {
+        while let Some(chunk) = recv.receive().await? {
+            recv_data.receive(&[chunk]);
+        }
+
+        <stream::Result<()>>::Ok(())
+    });
+
+    while let Some(chunk) = send_data.send_one(usize::MAX) {
+        send.send(chunk).await?;
+    }
+
+    Ok(())
+}
+
+/// Sends a request and waits for the entire response
+///
+/// The request is prefixed with the size of the expected response.
+async fn request(
+    mut stream: BidirectionalStream,
+    request_size: u64,
+    response_size: u64,
+) -> stream::Result<()> {
+    stream
+        .send(Bytes::copy_from_slice(&response_size.to_be_bytes()))
+        .await?;
+
+    let mut request = Data::new(request_size);
+    while let Some(chunk) = request.send_one(usize::MAX) {
+        stream.send(chunk).await?;
+    }
+    stream.finish()?;
+
+    let mut response = Data::new(response_size);
+    while let Some(chunk) = stream.receive().await? {
+        response.receive(&[chunk]);
+    }
+
+    Ok(())
+}
+
+/// Reads a request and responds with the requested number of bytes
+async fn respond(mut stream: BidirectionalStream) -> stream::Result<()> {
+    let mut prefix = vec![];
+    while let Some(chunk) = stream.receive().await? {
+        if prefix.len() < 8 {
+            let len = (8 - prefix.len()).min(chunk.len());
+            prefix.extend_from_slice(&chunk[..len]);
+        }
+    }
+
+    let Ok(prefix) = <[u8; 8]>::try_from(prefix) else {
+        return Ok(());
+    };
+
+    let mut response = Data::new(u64::from_be_bytes(prefix));
+    while let Some(chunk) = response.send_one(usize::MAX) {
+        stream.send(chunk).await?;
+    }
+    stream.finish()?;
+
+    Ok(())
+}
@@ -123,7 +123,7 @@ impl Setup {
     }
 }
 
-#[derive(Clone, Copy, Message)]
+#[derive(Clone, Message)]
 pub struct Parameters {
     #[prost(uint64, tag = "1")]
     pub seed: u64,
@@ -155,6 +155,40 @@ pub struct Parameters {
     pub inflight_delay: Option<Duration>,
     #[prost(uint64, tag = "15")]
     pub inflight_delay_threshold: u64,
+    #[prost(double, tag = "16")]
+    pub reorder_rate: f64,
+    #[prost(message, tag = "17")]
+    pub reorder_delay: Option<Duration>,
+    #[prost(double, tag = "18")]
+    pub duplicate_rate: f64,
+    #[prost(uint64, tag = "19")]
+    pub ce_threshold: u64,
+    #[prost(message, tag = "20")]
+    pub uplink_delay: Option<Duration>,
+    #[prost(message, tag = "21")]
+    pub downlink_delay: Option<Duration>,
+    #[prost(double, tag = "22")]
+    pub uplink_drop_rate: f64,
+    #[prost(double, tag = "23")]
+    pub downlink_drop_rate: f64,
+    #[prost(message, tag = "24")]
+    pub nat_rebind_interval: Option<Duration>,
+    #[prost(uint64, tag = "25")]
+    pub red_min_threshold: u64,
+    #[prost(uint64, tag = "26")]
+    pub red_max_threshold: u64,
+    #[prost(double, tag = "27")]
+    pub red_max_probability: f64,
+    #[prost(message, tag = "28")]
+    pub codel_target: Option<Duration>,
+    #[prost(message, tag = "29")]
+    pub codel_interval: Option<Duration>,
+    #[prost(message, repeated, tag = "30")]
+    pub phases: Vec<Phase>,
+    #[prost(uint32, tag = "31")]
+    pub workload: u32,
+    #[prost(uint32, tag = "32")]
+    pub congestion_controller: u32,
 }
 
 impl From<Parameters> for Stats {
@@ -169,7 +203,7 @@ impl Parameters {
     }
 }
 
-#[derive(Clone, Copy, Message)]
+#[derive(Clone, Message)]
 pub struct Connection {
     #[prost(uint64, optional, tag = "1")]
     pub client_id: Option<u64>,
@@ -211,6 +245,8 @@ pub struct Connection {
     pub min_rtt: Option<Duration>,
     #[prost(message, tag = "20")]
     pub smoothed_rtt: Option<Duration>,
+    #[prost(message, repeated, tag = "21")]
+    pub phases: Vec<PhaseCounts>,
 }
 
 impl From<Connection> for Stats {
@@ -268,6 +304,8 @@ pub struct Counts {
     pub stream_progress_start: Option<Duration>,
     #[prost(message, tag = "9")]
     pub stream_progress_end: Option<Duration>,
+    #[prost(uint64, tag = "10")]
+    pub datagrams: u64,
 }
 
 impl Counts {
@@ -291,6 +329,9 @@ impl Counts {
             StreamDataBlocked { .. } => {
                 self.stream_data_blocked += 1;
             }
+            Datagram { .. } => {
+                self.datagrams += 1;
+            }
             _ => {}
         }
     }
@@ -319,6 +360,58 @@ impl Counts {
     }
 }
 
+/// A timeline phase with the concrete times used in a simulation
+#[derive(Clone, Copy, Message, PartialEq, Eq)]
+pub struct Phase {
+    #[prost(message, tag = "1")]
+    pub start: Option<Duration>,
+    /// Unset if the phase lasts until the end of the simulation
+    #[prost(message, tag = "2")]
+    pub end: Option<Duration>,
+}
+
+impl Phase {
+    #[inline]
+    pub fn contains(&self, now: core::time::Duration) -> bool {
+        self.start.unwrap_or_default().as_duration() <= now
+            && self.end.is_none_or(|end| now < end.as_duration())
+    }
+
+    #[inline]
+    pub fn duration(&self) -> Option<core::time::Duration> {
+        self.end?
+            .as_duration()
+            .checked_sub(self.start.unwrap_or_default().as_duration())
+    }
+}
+
+/// Connection metrics for a single timeline phase
+#[derive(Clone, Copy, Message, PartialEq, Eq)]
+pub struct PhaseCounts {
+    /// The number of stream bytes sent or received during the phase
+    #[prost(uint64, tag = "1")]
+    pub stream_progress: u64,
+    #[prost(uint64, tag = "2")]
+    pub lost_packets: u64,
+    #[prost(uint64, tag = "3")]
+    pub congestion: u64,
+    /// The first time stream progress was made after the phase ended
+    #[prost(message, tag = "4")]
+    pub recovered: Option<Duration>,
+}
+
+impl PhaseCounts {
+    #[inline]
+    pub fn stream_progress(&mut self, phase: &Phase, now: core::time::Duration, bytes: usize) {
+        if phase.contains(now) {
+            self.stream_progress += bytes as u64;
+        } else if self.recovered.is_none() && phase.end.is_some_and(|end| end.as_duration() <= now)
+        {
+            self.recovered = Some(now.into());
+        }
+    }
+}
+
 #[derive(Clone, Copy, Message, PartialEq, Eq)]
 pub struct Handshake {
     #[prost(message, tag = "1")]
@@ -357,6 +450,7 @@ impl From<Duration> for core::time::Duration {
 }
 
 type Q = fn(&Parameters, &Connection, &[Connection]) -> Option<f64>;
+type PhaseQ = fn(&Phase, &PhaseCounts, &Connection) -> Option<f64>;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Type {
@@ -365,6 +459,8 @@ pub enum Type {
     Duration,
     Throughput,
     Bool,
+    /// A value with a name for each index
+    Enum(&'static [&'static str]),
 }
 
 impl Type {
@@ -376,6 +472,7 @@ impl Type {
             Self::Duration => "%Qms",
             Self::Throughput => "~s",
             Self::Bool => "c",
+            Self::Enum(_) => "d",
         }
     }
 
@@ -419,12 +516,26 @@ impl Type {
                 "false" | "FALSE" | "0" => Ok(0.0),
                 _ => Err(anyhow::anyhow!("invalid bool: {:?}", value)),
             },
+            Self::Enum(names) => {
+                if let Some(idx) = names.iter().position(|name| *name == value) {
+                    Ok(idx as _)
+                } else {
+                    let value: u64 = value.parse()?;
+                    Ok(value as _)
+                }
+            }
         }
     }
 }
 
 use Type::{Bool as B, Duration as T, Integer as I, Percent as P, Throughput as Tpt};
 
+/// The names of the `workload` values, in the order of their ids
+pub static WORKLOADS: &[&str] = &["bulk", "rpc", "concurrent", "datagram", "idle"];
+
+/// The names of the `congestion_controller` values, in the order of their ids
+pub static CONGESTION_CONTROLLERS: &[&str] = &["cubic", "bbr"];
+
 static QUERIES: &[(&str, Type, Q)] = &[
     ("conn.duration", T, |_params, conn, _conns| {
         let duration = conn.duration()?;
@@ -513,6 +624,9 @@ static QUERIES: &[(&str, Type, Q)] = &[
     ("conn.tx.stream-throughput", Tpt, |_params, conn, _conns| {
         conn.tx?.stream_throughput()
     }),
+    ("conn.tx.datagrams", I, |_params, conn, _conns| {
+        Some(conn.tx.unwrap_or_default().datagrams as _)
+    }),
     ("conn.rx.packets", I, |_params, conn, _conns| {
         Some(conn.rx.unwrap_or_default().packets() as _)
     }),
@@ -537,6 +651,9 @@ static QUERIES: &[(&str, Type, Q)] = &[
     ("conn.rx.stream-throughput", Tpt, |_params, conn, _conns| {
         conn.rx?.stream_throughput()
     }),
+    ("conn.rx.datagrams", I, |_params, conn, _conns| {
+        Some(conn.rx.unwrap_or_default().datagrams as _)
+    }),
     ("conn.lost.packets", I, |_params, conn, _conns| {
         Some(conn.loss.unwrap_or_default().packets() as _)
     }),
@@ -565,6 +682,16 @@ static QUERIES: &[(&str, Type, Q)] = &[
             None
         }
     }),
+    (
+        "net.workload",
+        Type::Enum(WORKLOADS),
+        |params, _conn, _conns| Some(params.workload as f64),
+    ),
+    (
+        "net.congestion-controller",
+        Type::Enum(CONGESTION_CONTROLLERS),
+        |params, _conn, _conns| Some(params.congestion_controller as f64),
+    ),
     ("net.drop-rate", P, |params, _conn, _conns| {
         Some(params.drop_rate)
     }),
@@ -630,16 +757,149 @@ static QUERIES: &[(&str, Type, Q)] = &[
                 .as_secs_f64(),
         )
     }),
+    ("net.reorder-rate", P, |params, _conn, _conns| {
+        Some(params.reorder_rate)
+    }),
+    ("net.reorder-delay", T, |params, _conn, _conns| {
+        Some(
+            params
+                .reorder_delay
+                .unwrap_or_default()
+                .as_duration()
+                .as_secs_f64(),
+        )
+    }),
+    ("net.duplicate-rate", P, |params, _conn, _conns| {
+        Some(params.duplicate_rate)
+    }),
+    ("net.ce-threshold", I, |params, _conn, _conns| {
+        Some(params.ce_threshold as f64)
+    }),
+    ("net.uplink-delay", T, |params, _conn, _conns| {
+        Some(
+            params
+                .uplink_delay
+                .unwrap_or_default()
+                .as_duration()
+                .as_secs_f64(),
+        )
+    }),
+    ("net.downlink-delay", T, |params, _conn, _conns| {
+        Some(
+            params
+                .downlink_delay
+                .unwrap_or_default()
+                .as_duration()
+                .as_secs_f64(),
+        )
+    }),
+    ("net.uplink-drop-rate", P, |params, _conn, _conns| {
+        Some(params.uplink_drop_rate)
+    }),
+    ("net.downlink-drop-rate", P, |params, _conn, _conns| {
+        Some(params.downlink_drop_rate)
+    }),
+    ("net.nat-rebind-interval", T, |params, _conn, _conns| {
+        Some(
+            params
+                .nat_rebind_interval
+                .unwrap_or_default()
+                .as_duration()
+                .as_secs_f64(),
+        )
+    }),
+    ("net.red-min-threshold", I, |params, _conn, _conns| {
+        Some(params.red_min_threshold as f64)
+    }),
+    ("net.red-max-threshold", I, |params, _conn, _conns| {
+        Some(params.red_max_threshold as f64)
+    }),
+    ("net.red-max-probability", P, |params, _conn, _conns| {
+        Some(params.red_max_probability)
+    }),
+    ("net.codel-target", T, |params, _conn, _conns| {
+        Some(
+            params
+                .codel_target
+                .unwrap_or_default()
+                .as_duration()
+                .as_secs_f64(),
+        )
+    }),
+    ("net.codel-interval", T, |params, _conn, _conns| {
+        Some(
+            params
+                .codel_interval
+                .unwrap_or_default()
+                .as_duration()
+                .as_secs_f64(),
+        )
+    }),
 ];
 
-pub static QUERY_NAMES: Lazy<Vec<&'static str>> =
-    Lazy::new(|| QUERIES.iter().map(|q| q.0).collect());
+/// Queries for a single timeline phase
+///
+/// The phase is selected with an index after the `phase` component (e.g.
+/// `conn.phase.1.recovery-time`) and defaults to the first phase.
+static PHASE_QUERIES: &[(&str, Type, PhaseQ)] = &[
+    ("phase.start", T, |phase, _counts, _conn| {
+        Some(phase.start?.as_duration().as_secs_f64())
+    }),
+    ("phase.end", T, |phase, _counts, _conn| {
+        Some(phase.end?.as_duration().as_secs_f64())
+    }),
+    ("phase.duration", T, |phase, _counts, _conn| {
+        Some(phase.duration()?.as_secs_f64())
+    }),
+    ("conn.phase.stream-progress", I, |_phase, counts, _conn| {
+        Some(counts.stream_progress as _)
+    }),
+    (
+        "conn.phase.stream-throughput",
+        Tpt,
+        |phase, counts, conn| {
+            let start = phase.start?.as_duration();
+            let end = phase.end.or(conn.end_time)?.as_duration();
+            let duration = end.checked_sub(start)?;
+            Some(counts.stream_progress as f64 / duration.as_secs_f64())
+        },
+    ),
+    ("conn.phase.lost-packets", I, |_phase, counts, _conn| {
+        Some(counts.lost_packets as _)
+    }),
+    ("conn.phase.congestion", I, |_phase, counts, _conn| {
+        Some(counts.congestion as _)
+    }),
+    ("conn.phase.recovery-time", T, |phase, counts, conn| {
+        let end = phase.end?.as_duration();
+        // connections that started after the phase didn't need to recover
+        if conn.start_time.unwrap_or_default().as_duration() >= end {
+            return None;
+        }
+        let recovered = counts.recovered?.as_duration();
+        Some(recovered.checked_sub(end)?.as_secs_f64())
+    }),
+];
+
+pub static QUERY_NAMES: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    QUERIES
+        .iter()
+        .map(|q| q.0)
+        .chain(PHASE_QUERIES.iter().map(|q| q.0))
+        .collect()
+});
 
 #[derive(Clone, Copy)]
 pub struct Query {
     pub name: &'static str,
     pub ty: Type,
-    pub query: Q,
+    kind: Kind,
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Global(Q),
+    Phase { index: usize, query: PhaseQ },
 }
 
 impl fmt::Debug for Query {
@@ -653,7 +913,13 @@ impl fmt::Debug for Query {
 
 impl fmt::Display for Query {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.name.fmt(f)
+        match self.kind {
+            Kind::Phase { index, .. } if index > 0 => {
+                let name = self.name.replacen("phase", &format!("phase.{index}"), 1);
+                name.fmt(f)
+            }
+            _ => self.name.fmt(f),
+        }
     }
 }
 
@@ -664,7 +930,14 @@ impl Query {
         conn: &Connection,
         conns: &[Connection],
     ) -> Option<f64> {
-        (self.query)(params, conn, conns)
+        match self.kind {
+            Kind::Global(query) => query(params, conn, conns),
+            Kind::Phase { index, query } => {
+                let phase = params.phases.get(index)?;
+                let counts = conn.phases.get(index).copied().unwrap_or_default();
+                query(phase, &counts, conn)
+            }
+        }
     }
 }
 
@@ -672,16 +945,41 @@ impl FromStr for Query {
     type Err = anyhow::Error;
 
     fn from_str(path: &str) -> Result<Self, Self::Err> {
-        for (name, ty, query) in QUERIES.iter().copied() {
-            if name
-                .split('-')
+        fn matches(name: &str, path: &str) -> bool {
+            name.split('-')
                 .eq(path.split('_').flat_map(|v| v.split('-')))
-            {
-                return Ok(Self { name, ty, query });
+        }
+
+        for (name, ty, query) in QUERIES.iter().copied() {
+            if matches(name, path) {
+                let kind = Kind::Global(query);
+                return Ok(Self { name, ty, kind });
+            }
+        }
+
+        // strip the phase index, if any
+        let mut components: Vec<_> = path.split('.').collect();
+        let mut index = 0;
+        if let Some(pos) = components.iter().position(|c| *c == "phase") {
+            if let Some(value) = components.get(pos + 1).and_then(|c| c.parse().ok()) {
+                index = value;
+                components.remove(pos + 1);
+            }
+        }
+        let phase_path = components.join(".");
+
+        for (name, ty, query) in PHASE_QUERIES.iter().copied() {
+            if matches(name, &phase_path) {
+                let kind = Kind::Phase { index, query };
+                return Ok(Self { name, ty, kind });
             }
         }
 
-        Err(anyhow::anyhow!("invalid query: {}", path))
+        Err(anyhow::anyhow!(
+            "invalid query: {}; expected one of {}",
+            path,
+            QUERY_NAMES.join(", ")
+        ))
     }
 }
 
@@ -61,6 +61,16 @@ impl<L: ConfigLoader> ConfigLoader for Client<L> {
     fn load(&mut self, cx: crate::ConnectionContext) -> s2n_tls::config::Config {
         self.loader.load(cx)
     }
+
+    #[inline]
+    fn supports_client_identity(&self) -> bool {
+        self.loader.supports_client_identity()
+    }
+
+    #[inline]
+    fn publish_events(&mut self, publisher: &mut dyn s2n_quic_core::event::EndpointPublisher) {
+        self.loader.publish_events(publisher)
+    }
 }
 
 pub struct Builder {
@@ -211,12 +221,63 @@ impl<L: ConfigLoader> tls::Endpoint for Client<L> {
     ) -> Self::Session {
         let config = self.loader.load(crate::ConnectionContext {
             server_name: Some(&server_name),
+            client_identity: None,
         });
         self.params.with(params, |params| {
             Session::new(endpoint::Type::Client, config, params, Some(server_name)).unwrap()
         })
     }
 
+    fn new_client_session_with_options<Params: EncoderValue>(
+        &mut self,
+        params: &Params,
+        server_name: ServerName,
+        options: &tls::ClientOptions,
+    ) -> Result<Self::Session, tls::Error> {
+        if options.identity.is_some() && !self.loader.supports_client_identity() {
+            return Err(tls::Error::INTERNAL_ERROR.with_reason(
+                "the config loader does not support per-connection client identities",
+            ));
+        }
+
+        let config = self.loader.load(crate::ConnectionContext {
+            server_name: Some(&server_name),
+            client_identity: options.identity.as_ref(),
+        });
+
+        let mut session = self
+            .params
+            .with(params, |params| {
+                Session::new(endpoint::Type::Client, config, params, Some(server_name))
+            })
+            .map_err(|_| tls::Error::INTERNAL_ERROR.with_reason("could not create TLS session"))?;
+
+        if let Some(protocols) = options.application_protocols.as_ref() {
+            session
+                .connection
+                .set_application_protocol_preference(protocols)
+                .map_err(|_| {
+                    tls::Error::INTERNAL_ERROR.with_reason("invalid application protocols")
+                })?;
+        }
+
+        if let Some(ticket) = options.session_ticket.as_ref() {
+            session
+                .connection
+                .set_session_ticket(ticket)
+                .map_err(|_| tls::Error::INTERNAL_ERROR.with_reason("invalid session ticket"))?;
+        }
+
+        Ok(session)
+    }
+
+    fn publish_events<Pub: s2n_quic_core::event::EndpointPublisher>(
+        &mut self,
+        publisher: &mut Pub,
+    ) {
+        self.loader.publish_events(publisher)
+    }
+
     fn max_tag_length(&self) -> usize {
         s2n_quic_crypto::MAX_TAG_LEN
     }
@@ -1,7 +1,15 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use s2n_quic_core::application::ServerName;
+//! A TLS provider for s2n-quic backed by [s2n-tls](https://github.com/aws/s2n-tls)
+//!
+//! Peers are authenticated with X.509 certificates. s2n-tls doesn't implement
+//! [raw public keys](https://www.rfc-editor.org/rfc/rfc7250), so applications which need them
+//! should use the rustls provider instead.
+
+use s2n_quic_core::{
+    application::ServerName, crypto::tls::ClientIdentity, event::EndpointPublisher,
+};
 
 /// Ensure memory is correctly managed in tests
 #[cfg(test)]
@@ -16,6 +24,11 @@ static DEFAULT_POLICY: &s2n_tls::security::Policy = &s2n_tls::security::DEFAULT_
 #[non_exhaustive]
 pub struct ConnectionContext<'a> {
     pub server_name: Option<&'a ServerName>,
+    /// The identity the client should present, if it was overridden for the connection
+    ///
+    /// s2n-tls loads client certificates from the config, so loaders that support per-connection
+    /// identities are expected to return a config containing this certificate.
+    pub client_identity: Option<&'a ClientIdentity>,
 }
 
 /// Loads a config for a given connection
@@ -23,6 +36,19 @@ pub struct ConnectionContext<'a> {
 /// This trait can be implemented to override the default config loading for a QUIC endpoint
 pub trait ConfigLoader: 'static + Send {
     fn load(&mut self, cx: ConnectionContext) -> config::Config;
+
+    /// Returns `true` if the loader returns configs for the `client_identity` in the
+    /// [`ConnectionContext`]
+    #[inline]
+    fn supports_client_identity(&self) -> bool {
+        true
+    }
+
+    /// Publishes any events from the loader, such as the outcome of reloading its configuration
+    #[inline]
+    fn publish_events(&mut self, publisher: &mut dyn EndpointPublisher) {
+        let _ = publisher;
+    }
 }
 
 impl ConfigLoader for config::Config {
@@ -30,6 +56,12 @@ impl ConfigLoader for config::Config {
     fn load(&mut self, _cx: ConnectionContext) -> config::Config {
         self.clone()
     }
+
+    #[inline]
+    fn supports_client_identity(&self) -> bool {
+        // a single config can't present a different identity for each connection
+        false
+    }
 }
 
 impl<T: FnMut(ConnectionContext) -> config::Config + Send + 'static> ConfigLoader for T {
@@ -44,6 +76,16 @@ impl ConfigLoader for Box<dyn ConfigLoader> {
     fn load(&mut self, cx: ConnectionContext) -> config::Config {
         (**self).load(cx)
     }
+
+    #[inline]
+    fn supports_client_identity(&self) -> bool {
+        (**self).supports_client_identity()
+    }
+
+    #[inline]
+    fn publish_events(&mut self, publisher: &mut dyn EndpointPublisher) {
+        (**self).publish_events(publisher)
+    }
 }
 
 mod callback;
@@ -53,6 +95,7 @@ mod session;
 
 pub mod certificate;
 pub mod client;
+pub mod reload;
 pub mod server;
 
 pub use client::Client;
@@ -0,0 +1,107 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reloads server certificates from PEM files without restarting the endpoint
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! use s2n_quic_tls::{reload, Server};
+//!
+//! let files = reload::Files::new("cert.pem", "key.pem");
+//! let reloader = reload::Reloader::server(files, |builder| {
+//!     builder.with_application_protocols(["h3"])
+//! })?;
+//! let server = Server::from_loader(reloader.clone());
+//!
+//! // new connections use the updated certificate once the reload succeeds
+//! reloader.reload()?;
+//! # let _ = server;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{keylog::KeyLogHandle, server, ConfigLoader, ConnectionContext};
+use s2n_quic_core::{crypto::tls::reload, event::EndpointPublisher};
+use s2n_tls::config::Config;
+use std::sync::Mutex;
+
+pub use reload::{Error, Files, Pem};
+
+/// A [`ConfigLoader`] which reloads its certificates from [`Files`]
+///
+/// Cloning the reloader returns a handle to the same configuration, which can be used to reload
+/// it after it has been passed to a [`Server`](crate::Server).
+#[derive(Clone, Debug)]
+pub struct Reloader(reload::Reloader<Loaded>);
+
+struct Loaded {
+    config: Config,
+    // the key log needs to outlive the config which references it
+    _keylog: Option<KeyLogHandle>,
+}
+
+impl Reloader {
+    /// Creates a reloader for server configurations
+    ///
+    /// `configure` is called with a new [`server::Builder`] each time the files are loaded. The
+    /// certificate and private key are then added to it, along with the trusted certificate, if
+    /// one was provided.
+    ///
+    /// If `configure` enables key logging, every configuration shares the key log which was opened
+    /// first. Connections keep using the configuration they were created with after a reload, so
+    /// the key log stays open for as long as the reloader.
+    pub fn server<F>(files: Files, configure: F) -> Result<Self, Error>
+    where
+        F: 'static
+            + Fn(server::Builder) -> Result<server::Builder, s2n_tls::error::Error>
+            + Send
+            + Sync,
+    {
+        let keylog = Mutex::new(None);
+
+        let reloader = reload::Reloader::new(files, move |pem| {
+            let mut builder = configure(server::Builder::default())?
+                .with_shared_key_log(&keylog)?
+                .with_certificate(&pem.certificate, &pem.private_key)?;
+
+            if let Some(trusted_certificate) = pem.trusted_certificate.as_ref() {
+                builder = builder.with_trusted_certificate(trusted_certificate)?;
+            }
+
+            let (config, keylog) = builder.build_config()?;
+            Ok(Loaded {
+                config,
+                _keylog: keylog,
+            })
+        })?;
+
+        Ok(Self(reloader))
+    }
+
+    /// Reloads the files and replaces the configuration used for new connections
+    ///
+    /// If the files can't be loaded, the previous configuration is kept and the error is
+    /// returned.
+    pub fn reload(&self) -> Result<(), Error> {
+        self.0.reload()
+    }
+}
+
+impl ConfigLoader for Reloader {
+    #[inline]
+    fn load(&mut self, _cx: ConnectionContext) -> Config {
+        self.0.config().config.clone()
+    }
+
+    #[inline]
+    fn supports_client_identity(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn publish_events(&mut self, publisher: &mut dyn EndpointPublisher) {
+        self.0.publish_events(publisher)
+    }
+}
@@ -20,7 +20,7 @@ use s2n_tls::{
     enums::ClientAuthType,
     error::Error,
 };
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 pub struct Server<L: ConfigLoader = Config> {
     loader: L,
@@ -65,11 +65,19 @@ impl<L: ConfigLoader> ConfigLoader for Server<L> {
     fn load(&mut self, cx: crate::ConnectionContext) -> s2n_tls::config::Config {
         self.loader.load(cx)
     }
+
+    #[inline]
+    fn publish_events(&mut self, publisher: &mut dyn s2n_quic_core::event::EndpointPublisher) {
+        self.loader.publish_events(publisher)
+    }
 }
 
 impl<L: ConfigLoader> From<Server<L>> for Config {
     fn from(mut server: Server<L>) -> Self {
-        server.load(crate::ConnectionContext { server_name: None })
+        server.load(crate::ConnectionContext {
+            server_name: None,
+            client_identity: None,
+        })
     }
 }
 
@@ -218,25 +226,61 @@ impl Builder {
         Ok(self)
     }
 
+    /// Points the key log callback at `shared` instead of the key log opened by this builder
+    ///
+    /// The first key log to be shared is kept, so configurations which replace each other all
+    /// write to the same key log and none of them reference one which was dropped.
+    pub(crate) fn with_shared_key_log(
+        mut self,
+        shared: &Mutex<Option<KeyLogHandle>>,
+    ) -> Result<Self, Error> {
+        use crate::keylog::KeyLog;
+
+        // key logging is disabled
+        let Some(keylog) = self.keylog.take() else {
+            return Ok(self);
+        };
+
+        let keylog = shared.lock().unwrap().get_or_insert(keylog).clone();
+
+        unsafe {
+            // Safety: the caller keeps the shared KeyLog alive for as long as the configs which
+            //         reference it
+            self.config
+                .set_key_log_callback(Some(KeyLog::callback), Arc::as_ptr(&keylog) as *mut _)?;
+        }
+
+        self.keylog = Some(keylog);
+        Ok(self)
+    }
+
     pub fn build(self) -> Result<Server, Error> {
-        #[cfg(feature = "fips")]
-        assert!(s2n_tls::init::fips_mode()?.is_enabled());
+        let (config, keylog) = self.build_config()?;
 
         Ok(Server {
-            loader: self.config.build()?,
-            keylog: self.keylog,
+            loader: config,
+            keylog,
             params: Default::default(),
         })
     }
+
+    /// Builds the config along with the key log it references, if enabled
+    pub(crate) fn build_config(self) -> Result<(Config, Option<KeyLogHandle>), Error> {
+        #[cfg(feature = "fips")]
+        assert!(s2n_tls::init::fips_mode()?.is_enabled());
+
+        Ok((self.config.build()?, self.keylog))
+    }
 }
 
 impl<L: ConfigLoader> tls::Endpoint for Server<L> {
     type Session = Session;
 
     fn new_server_session<Params: EncoderValue>(&mut self, params: &Params) -> Self::Session {
-        let config = self
-            .loader
-            .load(crate::ConnectionContext { server_name: None });
+        let config = self.loader.load(crate::ConnectionContext {
+            server_name: None,
+            client_identity: None,
+        });
         self.params.with(params, |params| {
             Session::new(endpoint::Type::Server, config, params, None).unwrap()
         })
@@ -250,6 +294,13 @@ impl<L: ConfigLoader> tls::Endpoint for Server<L> {
         panic!("cannot create a client session from a server config");
     }
 
+    fn publish_events<Pub: s2n_quic_core::event::EndpointPublisher>(
+        &mut self,
+        publisher: &mut Pub,
+    ) {
+        self.loader.publish_events(publisher)
+    }
+
     fn max_tag_length(&self) -> usize {
         s2n_quic_crypto::MAX_TAG_LEN
     }
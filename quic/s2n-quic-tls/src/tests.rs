@@ -515,3 +515,40 @@ fn config_loader() {
     // make sure the server can actually create a session
     let _ = server.new_server_session(&1);
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn config_reloader() {
+    use crate::{reload, Server};
+
+    let dir = std::env::temp_dir().join(format!("s2n-quic-tls-reload-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let cert = dir.join("cert.pem");
+    let key = dir.join("key.pem");
+    std::fs::write(&cert, CERT_PEM).unwrap();
+    std::fs::write(&key, KEY_PEM).unwrap();
+
+    let files = reload::Files::new(&cert, &key).without_watching();
+    let reloader = reload::Reloader::server(files, Ok).unwrap();
+    let mut server_endpoint = Server::from_loader(reloader.clone());
+
+    run(&mut server_endpoint, &mut s2n_client(), None);
+
+    // the client doesn't trust the new certificate
+    std::fs::write(&cert, UNTRUSTED_CERT_PEM).unwrap();
+    std::fs::write(&key, UNTRUSTED_KEY_PEM).unwrap();
+    reloader.reload().unwrap();
+    assert!(run_result(&mut server_endpoint, &mut s2n_client(), None).is_err());
+
+    // an invalid certificate keeps the previous config
+    std::fs::write(&cert, "invalid").unwrap();
+    assert!(reloader.reload().is_err());
+    assert!(run_result(&mut server_endpoint, &mut s2n_client(), None).is_err());
+
+    std::fs::write(&cert, CERT_PEM).unwrap();
+    std::fs::write(&key, KEY_PEM).unwrap();
+    reloader.reload().unwrap();
+    run(&mut server_endpoint, &mut s2n_client(), None);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
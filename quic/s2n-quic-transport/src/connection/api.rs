@@ -175,6 +175,11 @@ impl Connection {
     pub fn application_protocol(&self) -> Result<Bytes, connection::Error> {
         self.api.application_protocol()
     }
+
+    #[inline]
+    pub fn peer_public_key(&self) -> Result<Option<Bytes>, connection::Error> {
+        self.api.peer_public_key()
+    }
     #[inline]
     pub fn take_tls_context(&self) -> Option<Box<dyn Any + Send>> {
         self.api.take_tls_context()
@@ -203,6 +208,17 @@ impl Connection {
         self.api.remote_address()
     }
 
+    #[inline]
+    pub fn stats(&self) -> Result<connection::Stats, connection::Error> {
+        self.api.stats()
+    }
+
+    /// Records that the connection won a Happy Eyeballs race after `attempts` attempts
+    #[inline]
+    pub fn on_happy_eyeballs_winner(&self, attempts: u32) -> Result<(), connection::Error> {
+        self.api.on_happy_eyeballs_winner(attempts)
+    }
+
     #[inline]
     pub fn query_event_context(&self, query: &mut dyn Query) -> Result<(), connection::Error> {
         self.api.query_event_context(query)
@@ -59,6 +59,8 @@ pub(crate) trait ConnectionApiProvider: Sync + Send {
 
     fn application_protocol(&self) -> Result<Bytes, connection::Error>;
 
+    fn peer_public_key(&self) -> Result<Option<Bytes>, connection::Error>;
+
     fn take_tls_context(&self) -> Option<Box<dyn Any + Send>>;
 
     fn id(&self) -> u64;
@@ -71,6 +73,10 @@ pub(crate) trait ConnectionApiProvider: Sync + Send {
 
     fn remote_address(&self) -> Result<SocketAddress, connection::Error>;
 
+    fn stats(&self) -> Result<connection::Stats, connection::Error>;
+
+    fn on_happy_eyeballs_winner(&self, attempts: u32) -> Result<(), connection::Error>;
+
     fn query_event_context(&self, query: &mut dyn Query) -> Result<(), connection::Error>;
 
     fn query_event_context_mut(&self, query: &mut dyn QueryMut) -> Result<(), connection::Error>;
@@ -12,7 +12,7 @@ use core::{task::Poll, time::Duration};
 use s2n_quic_core::{
     counter::{self, Counter},
     event,
-    inet::ExplicitCongestionNotification,
+    inet::{Dscp, ExplicitCongestionNotification},
     io::tx,
     time::{timer, Timer, Timestamp},
 };
@@ -48,6 +48,7 @@ impl CloseSender {
     pub fn transmission<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>(
         &'a mut self,
         path: &'a mut Path<Config>,
+        dscp: Dscp,
         timestamp: Timestamp,
         publisher: &'a mut Pub,
     ) -> Transmission<'a, Config, Pub> {
@@ -66,6 +67,7 @@ impl CloseSender {
                 packet,
                 transmission,
                 path,
+                dscp,
                 timestamp,
                 publisher,
             }
@@ -125,6 +127,7 @@ pub struct Transmission<'a, Config: endpoint::Config, Pub: event::ConnectionPubl
     packet: &'a Bytes,
     transmission: &'a mut TransmissionState,
     path: &'a mut Path<Config>,
+    dscp: Dscp,
     timestamp: Timestamp,
     publisher: &'a mut Pub,
 }
@@ -146,6 +149,11 @@ impl<Config: endpoint::Config, Pub: event::ConnectionPublisher> tx::Message
             .ecn(transmission::Mode::Normal, self.timestamp)
     }
 
+    #[inline]
+    fn dscp(&mut self) -> Dscp {
+        self.dscp
+    }
+
     #[inline]
     fn ipv6_flow_label(&mut self) -> u32 {
         0
@@ -363,7 +371,7 @@ mod tests {
                 assert!(sender.can_transmit(path.transmission_constraint()));
                 let now = time::now();
                 let _ = sender
-                    .transmission(&mut path, now, &mut publisher)
+                    .transmission(&mut path, Dscp::DEFAULT, now, &mut publisher)
                     .write_payload(tx::PayloadBuffer::new(&mut buffer), 0);
 
                 for (gap, packet_size) in events {
@@ -385,7 +393,7 @@ mod tests {
                         let interest = sender.get_transmission_interest();
                         if interest.can_transmit(path.transmission_constraint()) {
                             let _ = sender
-                                .transmission(&mut path, now, &mut publisher)
+                                .transmission(&mut path, Dscp::DEFAULT, now, &mut publisher)
                                 .write_payload(tx::PayloadBuffer::new(&mut buffer), 0);
                             transmission_count += 1;
                         }
@@ -310,6 +310,11 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
     fn application_protocol(&self) -> Result<Bytes, connection::Error> {
         self.api_read_call(|conn| Ok(conn.application_protocol()))
     }
+
+    fn peer_public_key(&self) -> Result<Option<Bytes>, connection::Error> {
+        self.api_read_call(|conn| Ok(conn.peer_public_key()))
+    }
+
     fn take_tls_context(&self) -> Option<Box<dyn Any + Send>> {
         self.api_write_call(|conn| Ok::<_, connection::Error>(conn.take_tls_context()))
             .ok()
@@ -336,6 +341,14 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
         self.api_read_call(|conn| conn.remote_address())
     }
 
+    fn stats(&self) -> Result<connection::Stats, connection::Error> {
+        self.api_read_call(|conn| conn.stats())
+    }
+
+    fn on_happy_eyeballs_winner(&self, attempts: u32) -> Result<(), connection::Error> {
+        self.api_write_call(|conn| conn.on_happy_eyeballs_winner(attempts))
+    }
+
     #[inline]
     fn query_event_context(&self, query: &mut dyn Query) -> Result<(), connection::Error> {
         self.api_read_call(|conn| {
@@ -361,6 +374,15 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
     }
 }
 
+/// The application tasks waiting for a client connection's handshake to complete
+struct OpenWaiters {
+    /// The senders are a vector to allow multiple tasks to register interest in the same
+    /// connection being opened.
+    senders: SmallVec<[ConnectionSender; 1]>,
+    /// Whether the connection is closed once every task has stopped waiting on it
+    close_on_drop: bool,
+}
+
 /// Contains all secondary lists of Connections.
 ///
 /// A Connection can be a member in any of those, in addition to being a member of
@@ -375,10 +397,7 @@ struct InterestLists<C: connection::Trait, L: connection::Lock<C>> {
     /// Connections which are waiting for a timeout to occur
     waiting_for_timeout: RBTree<WaitingForTimeoutAdapter<C, L>>,
     /// Connections which are waiting for a handshake to complete.
-    ///
-    /// The senders are a vector to allow multiple tasks to register interest in the same
-    /// connection being opened.
-    waiting_for_open: BTreeMap<InternalConnectionId, SmallVec<[ConnectionSender; 1]>>,
+    waiting_for_open: BTreeMap<InternalConnectionId, OpenWaiters>,
     /// Inflight handshake count
     handshake_connections: usize,
     /// Total connection count
@@ -520,7 +539,8 @@ impl<C: connection::Trait, L: connection::Lock<C>> InterestLists<C, L> {
                     }
                 }
                 endpoint::Type::Client => {
-                    if let Some(mut senders) = self.waiting_for_open.remove(&id) {
+                    if let Some(OpenWaiters { mut senders, .. }) = self.waiting_for_open.remove(&id)
+                    {
                         let mut any_interest = false;
                         let last = senders.pop();
                         for sender in senders {
@@ -549,7 +569,7 @@ impl<C: connection::Trait, L: connection::Lock<C>> InterestLists<C, L> {
         if interests.finalization != node.done_connections_link.is_linked() {
             if interests.finalization {
                 if <C::Config as endpoint::Config>::ENDPOINT_TYPE.is_client() {
-                    if let Some(senders) = self.waiting_for_open.remove(&id) {
+                    if let Some(OpenWaiters { senders, .. }) = self.waiting_for_open.remove(&id) {
                         let err = node.inner.read(|conn| conn.error());
                         let err = match err {
                             Ok(Some(err)) => {
@@ -794,12 +814,16 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionContainer<C, L> {
         connection: C,
         internal_connection_id: InternalConnectionId,
         connection_sender: ConnectionSender,
+        close_on_drop: bool,
     ) {
         debug_assert!(<C::Config as endpoint::Config>::ENDPOINT_TYPE.is_client());
 
         self.interest_lists.waiting_for_open.insert(
             internal_connection_id,
-            smallvec::smallvec![connection_sender],
+            OpenWaiters {
+                senders: smallvec::smallvec![connection_sender],
+                close_on_drop,
+            },
         );
 
         self.insert_connection(connection, internal_connection_id)
@@ -818,13 +842,26 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionContainer<C, L> {
             .waiting_for_open
             .get_mut(internal_connection_id)
         {
-            list.push(connection_sender);
+            list.senders.push(connection_sender);
             Ok(())
         } else {
             Err(connection_sender)
         }
     }
 
+    /// Returns `true` if the client connection should be closed because every task waiting on
+    /// its handshake has dropped its attempt
+    ///
+    /// This only applies to connections which were opened with `close_on_drop`.
+    pub fn is_abandoned(&self, internal_connection_id: &InternalConnectionId) -> bool {
+        self.interest_lists
+            .waiting_for_open
+            .get(internal_connection_id)
+            .is_some_and(|waiters| {
+                waiters.close_on_drop && waiters.senders.iter().all(|sender| sender.is_canceled())
+            })
+    }
+
     pub(crate) fn poll_connection_request(
         &mut self,
         cx: &mut Context,
@@ -1114,6 +1151,55 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionContainer<C, L> {
         self.finalize_done_connections();
     }
 
+    /// Executes the given function on every `Connection` in the container
+    ///
+    /// The connections are visited in place, so this doesn't allocate unless a connection panics.
+    pub fn iterate_all<F>(&mut self, mut func: F)
+    where
+        F: FnMut(&mut C),
+    {
+        let mut poisoned = vec![];
+        let mut cursor = self.connection_map.front();
+
+        while let Some(node) = cursor.get() {
+            let on_write = |conn: &mut C| {
+                func(conn);
+                conn.interests()
+            };
+
+            // Update the interest lists after the interactions and outside of the per-connection
+            // Mutex. Nodes are only removed from the tree once the walk is done.
+            let is_ok = match node.inner.write(on_write) {
+                Ok(interests) => self
+                    .interest_lists
+                    .update_interests(
+                        &mut self.accept_queue,
+                        node,
+                        interests,
+                        ConnectionContainerIterationResult::Continue,
+                    )
+                    .is_ok(),
+                Err(_) => false,
+            };
+
+            if !is_ok {
+                poisoned.push(node.internal_connection_id);
+            }
+
+            cursor.move_next();
+        }
+
+        if !poisoned.is_empty() {
+            for id in poisoned {
+                self.remove_node_by_id(id);
+            }
+            self.interest_lists.handshake_connections = self.count_handshaking_connections();
+        }
+
+        self.finalize_done_connections();
+        self.ensure_counter_consistency();
+    }
+
     fn remove_node_by_id(&mut self, connection_id: InternalConnectionId) {
         // Remove the Connection from `connection_map`
         let mut cursor = self.connection_map.find_mut(&connection_id);
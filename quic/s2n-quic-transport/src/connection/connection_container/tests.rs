@@ -78,6 +78,10 @@ impl connection::Trait for TestConnection {
         self.accept_state == AcceptState::Active
     }
 
+    fn is_idle(&self) -> bool {
+        self.is_accepted() && !self.is_closed
+    }
+
     fn close(
         &mut self,
         _error: connection::Error,
@@ -302,6 +306,10 @@ impl connection::Trait for TestConnection {
         todo!()
     }
 
+    fn peer_public_key(&self) -> Option<Bytes> {
+        todo!()
+    }
+
     fn ping(&mut self) -> Result<(), connection::Error> {
         todo!()
     }
@@ -318,6 +326,14 @@ impl connection::Trait for TestConnection {
         Ok(SocketAddress::default())
     }
 
+    fn stats(&self) -> Result<connection::Stats, connection::Error> {
+        todo!()
+    }
+
+    fn on_happy_eyeballs_winner(&mut self, _attempts: u32) -> Result<(), connection::Error> {
+        Ok(())
+    }
+
     fn error(&self) -> Option<connection::Error> {
         None
     }
@@ -426,7 +442,8 @@ fn container_test() {
     check!().with_type::<Vec<Operation>>().for_each(|ops| {
         let mut id_gen = InternalConnectionIdGenerator::new();
         let mut connections = vec![];
-        let (handle, acceptor, connector, _close_handle) = endpoint::handle::Handle::new(100);
+        let (handle, acceptor, connector, _close_handle, _shutdown, _timers) =
+            endpoint::handle::Handle::new(100);
         let (waker, _wake_count) = futures_test::task::new_count_waker();
         let mut now = unsafe { Timestamp::from_duration(Duration::from_secs(0)) };
 
@@ -10,6 +10,7 @@ use crate::{
         id::{ConnectionInfo, Interest},
         limits::Limits,
         local_id_registry::LocalIdRegistrationError,
+        quotas::Quotas,
         ConnectionIdMapper, ConnectionInterests, ConnectionTimers, ConnectionTransmission,
         ConnectionTransmissionContext, InternalConnectionId, Parameters as ConnectionParameters,
         ProcessingError,
@@ -175,6 +176,8 @@ pub struct ConnectionImpl<Config: endpoint::Config> {
     error: Result<(), connection::Error>,
     /// Sends CONNECTION_CLOSE close frames after the connection is closed
     close_sender: CloseSender,
+    /// Enforces the byte and time quotas configured in the connection limits
+    quotas: Quotas,
     /// Manages all of the different packet spaces and their respective components
     space_manager: PacketSpaceManager<Config>,
     /// Holds the handle for waking up the endpoint from a application call
@@ -182,6 +185,11 @@ pub struct ConnectionImpl<Config: endpoint::Config> {
     /// A Waker to the connection.
     waker: Waker,
     event_context: EventContext<Config>,
+    /// The number of attempts in a Happy Eyeballs race this connection won, if any
+    ///
+    /// This is published as an event on the next wakeup, since application calls don't have
+    /// access to the event subscriber.
+    happy_eyeballs_winner: Option<u32>,
 }
 
 struct EventContext<Config: endpoint::Config> {
@@ -250,15 +258,26 @@ macro_rules! transmission_context {
         let ecn = $self.path_manager[$path_id]
             .ecn_controller
             .ecn($transmission_mode, $timestamp);
+        // the departure time needs to be queried before the packet is written, since writing the
+        // packet advances the pacer
+        let departure_time = $self.path_manager[$path_id].departure_time($timestamp);
+        let delay = departure_time.map_or(Duration::ZERO, |departure_time| {
+            departure_time.saturating_duration_since($timestamp)
+        });
 
         ConnectionTransmissionContext {
             quic_version: $self.event_context.quic_version,
-            timestamp: $timestamp,
+            // packets written ahead of their departure time are considered sent once they depart
+            // so RTT samples aren't inflated by the time the packet is held
+            timestamp: departure_time.unwrap_or($timestamp),
             path_id: $path_id,
             path_manager: &mut $self.path_manager,
             local_id_registry: &mut $self.local_id_registry,
             outcome: $outcome,
             ecn,
+            dscp: $self.limits.dscp(),
+            departure_time,
+            delay,
             min_packet_len: None,
             transmission_mode: $transmission_mode,
             publisher: &mut $self.event_context.publisher($timestamp, $subscriber),
@@ -369,8 +388,15 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
     fn on_processed_packet(
         &mut self,
         packet: &ProcessedPacket,
+        path_id: path::Id,
         subscriber: &mut Config::EventSubscriber,
     ) -> Result<(), connection::Error> {
+        let counters = &mut self.path_manager[path_id].counters;
+        counters.packets_received += 1;
+        counters.ecn_received.increment(packet.datagram.ecn);
+
+        self.quotas.on_packet_processed()?;
+
         //= https://www.rfc-editor.org/rfc/rfc9000#section-10.1
         //# An endpoint restarts its idle timer when a packet from its peer is
         //# received and processed successfully.
@@ -470,6 +496,9 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
                         outcome,
                         min_packet_len: None,
                         ecn,
+                        dscp: self.limits.dscp(),
+                        departure_time: None,
+                        delay: Duration::ZERO,
                         transmission_mode,
                         publisher: &mut self.event_context.publisher(timestamp, subscriber),
                         packet_interceptor,
@@ -585,6 +614,16 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         self.accept_state == AcceptState::Active
     }
 
+    fn is_idle(&self) -> bool {
+        if self.state != ConnectionState::Active || self.error.is_err() || !self.is_accepted() {
+            return false;
+        }
+
+        self.space_manager
+            .application()
+            .map_or(true, |space| space.stream_manager.nr_active_streams() == 0)
+    }
+
     /// Creates a new `Connection` instance with the given configuration
     fn new(parameters: ConnectionParameters<Self::Config>) -> Result<Self, connection::Error> {
         let mut event_context = EventContext {
@@ -650,10 +689,12 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             limits: parameters.limits,
             error: Ok(()),
             close_sender: CloseSender::default(),
+            quotas: Quotas::new(&parameters.limits, parameters.timestamp),
             space_manager: parameters.space_manager,
             wakeup_handle,
             waker,
             event_context,
+            happy_eyeballs_winner: None,
         };
 
         if Config::ENDPOINT_TYPE.is_client() {
@@ -739,6 +780,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
 
         // We don't need any timers anymore
         self.timers.cancel();
+        self.quotas.cancel();
         // Update the connection state based on the type of error
         self.state = error.into();
         self.error = Err(error);
@@ -878,11 +920,23 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 let mut outcome = transmission::Outcome::default();
                 let path_id = self.path_manager.active_path_id();
 
+                // If the queue enforces the pacing, packets can be written ahead of their departure
+                // time, up to the horizon of the queue
+                let pacing_horizon = if Tx::SUPPORTS_PACING {
+                    queue.pacing_horizon()
+                } else {
+                    Duration::ZERO
+                };
+                let departure_limit = timestamp + pacing_horizon;
+
                 // Send an MTU probe if necessary and the handshake has been confirmed
                 // MTU probes are prioritized over other data so they are not blocked by the
                 // congestion controller, as they are critical to achieving maximum throughput.
                 if self.state == ConnectionState::Active
-                    && self.path_manager.active_path().can_transmit(timestamp)
+                    && self
+                        .path_manager
+                        .active_path()
+                        .can_transmit(departure_limit)
                     && self.space_manager.is_handshake_confirmed()
                     && self
                         .path_manager
@@ -908,7 +962,10 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 }
 
                 // Send all other data for the active path
-                while self.path_manager.active_path().can_transmit(timestamp)
+                while self
+                    .path_manager
+                    .active_path()
+                    .can_transmit(departure_limit)
                     && queue
                         .push(ConnectionTransmission {
                             context: transmission_context!(
@@ -937,9 +994,11 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                     .congestion_controller
                     .earliest_departure_time()
                 {
-                    if !edt.has_elapsed(timestamp) {
+                    if !edt.has_elapsed(departure_limit) {
                         // We can't transmit more until a future time, so arm the pacing
                         // timer to pause transmission until the earliest departure time.
+                        // If the queue is pacing, wake up early enough to keep it filled.
+                        let edt = edt.checked_sub(pacing_horizon).unwrap_or(edt);
 
                         //= https://www.rfc-editor.org/rfc/rfc9002#section-7.7
                         //# A sender SHOULD pace sending of all in-flight packets based on input
@@ -997,11 +1056,12 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             ConnectionState::Closing => {
                 let mut publisher = self.event_context.publisher(timestamp, subscriber);
                 let path = self.path_manager.active_path_mut();
+                let dscp = self.limits.dscp();
 
                 if queue
                     .push(
                         self.close_sender
-                            .transmission(path, timestamp, &mut publisher),
+                            .transmission(path, dscp, timestamp, &mut publisher),
                     )
                     .is_ok()
                 {
@@ -1098,6 +1158,16 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             self.on_supervisor_timeout(timestamp, subscriber, supervisor_context)?;
         }
 
+        let application = self.space_manager.application();
+        let is_awaiting_peer_data =
+            application.is_some_and(|space| space.stream_manager.is_awaiting_peer_data());
+        self.quotas
+            .on_timeout(timestamp, is_awaiting_peer_data, |f| {
+                if let Some(space) = application {
+                    space.stream_manager.for_each_open_stream(f);
+                }
+            })?;
+
         // check to see if we're flushing the connection
         if self.poll_flush().is_ready() {
             return self.error;
@@ -1151,6 +1221,22 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 .discard_handshake(&mut self.path_manager, &mut publisher);
         }
 
+        if let Some(attempts) = self.happy_eyeballs_winner.take() {
+            let remote_address = *self.path_manager.active_path().handle.remote_address();
+            let address_family = match remote_address {
+                SocketAddress::IpV4(_) => event::builder::AddressFamily::IpV4,
+                SocketAddress::IpV6(_) => event::builder::AddressFamily::IpV6,
+            };
+
+            self.event_context
+                .publisher(timestamp, subscriber)
+                .on_happy_eyeballs_winner(event::builder::HappyEyeballsWinner {
+                    remote_address: remote_address.into_event(),
+                    address_family,
+                    attempts,
+                });
+        }
+
         // return an error if the application set one
         self.error?;
 
@@ -1196,6 +1282,8 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         publisher.on_datagram_received(event::builder::DatagramReceived {
             len: datagram.payload_len as u16,
         });
+        self.path_manager[id].counters.bytes_received += datagram.payload_len as u64;
+        self.quotas.on_datagram_received(datagram.payload_len);
 
         if amplification_outcome.is_active_path_unblocked() {
             //= https://www.rfc-editor.org/rfc/rfc9002#appendix-A.6
@@ -1369,7 +1457,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             )?;
 
             // notify the connection a packet was processed
-            self.on_processed_packet(&processed_packet, subscriber)?;
+            self.on_processed_packet(&processed_packet, path_id, subscriber)?;
         } else {
             let path = &self.path_manager[path_id];
             publisher.on_packet_dropped(event::builder::PacketDropped {
@@ -1484,7 +1572,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             )?;
 
             // notify the connection a packet was processed
-            self.on_processed_packet(&processed_packet, subscriber)?;
+            self.on_processed_packet(&processed_packet, path_id, subscriber)?;
         } else {
             let path = &self.path_manager[path_id];
             publisher.on_packet_dropped(event::builder::PacketDropped {
@@ -1611,7 +1699,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 )?;
             }
             // notify the connection a packet was processed
-            self.on_processed_packet(&processed_packet, subscriber)?;
+            self.on_processed_packet(&processed_packet, path_id, subscriber)?;
         } else {
             let path = &self.path_manager[path_id];
             publisher.on_packet_dropped(event::builder::PacketDropped {
@@ -1999,6 +2087,10 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         self.space_manager.application_protocol.clone()
     }
 
+    fn peer_public_key(&self) -> Option<Bytes> {
+        self.space_manager.peer_public_key.clone()
+    }
+
     fn take_tls_context(&mut self) -> Option<Box<dyn Any + Send>> {
         self.space_manager.tls_context.take()
     }
@@ -2047,6 +2139,43 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         Ok(*self.path_manager.active_path().handle.remote_address())
     }
 
+    fn stats(&self) -> Result<connection::Stats, connection::Error> {
+        let mut stats = connection::Stats::default();
+
+        let path = self.path_manager.active_path();
+        stats.path.local_address = *path.handle.local_address();
+        stats.path.remote_address = *path.handle.remote_address();
+        stats.path.mtu = path.mtu_controller.max_datagram_size() as u16;
+        stats.path.rtt.latest = path.rtt_estimator.latest_rtt();
+        stats.path.rtt.min = path.rtt_estimator.min_rtt();
+        stats.path.rtt.smoothed = path.rtt_estimator.smoothed_rtt();
+        stats.path.rtt.variance = path.rtt_estimator.rttvar();
+        stats.path.congestion_window = path.congestion_controller.congestion_window();
+        stats.path.bytes_in_flight = path.congestion_controller.bytes_in_flight();
+        stats.path.pacing_rate = path
+            .congestion_controller
+            .pacing_rate()
+            .map(|rate| rate.as_bytes_per_second());
+        stats.path.counters = path.counters;
+
+        stats.counters = self.path_manager.counters();
+
+        if let Some(space) = self.space_manager.application() {
+            space.stream_manager.stats(&mut stats.streams);
+        }
+
+        Ok(stats)
+    }
+
+    fn on_happy_eyeballs_winner(&mut self, attempts: u32) -> Result<(), connection::Error> {
+        self.error?;
+
+        self.happy_eyeballs_winner = Some(attempts);
+        self.wakeup_handle.wakeup();
+
+        Ok(())
+    }
+
     fn error(&self) -> Option<connection::Error> {
         self.error.err()
     }
@@ -2103,6 +2232,7 @@ impl<Config: endpoint::Config> timer::Provider for ConnectionImpl<Config> {
         // find the earliest armed timer
         self.timers.timers(query)?;
         self.close_sender.timers(query)?;
+        self.quotas.timers(query)?;
         self.local_id_registry.timers(query)?;
         self.path_manager.timers(query)?;
         self.space_manager.timers(query)?;
@@ -58,6 +58,10 @@ pub trait ConnectionTrait: 'static + Send + Sized {
     /// has been handed off to the application
     fn is_accepted(&self) -> bool;
 
+    /// Returns true if the connection has been handed off to the application
+    /// and doesn't have any open streams
+    fn is_idle(&self) -> bool;
+
     /// Initiates closing the connection as described in
     /// https://www.rfc-editor.org/rfc/rfc9000#section-10
     fn close(
@@ -490,6 +494,8 @@ pub trait ConnectionTrait: 'static + Send + Sized {
 
     fn application_protocol(&self) -> Bytes;
 
+    fn peer_public_key(&self) -> Option<Bytes>;
+
     fn ping(&mut self) -> Result<(), connection::Error>;
 
     fn keep_alive(&mut self, enabled: bool) -> Result<(), connection::Error>;
@@ -498,6 +504,11 @@ pub trait ConnectionTrait: 'static + Send + Sized {
 
     fn remote_address(&self) -> Result<SocketAddress, connection::Error>;
 
+    fn stats(&self) -> Result<connection::Stats, connection::Error>;
+
+    /// Records that the connection won a Happy Eyeballs race after `attempts` attempts
+    fn on_happy_eyeballs_winner(&mut self, attempts: u32) -> Result<(), connection::Error>;
+
     fn error(&self) -> Option<connection::Error>;
 
     fn query_event_context(&self, query: &mut dyn query::Query);
@@ -23,6 +23,7 @@ mod internal_connection_id;
 pub(crate) mod local_id_registry;
 pub(crate) mod open_token;
 pub(crate) mod peer_id_registry;
+mod quotas;
 pub(crate) mod transmission;
 
 pub(crate) use api_provider::{ConnectionApi, ConnectionApiProvider};
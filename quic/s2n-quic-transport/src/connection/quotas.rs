@@ -0,0 +1,303 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Enforces the per-connection byte and time quotas configured in `Limits`
+
+use alloc::vec::Vec;
+use core::time::Duration;
+use s2n_quic_core::{
+    application,
+    connection::{self, Limits},
+    stream::StreamId,
+    time::{timer, Timer, Timestamp},
+};
+
+/// How often the time-based quotas are evaluated
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+pub struct Quotas {
+    error_code: application::Error,
+    max_received_bytes: Option<u64>,
+    max_stream_lifetime: Option<Duration>,
+    min_receive_throughput: Option<u64>,
+    /// The total number of bytes received on the connection across all of its paths
+    received_bytes: u64,
+    /// The time at which the receive throughput starts being enforced
+    throughput_start: Timestamp,
+    /// The time and received byte count of the previous throughput sample
+    throughput_sample: Option<(Timestamp, u64)>,
+    /// The open streams, sorted by id, along with the time they were first observed
+    streams: Vec<(StreamId, Timestamp)>,
+    /// The streams from the previous check, kept around to reuse the allocation
+    previous_streams: Vec<(StreamId, Timestamp)>,
+    timer: Timer,
+}
+
+impl Quotas {
+    pub fn new(limits: &Limits, now: Timestamp) -> Self {
+        let (min_receive_throughput, grace_period) = match limits.min_receive_throughput() {
+            Some((rate, grace_period)) => (Some(rate), grace_period),
+            None => (None, Duration::ZERO),
+        };
+
+        let mut quotas = Self {
+            error_code: limits.quota_error_code(),
+            max_received_bytes: limits.max_received_bytes(),
+            max_stream_lifetime: limits.max_stream_lifetime(),
+            min_receive_throughput,
+            received_bytes: 0,
+            throughput_start: now + grace_period,
+            throughput_sample: None,
+            streams: Vec::new(),
+            previous_streams: Vec::new(),
+            timer: Timer::default(),
+        };
+
+        if quotas.has_time_quotas() {
+            quotas.timer.set(now + CHECK_INTERVAL);
+        }
+
+        quotas
+    }
+
+    /// Called when a datagram is received on any of the connection's paths
+    ///
+    /// The count is kept separately from the path counters so it never decreases when paths are
+    /// replaced.
+    #[inline]
+    pub fn on_datagram_received(&mut self, len: usize) {
+        self.received_bytes = self.received_bytes.saturating_add(len as u64);
+    }
+
+    /// Called after a packet was processed to check the received byte quota
+    #[inline]
+    pub fn on_packet_processed(&self) -> Result<(), connection::Error> {
+        match self.max_received_bytes {
+            Some(max) if self.received_bytes > max => Err(self.error()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Evaluates the time-based quotas if the check interval has elapsed
+    ///
+    /// `for_each_stream` is called with a function that should be invoked with the id of every
+    /// open stream on the connection. The receive throughput is only enforced while
+    /// `is_awaiting_peer_data` is `true`, i.e. the peer owes data on at least one stream.
+    pub fn on_timeout<F>(
+        &mut self,
+        now: Timestamp,
+        is_awaiting_peer_data: bool,
+        for_each_stream: F,
+    ) -> Result<(), connection::Error>
+    where
+        F: FnOnce(&mut dyn FnMut(StreamId)),
+    {
+        if self.timer.poll_expiration(now).is_pending() {
+            return Ok(());
+        }
+
+        self.timer.set(now + CHECK_INTERVAL);
+
+        self.on_streams(now, for_each_stream);
+
+        if let Some(max_stream_lifetime) = self.max_stream_lifetime {
+            let expired = self.streams.iter().any(|(_, first_seen)| {
+                now.saturating_duration_since(*first_seen) >= max_stream_lifetime
+            });
+
+            if expired {
+                return Err(self.error());
+            }
+        }
+
+        if let Some(rate) = self.min_receive_throughput {
+            // only measure throughput after the grace period and while the peer has a reason
+            // to be sending data
+            if now < self.throughput_start || !is_awaiting_peer_data {
+                self.throughput_sample = None;
+                return Ok(());
+            }
+
+            if let Some((start, start_bytes)) = self.throughput_sample {
+                let elapsed = now.saturating_duration_since(start);
+                let received = self.received_bytes.saturating_sub(start_bytes) as u128;
+                let required = rate as u128 * elapsed.as_micros() / 1_000_000;
+
+                if received < required {
+                    return Err(self.error());
+                }
+            }
+
+            self.throughput_sample = Some((now, self.received_bytes));
+        }
+
+        Ok(())
+    }
+
+    /// Stops all of the quota checks
+    #[inline]
+    pub fn cancel(&mut self) {
+        self.timer.cancel();
+    }
+
+    /// Refreshes the set of open streams, preserving the time each stream was first observed
+    fn on_streams<F>(&mut self, now: Timestamp, for_each_stream: F)
+    where
+        F: FnOnce(&mut dyn FnMut(StreamId)),
+    {
+        core::mem::swap(&mut self.streams, &mut self.previous_streams);
+        self.streams.clear();
+
+        let previous = &self.previous_streams;
+        let streams = &mut self.streams;
+
+        for_each_stream(&mut |id| {
+            let first_seen = previous
+                .binary_search_by_key(&id, |(id, _)| *id)
+                .map_or(now, |index| previous[index].1);
+            streams.push((id, first_seen));
+        });
+
+        self.streams.sort_unstable_by_key(|(id, _)| *id);
+    }
+
+    #[inline]
+    fn has_time_quotas(&self) -> bool {
+        self.max_stream_lifetime.is_some() || self.min_receive_throughput.is_some()
+    }
+
+    #[inline]
+    #[track_caller]
+    fn error(&self) -> connection::Error {
+        connection::Error::application(self.error_code)
+    }
+}
+
+impl timer::Provider for Quotas {
+    #[inline]
+    fn timers<Q: timer::Query>(&self, query: &mut Q) -> timer::Result {
+        self.timer.timers(query)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::{time::clock::testing as time, varint::VarInt};
+
+    fn stream(id: u8) -> StreamId {
+        StreamId::from_varint(VarInt::from_u8(id))
+    }
+
+    fn error_code() -> application::Error {
+        application::Error::from(VarInt::from_u8(42))
+    }
+
+    #[test]
+    fn max_received_bytes() {
+        let limits = Limits::default()
+            .with_max_received_bytes(100)
+            .unwrap()
+            .with_quota_error_code(error_code())
+            .unwrap();
+        let mut quotas = Quotas::new(&limits, time::now());
+
+        // byte quotas don't need a timer
+        assert!(!quotas.timer.is_armed());
+
+        quotas.on_datagram_received(60);
+        assert!(quotas.on_packet_processed().is_ok());
+        quotas.on_datagram_received(40);
+        assert!(quotas.on_packet_processed().is_ok());
+
+        quotas.on_datagram_received(1);
+        assert_eq!(
+            quotas.on_packet_processed(),
+            Err(connection::Error::application(error_code()))
+        );
+    }
+
+    #[test]
+    fn max_stream_lifetime() {
+        let limits = Limits::default()
+            .with_max_stream_lifetime(Duration::from_secs(3))
+            .unwrap();
+        let now = time::now();
+        let mut quotas = Quotas::new(&limits, now);
+
+        // nothing happens before the timer expires
+        assert!(quotas
+            .on_timeout(now, false, |_| panic!("streams should not be queried"))
+            .is_ok());
+
+        let mut now = now + CHECK_INTERVAL;
+        assert!(quotas.on_timeout(now, false, |f| f(stream(0))).is_ok());
+
+        // a new stream doesn't inherit the age of the first one
+        now += CHECK_INTERVAL;
+        assert!(quotas
+            .on_timeout(now, false, |f| {
+                f(stream(4));
+                f(stream(0));
+            })
+            .is_ok());
+
+        // the first stream is closed before it reaches the limit
+        now += CHECK_INTERVAL;
+        assert!(quotas.on_timeout(now, false, |f| f(stream(4))).is_ok());
+        now += CHECK_INTERVAL;
+        assert!(quotas.on_timeout(now, false, |f| f(stream(4))).is_ok());
+
+        now += CHECK_INTERVAL;
+        assert!(quotas.on_timeout(now, false, |f| f(stream(4))).is_err());
+    }
+
+    #[test]
+    fn min_receive_throughput() {
+        let limits = Limits::default()
+            .with_min_receive_throughput(1000, Duration::from_secs(2))
+            .unwrap();
+        let now = time::now();
+        let mut quotas = Quotas::new(&limits, now);
+
+        // the peer is allowed to stall during the grace period
+        let mut now = now + CHECK_INTERVAL;
+        assert!(quotas.on_timeout(now, true, |f| f(stream(0))).is_ok());
+
+        // the first sample after the grace period starts the measurement
+        now += CHECK_INTERVAL;
+        assert!(quotas.on_timeout(now, true, |f| f(stream(0))).is_ok());
+
+        quotas.on_datagram_received(1000);
+        now += CHECK_INTERVAL;
+        assert!(quotas.on_timeout(now, true, |f| f(stream(0))).is_ok());
+
+        // idle connections aren't held to the minimum
+        now += CHECK_INTERVAL;
+        assert!(quotas.on_timeout(now, false, |_| {}).is_ok());
+
+        // neither are open streams that aren't waiting on the peer
+        now += CHECK_INTERVAL;
+        assert!(quotas.on_timeout(now, false, |f| f(stream(4))).is_ok());
+        now += CHECK_INTERVAL;
+        assert!(quotas.on_timeout(now, false, |f| f(stream(4))).is_ok());
+
+        now += CHECK_INTERVAL;
+        assert!(quotas.on_timeout(now, true, |f| f(stream(4))).is_ok());
+
+        quotas.on_datagram_received(999);
+        now += CHECK_INTERVAL;
+        assert!(quotas.on_timeout(now, true, |f| f(stream(4))).is_err());
+    }
+
+    #[test]
+    fn no_quotas() {
+        let mut quotas = Quotas::new(&Limits::default(), time::now());
+        assert!(!quotas.timer.is_armed());
+        quotas.on_datagram_received(usize::MAX);
+        assert!(quotas.on_packet_processed().is_ok());
+    }
+}
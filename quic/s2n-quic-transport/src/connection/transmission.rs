@@ -10,7 +10,7 @@ use s2n_codec::{Encoder, EncoderBuffer};
 use s2n_quic_core::{
     event::{self, ConnectionPublisher as _},
     frame::ack_elicitation::AckElicitable,
-    inet::ExplicitCongestionNotification,
+    inet::{Dscp, ExplicitCongestionNotification},
     io::tx,
     packet::{encoding::PacketEncodingError, number::PacketNumberSpace},
     recovery::{CongestionController, MAX_BURST_PACKETS},
@@ -26,6 +26,14 @@ pub struct ConnectionTransmissionContext<'a, 'sub, Config: endpoint::Config> {
     pub local_id_registry: &'a mut connection::LocalIdRegistry,
     pub outcome: &'a mut transmission::Outcome,
     pub ecn: ExplicitCongestionNotification,
+    pub dscp: Dscp,
+    /// The time at which the packet should depart, as determined by the pacer
+    ///
+    /// This is only set if the packet is written ahead of its departure time. In that case,
+    /// `timestamp` is the departure time.
+    pub departure_time: Option<Timestamp>,
+    /// How long the packet should be held before departing, relative to when it was written
+    pub delay: Duration,
     pub min_packet_len: Option<usize>,
     pub transmission_mode: transmission::Mode,
     pub publisher: &'a mut event::ConnectionPublisherSubscriber<'sub, Config::EventSubscriber>,
@@ -60,10 +68,19 @@ impl<Config: endpoint::Config> tx::Message for ConnectionTransmission<'_, '_, Co
         self.context.ecn
     }
 
+    #[inline]
+    fn dscp(&mut self) -> Dscp {
+        self.context.dscp
+    }
+
     #[inline]
     fn delay(&mut self) -> Duration {
-        // TODO return delay from pacer
-        Default::default()
+        self.context.delay
+    }
+
+    #[inline]
+    fn departure_time(&mut self) -> Option<Timestamp> {
+        self.context.departure_time
     }
 
     #[inline]
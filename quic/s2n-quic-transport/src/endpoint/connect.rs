@@ -8,11 +8,19 @@ use crate::{
 use core::{
     fmt,
     future::Future,
+    hash::{Hash, Hasher},
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
 use futures_channel::oneshot;
-use s2n_quic_core::{application::ServerName, inet::SocketAddress, path::RemoteAddress};
+use s2n_quic_core::{
+    application::ServerName,
+    connection::Limits,
+    crypto::tls,
+    inet::SocketAddress,
+    path::{LocalAddress, RemoteAddress},
+};
 
 /// Held by connection Attempt future. Used to receive the actual connection.
 pub(crate) type ConnectionReceiver = oneshot::Receiver<Result<Connection, connection::Error>>;
@@ -21,11 +29,33 @@ pub(crate) type ConnectionReceiver = oneshot::Receiver<Result<Connection, connec
 /// its been created.
 pub(crate) type ConnectionSender = oneshot::Sender<Result<Connection, connection::Error>>;
 
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct Connect {
     pub(crate) remote_address: RemoteAddress,
     pub(crate) server_name: Option<ServerName>,
     pub(crate) deduplicate: bool,
+    pub(crate) local_address: Option<LocalAddress>,
+    pub(crate) limits: Option<Limits>,
+    pub(crate) handshake_timeout: Option<Duration>,
+    pub(crate) tls: tls::ClientOptions,
+    pub(crate) close_on_drop: bool,
+}
+
+// `Limits` only contains plain values so equality is reflexive
+impl Eq for Connect {}
+
+impl Hash for Connect {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // `Limits` isn't hashable so it's left out. This is fine since equal values will still
+        // produce equal hashes.
+        self.remote_address.hash(state);
+        self.server_name.hash(state);
+        self.deduplicate.hash(state);
+        self.local_address.hash(state);
+        self.handshake_timeout.hash(state);
+        self.tls.hash(state);
+        self.close_on_drop.hash(state);
+    }
 }
 
 impl fmt::Display for Connect {
@@ -51,6 +81,11 @@ impl Connect {
             remote_address: addr.into().into(),
             server_name: None,
             deduplicate: false,
+            local_address: None,
+            limits: None,
+            handshake_timeout: None,
+            tls: Default::default(),
+            close_on_drop: false,
         }
     }
 
@@ -78,6 +113,86 @@ impl Connect {
             ..self
         }
     }
+
+    /// Specifies the local address to send packets from
+    ///
+    /// The address must be one the endpoint's socket is able to send from. Only the IP is used to
+    /// select the source address of the connection's packets; the port of the bound socket is
+    /// always used.
+    #[must_use]
+    pub fn with_local_address<Addr: Into<SocketAddress>>(self, addr: Addr) -> Self {
+        Self {
+            local_address: Some(addr.into().into()),
+            ..self
+        }
+    }
+
+    /// Overrides the endpoint's connection limits for this connection
+    ///
+    /// This takes the place of the limits returned by the endpoint's `connection::Limiter`.
+    #[must_use]
+    pub fn with_limits(self, limits: Limits) -> Self {
+        Self {
+            limits: Some(limits),
+            ..self
+        }
+    }
+
+    /// Specifies the maximum amount of time the handshake may take before the connection is
+    /// closed
+    ///
+    /// This takes precedence over the `max_handshake_duration` of any configured limits.
+    #[must_use]
+    pub fn with_handshake_timeout(self, timeout: Duration) -> Self {
+        Self {
+            handshake_timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Specifies the application protocols (ALPN) to offer to the server, in order of preference
+    #[must_use]
+    pub fn with_application_protocols<P: IntoIterator<Item = I>, I: Into<tls::Bytes>>(
+        self,
+        protocols: P,
+    ) -> Self {
+        let tls = self.tls.with_application_protocols(protocols);
+        Self { tls, ..self }
+    }
+
+    /// Specifies the certificate and private key to present if the server requests client
+    /// authentication
+    #[must_use]
+    pub fn with_client_identity(self, identity: tls::ClientIdentity) -> Self {
+        let tls = self.tls.with_identity(identity);
+        Self { tls, ..self }
+    }
+
+    /// Specifies a session ticket from a previous connection to the server to resume
+    #[must_use]
+    pub fn with_session_ticket<T: Into<tls::Bytes>>(self, ticket: T) -> Self {
+        let tls = self.tls.with_session_ticket(ticket);
+        Self { tls, ..self }
+    }
+
+    /// Replaces all of the TLS overrides for the connection
+    #[must_use]
+    pub fn with_tls_options(self, tls: tls::ClientOptions) -> Self {
+        Self { tls, ..self }
+    }
+
+    /// Specifies whether to close the connection if the attempt is dropped before the handshake
+    /// completes
+    ///
+    /// By default, the handshake continues in the background and the connection is closed once
+    /// it completes.
+    #[must_use]
+    pub fn with_close_on_drop(self, close_on_drop: bool) -> Self {
+        Self {
+            close_on_drop,
+            ..self
+        }
+    }
 }
 
 /// Make it easy for applications to create a connection attempt without importing the `Connect` struct
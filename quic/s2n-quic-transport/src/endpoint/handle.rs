@@ -6,14 +6,16 @@
 use crate::{
     connection,
     connection::Connection,
-    endpoint::{close, close::CloseHandle, connect},
+    endpoint::{close, close::CloseHandle, connect, shutdown, timer},
 };
 use core::{
     pin::Pin,
     task::{Context, Poll, Waker},
+    time::Duration,
 };
 use futures_channel::mpsc;
 use futures_core::Stream;
+use s2n_quic_core::application;
 
 /// Held by application. Used to accept new connections.
 pub(crate) type AcceptorReceiver = mpsc::UnboundedReceiver<Connection>;
@@ -30,6 +32,16 @@ pub(crate) type CloseReceiver = mpsc::Receiver<Waker>;
 /// Held by the application. Used to submit connection close attempts to the library.
 pub(crate) type CloseSender = mpsc::Sender<Waker>;
 
+/// Held by library. Used to receive shutdown requests from the application.
+pub(crate) type ShutdownReceiver = mpsc::Receiver<shutdown::Request>;
+/// Held by the application. Used to submit shutdown requests to the library.
+pub(crate) type ShutdownSender = mpsc::Sender<shutdown::Request>;
+
+/// Held by library. Used to receive timer requests from the application.
+pub(crate) type TimerReceiver = mpsc::Receiver<timer::Request>;
+/// Held by the application. Used to submit timer requests to the library.
+pub(crate) type TimerSender = mpsc::Sender<timer::Request>;
+
 /// The [`Handle`] allows applications to accept and open QUIC connections on an `Endpoint`.
 #[derive(Debug)]
 pub(crate) struct Handle {
@@ -41,21 +53,40 @@ impl Handle {
     /// Creates a new `Handle` with a limit opening connection limit.
     pub(crate) fn new(
         max_opening_connections: usize,
-    ) -> (Self, AcceptorSender, ConnectorReceiver, CloseHandle) {
+    ) -> (
+        Self,
+        AcceptorSender,
+        ConnectorReceiver,
+        CloseHandle,
+        shutdown::Controller,
+        timer::Controller,
+    ) {
         let (acceptor_sender, acceptor_receiver) = mpsc::unbounded();
         let (connector_sender, connector_receiver) = mpsc::channel(max_opening_connections);
 
         let (close_sender, close_receiver) = mpsc::channel(max_opening_connections);
 
+        // shutdown requests only carry a deadline so there's no need for much capacity
+        let (shutdown_sender, shutdown_receiver) = mpsc::channel(1);
+        let shutdown_signal = shutdown::Signal::default();
+
+        // timers are mostly used to pace connection attempts so size the queue the same way
+        let (timer_sender, timer_receiver) = mpsc::channel(max_opening_connections);
+
         let endpoint_state = close::EndpointState::default();
         let closer = close::Closer::new(close_sender, endpoint_state.clone());
         let handle = Self {
             acceptor: Acceptor {
                 acceptor: acceptor_receiver,
+                shutdown: shutdown_sender.clone(),
+                shutdown_signal: shutdown_signal.clone(),
             },
             connector: Connector {
                 connector: connector_sender,
                 closer,
+                shutdown: shutdown_sender,
+                shutdown_signal: shutdown_signal.clone(),
+                timer: timer_sender,
             },
         };
         (
@@ -63,6 +94,8 @@ impl Handle {
             acceptor_sender,
             connector_receiver,
             CloseHandle::new(close_receiver, endpoint_state),
+            shutdown::Controller::new(shutdown_receiver, shutdown_signal),
+            timer::Controller::new(timer_receiver),
         )
     }
 }
@@ -70,6 +103,8 @@ impl Handle {
 #[derive(Debug)]
 pub struct Acceptor {
     acceptor: AcceptorReceiver,
+    shutdown: ShutdownSender,
+    shutdown_signal: shutdown::Signal,
 }
 
 impl Acceptor {
@@ -92,12 +127,25 @@ impl Acceptor {
             Poll::Pending => Poll::Pending,
         }
     }
+
+    /// Gracefully shuts down the endpoint and returns a future to be awaited
+    pub fn shutdown(&self, deadline: Duration, error: application::Error) -> shutdown::Shutdown {
+        shutdown::Shutdown::new(&self.shutdown, deadline, error)
+    }
+
+    /// Returns a future which resolves once the endpoint starts shutting down
+    pub fn shutdown_signal(&self) -> shutdown::Signal {
+        self.shutdown_signal.clone()
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct Connector {
     connector: ConnectorSender,
     closer: close::Closer,
+    shutdown: ShutdownSender,
+    shutdown_signal: shutdown::Signal,
+    timer: TimerSender,
 }
 
 impl Connector {
@@ -110,4 +158,19 @@ impl Connector {
     pub fn poll_close(&mut self, context: &mut Context) -> Poll<Result<(), connection::Error>> {
         self.closer.poll_close(context)
     }
+
+    /// Gracefully shuts down the endpoint and returns a future to be awaited
+    pub fn shutdown(&self, deadline: Duration, error: application::Error) -> shutdown::Shutdown {
+        shutdown::Shutdown::new(&self.shutdown, deadline, error)
+    }
+
+    /// Returns a future which resolves once the endpoint starts shutting down
+    pub fn shutdown_signal(&self) -> shutdown::Signal {
+        self.shutdown_signal.clone()
+    }
+
+    /// Returns a future which resolves once `duration` has elapsed on the endpoint's clock
+    pub fn sleep(&self, duration: Duration) -> timer::Sleep {
+        timer::Sleep::new(&self.timer, duration)
+    }
 }
@@ -258,6 +258,10 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
             Some(quic_version),
             endpoint_context.event_subscriber,
         );
+
+        // creating the session may have reloaded the TLS configuration
+        endpoint_context.tls.publish_events(&mut endpoint_publisher);
+
         let mtu_config = endpoint_context
             .mtu
             .config(&remote_address)
@@ -289,6 +293,7 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
             initial_key,
             initial_header_key,
             datagram.timestamp,
+            limits.max_crypto_buffer_size(),
             &mut publisher,
         );
 
@@ -16,10 +16,10 @@ use crate::{
     space::PacketSpaceManager,
     wakeup_queue::WakeupQueue,
 };
-use alloc::collections::VecDeque;
+use alloc::{collections::VecDeque, sync::Arc};
 use core::{
     convert::TryInto,
-    task::{self, Poll},
+    task::{self, Poll, Waker},
 };
 use s2n_codec::{DecoderBuffer, DecoderBufferMut};
 use s2n_quic_core::{
@@ -54,7 +54,9 @@ pub mod handle;
 mod initial;
 mod packet_buffer;
 mod retry;
+pub mod shutdown;
 mod stateless_reset;
+pub mod timer;
 mod version;
 
 // exports
@@ -79,6 +81,10 @@ pub struct Endpoint<Cfg: Config> {
     wakeup_queue: WakeupQueue<InternalConnectionId>,
     /// Used to receive close attempts and track close state.
     close_handle: CloseHandle,
+    /// Used to receive shutdown requests and track the shutdown progress.
+    shutdown: shutdown::Controller,
+    /// Used to receive timer requests from the application and expire them.
+    timers: timer::Controller,
     /// This queue contains wakeups we retrieved from the [`Self::wakeup_queue`] earlier.
     /// This is not a local variable in order to reuse the allocated queue capacity in between
     /// [`Endpoint`] interactions.
@@ -174,6 +180,21 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
         cx: &mut task::Context<'_>,
         clock: &C,
     ) -> Poll<Result<usize, s2n_quic_core::endpoint::CloseError>> {
+        let mut wakeup_count = 0;
+
+        if self.connections.is_open() {
+            if self
+                .shutdown
+                .poll_requests(cx, clock.get_time(), self.connections.len())
+            {
+                wakeup_count += self.on_shutdown(clock.get_time());
+            } else if self.shutdown.is_shutting_down() {
+                self.on_shutdown_progress();
+            }
+
+            wakeup_count += self.timers.poll_requests(cx, clock.get_time());
+        }
+
         if self.close_handle.poll_interest().is_ready() // poll for close interest
             && self.connections.is_empty() // wait for all connections to close gracefully
             && self.connections.is_open()
@@ -194,11 +215,14 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
             .poll_pending_wakeups(&mut self.dequeued_wakeups, cx);
 
         let mut now: Option<Timestamp> = None;
-        let mut wakeup_count = self.dequeued_wakeups.len();
+        wakeup_count += self.dequeued_wakeups.len();
         let close_packet_buffer = &mut self.close_packet_buffer;
-        let endpoint_context = self.config.context();
+        let mut endpoint_context = self.config.context();
+        let shutdown = &mut self.shutdown;
 
         for internal_id in self.dequeued_wakeups.drain(..) {
+            let is_abandoned = self.connections.is_abandoned(&internal_id);
+
             self.connections.with_connection(internal_id, |conn| {
                 let timestamp = match now {
                     Some(now) => now,
@@ -209,13 +233,20 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
                     }
                 };
 
-                if let Err(error) = conn.on_wakeup(
+                let mut result = conn.on_wakeup(
                     timestamp,
                     endpoint_context.event_subscriber,
                     endpoint_context.datagram,
                     endpoint_context.dc,
                     endpoint_context.connection_limits,
-                ) {
+                );
+
+                if is_abandoned && result.is_ok() {
+                    // every task waiting on the handshake dropped its attempt
+                    result = Err(connection::Error::closed(Location::Local));
+                }
+
+                if let Err(error) = result {
                     conn.close(
                         error,
                         endpoint_context.connection_close_formatter,
@@ -224,7 +255,17 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
                         endpoint_context.event_subscriber,
                         endpoint_context.packet_interceptor,
                     );
+                    return;
                 }
+
+                // the application may have finished with the connection's last stream
+                Self::close_if_idle(
+                    conn,
+                    shutdown,
+                    close_packet_buffer,
+                    timestamp,
+                    &mut endpoint_context,
+                );
             });
         }
 
@@ -236,11 +277,31 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
                     Poll::Ready(Some(request)) => {
                         wakeup_count += 1;
 
+                        if self.shutdown.is_shutting_down() {
+                            self.shutdown.on_rejected_connection();
+                            if request
+                                .sender
+                                .send(Err(connection::Error::endpoint_closing()))
+                                .is_err()
+                            {
+                                // the application is no longer waiting so skip
+                            }
+                            continue;
+                        }
+
                         let time = clock.get_time();
-                        if let Err(err) = self.create_client_connection(request, time) {
-                            // TODO report that the connection was not successfully created
-                            // TODO emit event
-                            dbg!(err);
+                        if let Err(error) = self.create_client_connection(request, time) {
+                            let mut publisher = event::EndpointPublisherSubscriber::new(
+                                event::builder::EndpointMeta {
+                                    endpoint_type: Cfg::ENDPOINT_TYPE,
+                                    timestamp: time,
+                                },
+                                None,
+                                self.config.context().event_subscriber,
+                            );
+                            publisher.on_endpoint_connection_attempt_failed(
+                                event::builder::EndpointConnectionAttemptFailed { error },
+                            );
                         }
                     }
                     Poll::Ready(None) => {
@@ -260,7 +321,14 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
 
     #[inline]
     fn timeout(&self) -> Option<Timestamp> {
-        self.connections.next_expiration()
+        [
+            self.connections.next_expiration(),
+            self.shutdown.timeout(),
+            self.timers.timeout(),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
     }
 
     #[inline]
@@ -298,7 +366,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
     fn new(mut config: Cfg) -> (Self, handle::Handle) {
         // TODO make this limit configurable
         let max_opening_connections = 1000;
-        let (handle, acceptor_sender, connector_receiver, close_handle) =
+        let (handle, acceptor_sender, connector_receiver, close_handle, shutdown, timers) =
             handle::Handle::new(max_opening_connections);
 
         let connection_id_mapper =
@@ -311,6 +379,8 @@ impl<Cfg: Config> Endpoint<Cfg> {
             connection_id_mapper,
             wakeup_queue: WakeupQueue::new(),
             close_handle,
+            shutdown,
+            timers,
             dequeued_wakeups: VecDeque::new(),
             version_negotiator: version::Negotiator::default(),
             retry_dispatch: retry::Dispatch::default(),
@@ -340,7 +410,8 @@ impl<Cfg: Config> Endpoint<Cfg> {
             self.connections.len(),
             &remote_address,
             timestamp.into_event(),
-        );
+        )
+        .with_destination_connection_id(packet.destination_connection_id());
 
         let context = self.config.context();
         let outcome = context.endpoint_limits.on_connection_attempt(&attempt);
@@ -353,6 +424,23 @@ impl<Cfg: Config> Endpoint<Cfg> {
             context.event_subscriber,
         );
 
+        let limited = match outcome {
+            Outcome::Allow { .. } => None,
+            Outcome::Retry { .. } => Some(event::builder::ConnectionAttemptOutcome::Retry),
+            Outcome::Close { .. } => Some(event::builder::ConnectionAttemptOutcome::Close),
+            // Outcome is non_exhaustive so anything we don't understand is dropped below
+            _ => Some(event::builder::ConnectionAttemptOutcome::Drop),
+        };
+
+        if let Some(outcome) = limited {
+            publisher.on_endpoint_connection_attempt_limited(
+                event::builder::EndpointConnectionAttemptLimited {
+                    remote_address: remote_address.into_event(),
+                    outcome,
+                },
+            );
+        }
+
         match outcome {
             Outcome::Allow { .. } => Some(()),
             Outcome::Retry { .. } => {
@@ -422,7 +510,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
         payload: &mut [u8],
         timestamp: Timestamp,
     ) {
-        let endpoint_context = self.config.context();
+        let mut endpoint_context = self.config.context();
 
         // Try to decode the first packet in the datagram
         let payload_len = payload.len();
@@ -552,6 +640,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
 
         // TODO validate the connection ID before looking up the connection in the map
         let close_packet_buffer = &mut self.close_packet_buffer;
+        let shutdown = &mut self.shutdown;
 
         // Try to lookup the internal connection ID and dispatch the packet
         // to the Connection
@@ -652,6 +741,15 @@ impl<Cfg: Config> Endpoint<Cfg> {
                     return Err(());
                 }
 
+                // the peer may have finished the connection's last stream
+                Self::close_if_idle(
+                    conn,
+                    shutdown,
+                    close_packet_buffer,
+                    datagram.timestamp,
+                    &mut endpoint_context,
+                );
+
                 Ok(())
             });
 
@@ -679,6 +777,19 @@ impl<Cfg: Config> Endpoint<Cfg> {
                         }
                     };
 
+                // new connections are refused once the server starts shutting down, including
+                // ones which already completed a Retry round trip or carry a NEW_TOKEN token
+                if self.shutdown.is_shutting_down() {
+                    self.shutdown.on_rejected_connection();
+                    publisher.on_endpoint_datagram_dropped(
+                        event::builder::EndpointDatagramDropped {
+                            len: payload_len as u16,
+                            reason: event::builder::DatagramDropReason::RejectedConnectionAttempt,
+                        },
+                    );
+                    return;
+                }
+
                 //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1
                 //= type=TODO
                 //= tracking-issue=140
@@ -915,9 +1026,30 @@ impl<Cfg: Config> Endpoint<Cfg> {
     }
 
     fn on_timeout(&mut self, timestamp: Timestamp) {
+        if self.shutdown.on_timeout(timestamp) {
+            self.on_shutdown_deadline(timestamp);
+        }
+
+        self.timers.on_timeout(timestamp);
+
+        // publish the outcome of any TLS configuration reloads requested by the application
+        {
+            let endpoint_context = self.config.context();
+            let mut publisher = event::EndpointPublisherSubscriber::new(
+                event::builder::EndpointMeta {
+                    endpoint_type: Cfg::ENDPOINT_TYPE,
+                    timestamp,
+                },
+                None,
+                endpoint_context.event_subscriber,
+            );
+            endpoint_context.tls.publish_events(&mut publisher);
+        }
+
         let connection_id_mapper = &mut self.connection_id_mapper;
         let close_packet_buffer = &mut self.close_packet_buffer;
-        let endpoint_context = self.config.context();
+        let mut endpoint_context = self.config.context();
+        let shutdown = &mut self.shutdown;
 
         self.connections
             .iterate_timeout_list(timestamp, |conn, supervisor_context| {
@@ -936,7 +1068,16 @@ impl<Cfg: Config> Endpoint<Cfg> {
                         endpoint_context.event_subscriber,
                         endpoint_context.packet_interceptor,
                     );
+                    return;
                 }
+
+                Self::close_if_idle(
+                    conn,
+                    shutdown,
+                    close_packet_buffer,
+                    timestamp,
+                    &mut endpoint_context,
+                );
             });
 
         // allow connections to generate a new connection id
@@ -955,8 +1096,120 @@ impl<Cfg: Config> Endpoint<Cfg> {
                     panic!("Generated connection ID was already in use");
                 }
             });
+
+        if self.shutdown.is_shutting_down() {
+            self.on_shutdown_progress();
+        }
+    }
+
+    /// Closes the connections which don't have any open streams once the shutdown starts
+    ///
+    /// Busy connections are closed when they become idle or once the deadline passes. Returns
+    /// the number of connections which were closed.
+    fn on_shutdown(&mut self, timestamp: Timestamp) -> usize {
+        let shutdown = &mut self.shutdown;
+        let close_packet_buffer = &mut self.close_packet_buffer;
+        let mut endpoint_context = self.config.context();
+        let mut closed = 0;
+
+        self.connections.iterate_all(|conn| {
+            if Self::close_if_idle(
+                conn,
+                shutdown,
+                close_packet_buffer,
+                timestamp,
+                &mut endpoint_context,
+            ) {
+                closed += 1;
+            }
+        });
+
+        self.on_shutdown_progress();
+
+        closed
+    }
+
+    /// Closes all of the remaining connections once the shutdown deadline has passed
+    fn on_shutdown_deadline(&mut self, timestamp: Timestamp) {
+        let Some(error) = self.shutdown.error() else {
+            return;
+        };
+        let error = connection::Error::application(error);
+
+        let shutdown = &mut self.shutdown;
+        let close_packet_buffer = &mut self.close_packet_buffer;
+        let endpoint_context = self.config.context();
+
+        self.connections.iterate_all(|conn| {
+            // the connection is already closing
+            if conn.error().is_some() {
+                return;
+            }
+
+            shutdown.on_forced_connection();
+            conn.close(
+                error,
+                endpoint_context.connection_close_formatter,
+                close_packet_buffer,
+                timestamp,
+                endpoint_context.event_subscriber,
+                endpoint_context.packet_interceptor,
+            );
+        });
     }
 
+    /// Closes the connection if the endpoint is shutting down and the connection doesn't have
+    /// any open streams
+    ///
+    /// Returns `true` if the connection was closed.
+    #[inline]
+    fn close_if_idle(
+        conn: &mut Cfg::Connection,
+        shutdown: &mut shutdown::Controller,
+        close_packet_buffer: &mut PacketBuffer,
+        timestamp: Timestamp,
+        endpoint_context: &mut Context<Cfg>,
+    ) -> bool {
+        let Some(error) = shutdown.error() else {
+            return false;
+        };
+
+        if !conn.is_idle() {
+            return false;
+        }
+
+        shutdown.on_idle_connection();
+        conn.close(
+            connection::Error::application(error),
+            endpoint_context.connection_close_formatter,
+            close_packet_buffer,
+            timestamp,
+            endpoint_context.event_subscriber,
+            endpoint_context.packet_interceptor,
+        );
+
+        true
+    }
+
+    /// Finishes the shutdown once all of the connections have closed
+    fn on_shutdown_progress(&mut self) {
+        if !self.connections.is_empty() {
+            return;
+        }
+
+        self.shutdown.finish();
+
+        // transition to close state
+        self.close_handle.close();
+
+        // stop accepting new connections and prepare to close the endpoint
+        self.connections.close();
+    }
+
+    /// Creates a connection for the client's `request`
+    ///
+    /// On failure the error is sent to the application and returned so the caller can publish
+    /// the failed attempt.
     fn create_client_connection(
         &mut self,
         request: endpoint::connect::Request,
@@ -973,8 +1226,13 @@ impl<Cfg: Config> Endpoint<Cfg> {
                     remote_address,
                     server_name: hostname,
                     deduplicate,
+                    local_address,
+                    limits: limits_override,
+                    handshake_timeout,
+                    tls: tls_options,
+                    close_on_drop,
                 },
-            sender,
+            mut sender,
         } = request;
 
         let internal_connection_id = self.connection_id_generator.generate_id();
@@ -986,9 +1244,11 @@ impl<Cfg: Config> Endpoint<Cfg> {
             // places internally).
             //
             // Just prevent that configuration for now.
-            return Err(connection::Error::invalid_configuration(
+            let error = connection::Error::invalid_configuration(
                 "Requested connection deduplication which is not supported without (unstable) dc provider configured",
-            ));
+            );
+            let _ = sender.send(Err(error));
+            return Err(error);
         }
 
         let open_registry = if deduplicate {
@@ -998,6 +1258,11 @@ impl<Cfg: Config> Endpoint<Cfg> {
                     remote_address,
                     server_name: hostname.clone(),
                     deduplicate,
+                    local_address,
+                    limits: limits_override,
+                    handshake_timeout,
+                    tls: tls_options.clone(),
+                    close_on_drop,
                 },
             ) {
                 Ok(existing) => {
@@ -1115,31 +1380,35 @@ impl<Cfg: Config> Endpoint<Cfg> {
             initial_source_connection_id: Some(local_connection_id.into()),
             ..Default::default()
         };
-        let limits = endpoint_context
-            .connection_limits
-            .on_connection(&LimitsInfo::new(&remote_address));
-
-        let mut endpoint_publisher = event::EndpointPublisherSubscriber::new(
-            event::builder::EndpointMeta {
-                endpoint_type: Cfg::ENDPOINT_TYPE,
-                timestamp,
-            },
-            Some(quic_version),
-            endpoint_context.event_subscriber,
-        );
+        let mut limits = limits_override.unwrap_or_else(|| {
+            endpoint_context
+                .connection_limits
+                .on_connection(&LimitsInfo::new(&remote_address))
+        });
 
-        let mtu_config = endpoint_context
-            .mtu
-            .config(&remote_address)
-            .map_err(|_err| {
+        let mtu_config = match endpoint_context.mtu.config(&remote_address) {
+            Ok(mtu_config) => mtu_config,
+            Err(_err) => {
                 let error = connection::Error::invalid_configuration(
                     "MTU provider produced an invalid MTU configuration",
                 );
-                endpoint_publisher.on_endpoint_connection_attempt_failed(
-                    event::builder::EndpointConnectionAttemptFailed { error },
-                );
-                error
-            })?;
+                let _ = sender.send(Err(error));
+                return Err(error);
+            }
+        };
+
+        if let Some(timeout) = handshake_timeout {
+            limits = match limits.with_max_handshake_duration(timeout) {
+                Ok(limits) => limits,
+                Err(_err) => {
+                    let error = connection::Error::invalid_configuration(
+                        "the connection handshake timeout is invalid",
+                    );
+                    let _ = sender.send(Err(error));
+                    return Err(error);
+                }
+            };
+        }
 
         let mut publisher = event::ConnectionPublisherSubscriber::new(
             meta,
@@ -1185,20 +1454,29 @@ impl<Cfg: Config> Endpoint<Cfg> {
             <<Cfg::TLSEndpoint as tls::Endpoint>::Session as CryptoSuite>::InitialKey::new_client(
                 original_destination_connection_id.as_bytes(),
             );
-        let tls_session = endpoint_context
+        let tls_session = match endpoint_context
             .tls
             // TODO should SNI be optional? rustls expects a SNI but other tls providers dont seem
             // to require this value.
-            .new_client_session(
+            .new_client_session_with_options(
                 &transport_parameters,
                 hostname.expect("application should provide a valid server name"),
-            );
+                &tls_options,
+            ) {
+            Ok(session) => session,
+            Err(err) => {
+                let error = connection::Error::invalid_configuration(err.reason);
+                let _ = sender.send(Err(error));
+                return Err(error);
+            }
+        };
         let space_manager = PacketSpaceManager::new(
             original_destination_connection_id,
             tls_session,
             initial_key,
             initial_header_key,
             timestamp,
+            limits.max_crypto_buffer_size(),
             &mut publisher,
         );
 
@@ -1206,10 +1484,27 @@ impl<Cfg: Config> Endpoint<Cfg> {
             .wakeup_queue
             .create_wakeup_handle(internal_connection_id);
 
-        let path_handle =
+        if close_on_drop {
+            // wake up the connection when the application drops the attempt so it can be closed
+            let waker = Waker::from(Arc::new(
+                self.wakeup_queue
+                    .create_wakeup_handle(internal_connection_id),
+            ));
+            if sender
+                .poll_canceled(&mut task::Context::from_waker(&waker))
+                .is_ready()
+            {
+                waker.wake();
+            }
+        }
+
+        let mut path_handle =
             <<Cfg as endpoint::Config>::PathHandle as path::Handle>::from_remote_address(
                 remote_address,
             );
+        if let Some(local_address) = local_address {
+            path::Handle::set_local_address(&mut path_handle, local_address);
+        }
 
         let connection_parameters = connection::Parameters {
             internal_connection_id,
@@ -1233,9 +1528,20 @@ impl<Cfg: Config> Endpoint<Cfg> {
             open_registry,
             limits_endpoint: endpoint_context.connection_limits,
         };
-        let connection = <Cfg as crate::endpoint::Config>::Connection::new(connection_parameters)?;
-        self.connections
-            .insert_client_connection(connection, internal_connection_id, sender);
+        let connection =
+            match <Cfg as crate::endpoint::Config>::Connection::new(connection_parameters) {
+                Ok(connection) => connection,
+                Err(error) => {
+                    let _ = sender.send(Err(error));
+                    return Err(error);
+                }
+            };
+        self.connections.insert_client_connection(
+            connection,
+            internal_connection_id,
+            sender,
+            close_on_drop,
+        );
         Ok(())
     }
 }
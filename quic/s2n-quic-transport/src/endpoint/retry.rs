@@ -8,7 +8,7 @@ use s2n_quic_core::{
     connection,
     crypto::RetryKey,
     event,
-    inet::ExplicitCongestionNotification,
+    inet::{Dscp, ExplicitCongestionNotification},
     io::tx,
     packet,
     path::{self, MINIMUM_MAX_DATAGRAM_SIZE},
@@ -145,6 +145,11 @@ impl<Path: path::Handle> tx::Message for &Transmission<Path> {
         Default::default()
     }
 
+    #[inline]
+    fn dscp(&mut self) -> Dscp {
+        Dscp::DEFAULT
+    }
+
     #[inline]
     fn delay(&mut self) -> time::Duration {
         Default::default()
@@ -0,0 +1,340 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Allows applications to gracefully shut down an endpoint
+//!
+//! Once a shutdown is requested, the endpoint stops accepting new connections and closes any
+//! connections which don't have open streams. Connections with open streams are given until the
+//! deadline to finish, after which they are closed as well.
+
+use crate::endpoint::handle::{ShutdownReceiver, ShutdownSender};
+use alloc::sync::Arc;
+use core::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+use futures_channel::oneshot;
+use s2n_quic_core::{application, time::Timestamp};
+use std::sync::Mutex;
+
+/// Held by the [`Shutdown`] future. Used to receive the summary once the endpoint has shut down.
+type SummaryReceiver = oneshot::Receiver<Summary>;
+/// Held by the endpoint. Used to send the summary once all of the connections have closed.
+type SummarySender = oneshot::Sender<Summary>;
+
+/// A summary of how connections ended during a graceful shutdown
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Summary {
+    /// The number of connections which were closed because they didn't have any open streams
+    pub idle: usize,
+    /// The number of connections which closed on their own before the deadline
+    pub finished: usize,
+    /// The number of connections which were still open at the deadline and had to be closed
+    pub forced: usize,
+    /// The number of connection attempts which were rejected during the shutdown
+    ///
+    /// For servers, this counts the rejected Initial packets, which can include
+    /// retransmissions from the same peer.
+    pub rejected: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct Request {
+    pub deadline: Duration,
+    pub error: application::Error,
+    pub sender: SummarySender,
+}
+
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Shutdown {
+    state: ShutdownState,
+}
+
+impl Shutdown {
+    /// Creates a shutdown request
+    ///
+    /// Connections which are closed by the endpoint are closed with the given `error`. Any
+    /// connections which are still open after `deadline` are closed as well.
+    pub(crate) fn new(
+        shutdown: &ShutdownSender,
+        deadline: Duration,
+        error: application::Error,
+    ) -> Self {
+        let (sender, receiver) = oneshot::channel();
+        let request = Request {
+            deadline,
+            error,
+            sender,
+        };
+        Self {
+            state: ShutdownState::Request(request, shutdown.clone(), receiver),
+        }
+    }
+
+    #[inline]
+    fn poll_state(&mut self, cx: &mut Context) -> Poll<Summary> {
+        loop {
+            match core::mem::replace(&mut self.state, ShutdownState::Unreachable) {
+                ShutdownState::Request(request, mut shutdown, receiver) => {
+                    match shutdown.poll_ready(cx) {
+                        Poll::Ready(Ok(())) => match shutdown.try_send(request) {
+                            Ok(_) => {
+                                // transition to the waiting state
+                                self.state = ShutdownState::Waiting(receiver);
+                                continue;
+                            }
+                            Err(err) if err.is_full() => {
+                                // reset to the original state
+                                self.state =
+                                    ShutdownState::Request(err.into_inner(), shutdown, receiver);
+
+                                // yield and wake up the task since the sender misreported its ready state
+                                cx.waker().wake_by_ref();
+                            }
+                            Err(_) => {
+                                // The endpoint has already closed
+                                return Poll::Ready(Summary::default());
+                            }
+                        },
+                        Poll::Ready(Err(_)) => {
+                            // The endpoint has already closed
+                            return Poll::Ready(Summary::default());
+                        }
+                        Poll::Pending => {
+                            // reset to the original state
+                            self.state = ShutdownState::Request(request, shutdown, receiver);
+                        }
+                    }
+
+                    return Poll::Pending;
+                }
+                ShutdownState::Waiting(mut receiver) => {
+                    return match Pin::new(&mut receiver).poll(cx) {
+                        Poll::Ready(Ok(summary)) => Poll::Ready(summary),
+                        Poll::Ready(Err(_)) => {
+                            // The endpoint was dropped before it finished shutting down
+                            Poll::Ready(Summary::default())
+                        }
+                        Poll::Pending => {
+                            self.state = ShutdownState::Waiting(receiver);
+                            Poll::Pending
+                        }
+                    };
+                }
+                ShutdownState::Unreachable => {
+                    unreachable!(
+                        "Unreachable is an immediate state and should not exist across polls"
+                    );
+                }
+            }
+        }
+    }
+}
+
+enum ShutdownState {
+    /// The request is currently waiting for capacity in the `ShutdownSender`
+    Request(Request, ShutdownSender, SummaryReceiver),
+    /// The request was sent and is waiting for the endpoint to finish shutting down
+    Waiting(SummaryReceiver),
+    /// This is an intermediate state and should not persist across calls to `poll`
+    Unreachable,
+}
+
+impl Future for Shutdown {
+    type Output = Summary;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        s2n_quic_core::task::waker::debug_assert_contract(cx, |cx| self.poll_state(cx))
+    }
+}
+
+/// A future which resolves once the endpoint starts shutting down
+///
+/// Applications can use this to finish any in-progress work on busy connections before the
+/// shutdown deadline.
+#[derive(Clone, Debug, Default)]
+pub struct Signal(Arc<SignalState>);
+
+#[derive(Debug, Default)]
+struct SignalState {
+    is_notified: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl Signal {
+    /// Returns `true` if the endpoint has started shutting down
+    #[inline]
+    pub fn is_shutting_down(&self) -> bool {
+        self.0.is_notified.load(Ordering::Acquire)
+    }
+
+    fn notify(&self) {
+        self.0.is_notified.store(true, Ordering::Release);
+
+        let wakers = core::mem::take(&mut *self.0.wakers.lock().unwrap());
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl Future for Signal {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.is_shutting_down() {
+            return Poll::Ready(());
+        }
+
+        let mut wakers = self.0.wakers.lock().unwrap();
+
+        // check again now that we're holding the lock to avoid missing the notification
+        if self.is_shutting_down() {
+            return Poll::Ready(());
+        }
+
+        if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            wakers.push(cx.waker().clone());
+        }
+
+        Poll::Pending
+    }
+}
+
+/// Held by library. Used to receive shutdown requests and track the shutdown progress.
+#[derive(Debug)]
+pub(crate) struct Controller {
+    receiver: ShutdownReceiver,
+    signal: Signal,
+    state: Option<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    /// The time at which the remaining connections are closed. This is cleared once the
+    /// deadline has been processed.
+    deadline: Option<Timestamp>,
+    error: application::Error,
+    /// The number of connections which were open when the shutdown started
+    connections: usize,
+    summary: Summary,
+    senders: Vec<SummarySender>,
+}
+
+impl Controller {
+    pub fn new(receiver: ShutdownReceiver, signal: Signal) -> Self {
+        Self {
+            receiver,
+            signal,
+            state: None,
+        }
+    }
+
+    /// Polls for shutdown requests from the application
+    ///
+    /// Returns `true` if the endpoint started shutting down.
+    pub fn poll_requests(&mut self, cx: &mut Context, now: Timestamp, connections: usize) -> bool {
+        let mut started = false;
+
+        while let Poll::Ready(Some(request)) =
+            futures_core::Stream::poll_next(Pin::new(&mut self.receiver), cx)
+        {
+            let deadline = now + request.deadline;
+
+            if let Some(state) = self.state.as_mut() {
+                // use the earliest deadline if the application requests the shutdown again
+                if let Some(prev) = state.deadline.as_mut() {
+                    *prev = (*prev).min(deadline);
+                }
+                state.senders.push(request.sender);
+                continue;
+            }
+
+            self.state = Some(State {
+                deadline: Some(deadline),
+                error: request.error,
+                connections,
+                summary: Summary::default(),
+                senders: vec![request.sender],
+            });
+            self.signal.notify();
+            started = true;
+        }
+
+        started
+    }
+
+    /// Returns the error to close connections with if the endpoint is shutting down
+    #[inline]
+    pub fn error(&self) -> Option<application::Error> {
+        Some(self.state.as_ref()?.error)
+    }
+
+    #[inline]
+    pub fn is_shutting_down(&self) -> bool {
+        self.state.is_some()
+    }
+
+    /// Returns the time at which the remaining connections should be closed
+    #[inline]
+    pub fn timeout(&self) -> Option<Timestamp> {
+        self.state.as_ref()?.deadline
+    }
+
+    /// Returns `true` if the deadline has passed and clears it
+    #[inline]
+    pub fn on_timeout(&mut self, now: Timestamp) -> bool {
+        let Some(state) = self.state.as_mut() else {
+            return false;
+        };
+        match state.deadline {
+            Some(deadline) if deadline.has_elapsed(now) => {
+                state.deadline = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    #[inline]
+    pub fn on_idle_connection(&mut self) {
+        if let Some(state) = self.state.as_mut() {
+            state.summary.idle += 1;
+        }
+    }
+
+    #[inline]
+    pub fn on_forced_connection(&mut self) {
+        if let Some(state) = self.state.as_mut() {
+            state.summary.forced += 1;
+        }
+    }
+
+    #[inline]
+    pub fn on_rejected_connection(&mut self) {
+        if let Some(state) = self.state.as_mut() {
+            state.summary.rejected += 1;
+        }
+    }
+
+    /// Notifies the application that all of the connections have closed
+    pub fn finish(&mut self) {
+        let Some(state) = self.state.take() else {
+            return;
+        };
+
+        let mut summary = state.summary;
+        summary.finished = state
+            .connections
+            .saturating_sub(summary.idle + summary.forced);
+
+        for sender in state.senders {
+            // the application may no longer be waiting on the summary
+            let _ = sender.send(summary);
+        }
+    }
+}
@@ -4,8 +4,12 @@
 use crate::endpoint;
 use alloc::collections::VecDeque;
 use s2n_quic_core::{
-    event, inet::ExplicitCongestionNotification, io::tx, packet, path,
-    path::MINIMUM_MAX_DATAGRAM_SIZE, random, stateless_reset, time,
+    event,
+    inet::{Dscp, ExplicitCongestionNotification},
+    io::tx,
+    packet, path,
+    path::MINIMUM_MAX_DATAGRAM_SIZE,
+    random, stateless_reset, time,
 };
 
 #[derive(Debug)]
@@ -133,6 +137,11 @@ impl<Path: path::Handle> tx::Message for &Transmission<Path> {
         Default::default()
     }
 
+    #[inline]
+    fn dscp(&mut self) -> Dscp {
+        Dscp::DEFAULT
+    }
+
     #[inline]
     fn delay(&mut self) -> time::Duration {
         Default::default()
@@ -0,0 +1,256 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Allows applications to wait on the endpoint's clock
+//!
+//! Timers are driven by the endpoint rather than the application's runtime so they follow the
+//! same clock as the connections, including any simulated clock used by the IO provider.
+
+use crate::endpoint::handle::{TimerReceiver, TimerSender};
+use alloc::sync::Arc;
+use core::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+use s2n_quic_core::time::Timestamp;
+use std::sync::Mutex;
+
+#[derive(Debug)]
+pub(crate) struct Request {
+    pub duration: Duration,
+    state: Arc<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_expired: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl State {
+    #[inline]
+    fn is_expired(&self) -> bool {
+        self.is_expired.load(Ordering::Acquire)
+    }
+
+    fn expire(&self) {
+        self.is_expired.store(true, Ordering::Release);
+
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+}
+
+/// A future which resolves once the duration has elapsed on the endpoint's clock
+///
+/// The future also resolves if the endpoint closes before the duration has elapsed.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Sleep {
+    state: Arc<State>,
+    /// The request to send to the endpoint on the first poll
+    request: Option<(Duration, TimerSender)>,
+}
+
+impl Sleep {
+    pub(crate) fn new(timer: &TimerSender, duration: Duration) -> Self {
+        Self {
+            state: Default::default(),
+            request: Some((duration, timer.clone())),
+        }
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some((duration, mut sender)) = self.request.take() {
+            match sender.poll_ready(cx) {
+                Poll::Ready(Ok(())) => {
+                    let request = Request {
+                        duration,
+                        state: self.state.clone(),
+                    };
+
+                    match sender.try_send(request) {
+                        Ok(()) => {}
+                        Err(err) if err.is_full() => {
+                            self.request = Some((duration, sender));
+
+                            // yield and wake up the task since the sender misreported its ready state
+                            cx.waker().wake_by_ref();
+                            return Poll::Pending;
+                        }
+                        Err(_) => {
+                            // The endpoint has already closed
+                            return Poll::Ready(());
+                        }
+                    }
+                }
+                Poll::Ready(Err(_)) => {
+                    // The endpoint has already closed
+                    return Poll::Ready(());
+                }
+                Poll::Pending => {
+                    // wait for the endpoint to make room for the request
+                    self.request = Some((duration, sender));
+                    return Poll::Pending;
+                }
+            }
+        }
+
+        if self.state.is_expired() {
+            return Poll::Ready(());
+        }
+
+        let mut waker = self.state.waker.lock().unwrap();
+
+        // check again now that we're holding the lock to avoid missing the expiration
+        if self.state.is_expired() {
+            return Poll::Ready(());
+        }
+
+        match waker.as_mut() {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            _ => *waker = Some(cx.waker().clone()),
+        }
+
+        Poll::Pending
+    }
+}
+
+/// Held by library. Used to receive timer requests and expire them.
+#[derive(Debug)]
+pub(crate) struct Controller {
+    receiver: TimerReceiver,
+    timers: Vec<(Timestamp, Arc<State>)>,
+}
+
+impl Controller {
+    pub fn new(receiver: TimerReceiver) -> Self {
+        Self {
+            receiver,
+            timers: Vec::new(),
+        }
+    }
+
+    /// Polls for timer requests from the application and returns the number received
+    pub fn poll_requests(&mut self, cx: &mut Context, now: Timestamp) -> usize {
+        let mut count = 0;
+
+        while let Poll::Ready(Some(request)) =
+            futures_core::Stream::poll_next(Pin::new(&mut self.receiver), cx)
+        {
+            self.timers.push((now + request.duration, request.state));
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Returns the earliest time at which a timer expires
+    #[inline]
+    pub fn timeout(&self) -> Option<Timestamp> {
+        self.timers.iter().map(|(expiration, _)| *expiration).min()
+    }
+
+    /// Expires any timers which have elapsed
+    pub fn on_timeout(&mut self, now: Timestamp) {
+        self.timers.retain(|(expiration, state)| {
+            // the application dropped the `Sleep` future so there's nothing to notify
+            if Arc::strong_count(state) == 1 {
+                return false;
+            }
+
+            if expiration.has_elapsed(now) {
+                state.expire();
+                return false;
+            }
+
+            true
+        });
+    }
+}
+
+impl Drop for Controller {
+    fn drop(&mut self) {
+        // notify any pending timers so they don't wait on a closed endpoint forever
+        for (_, state) in self.timers.drain(..) {
+            state.expire();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_channel::mpsc;
+    use futures_test::task::new_count_waker;
+    use s2n_quic_core::time::clock::testing as time;
+
+    #[test]
+    fn sleep_expires_on_timeout() {
+        let (sender, receiver) = mpsc::channel(1);
+        let mut controller = Controller::new(receiver);
+        let (waker, wake_count) = new_count_waker();
+        let mut cx = Context::from_waker(&waker);
+        let now = time::now();
+
+        let mut sleep = Sleep::new(&sender, Duration::from_millis(100));
+        assert!(Pin::new(&mut sleep).poll(&mut cx).is_pending());
+
+        assert_eq!(controller.poll_requests(&mut cx, now), 1);
+        assert_eq!(controller.timeout(), Some(now + Duration::from_millis(100)));
+
+        controller.on_timeout(now + Duration::from_millis(99));
+        assert_eq!(wake_count.get(), 0);
+        assert!(Pin::new(&mut sleep).poll(&mut cx).is_pending());
+
+        controller.on_timeout(now + Duration::from_millis(100));
+        assert_eq!(wake_count.get(), 1);
+        assert_eq!(controller.timeout(), None);
+        assert!(Pin::new(&mut sleep).poll(&mut cx).is_ready());
+    }
+
+    #[test]
+    fn dropped_sleep_is_removed() {
+        let (sender, receiver) = mpsc::channel(1);
+        let mut controller = Controller::new(receiver);
+        let (waker, _wake_count) = new_count_waker();
+        let mut cx = Context::from_waker(&waker);
+        let now = time::now();
+
+        let mut sleep = Sleep::new(&sender, Duration::from_millis(100));
+        assert!(Pin::new(&mut sleep).poll(&mut cx).is_pending());
+        controller.poll_requests(&mut cx, now);
+        drop(sleep);
+
+        controller.on_timeout(now);
+        assert_eq!(controller.timeout(), None);
+    }
+
+    #[test]
+    fn closed_endpoint_expires_sleep() {
+        let (sender, receiver) = mpsc::channel(1);
+        let mut controller = Controller::new(receiver);
+        let (waker, wake_count) = new_count_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let mut sleep = Sleep::new(&sender, Duration::from_secs(1));
+        assert!(Pin::new(&mut sleep).poll(&mut cx).is_pending());
+        controller.poll_requests(&mut cx, time::now());
+
+        drop(controller);
+        assert_eq!(wake_count.get(), 1);
+        assert!(Pin::new(&mut sleep).poll(&mut cx).is_ready());
+
+        // sleeps created after the endpoint closes resolve immediately
+        let mut sleep = Sleep::new(&sender, Duration::from_secs(1));
+        assert!(Pin::new(&mut sleep).poll(&mut cx).is_ready());
+    }
+}
@@ -7,7 +7,7 @@ use core::time::Duration;
 use s2n_codec::{Encoder, EncoderBuffer, EncoderValue};
 use s2n_quic_core::{
     event,
-    inet::ExplicitCongestionNotification,
+    inet::{Dscp, ExplicitCongestionNotification},
     io::tx,
     packet,
     packet::ProtectedPacket,
@@ -248,6 +248,11 @@ impl<Path: path::Handle> tx::Message for &Transmission<Path> {
         Default::default()
     }
 
+    #[inline]
+    fn dscp(&mut self) -> Dscp {
+        Dscp::DEFAULT
+    }
+
     #[inline]
     fn delay(&mut self) -> Duration {
         Default::default()
@@ -69,6 +69,12 @@ pub struct Manager<Config: endpoint::Config> {
     /// The `paths` data structure will need to be enhanced to include garbage collection
     /// of old paths to overcome this limitation.
     pending_packet_authentication: Option<u8>,
+
+    /// The counters of paths which have been replaced
+    ///
+    /// Path slots are reused, so the counters of a path are added here before it's replaced to
+    /// keep the connection's totals from going backwards.
+    retired_counters: connection::stats::Counters,
 }
 
 impl<Config: endpoint::Config> Manager<Config> {
@@ -79,6 +85,7 @@ impl<Config: endpoint::Config> Manager<Config> {
             active: 0,
             last_known_active_validated_path: None,
             pending_packet_authentication: None,
+            retired_counters: Default::default(),
         };
         manager.paths[0].activated = true;
         manager.paths[0].is_active = true;
@@ -152,6 +159,17 @@ impl<Config: endpoint::Config> Manager<Config> {
         &mut self.paths[self.active as usize]
     }
 
+    /// Returns the counters accumulated across all of the paths, including the ones which have
+    /// been replaced
+    #[inline]
+    pub fn counters(&self) -> connection::stats::Counters {
+        let mut counters = self.retired_counters;
+        for path in self.paths.iter() {
+            counters += path.counters;
+        }
+        counters
+    }
+
     /// Return the Id of the active path
     #[inline]
     pub fn active_path_id(&self) -> Id {
@@ -489,6 +507,7 @@ impl<Config: endpoint::Config> Manager<Config> {
 
         // create a new path
         if new_path_idx < self.paths.len() {
+            self.retired_counters += self.paths[new_path_idx].counters;
             self.paths[new_path_idx] = path;
         } else {
             self.paths.push(path);
@@ -1678,6 +1678,62 @@ fn temporary_until_authenticated() {
     );
 }
 
+#[test]
+// Ensure the counters of a replaced path are still included in the connection's totals
+fn counters_include_replaced_paths() {
+    let mut publisher = Publisher::no_snapshot();
+    let now = NoopClock {}.get_time();
+    let datagram = DatagramInfo {
+        timestamp: now,
+        payload_len: 0,
+        ecn: ExplicitCongestionNotification::default(),
+        destination_connection_id: connection::LocalId::TEST_ID,
+        destination_connection_id_classification: connection::id::Classification::Local,
+        source_connection_id: None,
+    };
+
+    let first_addr: SocketAddr = "127.0.0.1:8001".parse().unwrap();
+    let first_addr = RemoteAddress::from(SocketAddress::from(first_addr));
+    let first_path = ServerPath::new(
+        first_addr,
+        connection::PeerId::try_from_bytes(&[1]).unwrap(),
+        connection::LocalId::TEST_ID,
+        RttEstimator::default(),
+        Default::default(),
+        false,
+        mtu::Config::default(),
+        ANTI_AMPLIFICATION_MULTIPLIER,
+    );
+    let mut manager = manager_server(first_path);
+    manager[path_id(0)].counters.bytes_received = 100;
+
+    let mut expected = 100;
+    for addr in ["127.0.0.2:8001", "127.0.0.3:8001"] {
+        let addr: SocketAddr = addr.parse().unwrap();
+        let addr = RemoteAddress::from(SocketAddress::from(addr));
+
+        // the path isn't authenticated so the second one reuses its slot
+        let (id, _amplification_outcome) = manager
+            .on_datagram_received(
+                &addr,
+                &datagram,
+                true,
+                &mut Default::default(),
+                &mut migration::allow_all::Validator,
+                &mut mtu::Manager::new(mtu::Config::default()),
+                &Limits::default(),
+                &mut publisher,
+            )
+            .unwrap();
+        assert_eq!(id, path_id(1));
+
+        manager[id].counters.bytes_received += 50;
+        expected += 50;
+
+        assert_eq!(manager.counters().bytes_received, expected);
+    }
+}
+
 // The last_known_active_validated_path needs to be both validated and also
 // activated (the active path at some point in the connection).
 //
@@ -60,6 +60,8 @@ pub struct Path<Config: endpoint::Config> {
     pub mtu_controller: mtu::Controller,
     /// Controller for determining the ECN capability of the path
     pub ecn_controller: ecn::Controller,
+    /// Counters for the packets sent and received on the path
+    pub counters: connection::stats::Counters,
 
     /// True if the path has been validated by the peer
     peer_validated: bool,
@@ -95,6 +97,7 @@ impl<Config: endpoint::Config> Clone for Path<Config> {
             state: self.state,
             mtu_controller: self.mtu_controller.clone(),
             ecn_controller: self.ecn_controller.clone(),
+            counters: self.counters,
             peer_validated: self.peer_validated,
             challenge: self.challenge.clone(),
             response_data: self.response_data,
@@ -143,6 +146,7 @@ impl<Config: endpoint::Config> Path<Config> {
             state,
             mtu_controller: mtu::Controller::new(mtu_config, &peer_socket_address),
             ecn_controller: ecn::Controller::default(),
+            counters: Default::default(),
             peer_validated,
             challenge: Challenge::disabled(),
             response_data: None,
@@ -309,6 +313,17 @@ impl<Config: endpoint::Config> Path<Config> {
                 .is_none_or(|edt| edt.has_elapsed(timestamp))
     }
 
+    /// Returns the time at which a packet written at the given timestamp should depart
+    ///
+    /// This is only set if the packet is written ahead of the pacer's earliest departure time,
+    /// which is the case when the IO provider enforces the pacing.
+    #[inline]
+    pub fn departure_time(&self, timestamp: Timestamp) -> Option<Timestamp> {
+        self.congestion_controller
+            .earliest_departure_time()
+            .filter(|edt| *edt > timestamp)
+    }
+
     /// Only PATH_CHALLENGE and PATH_RESPONSE frames should be transmitted here.
     #[inline]
     pub fn on_transmit<W: WriteContext>(&mut self, context: &mut W) {
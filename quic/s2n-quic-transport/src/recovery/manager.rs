@@ -264,6 +264,10 @@ impl<Config: endpoint::Config> Manager<Config> {
             .on_packet_sent(ecn, path_event!(path, path_id), publisher);
         self.sent_packet_ecn_counts.increment(ecn);
 
+        path.counters.packets_sent += 1;
+        path.counters.bytes_sent += outcome.bytes_sent as u64;
+        path.counters.ecn_sent.increment(ecn);
+
         if outcome.ack_elicitation.is_ack_eliciting() {
             self.time_of_last_ack_eliciting_packet = Some(time_sent);
             //= https://www.rfc-editor.org/rfc/rfc9002#section-6.2.1
@@ -1002,6 +1006,9 @@ impl<Config: endpoint::Config> Manager<Config> {
                 is_mtu_probe: sent_info.transmission_mode.is_mtu_probing(),
             });
 
+            path.counters.packets_lost += 1;
+            path.counters.bytes_lost += sent_info.sent_bytes as u64;
+
             let path_id = sent_info.path_id;
 
             // Notify the ECN controller of packet loss for blackhole detection.
@@ -105,6 +105,7 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
         keep_alive: KeepAlive,
         datagram_manager: datagram::Manager<Config>,
         dc_manager: dc::Manager<Config>,
+        max_crypto_buffer_size: Option<u32>,
     ) -> Self {
         let key_set = KeySet::new(key, Self::key_limits());
 
@@ -113,7 +114,7 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
             ack_manager,
             spin_bit: SpinBit::Zero,
             stream_manager,
-            crypto_stream: CryptoStream::new(),
+            crypto_stream: CryptoStream::new(max_crypto_buffer_size),
             key_set,
             header_key,
             ping: flag::Ping::default(),
@@ -35,22 +35,26 @@ pub struct CryptoStream {
     pub tx: TxCryptoStream,
     pub rx: Reassembler,
     is_finished: bool,
+    max_buffer_size: Option<u32>,
 }
 
 const TX_MAX_BUFFER_CAPACITY: u32 = 4096;
 
 impl Default for CryptoStream {
     fn default() -> Self {
-        Self::new()
+        Self::new(None)
     }
 }
 
 impl CryptoStream {
-    pub fn new() -> Self {
+    /// Creates a crypto stream which buffers at most `max_buffer_size` bytes of received data
+    /// ahead of what has been consumed by the TLS session
+    pub fn new(max_buffer_size: Option<u32>) -> Self {
         Self {
             tx: TxCryptoStream::new(Default::default(), TX_MAX_BUFFER_CAPACITY),
             rx: Reassembler::default(),
             is_finished: false,
+            max_buffer_size,
         }
     }
 
@@ -87,20 +91,24 @@ impl CryptoStream {
         }
 
         //= https://www.rfc-editor.org/rfc/rfc9000#section-7.5
-        //= type=TODO
-        //= tracking-issue=356
-        //= feature=Crypto buffer limits
         //# Implementations MUST support buffering at least 4096 bytes of data
         //# received in out-of-order CRYPTO frames.
 
         //= https://www.rfc-editor.org/rfc/rfc9000#section-7.5
-        //= type=TODO
-        //= tracking-issue=356
-        //= feature=Crypto buffer limits
         //# Endpoints MAY choose to
         //# allow more data to be buffered during the handshake.
 
-        //TODO we need to limit the buffer size here
+        // The configured limit is validated to be at least 4096 bytes. When no limit is
+        // configured, the buffer is only bounded by the reassembler.
+        if let Some(max_buffer_size) = self.max_buffer_size {
+            let end = frame.offset.as_u64() + frame.data.len() as u64;
+            if end > self.rx.consumed_len() + max_buffer_size as u64 {
+                //= https://www.rfc-editor.org/rfc/rfc9000#section-7.5
+                //# If an endpoint does not expand its buffer, it MUST close
+                //# the connection with a CRYPTO_BUFFER_EXCEEDED error code.
+                return Err(transport::Error::CRYPTO_BUFFER_EXCEEDED);
+            }
+        }
 
         self.rx.write_at(frame.offset, frame.data).map_err(|_| {
             //= https://www.rfc-editor.org/rfc/rfc9000#section-7.5
@@ -138,3 +146,39 @@ impl transmission::interest::Provider for CryptoStream {
         self.tx.transmission_interest(query)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(offset: u32, data: &[u8]) -> CryptoRef {
+        CryptoRef {
+            offset: VarInt::from_u32(offset),
+            data,
+        }
+    }
+
+    #[test]
+    fn max_buffer_size() {
+        let mut stream = CryptoStream::new(Some(4096));
+        let data = [0; 1024];
+
+        // out-of-order data up to the limit is buffered
+        assert!(stream.on_crypto_frame(frame(3072, &data)).is_ok());
+        assert_eq!(
+            stream.on_crypto_frame(frame(3073, &data)),
+            Err(transport::Error::CRYPTO_BUFFER_EXCEEDED)
+        );
+
+        // consuming data makes room for more
+        assert!(stream.on_crypto_frame(frame(0, &data)).is_ok());
+        assert_eq!(stream.rx.pop().unwrap().len(), 1024);
+        assert!(stream.on_crypto_frame(frame(4096, &data)).is_ok());
+    }
+
+    #[test]
+    fn unlimited_buffer_size() {
+        let mut stream = CryptoStream::new(None);
+        assert!(stream.on_crypto_frame(frame(1_000_000, &[0; 1024])).is_ok());
+    }
+}
@@ -62,12 +62,13 @@ impl<Config: endpoint::Config> HandshakeSpace<Config> {
         header_key: <<Config::TLSEndpoint as tls::Endpoint>::Session as CryptoSuite>::HandshakeHeaderKey,
         now: Timestamp,
         ack_manager: AckManager,
+        max_crypto_buffer_size: Option<u32>,
     ) -> Self {
         Self {
             ack_manager,
             key,
             header_key,
-            crypto_stream: CryptoStream::new(),
+            crypto_stream: CryptoStream::new(max_crypto_buffer_size),
             tx_packet_numbers: TxPacketNumbers::new(PacketNumberSpace::Handshake, now),
             processed_packet_numbers: SlidingWindow::default(),
             recovery_manager: recovery::Manager::new(PacketNumberSpace::Handshake),
@@ -69,12 +69,13 @@ impl<Config: endpoint::Config> InitialSpace<Config> {
         header_key: <<Config::TLSEndpoint as tls::Endpoint>::Session as CryptoSuite>::InitialHeaderKey,
         now: Timestamp,
         ack_manager: AckManager,
+        max_crypto_buffer_size: Option<u32>,
     ) -> Self {
         Self {
             ack_manager,
             key,
             header_key,
-            crypto_stream: CryptoStream::new(),
+            crypto_stream: CryptoStream::new(max_crypto_buffer_size),
             tx_packet_numbers: TxPacketNumbers::new(PacketNumberSpace::Initial, now),
             received_hello_message: false,
             retry_token: Vec::new(),
@@ -79,6 +79,8 @@ pub struct PacketSpaceManager<Config: endpoint::Config> {
     //# another mechanism is used for agreeing on an application protocol,
     //# endpoints MUST use ALPN for this purpose.
     pub application_protocol: Bytes,
+    /// The SubjectPublicKeyInfo of the peer, if it authenticated with a raw public key
+    pub peer_public_key: Option<Bytes>,
 }
 
 impl<Config: endpoint::Config> fmt::Debug for PacketSpaceManager<Config> {
@@ -113,6 +115,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
         initial_key: <<Config::TLSEndpoint as tls::Endpoint>::Session as CryptoSuite>::InitialKey,
         header_key: <<Config::TLSEndpoint as tls::Endpoint>::Session as CryptoSuite>::InitialHeaderKey,
         now: Timestamp,
+        max_crypto_buffer_size: Option<u32>,
         publisher: &mut Pub,
     ) -> Self {
         let ack_manager = AckManager::new(PacketNumberSpace::Initial, ack::Settings::EARLY);
@@ -133,6 +136,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
                 header_key,
                 now,
                 ack_manager,
+                max_crypto_buffer_size,
             ))),
             handshake: None,
             application: None,
@@ -140,6 +144,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
             handshake_status: HandshakeStatus::default(),
             server_name: None,
             application_protocol: Bytes::new(),
+            peer_public_key: None,
         }
     }
 
@@ -269,6 +274,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
                 limits,
                 server_name: &mut self.server_name,
                 application_protocol: &mut self.application_protocol,
+                peer_public_key: &mut self.peer_public_key,
                 waker,
                 publisher,
                 datagram,
@@ -319,6 +325,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
                 limits,
                 server_name: &mut self.server_name,
                 application_protocol: &mut self.application_protocol,
+                peer_public_key: &mut self.peer_public_key,
                 waker,
                 publisher,
                 datagram,
@@ -63,6 +63,7 @@ pub struct SessionContext<'a, Config: endpoint::Config, Pub: event::ConnectionPu
     pub limits: &'a mut Limits,
     pub server_name: &'a mut Option<ServerName>,
     pub application_protocol: &'a mut Bytes,
+    pub peer_public_key: &'a mut Option<Bytes>,
     pub waker: &'a Waker,
     pub publisher: &'a mut Pub,
     pub datagram: &'a mut Config::DatagramEndpoint,
@@ -355,6 +356,7 @@ impl<Config: endpoint::Config, Pub: event::ConnectionPublisher>
             header_key,
             self.now,
             ack_manager,
+            self.limits.max_crypto_buffer_size(),
         )));
         self.publisher.on_key_update(event::builder::KeyUpdate {
             key_type: event::builder::KeyType::Handshake,
@@ -506,6 +508,7 @@ impl<Config: endpoint::Config, Pub: event::ConnectionPublisher>
             keep_alive,
             datagram_manager,
             dc_manager,
+            self.limits.max_crypto_buffer_size(),
         )));
         self.publisher.on_key_update(event::builder::KeyUpdate {
             key_type: event::builder::KeyType::OneRtt { generation: 0 },
@@ -549,6 +552,12 @@ impl<Config: endpoint::Config, Pub: event::ConnectionPublisher>
         Ok(())
     }
 
+    fn on_peer_public_key(&mut self, public_key: Bytes) -> Result<(), transport::Error> {
+        *self.peer_public_key = Some(public_key);
+
+        Ok(())
+    }
+
     fn on_tls_exporter_ready(
         &mut self,
         session: &impl tls::TlsSession,
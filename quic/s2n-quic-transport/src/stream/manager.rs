@@ -993,6 +993,42 @@ impl<S: 'static + StreamTrait> stream::Manager for AbstractStreamManager<S> {
     fn has_pending_streams(&self) -> bool {
         self.inner.streams.has_pending_streams()
     }
+
+    fn nr_active_streams(&self) -> usize {
+        self.inner.streams.nr_active_streams()
+    }
+
+    fn stats(&self, stats: &mut connection::stats::Streams) {
+        let local_endpoint_type = self.inner.local_endpoint_type;
+
+        self.inner.streams.for_each(|stream| {
+            let stream_id = stream.stream_id();
+            let count = match (
+                stream_id.initiator() == local_endpoint_type,
+                stream_id.stream_type(),
+            ) {
+                (true, StreamType::Bidirectional) => &mut stats.local_bidirectional,
+                (true, StreamType::Unidirectional) => &mut stats.local_unidirectional,
+                (false, StreamType::Bidirectional) => &mut stats.remote_bidirectional,
+                (false, StreamType::Unidirectional) => &mut stats.remote_unidirectional,
+            };
+            *count += 1;
+
+            stream.stats(stats);
+        });
+    }
+
+    fn for_each_open_stream(&self, f: &mut dyn FnMut(StreamId)) {
+        self.inner.streams.for_each(|stream| f(stream.stream_id()));
+    }
+
+    fn is_awaiting_peer_data(&self) -> bool {
+        let mut is_awaiting = false;
+        self.inner.streams.for_each(|stream| {
+            is_awaiting |= stream.is_awaiting_peer_data();
+        });
+        is_awaiting
+    }
 }
 
 impl<S: StreamTrait> timer::Provider for AbstractStreamManager<S> {
@@ -303,6 +303,12 @@ impl StreamTrait for MockStream {
         self.on_connection_window_available_retrieve_window -= Into::<u64>::into(acquired_window);
     }
 
+    fn stats(&self, _stats: &mut connection::stats::Streams) {}
+
+    fn is_awaiting_peer_data(&self) -> bool {
+        false
+    }
+
     fn poll_request(
         &mut self,
         request: &mut ops::Request,
@@ -591,6 +597,34 @@ fn returns_finalization_interest_after_last_stream_is_drained() {
     assert!(manager.finalization_status().is_final());
 }
 
+#[test]
+fn stats_count_streams_by_initiator_and_type() {
+    let mut manager = create_stream_manager(endpoint::Type::Server);
+
+    for _ in 0..2 {
+        try_open(&mut manager, StreamType::Bidirectional).unwrap();
+    }
+    try_open(&mut manager, StreamType::Unidirectional).unwrap();
+
+    // receiving data on the second stream implicitly opens the first one
+    let stream_id = StreamId::nth(endpoint::Type::Client, StreamType::Bidirectional, 1).unwrap();
+    manager
+        .on_data(&stream_data(stream_id, VarInt::from_u32(0), &[], false))
+        .unwrap();
+    let stream_id = StreamId::nth(endpoint::Type::Client, StreamType::Unidirectional, 0).unwrap();
+    manager
+        .on_data(&stream_data(stream_id, VarInt::from_u32(0), &[], false))
+        .unwrap();
+
+    let mut stats = connection::stats::Streams::default();
+    manager.stats(&mut stats);
+
+    assert_eq!(stats.local_bidirectional, 2);
+    assert_eq!(stats.local_unidirectional, 1);
+    assert_eq!(stats.remote_bidirectional, 2);
+    assert_eq!(stats.remote_unidirectional, 1);
+}
+
 #[test]
 fn remote_messages_which_target_locally_initiated_unopened_streams_error() {
     for initiator_type in &[endpoint::Type::Server, endpoint::Type::Client] {
@@ -142,4 +142,16 @@ pub trait Manager:
 
     /// Returns whether or not streams have data to send
     fn has_pending_streams(&self) -> bool;
+
+    /// Returns the number of streams which are currently open
+    fn nr_active_streams(&self) -> usize;
+
+    /// Records the number of open streams in each state
+    fn stats(&self, stats: &mut connection::stats::Streams);
+
+    /// Calls `f` with the id of every stream which is currently open
+    fn for_each_open_stream(&self, f: &mut dyn FnMut(StreamId));
+
+    /// Returns `true` if any of the open streams is waiting on data from the peer
+    fn is_awaiting_peer_data(&self) -> bool;
 }
@@ -19,6 +19,7 @@ use core::{
 use s2n_quic_core::{
     ack, application,
     buffer::{self, Reassembler},
+    connection::stats,
     frame::{stream::StreamRef, MaxStreamData, ResetStream, StopSending, StreamDataBlocked},
     packet::number::PacketNumber,
     stream::{ops, StreamId},
@@ -954,6 +955,40 @@ impl ReceiveStream {
             _ => {}
         }
     }
+
+    /// Records the state of the receiving part of the stream
+    #[inline]
+    pub fn stats(&self, stats: &mut stats::ReceiveStreams) {
+        let count = match self.state {
+            ReceiveStreamState::Receiving | ReceiveStreamState::Stopping { .. } => &mut stats.recv,
+            ReceiveStreamState::DataRead => &mut stats.data_read,
+            ReceiveStreamState::Reset(_) => &mut stats.reset,
+        };
+        *count += 1;
+    }
+
+    /// Returns `true` if the stream is waiting on data from the peer
+    ///
+    /// This is the case when the application is blocked on a read, or when the peer has left
+    /// gaps below the highest offset or final size it has announced.
+    #[inline]
+    pub fn is_awaiting_peer_data(&self) -> bool {
+        if !matches!(self.state, ReceiveStreamState::Receiving) {
+            return false;
+        }
+
+        if self.read_waiter.is_some() {
+            return true;
+        }
+
+        let highest_offset = self.flow_controller.acquired_connection_window.as_u64();
+        let expected_len = self
+            .receive_buffer
+            .final_size()
+            .map_or(highest_offset, |final_size| final_size.max(highest_offset));
+
+        self.receive_buffer.total_received_len() < expected_len
+    }
 }
 
 impl StreamInterestProvider for ReceiveStream {
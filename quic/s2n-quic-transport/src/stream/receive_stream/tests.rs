@@ -361,6 +361,38 @@ fn receive_fin_with_gap() {
     assert_eq!(test_env.wake_counter, 1);
 }
 
+#[test]
+fn awaiting_peer_data() {
+    let mut test_env = setup_receive_only_test_env();
+    assert!(!test_env.stream.is_awaiting_peer_data());
+
+    // a blocked reader is waiting on the peer
+    test_env.assert_no_read_data();
+    assert!(test_env.stream.is_awaiting_peer_data());
+
+    // buffered data which hasn't been read yet doesn't need anything from the peer
+    test_env.feed_data(VarInt::from_u8(0), 4);
+    assert!(!test_env.stream.is_awaiting_peer_data());
+
+    // a gap below the final size needs to be filled by the peer
+    let mut events = StreamEvents::new();
+    assert!(test_env
+        .stream
+        .on_data(
+            &stream_data(test_env.stream.stream_id, VarInt::from_u8(8), &[8, 9], true),
+            &mut events
+        )
+        .is_ok());
+    assert!(test_env.stream.is_awaiting_peer_data());
+
+    test_env.feed_data(VarInt::from_u8(4), 4);
+    assert!(!test_env.stream.is_awaiting_peer_data());
+
+    test_env.assert_receive_data(&[0, 0, 0, 0, 0, 0, 0, 0, 8, 9]);
+    test_env.assert_end_of_stream();
+    assert!(!test_env.stream.is_awaiting_peer_data());
+}
+
 #[test]
 fn receive_fin_twice_at_same_position() {
     let mut test_env = setup_receive_only_test_env();
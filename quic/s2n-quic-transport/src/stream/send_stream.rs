@@ -24,6 +24,7 @@ use core::{
 };
 use s2n_quic_core::{
     ack, application,
+    connection::stats,
     frame::{MaxStreamData, ResetStream, StopSending, StreamDataBlocked},
     packet::number::PacketNumber,
     stream::{ops, StreamId},
@@ -1015,6 +1016,23 @@ impl SendStream {
 
         InitResetResult::ResetInitiated
     }
+
+    /// Records the state of the sending part of the stream
+    #[inline]
+    pub fn stats(&self, stats: &mut stats::SendStreams) {
+        let count = match self.state {
+            SendStreamState::Sending => match self.data_sender.state() {
+                data_sender::State::Sending
+                | data_sender::State::Finishing(data_sender::FinState::Pending) => &mut stats.send,
+                data_sender::State::Finishing(_) => &mut stats.data_sent,
+                data_sender::State::Finished => &mut stats.data_recvd,
+                data_sender::State::Cancelled(_) => &mut stats.reset_sent,
+            },
+            SendStreamState::ResetSent(_) => &mut stats.reset_sent,
+            SendStreamState::ResetAcknowledged(_) => &mut stats.reset_recvd,
+        };
+        *count += 1;
+    }
 }
 
 impl timer::Provider for SendStream {
@@ -694,6 +694,17 @@ impl<S: StreamTrait> StreamContainer<S> {
         }
     }
 
+    /// Iterates over all `Stream`s which are part of this container without
+    /// modifying them
+    pub fn for_each<F>(&self, mut func: F)
+    where
+        F: FnMut(&S),
+    {
+        for stream in self.stream_map.iter() {
+            func(&stream.inner.borrow());
+        }
+    }
+
     /// Returns whether or not streams have data to send
     pub fn has_pending_streams(&self) -> bool {
         !self.interest_lists.waiting_for_transmission.is_empty()
@@ -15,7 +15,9 @@ use crate::{
 };
 use core::{task::Context, time::Duration};
 use s2n_quic_core::{
-    ack, endpoint,
+    ack,
+    connection::stats,
+    endpoint,
     frame::{stream::StreamRef, MaxStreamData, ResetStream, StopSending, StreamDataBlocked},
     stream::{ops, StreamId},
     time::{timer, Timestamp},
@@ -122,6 +124,12 @@ pub trait StreamTrait: StreamInterestProvider + timer::Provider + core::fmt::Deb
     /// This method is called when a connection window is available
     fn on_connection_window_available(&mut self);
 
+    /// Records the states of the sending and receiving parts of the stream
+    fn stats(&self, stats: &mut stats::Streams);
+
+    /// Returns `true` if the receiving part of the stream is waiting on data from the peer
+    fn is_awaiting_peer_data(&self) -> bool;
+
     // These functions are called from the client API
 
     fn poll_request(
@@ -327,6 +335,25 @@ impl StreamTrait for StreamImpl {
         self.send_stream.on_connection_window_available()
     }
 
+    #[inline]
+    fn stats(&self, stats: &mut stats::Streams) {
+        if self.has_send {
+            self.send_stream.stats(&mut stats.send);
+        }
+
+        // unidirectional streams only have a receiving part if we can't send on them
+        if self.stream_id.stream_type().is_bidirectional() || !self.has_send {
+            self.receive_stream.stats(&mut stats.receive);
+        }
+    }
+
+    #[inline]
+    fn is_awaiting_peer_data(&self) -> bool {
+        // unidirectional streams only have a receiving part if we can't send on them
+        (self.stream_id.stream_type().is_bidirectional() || !self.has_send)
+            && self.receive_stream.is_awaiting_peer_data()
+    }
+
     // These functions are called from the client API
 
     fn poll_request(
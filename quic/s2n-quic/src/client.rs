@@ -2,22 +2,29 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    application,
     connection::{self, Connection},
     provider::*,
+    shutdown,
 };
 use core::{
     fmt,
     future::Future,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
 use s2n_quic_transport::endpoint::{connect, handle::Connector};
+use std::sync::Arc;
 
 mod builder;
+mod happy_eyeballs;
+pub mod pool;
 mod providers;
 
 pub use builder::*;
 pub use connect::Connect;
+pub use happy_eyeballs::ConnectHostError;
 pub use providers::*;
 
 /// A QUIC client endpoint, capable of opening connections
@@ -25,6 +32,7 @@ pub use providers::*;
 pub struct Client {
     connector: Connector,
     local_addr: s2n_quic_core::inet::SocketAddress,
+    resolver: Arc<dyn resolver::Resolver>,
 }
 
 impl fmt::Debug for Client {
@@ -110,6 +118,44 @@ impl Client {
         ConnectionAttempt(attempt)
     }
 
+    /// Establishes a connection to the specified host name
+    ///
+    /// The name is resolved with the configured [`resolver`] provider. If it resolves to
+    /// multiple addresses, connection attempts are raced between them using the "Happy
+    /// Eyeballs" algorithm from [RFC 8305](https://www.rfc-editor.org/rfc/rfc8305): attempts
+    /// alternate between IPv6 and IPv4 addresses, starting with IPv6, and a new attempt is
+    /// started every 250ms or as soon as the previous attempt fails. The first connection to
+    /// complete its handshake is returned and the remaining attempts are canceled.
+    ///
+    /// The winning connection emits a `HappyEyeballsWinner` event recording which address
+    /// family it used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::error::Error;
+    /// use s2n_quic::Client;
+    /// use std::path::Path;
+    ///
+    /// # async fn connect() -> Result<(), Box<dyn Error>> {
+    /// let client = Client::builder()
+    ///     .with_tls(Path::new("./certs/cert.pem"))?
+    ///     .with_io("[::]:0")?
+    ///     .start()?;
+    ///
+    /// let connection = client.connect_host("example.com", 443).await?;
+    /// #
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn connect_host(
+        &self,
+        host: &str,
+        port: u16,
+    ) -> Result<Connection, ConnectHostError> {
+        happy_eyeballs::connect(self, host, port).await
+    }
+
     /// Wait for the client endpoint to finish handling all outstanding connections
     ///
     /// Notifies the endpoint of application interest in closing the endpoint. The
@@ -144,6 +190,57 @@ impl Client {
         futures::future::poll_fn(|cx| self.connector.poll_close(cx)).await
     }
 
+    /// Gracefully shuts down the [`Client`]
+    ///
+    /// The endpoint stops accepting new connections and closes any connections without open
+    /// streams. Connections with open streams are given until the `deadline` to finish, after
+    /// which they are closed as well. The returned future resolves with a [`shutdown::Summary`]
+    /// of how the connections ended, once all of them have closed.
+    ///
+    /// Connections are closed with an [`application::Error::UNKNOWN`] error code. Use
+    /// [`Self::shutdown_with_error`] to close them with a different code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::{error::Error, path::Path, time::Duration};
+    /// # use s2n_quic::Client;
+    /// #
+    /// # async fn shutdown() -> Result<(), Box<dyn Error>> {
+    /// let client = Client::builder()
+    ///     .with_tls(Path::new("./certs/cert.pem"))?
+    ///     .with_io("0.0.0.0:0")?
+    ///     .start()?;
+    ///
+    /// let summary = client.shutdown(Duration::from_secs(30)).await;
+    /// println!("{} connections were forcibly closed", summary.forced);
+    /// #
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn shutdown(&self, deadline: Duration) -> shutdown::Shutdown {
+        self.shutdown_with_error(deadline, application::Error::UNKNOWN)
+    }
+
+    /// Gracefully shuts down the [`Client`], closing connections with the provided `error`
+    ///
+    /// See [`Self::shutdown`] for more details.
+    pub fn shutdown_with_error(
+        &self,
+        deadline: Duration,
+        error: application::Error,
+    ) -> shutdown::Shutdown {
+        self.connector.shutdown(deadline, error)
+    }
+
+    /// Returns a future which resolves once the [`Client`] starts shutting down
+    ///
+    /// Applications can use this to notify busy connections that they should finish their work
+    /// before the shutdown deadline.
+    pub fn shutdown_signal(&self) -> shutdown::Signal {
+        self.connector.shutdown_signal()
+    }
+
     /// Returns the local address that this listener is bound to.
     ///
     /// This can be useful, for example, when binding to port `0` to figure out which
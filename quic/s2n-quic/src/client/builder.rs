@@ -324,6 +324,35 @@ impl<Providers: ClientProviders> Builder<Providers> {
         ClientProviders
     );
 
+    impl_provider_method!(
+        /// Sets the resolver provider for the [`Client`]
+        ///
+        /// The resolver is used to look up the addresses for [`Client::connect_host`].
+        ///
+        /// # Examples
+        ///
+        /// Resolves a host name to a fixed set of addresses
+        ///
+        /// ```rust,no_run
+        /// # use std::error::Error;
+        /// use s2n_quic::{Client, provider::resolver};
+        /// #
+        /// # fn main() -> Result<(), Box<dyn Error>> {
+        /// let resolver = resolver::Static::default()
+        ///     .with_host("example.com", ["2001:db8::1".parse()?, "192.0.2.1".parse()?]);
+        ///
+        /// let client = Client::builder()
+        ///     .with_resolver(resolver)?
+        ///     .start()?;
+        /// #
+        /// #    Ok(())
+        /// # }
+        /// ```
+        with_resolver,
+        resolver,
+        ClientProviders
+    );
+
     /// Starts the [`Client`] with the configured providers
     ///
     /// # Examples
@@ -0,0 +1,212 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Races connection attempts across address families, as described in
+//! [RFC 8305](https://www.rfc-editor.org/rfc/rfc8305).
+
+use super::{Client, Connect};
+use crate::connection::{self, Connection};
+use core::{fmt, task::Poll, time::Duration};
+use futures::{future::poll_fn, stream::FuturesUnordered, FutureExt as _, StreamExt as _};
+use std::{io, net::SocketAddr};
+
+/// The time to wait before starting the next connection attempt
+///
+/// See [RFC 8305 Section 8](https://www.rfc-editor.org/rfc/rfc8305#section-8).
+const CONNECTION_ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
+/// An error returned by [`Client::connect_host`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConnectHostError {
+    /// The host name couldn't be resolved to any addresses
+    Resolve(io::Error),
+    /// None of the resolved addresses could be connected to
+    ///
+    /// This contains the error from the last attempt to fail.
+    Connection(connection::Error),
+}
+
+impl fmt::Display for ConnectHostError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Resolve(error) => write!(f, "failed to resolve host: {error}"),
+            Self::Connection(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ConnectHostError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Resolve(error) => Some(error),
+            Self::Connection(error) => Some(error),
+        }
+    }
+}
+
+impl From<connection::Error> for ConnectHostError {
+    fn from(error: connection::Error) -> Self {
+        Self::Connection(error)
+    }
+}
+
+pub(super) async fn connect(
+    client: &Client,
+    host: &str,
+    port: u16,
+) -> Result<Connection, ConnectHostError> {
+    let addrs = client
+        .resolver
+        .resolve(host, port)
+        .await
+        .map_err(ConnectHostError::Resolve)?;
+
+    let addrs = sort_addresses(addrs);
+    if addrs.is_empty() {
+        let error = io::Error::new(io::ErrorKind::NotFound, "host resolved to no addresses");
+        return Err(ConnectHostError::Resolve(error));
+    }
+
+    race(client, host, addrs).await
+}
+
+/// Orders the addresses so the address families are interleaved, starting with IPv6
+fn sort_addresses(addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
+    // See https://www.rfc-editor.org/rfc/rfc8305#section-4
+    let mut v6 = vec![];
+    let mut v4 = vec![];
+    for addr in addrs {
+        let family = if addr.is_ipv6() { &mut v6 } else { &mut v4 };
+        if !family.contains(&addr) {
+            family.push(addr);
+        }
+    }
+
+    let mut sorted = Vec::with_capacity(v6.len() + v4.len());
+    let mut v6 = v6.into_iter();
+    let mut v4 = v4.into_iter();
+    loop {
+        match (v6.next(), v4.next()) {
+            (None, None) => return sorted,
+            (a, b) => sorted.extend(a.into_iter().chain(b)),
+        }
+    }
+}
+
+/// Starts a connection attempt for each address in turn until one of them succeeds
+///
+/// The next attempt is started once the previous one fails or after the connection attempt
+/// delay, whichever comes first. Any attempts which are still in progress when another one
+/// succeeds are dropped, which closes their connections right away rather than letting their
+/// handshakes run to completion.
+async fn race(
+    client: &Client,
+    host: &str,
+    addrs: Vec<SocketAddr>,
+) -> Result<Connection, ConnectHostError> {
+    let mut addrs = addrs.into_iter();
+    let mut attempts = FuturesUnordered::new();
+    let mut started = 0u32;
+    let mut delay = None;
+    let mut last_error = None;
+
+    loop {
+        if delay.is_none() {
+            if let Some(addr) = addrs.next() {
+                let connect = Connect::new(addr)
+                    .with_server_name(host)
+                    .with_close_on_drop(true);
+                attempts.push(client.connect(connect));
+                started += 1;
+                delay = Some(client.connector.sleep(CONNECTION_ATTEMPT_DELAY));
+            }
+        }
+
+        if attempts.is_empty() {
+            // every address has been tried
+            let error = last_error.unwrap_or_else(connection::Error::unspecified);
+            return Err(error.into());
+        }
+
+        let result = poll_fn(|cx| {
+            if let Poll::Ready(Some(result)) = attempts.poll_next_unpin(cx) {
+                return Poll::Ready(Some(result));
+            }
+
+            if let Some(sleep) = delay.as_mut() {
+                if sleep.poll_unpin(cx).is_ready() {
+                    return Poll::Ready(None);
+                }
+            }
+
+            Poll::Pending
+        })
+        .await;
+
+        match result {
+            Some(Ok(connection)) => {
+                // returning drops the remaining attempts, which cancels them
+                //
+                // the event is best-effort so ignore any errors
+                let _ = connection.on_happy_eyeballs_winner(started);
+                return Ok(connection);
+            }
+            Some(Err(error)) => {
+                // start the next attempt right away rather than waiting for the delay
+                last_error = Some(error);
+                delay = None;
+            }
+            None => {
+                // the attempt delay expired
+                delay = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addrs(addrs: &[&str]) -> Vec<SocketAddr> {
+        addrs.iter().map(|addr| addr.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn sort_addresses_test() {
+        let tests = [
+            (vec![], vec![]),
+            (vec!["192.0.2.1:443"], vec!["192.0.2.1:443"]),
+            (
+                vec!["192.0.2.1:443", "[2001:db8::1]:443"],
+                vec!["[2001:db8::1]:443", "192.0.2.1:443"],
+            ),
+            (
+                vec![
+                    "192.0.2.1:443",
+                    "192.0.2.2:443",
+                    "192.0.2.3:443",
+                    "[2001:db8::1]:443",
+                    "[2001:db8::2]:443",
+                ],
+                vec![
+                    "[2001:db8::1]:443",
+                    "192.0.2.1:443",
+                    "[2001:db8::2]:443",
+                    "192.0.2.2:443",
+                    "192.0.2.3:443",
+                ],
+            ),
+            // duplicates are removed
+            (
+                vec!["192.0.2.1:443", "192.0.2.1:443", "[2001:db8::1]:443"],
+                vec!["[2001:db8::1]:443", "192.0.2.1:443"],
+            ),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(sort_addresses(addrs(&input)), addrs(&expected));
+        }
+    }
+}
@@ -0,0 +1,329 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A pool of client connections which hands out streams
+//!
+//! Connections are shared between streams to the same peer, which is identified by a [`Key`].
+//! New streams are opened on the connection with the fewest open streams. Additional connections
+//! are opened once every connection has exhausted the peer's `MAX_STREAMS` limit, and
+//! connections are replaced once they close.
+
+use super::{Client, Connect};
+use crate::{
+    connection::{self, Handle},
+    server::Name as ServerName,
+    stream::BidirectionalStream,
+};
+use bytes::Bytes;
+use core::task::{Context, Poll};
+use futures::future::{poll_fn, select_ok};
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::Notify;
+
+/// The default maximum number of connections to open for each [`Key`]
+const DEFAULT_MAX_CONNECTIONS: usize = 4;
+
+/// Identifies the connections which can be shared by streams
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Key {
+    remote_address: SocketAddr,
+    server_name: Option<ServerName>,
+    application_protocols: Vec<Bytes>,
+}
+
+impl Key {
+    /// Creates a key for connections to the specified address
+    pub fn new(remote_address: SocketAddr) -> Self {
+        Self {
+            remote_address,
+            server_name: None,
+            application_protocols: Vec::new(),
+        }
+    }
+
+    /// Sets the server name used to verify the peer's certificate
+    #[must_use]
+    pub fn with_server_name<Name: Into<ServerName>>(mut self, server_name: Name) -> Self {
+        self.server_name = Some(server_name.into());
+        self
+    }
+
+    /// Sets the application protocols (ALPN) to offer to the peer, in order of preference
+    ///
+    /// If none are set, the protocols configured on the client's TLS provider are offered.
+    #[must_use]
+    pub fn with_application_protocols<P: IntoIterator<Item = I>, I: Into<Bytes>>(
+        mut self,
+        protocols: P,
+    ) -> Self {
+        self.application_protocols = protocols.into_iter().map(Into::into).collect();
+        self
+    }
+
+    fn connect(&self) -> Connect {
+        let mut connect = Connect::new(self.remote_address);
+
+        if let Some(server_name) = self.server_name.clone() {
+            connect = connect.with_server_name(server_name);
+        }
+
+        if !self.application_protocols.is_empty() {
+            connect = connect.with_application_protocols(self.application_protocols.clone());
+        }
+
+        connect
+    }
+}
+
+/// The connections which have been opened for a single [`Key`]
+///
+/// The lock is never held across an `.await`. Instead, callers which open a new connection
+/// reserve a slot for it so that concurrent callers don't exceed the connection limit while the
+/// handshake is in progress.
+#[derive(Debug, Default)]
+struct Connections {
+    state: Mutex<State>,
+    /// Notified each time a connection attempt completes
+    attempt_completed: Notify,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    handles: Vec<Handle>,
+    /// The number of connections which are currently being established
+    pending: usize,
+}
+
+impl Connections {
+    /// Opens a stream on the pooled connection with the fewest open streams, if any of them are
+    /// below the peer's stream limit
+    ///
+    /// Connections which have closed are removed from the pool.
+    fn try_open(&self, cx: &mut Context) -> Option<BidirectionalStream> {
+        let mut state = self.state.lock().unwrap();
+
+        // spread the streams across the connections by trying the least loaded ones first
+        state.handles.sort_by_cached_key(|handle| {
+            handle
+                .stats()
+                .map_or(u64::MAX, |stats| stats.streams.local_bidirectional)
+        });
+
+        let mut index = 0;
+        while let Some(handle) = state.handles.get_mut(index) {
+            match handle.poll_open_bidirectional_stream(cx) {
+                Poll::Ready(Ok(stream)) => return Some(stream),
+                Poll::Ready(Err(_)) => {
+                    // the connection has closed so replace it
+                    state.handles.swap_remove(index);
+                }
+                // the peer's stream limit has been reached
+                Poll::Pending => index += 1,
+            }
+        }
+
+        None
+    }
+
+    /// Returns a copy of the pooled connections
+    fn handles(&self) -> Vec<Handle> {
+        self.state.lock().unwrap().handles.clone()
+    }
+
+    /// Removes a connection which has closed from the pool
+    fn remove(&self, id: u64) {
+        self.state
+            .lock()
+            .unwrap()
+            .handles
+            .retain(|handle| handle.id() != id);
+    }
+
+    /// Reserves a slot for a new connection if the pool is below `max_connections`
+    fn reserve(&self, max_connections: usize) -> Option<Reservation<'_>> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.handles.len() + state.pending >= max_connections {
+            return None;
+        }
+
+        state.pending += 1;
+
+        Some(Reservation {
+            connections: self,
+            handle: None,
+        })
+    }
+
+    fn is_idle(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.handles.is_empty() && state.pending == 0
+    }
+}
+
+/// A slot in the pool for a connection which is being established
+///
+/// The slot is released once the reservation is dropped, at which point the connection is added
+/// to the pool if it was established.
+struct Reservation<'a> {
+    connections: &'a Connections,
+    handle: Option<Handle>,
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        let mut state = self.connections.state.lock().unwrap();
+        state.pending -= 1;
+        state.handles.extend(self.handle.take());
+        drop(state);
+
+        self.connections.attempt_completed.notify_waiters();
+    }
+}
+
+/// A pool of [`Client`] connections
+///
+/// Cloning the pool returns a handle to the same set of connections.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use std::{error::Error, path::Path};
+/// use s2n_quic::{client::pool, Client};
+///
+/// # async fn pool() -> Result<(), Box<dyn Error>> {
+/// let client = Client::builder()
+///     .with_tls(Path::new("./certs/cert.pem"))?
+///     .with_io("0.0.0.0:0")?
+///     .start()?;
+///
+/// let pool = pool::Pool::new(client).with_max_connections(8);
+/// let key = pool::Key::new("127.0.0.1:443".parse()?).with_server_name("localhost");
+///
+/// let mut stream = pool.open_bidirectional_stream(&key).await?;
+/// stream.send(bytes::Bytes::from_static(b"hello")).await?;
+/// #
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Pool {
+    client: Client,
+    max_connections: usize,
+    connections: Arc<Mutex<HashMap<Key, Arc<Connections>>>>,
+}
+
+impl Pool {
+    /// Creates a pool which opens connections with the provided `client`
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            connections: Default::default(),
+        }
+    }
+
+    /// Sets the maximum number of connections to open for each [`Key`]
+    ///
+    /// Once every connection has reached the peer's stream limit, opening a stream waits for
+    /// one of them to free up. Defaults to 4.
+    #[must_use]
+    pub fn with_max_connections(mut self, max_connections: usize) -> Self {
+        self.max_connections = max_connections.max(1);
+        self
+    }
+
+    /// Opens a [`BidirectionalStream`] on a connection for the provided `key`
+    ///
+    /// The stream is opened on the pooled connection with the fewest open streams. A new
+    /// connection is opened if there aren't any, or if every pooled connection has reached the
+    /// peer's stream limit. Connections which have closed are removed from the pool.
+    pub async fn open_bidirectional_stream(
+        &self,
+        key: &Key,
+    ) -> connection::Result<BidirectionalStream> {
+        let connections = self.connections_for(key);
+
+        loop {
+            if let Some(stream) = poll_fn(|cx| Poll::Ready(connections.try_open(cx))).await {
+                return Ok(stream);
+            }
+
+            // register for notifications before checking the state so a completed attempt isn't
+            // missed
+            let attempt_completed = connections.attempt_completed.notified();
+
+            if let Some(mut reservation) = connections.reserve(self.max_connections) {
+                let connection = self.client.connect(key.connect()).await?;
+                let mut handle = connection.handle();
+                let stream = handle.open_bidirectional_stream().await?;
+                reservation.handle = Some(handle);
+                return Ok(stream);
+            }
+
+            let handles = connections.handles();
+
+            if handles.is_empty() {
+                // the only connections are still being established so wait for one of them
+                attempt_completed.await;
+                continue;
+            }
+
+            // every connection is at its stream limit so wait for the first one to free up
+            let attempts = handles.into_iter().map(|mut handle| {
+                let connections = &connections;
+                Box::pin(async move {
+                    let result = handle.open_bidirectional_stream().await;
+                    if result.is_err() {
+                        connections.remove(handle.id());
+                    }
+                    result
+                })
+            });
+
+            // if every connection closed while waiting, try again with new ones
+            if let Ok((stream, _)) = select_ok(attempts).await {
+                return Ok(stream);
+            }
+        }
+    }
+
+    /// Checks the health of every pooled connection
+    ///
+    /// A PING frame is sent on each connection, which also keeps idle connections from timing
+    /// out. Connections which have closed are removed from the pool. Returns the number of
+    /// connections which were removed.
+    pub async fn health_check(&self) -> usize {
+        let entries: Vec<_> = self.connections.lock().unwrap().values().cloned().collect();
+
+        let mut removed = 0;
+        for connections in entries {
+            let mut state = connections.state.lock().unwrap();
+            let len = state.handles.len();
+            state.handles.retain_mut(|handle| handle.ping().is_ok());
+            removed += len - state.handles.len();
+        }
+
+        // forget about any keys which no longer have connections and aren't in use
+        self.connections
+            .lock()
+            .unwrap()
+            .retain(|_, connections| Arc::strong_count(connections) > 1 || !connections.is_idle());
+
+        removed
+    }
+
+    fn connections_for(&self, key: &Key) -> Arc<Connections> {
+        let mut connections = self.connections.lock().unwrap();
+
+        if let Some(connections) = connections.get(key) {
+            return connections.clone();
+        }
+
+        connections.entry(key.clone()).or_default().clone()
+    }
+}
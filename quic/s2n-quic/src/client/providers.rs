@@ -23,6 +23,7 @@ impl_providers_state! {
         tls: Tls,
         datagram: Datagram,
         dc: Dc,
+        resolver: Resolver,
     }
 
     /// Opaque trait containing all of the configured providers
@@ -44,6 +45,7 @@ impl<
         Tls: tls::Provider,
         Datagram: datagram::Provider,
         Dc: dc::Provider,
+        Resolver: resolver::Provider,
     >
     Providers<
         CongestionController,
@@ -60,6 +62,7 @@ impl<
         Tls,
         Datagram,
         Dc,
+        Resolver,
     >
 {
     pub fn start(self) -> Result<Client, StartError> {
@@ -78,6 +81,7 @@ impl<
             tls,
             datagram,
             dc,
+            resolver,
         } = self;
 
         let congestion_controller = congestion_controller.start().map_err(StartError::new)?;
@@ -98,6 +102,7 @@ impl<
         let tls = tls.start_client().map_err(StartError::new)?;
         let datagram = datagram.start().map_err(StartError::new)?;
         let dc = dc.start().map_err(StartError::new)?;
+        let resolver = resolver.start().map_err(StartError::new)?;
 
         // Validate providers
         // TODO: Add more validation https://github.com/aws/s2n-quic/issues/285
@@ -140,6 +145,7 @@ impl<
         Ok(Client {
             connector,
             local_addr,
+            resolver: Arc::new(resolver),
         })
     }
 }
@@ -11,12 +11,16 @@ mod handle;
 
 pub use acceptor::*;
 pub use handle::*;
-pub use s2n_quic_core::connection::Error;
+pub use s2n_quic_core::connection::{Error, Stats};
 
 pub mod error {
     pub use s2n_quic_core::transport::error::Code;
 }
 
+pub mod stats {
+    pub use s2n_quic_core::connection::stats::*;
+}
+
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
 pub struct Connection(Inner);
@@ -33,6 +37,12 @@ impl Connection {
         Self(inner)
     }
 
+    /// Records that the connection won a Happy Eyeballs race after `attempts` attempts
+    #[inline]
+    pub(crate) fn on_happy_eyeballs_winner(&self, attempts: u32) -> Result<()> {
+        self.0.on_happy_eyeballs_winner(attempts)
+    }
+
     impl_accept_api!();
     impl_handle_api!(|handle, call| call!(handle));
 
@@ -157,6 +157,26 @@ macro_rules! impl_handle_api {
             self.0.remote_address().map(std::net::SocketAddr::from)
         }
 
+        /// Returns a snapshot of the connection's statistics
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # fn test() -> s2n_quic::connection::Result<()> {
+        /// #   let handle: s2n_quic::connection::Handle = todo!();
+        /// #
+        /// let stats = handle.stats()?;
+        /// println!("smoothed rtt: {:?}", stats.path.rtt.smoothed);
+        /// println!("packets lost: {}", stats.counters.packets_lost);
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn stats(&self) -> $crate::connection::Result<$crate::connection::Stats> {
+            self.0.stats()
+        }
+
         /// Returns the negotiated server name the connection is using.
         #[inline]
         pub fn server_name(&self) -> $crate::connection::Result<Option<$crate::server::Name>> {
@@ -169,6 +189,16 @@ macro_rules! impl_handle_api {
             self.0.application_protocol()
         }
 
+        /// Returns the DER-encoded SubjectPublicKeyInfo of the peer, if it authenticated with a
+        /// [raw public key](https://www.rfc-editor.org/rfc/rfc7250) instead of a certificate
+        ///
+        /// This is available once the handshake has completed. Raw public keys are only
+        /// supported by the rustls provider, so this is always `None` with s2n-tls.
+        #[inline]
+        pub fn peer_public_key(&self) -> $crate::connection::Result<Option<::bytes::Bytes>> {
+            self.0.peer_public_key()
+        }
+
         /// Takes the context provided by the TLS provider.
         ///
         /// This functionality is useful when you need to pass information from the TLS provider to the
@@ -125,6 +125,10 @@ pub mod application {
     pub use s2n_quic_core::application::Error;
 }
 
+pub mod shutdown {
+    pub use s2n_quic_transport::endpoint::shutdown::{Shutdown, Signal, Summary};
+}
+
 pub use client::Client;
 pub use connection::Connection;
 pub use server::Server;
@@ -15,6 +15,7 @@ pub mod event;
 pub mod io;
 pub mod limits;
 pub mod mtu;
+pub mod resolver;
 pub mod stateless_reset_token;
 pub mod tls;
 
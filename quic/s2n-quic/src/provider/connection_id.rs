@@ -248,3 +248,117 @@ pub mod default {
         }
     }
 }
+
+pub mod sharded {
+    //! A connection ID format for servers whose workers share a port
+    //!
+    //! See [`crate::server::sharded`] for running a server with this format.
+
+    use super::default;
+    use core::time::Duration;
+    use rand::prelude::*;
+    use s2n_quic_core::connection::{
+        self,
+        id::{ConnectionInfo, Generator, Validator},
+    };
+
+    /// The offset of the byte which identifies the worker in each connection ID
+    pub const WORKER_OFFSET: u8 = 0;
+
+    /// Randomly generated connection Id format which encodes the index of the worker that
+    /// owns the connection
+    ///
+    /// The byte at [`WORKER_OFFSET`] is congruent to the worker index modulo the number of
+    /// workers. The remaining bytes are random.
+    #[derive(Debug)]
+    pub struct Format {
+        inner: default::Format,
+        worker: u8,
+        workers: u8,
+    }
+
+    impl Format {
+        /// Creates a format for the worker at `worker` out of `workers`
+        ///
+        /// # Panics
+        ///
+        /// Panics if `worker` is not less than `workers`.
+        pub fn new(worker: u8, workers: u8) -> Self {
+            assert!(worker < workers, "worker index out of range");
+            Self {
+                inner: default::Format::default(),
+                worker,
+                workers,
+            }
+        }
+
+        /// Returns the index of the worker that owns the connection ID
+        #[inline]
+        pub fn worker(&self, id: &[u8]) -> Option<u8> {
+            let byte = *id.get(WORKER_OFFSET as usize)?;
+            Some(byte % self.workers)
+        }
+    }
+
+    impl Generator for Format {
+        fn generate(&mut self, connection_info: &ConnectionInfo) -> connection::LocalId {
+            let id = self.inner.generate(connection_info);
+            let mut bytes = [0u8; connection::id::MAX_LEN];
+            let bytes = &mut bytes[..id.len()];
+            bytes.copy_from_slice(id.as_ref());
+
+            // pick a random byte which maps to this worker so the index isn't visible in the clear
+            let multiples = (u8::MAX - self.worker) / self.workers;
+            let multiple = rand::rng().random_range(0..=multiples);
+            bytes[WORKER_OFFSET as usize] = self.worker + multiple * self.workers;
+
+            (&*bytes).try_into().expect("length already checked")
+        }
+
+        fn lifetime(&self) -> Option<Duration> {
+            self.inner.lifetime()
+        }
+
+        fn rotate_handshake_connection_id(&self) -> bool {
+            self.inner.rotate_handshake_connection_id()
+        }
+    }
+
+    impl Validator for Format {
+        fn validate(&self, connection_info: &ConnectionInfo, buffer: &[u8]) -> Option<usize> {
+            self.inner.validate(connection_info, buffer)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn generator_test() {
+            let remote_address = &s2n_quic_core::inet::SocketAddress::default();
+            let connection_info = ConnectionInfo::new(remote_address);
+
+            for workers in [1, 2, 3, 7, 16, 255] {
+                for worker in [0, workers / 2, workers - 1] {
+                    let mut format = Format::new(worker, workers);
+
+                    for _ in 0..100 {
+                        let id = format.generate(&connection_info);
+                        assert_eq!(format.worker(id.as_ref()), Some(worker));
+                        assert_eq!(
+                            format.validate(&connection_info, id.as_ref()),
+                            Some(id.len())
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn invalid_worker_test() {
+            Format::new(2, 2);
+        }
+    }
+}
@@ -20,6 +20,8 @@ pub trait Provider: 'static {
 use core::time::Duration;
 pub use default::Limits as Default;
 
+pub mod rate_limiter;
+
 impl_provider_utils!();
 
 impl<T: 'static + Limiter> Provider for T {
@@ -0,0 +1,948 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! An endpoint limiter which rate limits connection attempts from each source
+//!
+//! Each source address and each network prefix is given a token bucket. Connection attempts
+//! which find tokens in both buckets are considered within the limit. As the number of inflight
+//! handshakes rises, the limiter escalates its response from allowing connections, to requiring
+//! address validation with a Retry packet, to dropping connection attempts entirely.
+//!
+//! The number of tracked sources and prefixes is bounded, which keeps memory usage constant
+//! during address-spray attacks. Once a table is full, entries which haven't been used recently
+//! are evicted to make room for new sources.
+//!
+//! Clients retransmit their first Initial packet with the same Destination Connection ID until
+//! they hear back from the server. These retransmissions reuse the decision made for the first
+//! packet instead of taking more tokens.
+//!
+//! Decisions other than `allow` are reported to the endpoint's event subscriber with the
+//! `EndpointConnectionAttemptLimited` event.
+
+use super::{ConnectionAttempt, Limiter, Outcome};
+use core::{fmt, hash::Hash, str::FromStr, time::Duration};
+use s2n_quic_core::connection::InitialId;
+use std::{
+    collections::HashMap,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// How long a decision is reused for retransmissions of the same Initial packet
+///
+/// This covers the first few retransmissions a client makes before giving up on its attempt.
+const RETRANSMISSION_WINDOW: Duration = Duration::from_secs(10);
+
+/// An error returned when the rate limiter is misconfigured
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Error(&'static str);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A range of IP addresses in CIDR notation
+///
+/// # Examples
+///
+/// ```rust
+/// use s2n_quic::provider::endpoint_limits::rate_limiter::Cidr;
+///
+/// let cidr: Cidr = "192.0.2.0/24".parse().unwrap();
+/// assert!(cidr.contains("192.0.2.1".parse().unwrap()));
+/// assert!(!cidr.contains("198.51.100.1".parse().unwrap()));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cidr {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl Cidr {
+    /// Creates a range containing all of the addresses which share the first `prefix_len` bits
+    /// with `addr`
+    pub fn new(addr: IpAddr, prefix_len: u8) -> Result<Self, Error> {
+        let addr = addr.to_canonical();
+        if prefix_len > max_prefix_len(addr) {
+            return Err(Error("prefix length exceeds the length of the address"));
+        }
+        Ok(Self {
+            addr: mask(addr, prefix_len),
+            prefix_len,
+        })
+    }
+
+    /// Returns the first address in the range
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Returns the number of leading bits shared by the addresses in the range
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns `true` if the range contains `addr`
+    ///
+    /// IPv4-mapped IPv6 addresses are treated as their IPv4 equivalent.
+    pub fn contains(&self, addr: IpAddr) -> bool {
+        let addr = addr.to_canonical();
+        addr.is_ipv4() == self.addr.is_ipv4() && mask(addr, self.prefix_len) == self.addr
+    }
+}
+
+impl From<IpAddr> for Cidr {
+    fn from(addr: IpAddr) -> Self {
+        let addr = addr.to_canonical();
+        Self {
+            addr,
+            prefix_len: max_prefix_len(addr),
+        }
+    }
+}
+
+impl FromStr for Cidr {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((addr, prefix_len)) = s.split_once('/') else {
+            let addr: IpAddr = s.parse().map_err(|_| Error("invalid IP address"))?;
+            return Ok(addr.into());
+        };
+        let addr = addr.parse().map_err(|_| Error("invalid IP address"))?;
+        let prefix_len = prefix_len
+            .parse()
+            .map_err(|_| Error("invalid prefix length"))?;
+        Self::new(addr, prefix_len)
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+#[inline]
+fn max_prefix_len(addr: IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+/// Clears all of the bits in `addr` after the first `prefix_len`
+#[inline]
+fn mask(addr: IpAddr, prefix_len: u8) -> IpAddr {
+    match addr {
+        IpAddr::V4(addr) => {
+            let mask = u32::MAX.checked_shl(32 - prefix_len as u32).unwrap_or(0);
+            IpAddr::V4(Ipv4Addr::from(u32::from(addr) & mask))
+        }
+        IpAddr::V6(addr) => {
+            let mask = u128::MAX.checked_shl(128 - prefix_len as u32).unwrap_or(0);
+            IpAddr::V6(Ipv6Addr::from(u128::from(addr) & mask))
+        }
+    }
+}
+
+/// The rate at which a token bucket refills
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Rate {
+    per_second: u32,
+    burst: u32,
+}
+
+impl Rate {
+    fn new(per_second: u32, burst: u32) -> Result<Self, Error> {
+        if burst == 0 {
+            return Err(Error("burst must be greater than 0"));
+        }
+        Ok(Self { per_second, burst })
+    }
+
+    #[inline]
+    fn refill(&self, bucket: &mut Bucket, now: Duration) {
+        if bucket.tokens >= self.burst {
+            bucket.updated = now;
+            return;
+        }
+
+        let elapsed = now.saturating_sub(bucket.updated);
+        let tokens = elapsed.as_nanos() * self.per_second as u128 / NANOS_PER_SECOND;
+        if tokens == 0 {
+            return;
+        }
+
+        let tokens = tokens.min((self.burst - bucket.tokens) as u128) as u32;
+        bucket.tokens += tokens;
+
+        if bucket.tokens >= self.burst {
+            bucket.updated = now;
+        } else {
+            // only advance by the time it took to earn the tokens so partial progress isn't lost
+            let nanos = tokens as u128 * NANOS_PER_SECOND / self.per_second as u128;
+            bucket.updated += Duration::from_nanos(nanos as u64);
+        }
+    }
+
+    /// Takes a token from the bucket, returning `false` if it is empty
+    #[inline]
+    fn take(&self, bucket: &mut Bucket, now: Duration) -> bool {
+        self.refill(bucket, now);
+        if bucket.tokens == 0 {
+            return false;
+        }
+        bucket.tokens -= 1;
+        true
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Bucket {
+    tokens: u32,
+    updated: Duration,
+}
+
+impl Bucket {
+    #[inline]
+    fn full(rate: Rate, now: Duration) -> Self {
+        Self {
+            tokens: rate.burst,
+            updated: now,
+        }
+    }
+}
+
+/// A map with a fixed capacity which evicts entries with the CLOCK algorithm once it is full
+///
+/// Entries which were used since the clock hand last passed them get a second chance, so
+/// sources which keep making attempts aren't pushed out by a spray of new addresses.
+#[derive(Clone, Debug)]
+struct Table<K, V> {
+    capacity: usize,
+    index: HashMap<K, usize>,
+    slots: Vec<Slot<K, V>>,
+    /// The next slot to consider for eviction
+    hand: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Slot<K, V> {
+    key: K,
+    value: V,
+    referenced: bool,
+}
+
+impl<K: Copy + Hash + Eq, V> Table<K, V> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            index: HashMap::new(),
+            slots: Vec::new(),
+            hand: 0,
+        }
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let slot = &mut self.slots[*self.index.get(key)?];
+        slot.referenced = true;
+        Some(&mut slot.value)
+    }
+
+    /// Inserts the value for `key`, evicting another entry if the table is full
+    fn insert(&mut self, key: K, value: V) -> &mut V {
+        if let Some(&index) = self.index.get(&key) {
+            let slot = &mut self.slots[index];
+            slot.value = value;
+            slot.referenced = true;
+            return &mut slot.value;
+        }
+
+        let slot = Slot {
+            key,
+            value,
+            referenced: false,
+        };
+
+        if self.slots.len() < self.capacity {
+            self.index.insert(key, self.slots.len());
+            self.slots.push(slot);
+            return &mut self.slots.last_mut().unwrap().value;
+        }
+
+        // give every referenced entry a second chance; this takes at most one pass
+        while core::mem::take(&mut self.slots[self.hand].referenced) {
+            self.hand = (self.hand + 1) % self.slots.len();
+        }
+
+        let index = self.hand;
+        self.hand = (self.hand + 1) % self.slots.len();
+
+        let evicted = core::mem::replace(&mut self.slots[index], slot);
+        self.index.remove(&evicted.key);
+        self.index.insert(key, index);
+        &mut self.slots[index].value
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.slots.len()
+    }
+}
+
+/// A bounded table of token buckets
+#[derive(Clone, Debug)]
+struct Buckets<K> {
+    rate: Rate,
+    entries: Table<K, Bucket>,
+}
+
+impl<K: Copy + Hash + Eq> Buckets<K> {
+    fn new(rate: Rate, capacity: usize) -> Self {
+        Self {
+            rate,
+            entries: Table::new(capacity),
+        }
+    }
+
+    /// Takes a token from the bucket for `key`, returning `false` if it is empty
+    fn take(&mut self, key: K, now: Duration) -> bool {
+        let bucket = match self.entries.get_mut(&key) {
+            Some(bucket) => bucket,
+            None => self.entries.insert(key, Bucket::full(self.rate, now)),
+        };
+        self.rate.take(bucket, now)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Load {
+    Normal,
+    Elevated,
+    Critical,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Policy {
+    Allow,
+    Deny,
+}
+
+/// Allows the rate limiter to be built with specific values
+///
+/// # Examples
+///
+/// ```rust
+/// use s2n_quic::provider::endpoint_limits::rate_limiter::Limits;
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let limits = Limits::builder()
+///     // allow each source to make 5 attempts per second, with bursts of 10
+///     .with_source_rate(5, 10)?
+///     // allow each /24 (IPv4) or /48 (IPv6) to make 50 attempts per second, with bursts of 100
+///     .with_prefix_rate(50, 100)?
+///     .with_allow("10.0.0.0/8".parse()?)?
+///     .with_deny("192.0.2.0/24".parse()?)?
+///     // require address validation once there are 1000 inflight handshakes
+///     .with_retry_threshold(1000)?
+///     // drop all new connection attempts once there are 10000 inflight handshakes
+///     .with_drop_threshold(10000)?
+///     .build()?;
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Builder {
+    source_rate: Rate,
+    prefix_rate: Rate,
+    ipv4_prefix_len: u8,
+    ipv6_prefix_len: u8,
+    max_sources: usize,
+    max_prefixes: usize,
+    allow: Vec<Cidr>,
+    deny: Vec<Cidr>,
+    retry_threshold: Option<usize>,
+    drop_threshold: Option<usize>,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            source_rate: Rate {
+                per_second: 10,
+                burst: 20,
+            },
+            prefix_rate: Rate {
+                per_second: 100,
+                burst: 200,
+            },
+            ipv4_prefix_len: 24,
+            ipv6_prefix_len: 48,
+            max_sources: 10_000,
+            max_prefixes: 1_000,
+            allow: Vec::new(),
+            deny: Vec::new(),
+            retry_threshold: None,
+            drop_threshold: None,
+        }
+    }
+}
+
+impl Builder {
+    /// Sets the number of connection attempts each source address can make per second, along
+    /// with the number of attempts it can make in a burst
+    ///
+    /// Defaults to 10 per second with bursts of 20.
+    pub fn with_source_rate(mut self, per_second: u32, burst: u32) -> Result<Self, Error> {
+        self.source_rate = Rate::new(per_second, burst)?;
+        Ok(self)
+    }
+
+    /// Sets the number of connection attempts each network prefix can make per second, along
+    /// with the number of attempts it can make in a burst
+    ///
+    /// Defaults to 100 per second with bursts of 200.
+    pub fn with_prefix_rate(mut self, per_second: u32, burst: u32) -> Result<Self, Error> {
+        self.prefix_rate = Rate::new(per_second, burst)?;
+        Ok(self)
+    }
+
+    /// Sets the length of the prefixes which source addresses are grouped into
+    ///
+    /// Defaults to /24 for IPv4 and /48 for IPv6.
+    pub fn with_prefix_len(mut self, ipv4: u8, ipv6: u8) -> Result<Self, Error> {
+        if ipv4 > 32 || ipv6 > 128 {
+            return Err(Error("prefix length exceeds the length of the address"));
+        }
+        self.ipv4_prefix_len = ipv4;
+        self.ipv6_prefix_len = ipv6;
+        Ok(self)
+    }
+
+    /// Sets the maximum number of source addresses to track
+    ///
+    /// Defaults to 10000.
+    pub fn with_max_sources(mut self, limit: usize) -> Result<Self, Error> {
+        if limit == 0 {
+            return Err(Error(
+                "the maximum number of sources must be greater than 0",
+            ));
+        }
+        self.max_sources = limit;
+        Ok(self)
+    }
+
+    /// Sets the maximum number of network prefixes to track
+    ///
+    /// Defaults to 1000.
+    pub fn with_max_prefixes(mut self, limit: usize) -> Result<Self, Error> {
+        if limit == 0 {
+            return Err(Error(
+                "the maximum number of prefixes must be greater than 0",
+            ));
+        }
+        self.max_prefixes = limit;
+        Ok(self)
+    }
+
+    /// Always allows connection attempts from addresses in `cidr`, without rate limiting them
+    ///
+    /// If an address is in both an allowed and a denied range, the more specific range is used.
+    pub fn with_allow(mut self, cidr: Cidr) -> Result<Self, Error> {
+        self.allow.push(cidr);
+        Ok(self)
+    }
+
+    /// Always drops connection attempts from addresses in `cidr`
+    ///
+    /// If an address is in both an allowed and a denied range, the more specific range is used.
+    pub fn with_deny(mut self, cidr: Cidr) -> Result<Self, Error> {
+        self.deny.push(cidr);
+        Ok(self)
+    }
+
+    /// Sets the number of inflight handshakes at which connection attempts within the rate
+    /// limit are sent a Retry packet and attempts exceeding it are dropped
+    ///
+    /// Below this threshold, attempts within the rate limit are allowed and attempts exceeding
+    /// it are sent a Retry packet.
+    pub fn with_retry_threshold(mut self, inflight_handshakes: usize) -> Result<Self, Error> {
+        self.retry_threshold = Some(inflight_handshakes);
+        Ok(self)
+    }
+
+    /// Sets the number of inflight handshakes at which all connection attempts are dropped
+    ///
+    /// Addresses in an allowed range are not affected.
+    pub fn with_drop_threshold(mut self, inflight_handshakes: usize) -> Result<Self, Error> {
+        self.drop_threshold = Some(inflight_handshakes);
+        Ok(self)
+    }
+
+    /// Build the limits
+    pub fn build(self) -> Result<Limits, Error> {
+        if let (Some(retry), Some(drop)) = (self.retry_threshold, self.drop_threshold) {
+            if retry > drop {
+                return Err(Error(
+                    "the retry threshold must not exceed the drop threshold",
+                ));
+            }
+        }
+
+        Ok(Limits {
+            sources: Buckets::new(self.source_rate, self.max_sources),
+            prefixes: Buckets::new(self.prefix_rate, self.max_prefixes),
+            attempts: Table::new(self.max_sources),
+            ipv4_prefix_len: self.ipv4_prefix_len,
+            ipv6_prefix_len: self.ipv6_prefix_len,
+            allow: self.allow,
+            deny: self.deny,
+            retry_threshold: self.retry_threshold.unwrap_or(usize::MAX),
+            drop_threshold: self.drop_threshold.unwrap_or(usize::MAX),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Limits {
+    sources: Buckets<IpAddr>,
+    prefixes: Buckets<IpAddr>,
+    /// The time and decision of recent attempts, keyed by source and Destination Connection ID
+    attempts: Table<(IpAddr, InitialId), (Duration, bool)>,
+    ipv4_prefix_len: u8,
+    ipv6_prefix_len: u8,
+    allow: Vec<Cidr>,
+    deny: Vec<Cidr>,
+    retry_threshold: usize,
+    drop_threshold: usize,
+}
+
+impl Limits {
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Returns the policy of the most specific range containing `addr`
+    fn policy(&self, addr: IpAddr) -> Option<Policy> {
+        let longest_match = |ranges: &[Cidr]| {
+            ranges
+                .iter()
+                .filter(|cidr| cidr.contains(addr))
+                .map(|cidr| cidr.prefix_len())
+                .max()
+        };
+
+        match (longest_match(&self.allow), longest_match(&self.deny)) {
+            (Some(allow), Some(deny)) if allow > deny => Some(Policy::Allow),
+            (_, Some(_)) => Some(Policy::Deny),
+            (Some(_), None) => Some(Policy::Allow),
+            (None, None) => None,
+        }
+    }
+
+    fn load(&self, inflight_handshakes: usize) -> Load {
+        if inflight_handshakes >= self.drop_threshold {
+            Load::Critical
+        } else if inflight_handshakes >= self.retry_threshold {
+            Load::Elevated
+        } else {
+            Load::Normal
+        }
+    }
+
+    /// Takes a token from the source and prefix buckets for the attempt, returning `false` if
+    /// either of them is empty
+    ///
+    /// Retransmissions of an Initial packet reuse the earlier decision without being charged.
+    fn charge(&mut self, addr: IpAddr, destination_connection_id: &[u8], now: Duration) -> bool {
+        let key = InitialId::try_from_bytes(destination_connection_id).map(|id| (addr, id));
+
+        if let Some(&mut (charged_at, within_limit)) =
+            key.and_then(|key| self.attempts.get_mut(&key))
+        {
+            if now.saturating_sub(charged_at) < RETRANSMISSION_WINDOW {
+                return within_limit;
+            }
+        }
+
+        let prefix = self.prefix(addr);
+        // charge both buckets, even if the first is already empty
+        let within_limit = self.sources.take(addr, now) & self.prefixes.take(prefix, now);
+
+        if let Some(key) = key {
+            self.attempts.insert(key, (now, within_limit));
+        }
+
+        within_limit
+    }
+
+    fn prefix(&self, addr: IpAddr) -> IpAddr {
+        let prefix_len = match addr {
+            IpAddr::V4(_) => self.ipv4_prefix_len,
+            IpAddr::V6(_) => self.ipv6_prefix_len,
+        };
+        mask(addr, prefix_len)
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Builder::default()
+            .build()
+            .expect("the default configuration is valid")
+    }
+}
+
+impl Limiter for Limits {
+    fn on_connection_attempt(&mut self, info: &ConnectionAttempt) -> Outcome {
+        let remote_address = SocketAddr::from(info.remote_address.clone());
+        if s2n_quic_core::path::remote_port_blocked(remote_address.port()) {
+            return Outcome::drop();
+        }
+
+        let addr = remote_address.ip().to_canonical();
+        match self.policy(addr) {
+            Some(Policy::Allow) => return Outcome::allow(),
+            Some(Policy::Deny) => return Outcome::drop(),
+            None => {}
+        }
+
+        let load = self.load(info.inflight_handshakes);
+        if load == Load::Critical {
+            return Outcome::drop();
+        }
+
+        let now = info.timestamp.duration_since_start();
+        let within_limit = self.charge(addr, info.destination_connection_id, now);
+
+        match (load, within_limit) {
+            (Load::Normal, true) => Outcome::allow(),
+            (Load::Normal, false) | (Load::Elevated, true) => Outcome::retry(),
+            (Load::Elevated, false) | (Load::Critical, _) => Outcome::drop(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::{
+        event::IntoEvent,
+        inet,
+        time::{testing::Clock as MockClock, Clock},
+    };
+
+    fn attempt(
+        limits: &mut Limits,
+        clock: &MockClock,
+        addr: &str,
+        inflight_handshakes: usize,
+    ) -> Outcome {
+        attempt_with_id(limits, clock, addr, inflight_handshakes, &[])
+    }
+
+    fn attempt_with_id(
+        limits: &mut Limits,
+        clock: &MockClock,
+        addr: &str,
+        inflight_handshakes: usize,
+        destination_connection_id: &[u8],
+    ) -> Outcome {
+        let addr: SocketAddr = addr.parse().unwrap();
+        let addr: inet::SocketAddress = addr.into();
+        let info =
+            ConnectionAttempt::new(inflight_handshakes, 0, &addr, clock.get_time().into_event())
+                .with_destination_connection_id(destination_connection_id);
+        limits.on_connection_attempt(&info)
+    }
+
+    #[test]
+    fn cidr_test() {
+        let cidr: Cidr = "192.0.2.77/24".parse().unwrap();
+        assert_eq!(cidr.to_string(), "192.0.2.0/24");
+        assert!(cidr.contains("192.0.2.1".parse().unwrap()));
+        assert!(cidr.contains("::ffff:192.0.2.1".parse().unwrap()));
+        assert!(!cidr.contains("192.0.3.1".parse().unwrap()));
+        assert!(!cidr.contains("c000:201::".parse().unwrap()));
+
+        let cidr: Cidr = "2001:db8::/32".parse().unwrap();
+        assert!(cidr.contains("2001:db8:1::1".parse().unwrap()));
+        assert!(!cidr.contains("2001:db9::1".parse().unwrap()));
+
+        let cidr: Cidr = "0.0.0.0/0".parse().unwrap();
+        assert!(cidr.contains("198.51.100.1".parse().unwrap()));
+
+        let cidr: Cidr = "198.51.100.1".parse().unwrap();
+        assert_eq!(cidr.prefix_len(), 32);
+
+        assert!("192.0.2.0/33".parse::<Cidr>().is_err());
+        assert!("2001:db8::/129".parse::<Cidr>().is_err());
+        assert!("192.0.2.0/".parse::<Cidr>().is_err());
+        assert!("localhost/8".parse::<Cidr>().is_err());
+    }
+
+    #[test]
+    fn builder_test() {
+        assert!(Limits::builder().with_source_rate(1, 0).is_err());
+        assert!(Limits::builder().with_prefix_len(33, 48).is_err());
+        assert!(Limits::builder().with_max_sources(0).is_err());
+        assert!(Limits::builder()
+            .with_retry_threshold(10)
+            .unwrap()
+            .with_drop_threshold(5)
+            .unwrap()
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn source_rate_test() {
+        let mut clock = MockClock::default();
+        let mut limits = Limits::builder()
+            .with_source_rate(2, 4)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        for _ in 0..4 {
+            assert_eq!(
+                attempt(&mut limits, &clock, "192.0.2.1:443", 0),
+                Outcome::allow()
+            );
+        }
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.1:443", 0),
+            Outcome::retry()
+        );
+
+        // other sources are not affected
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.2:443", 0),
+            Outcome::allow()
+        );
+
+        // the bucket refills at the configured rate
+        clock.inc_by(Duration::from_millis(500));
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.1:443", 0),
+            Outcome::allow()
+        );
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.1:443", 0),
+            Outcome::retry()
+        );
+    }
+
+    #[test]
+    fn prefix_rate_test() {
+        let clock = MockClock::default();
+        let mut limits = Limits::builder()
+            .with_prefix_rate(0, 3)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        for host in 1..=3 {
+            let addr = format!("192.0.2.{host}:443");
+            assert_eq!(attempt(&mut limits, &clock, &addr, 0), Outcome::allow());
+        }
+
+        // the prefix has used all of its tokens
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.4:443", 0),
+            Outcome::retry()
+        );
+        assert_eq!(
+            attempt(&mut limits, &clock, "[2001:db8::1]:443", 0),
+            Outcome::allow()
+        );
+    }
+
+    #[test]
+    fn policy_test() {
+        let clock = MockClock::default();
+        let mut limits = Limits::builder()
+            .with_source_rate(0, 1)
+            .unwrap()
+            .with_deny("192.0.2.0/24".parse().unwrap())
+            .unwrap()
+            .with_allow("192.0.2.128/25".parse().unwrap())
+            .unwrap()
+            .with_allow("2001:db8::/32".parse().unwrap())
+            .unwrap()
+            .with_deny("2001:db8::/32".parse().unwrap())
+            .unwrap()
+            .with_drop_threshold(10)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.1:443", 0),
+            Outcome::drop()
+        );
+
+        // the more specific range takes precedence and bypasses the rate and load limits
+        for _ in 0..3 {
+            assert_eq!(
+                attempt(&mut limits, &clock, "192.0.2.200:443", 100),
+                Outcome::allow()
+            );
+        }
+
+        // deny wins ties
+        assert_eq!(
+            attempt(&mut limits, &clock, "[2001:db8::1]:443", 0),
+            Outcome::drop()
+        );
+    }
+
+    #[test]
+    fn escalation_test() {
+        let clock = MockClock::default();
+        let mut limits = Limits::builder()
+            .with_source_rate(0, 1)
+            .unwrap()
+            .with_retry_threshold(10)
+            .unwrap()
+            .with_drop_threshold(20)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.1:443", 0),
+            Outcome::allow()
+        );
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.1:443", 0),
+            Outcome::retry()
+        );
+
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.2:443", 10),
+            Outcome::retry()
+        );
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.2:443", 10),
+            Outcome::drop()
+        );
+
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.3:443", 20),
+            Outcome::drop()
+        );
+    }
+
+    #[test]
+    fn address_spray_test() {
+        const MAX_SOURCES: usize = 16;
+
+        let clock = MockClock::default();
+        let mut limits = Limits::builder()
+            .with_source_rate(0, 1)
+            .unwrap()
+            .with_prefix_rate(0, 100)
+            .unwrap()
+            .with_max_sources(MAX_SOURCES)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut allowed = 0;
+        for host in 0..=255 {
+            let addr = format!("192.0.2.{host}:443");
+            if attempt(&mut limits, &clock, &addr, 0) == Outcome::allow() {
+                allowed += 1;
+            }
+        }
+
+        // the number of tracked sources is bounded and the prefix limits the spray
+        assert_eq!(limits.sources.entries.len(), MAX_SOURCES);
+        assert_eq!(allowed, 100);
+    }
+
+    #[test]
+    fn eviction_test() {
+        let clock = MockClock::default();
+        let mut limits = Limits::builder()
+            .with_source_rate(0, 1)
+            .unwrap()
+            .with_max_sources(4)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        for host in 1..=4 {
+            let addr = format!("192.0.2.{host}:443");
+            assert_eq!(attempt(&mut limits, &clock, &addr, 0), Outcome::allow());
+        }
+
+        // the first source keeps making attempts
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.1:443", 0),
+            Outcome::retry()
+        );
+
+        // a new source gets its own bucket by evicting an entry which wasn't used recently
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.5:443", 0),
+            Outcome::allow()
+        );
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.1:443", 0),
+            Outcome::retry()
+        );
+        assert_eq!(
+            attempt(&mut limits, &clock, "192.0.2.2:443", 0),
+            Outcome::allow()
+        );
+        assert_eq!(limits.sources.entries.len(), 4);
+    }
+
+    #[test]
+    fn retransmission_test() {
+        let mut clock = MockClock::default();
+        let mut limits = Limits::builder()
+            .with_source_rate(0, 1)
+            .unwrap()
+            .with_retry_threshold(10)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let first_id = [1u8; 8];
+        let second_id = [2u8; 8];
+
+        assert_eq!(
+            attempt_with_id(&mut limits, &clock, "192.0.2.1:443", 10, &first_id),
+            Outcome::retry()
+        );
+
+        // the Retry packet was lost so the client retransmits its Initial
+        assert_eq!(
+            attempt_with_id(&mut limits, &clock, "192.0.2.1:443", 10, &first_id),
+            Outcome::retry()
+        );
+
+        // a new attempt from the same source is charged
+        assert_eq!(
+            attempt_with_id(&mut limits, &clock, "192.0.2.1:443", 10, &second_id),
+            Outcome::drop()
+        );
+
+        // the decision isn't reused forever
+        clock.inc_by(RETRANSMISSION_WINDOW);
+        assert_eq!(
+            attempt_with_id(&mut limits, &clock, "192.0.2.1:443", 10, &first_id),
+            Outcome::drop()
+        );
+    }
+}
@@ -33,6 +33,10 @@ pub mod tracing;
 #[cfg(feature = "provider-event-console-perf")]
 pub mod console_perf;
 
+/// Provides an HTTP handler which serves aggregate metrics to Prometheus
+#[cfg(any(feature = "provider-event-openmetrics", test))]
+pub mod openmetrics;
+
 cfg_if! {
     if #[cfg(any(feature = "provider-event-tracing", test))] {
         pub use self::tracing as default;
@@ -0,0 +1,323 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serves aggregate metrics to Prometheus in the
+//! [OpenMetrics](https://github.com/prometheus/OpenMetrics/blob/main/specification/OpenMetrics.md)
+//! text format
+//!
+//! ```rust,no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use s2n_quic::provider::event::openmetrics;
+//!
+//! let registry = openmetrics::Registry::new();
+//! let _metrics = openmetrics::Server::bind(registry.clone(), "127.0.0.1:9090")?;
+//!
+//! let server = s2n_quic::Server::builder()
+//!     .with_event(openmetrics::Subscriber::new(registry))?
+//!     .with_io("127.0.0.1:443")?
+//!     .start()?;
+//! # let _ = server;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    io::{self, Read, Write},
+    net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+pub use s2n_quic_core::event::metrics::aggregate::openmetrics::{Registry, CONTENT_TYPE};
+
+/// An event subscriber which records metrics in a [`Registry`]
+pub type Subscriber = s2n_quic_core::event::metrics::aggregate::Subscriber<Registry>;
+
+/// The maximum size of a request head that will be read
+const MAX_REQUEST_LEN: usize = 8 * 1024;
+
+/// How long to wait on a client before giving up on the request
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long to back off after accepting a connection fails
+///
+/// This keeps the listener from spinning on persistent errors, like running out of file
+/// descriptors.
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(50);
+
+/// How long the listener thread waits on a client it is turning away
+const UNAVAILABLE_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// The maximum number of requests which are handled at the same time
+///
+/// Connections beyond this are answered with `503 Service Unavailable` from the listener thread
+/// so a flood of clients can't exhaust threads.
+const MAX_CONNECTIONS: usize = 16;
+
+/// A running metrics HTTP handler
+///
+/// The handler responds to `GET /metrics` (as well as `GET /`) with the encoded metrics. It is
+/// stopped when the value is dropped.
+#[derive(Debug)]
+pub struct Server {
+    local_addr: SocketAddr,
+    is_open: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Server {
+    /// Serves the metrics in `registry` over HTTP on the given address
+    ///
+    /// Each connection is handled on its own thread, so a slow client doesn't hold up other
+    /// scrapes.
+    pub fn bind<A: ToSocketAddrs>(registry: Registry, addr: A) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let local_addr = listener.local_addr()?;
+        let is_open = Arc::new(AtomicBool::new(true));
+
+        let thread = thread::Builder::new()
+            .name("s2n-quic-metrics".into())
+            .spawn({
+                let is_open = is_open.clone();
+                move || listen(&registry, &listener, &is_open)
+            })?;
+
+        Ok(Self {
+            local_addr,
+            is_open,
+            thread: Some(thread),
+        })
+    }
+
+    /// Returns the address the handler is listening on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.is_open.store(false, Ordering::Release);
+
+        // the listener is closed once the thread exits. Connections which were already accepted
+        // finish on their own threads.
+        if let Some(thread) = self.thread.take() {
+            // wake the blocking accept so the thread notices it has been stopped. If the
+            // connection fails the listener is unreachable anyway, so don't wait on the thread.
+            if TcpStream::connect_timeout(&wake_addr(self.local_addr), TIMEOUT).is_ok() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+/// Returns the address used to wake the listener bound to `local_addr`
+fn wake_addr(local_addr: SocketAddr) -> SocketAddr {
+    let mut addr = local_addr;
+    if addr.ip().is_unspecified() {
+        match addr {
+            SocketAddr::V4(_) => addr.set_ip(Ipv4Addr::LOCALHOST.into()),
+            SocketAddr::V6(_) => addr.set_ip(Ipv6Addr::LOCALHOST.into()),
+        }
+    }
+    addr
+}
+
+fn listen(registry: &Registry, listener: &TcpListener, is_open: &AtomicBool) {
+    let connections = Arc::new(AtomicUsize::new(0));
+
+    loop {
+        let accepted = listener.accept();
+
+        // the handler was dropped and connected to wake the listener
+        if !is_open.load(Ordering::Acquire) {
+            return;
+        }
+
+        let stream = match accepted {
+            Ok((stream, _addr)) => stream,
+            Err(_) => {
+                thread::sleep(ACCEPT_BACKOFF);
+                continue;
+            }
+        };
+
+        if connections.fetch_add(1, Ordering::Relaxed) >= MAX_CONNECTIONS {
+            connections.fetch_sub(1, Ordering::Relaxed);
+            let _ = unavailable(stream);
+            continue;
+        }
+
+        let spawned = thread::Builder::new()
+            .name("s2n-quic-metrics-conn".into())
+            .spawn({
+                let registry = registry.clone();
+                let connections = connections.clone();
+                move || {
+                    // a misbehaving client shouldn't take down the handler
+                    let _ = handle(&registry, stream);
+                    connections.fetch_sub(1, Ordering::Relaxed);
+                }
+            });
+
+        if spawned.is_err() {
+            connections.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+fn handle(registry: &Registry, mut stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        let len = stream.read(&mut buffer)?;
+        if len == 0 {
+            // the client closed the connection before finishing the request
+            return Ok(());
+        }
+        request.extend_from_slice(&buffer[..len]);
+        if request.len() > MAX_REQUEST_LEN {
+            return respond(&mut stream, "431 Request Header Fields Too Large", &[]);
+        }
+    }
+
+    let request = String::from_utf8_lossy(&request);
+    let mut request_line = request.lines().next().unwrap_or_default().split(' ');
+    let method = request_line.next().unwrap_or_default();
+    let target = request_line.next().unwrap_or_default();
+    let path = target.split('?').next().unwrap_or_default();
+
+    if !matches!(path, "/" | "/metrics") {
+        return respond(&mut stream, "404 Not Found", &[]);
+    }
+
+    match method {
+        "GET" => {
+            let body = registry.encode();
+            respond(&mut stream, "200 OK", body.as_bytes())
+        }
+        "HEAD" => {
+            // the headers describe the body a GET would return
+            let body = registry.encode();
+            write_head(&mut stream, "200 OK", body.len())?;
+            stream.flush()
+        }
+        _ => respond(&mut stream, "405 Method Not Allowed", &[]),
+    }
+}
+
+/// Tells a client that there are too many requests in progress
+///
+/// This runs on the listener thread so it doesn't wait on the client: the response fits in the
+/// socket's send buffer and the request is only drained if it has already arrived.
+fn unavailable(mut stream: TcpStream) -> io::Result<()> {
+    stream.set_write_timeout(Some(UNAVAILABLE_TIMEOUT))?;
+    respond(&mut stream, "503 Service Unavailable", &[])?;
+    stream.shutdown(Shutdown::Write)?;
+
+    // closing a socket with unread data resets the connection, which can discard the response
+    // before the client reads it
+    stream.set_nonblocking(true)?;
+    let mut buffer = [0; 1024];
+    while matches!(stream.read(&mut buffer), Ok(len) if len > 0) {}
+
+    Ok(())
+}
+
+fn respond(stream: &mut TcpStream, status: &str, body: &[u8]) -> io::Result<()> {
+    write_head(stream, status, body.len())?;
+    stream.write_all(body)?;
+    stream.flush()
+}
+
+fn write_head(stream: &mut TcpStream, status: &str, content_length: usize) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {CONTENT_TYPE}\r\nContent-Length: {content_length}\r\nConnection: close\r\n\r\n",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(addr: SocketAddr, req: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(req.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http() {
+        let server = Server::bind(Registry::new(), "127.0.0.1:0").unwrap();
+        let addr = server.local_addr();
+
+        let response = request(addr, "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains(&format!("Content-Type: {CONTENT_TYPE}\r\n")));
+        assert!(response.ends_with("\r\n\r\n# EOF\n"));
+
+        // HEAD reports the length of the body without sending it
+        let response = request(addr, "HEAD /metrics HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Length: 6\r\n"), "{response}");
+        assert!(response.ends_with("\r\n\r\n"));
+
+        let response = request(addr, "POST /metrics HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+
+        let response = request(addr, "GET /other HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+        drop(server);
+        assert!(TcpStream::connect(addr).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn slow_client() {
+        let server = Server::bind(Registry::new(), "127.0.0.1:0").unwrap();
+        let addr = server.local_addr();
+
+        // a client which never finishes its request doesn't block other scrapes
+        let _stalled = TcpStream::connect(addr).unwrap();
+        let start = std::time::Instant::now();
+        let response = request(addr, "GET / HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(start.elapsed() < TIMEOUT);
+
+        // dropping the handler doesn't wait on the stalled client
+        let start = std::time::Instant::now();
+        drop(server);
+        assert!(start.elapsed() < TIMEOUT);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn too_many_connections() {
+        let server = Server::bind(Registry::new(), "127.0.0.1:0").unwrap();
+        let addr = server.local_addr();
+
+        // the connections are accepted in order so these take up every handler thread
+        let _stalled: Vec<_> = (0..MAX_CONNECTIONS)
+            .map(|_| TcpStream::connect(addr).unwrap())
+            .collect();
+
+        let response = request(addr, "GET /metrics HTTP/1.1\r\n\r\n");
+        assert!(
+            response.starts_with("HTTP/1.1 503 Service Unavailable\r\n"),
+            "{response}"
+        );
+        assert!(response.contains("Content-Length: 0\r\n"), "{response}");
+    }
+}
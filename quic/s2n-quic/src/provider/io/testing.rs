@@ -24,6 +24,19 @@ impl super::Provider for Io {
     }
 }
 
+impl super::Provider for replay::Io {
+    type PathHandle = PathHandle;
+    type Error = io::Error;
+
+    fn start<E: Endpoint<PathHandle = Self::PathHandle>>(
+        self,
+        endpoint: E,
+    ) -> Result<SocketAddress, Self::Error> {
+        let (_join_handle, local_addr) = replay::Io::start(self, endpoint)?;
+        Ok(local_addr)
+    }
+}
+
 /// Runs a single test with the given network
 ///
 /// Returns the total runtime of the test
@@ -42,6 +55,10 @@ pub fn test_seed<N: Network, F: FnOnce(&Handle) -> Result<O>, O>(
     let mut executor = Executor::new(network, seed);
     let handle = executor.handle().clone();
 
+    if let Some(pcap) = pcap_from_env(seed) {
+        handle.capture(pcap);
+    }
+
     executor.enter(|| f(&handle))?;
 
     executor.run();
@@ -53,3 +70,65 @@ pub fn test_seed<N: Network, F: FnOnce(&Handle) -> Result<O>, O>(
 
     Ok(now)
 }
+
+/// Creates a packet capture if the `S2N_QUIC_PCAP` environment variable is set
+///
+/// The variable specifies the directory in which captures are written. Each capture is named
+/// after the current thread, which is the test name when running under `cargo test`, and the
+/// seed. If `SSLKEYLOGFILE` is also set, the secrets are embedded in the capture so it can be
+/// decrypted directly by Wireshark.
+fn pcap_from_env(seed: u64) -> Option<Pcap> {
+    use std::cell::Cell;
+
+    thread_local! {
+        static CAPTURES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    let dir = std::env::var_os("S2N_QUIC_PCAP")?;
+
+    let thread = std::thread::current();
+    let name = thread.name().unwrap_or("capture").replace("::", "__");
+
+    // tests can run more than one simulation on a thread so make sure each one is unique
+    let count = CAPTURES.with(|count| count.replace(count.get() + 1));
+    let name = if count == 0 {
+        format!("{name}-{seed}.pcapng")
+    } else {
+        format!("{name}-{seed}-{count}.pcapng")
+    };
+
+    let mut pcap = Pcap::new(std::path::Path::new(&dir).join(name));
+
+    if let Some(keylog) = std::env::var_os("SSLKEYLOGFILE") {
+        pcap = pcap.with_keylog(keylog);
+    }
+
+    Some(pcap)
+}
+
+/// Returns a TLS server for use on the testing network
+///
+/// If `SSLKEYLOGFILE` is set, secrets are logged so captures of the simulated network can be
+/// decrypted.
+pub fn tls_server(cert: &str, key: &str) -> Result<crate::provider::tls::default::Server> {
+    let mut builder = crate::provider::tls::default::Server::builder();
+
+    if std::env::var_os("SSLKEYLOGFILE").is_some() {
+        builder = builder.with_key_logging()?;
+    }
+
+    Ok(builder.with_certificate(cert, key)?.build()?)
+}
+
+/// Returns a TLS client for use on the testing network
+///
+/// See [`tls_server`] for details on key logging.
+pub fn tls_client(ca_cert: &str) -> Result<crate::provider::tls::default::Client> {
+    let mut builder = crate::provider::tls::default::Client::builder();
+
+    if std::env::var_os("SSLKEYLOGFILE").is_some() {
+        builder = builder.with_key_logging()?;
+    }
+
+    Ok(builder.with_certificate(ca_cert)?.build()?)
+}
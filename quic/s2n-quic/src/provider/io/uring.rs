@@ -0,0 +1,29 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Provides an implementation of the [`io::Provider`](crate::provider::io::Provider)
+//! that drives the sockets with [io_uring](https://man7.org/linux/man-pages/man7/io_uring.7.html).
+//!
+//! This is the [`tokio`](super::tokio) provider with io_uring enabled. If the running kernel
+//! doesn't support the required io_uring operations, the default socket tasks are used instead.
+//!
+//! Only the endpoint's sockets are driven by io_uring. The per-stream sockets in `s2n-quic-dc`
+//! continue to use plain syscalls.
+
+use std::io;
+
+pub use super::tokio::{Builder, Provider};
+
+/// Returns a [`Builder`] with io_uring enabled
+pub fn builder() -> io::Result<Builder> {
+    Provider::builder().with_io_uring(true)
+}
+
+/// Creates a [`Provider`] with io_uring enabled that listens on the given address
+pub fn new<A: std::net::ToSocketAddrs>(addr: A) -> io::Result<Provider> {
+    let address = addr
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing bind address"))?;
+    builder()?.with_receive_address(address)?.build()
+}
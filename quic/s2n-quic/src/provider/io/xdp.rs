@@ -3,6 +3,24 @@
 
 //! Provides an implementation of the [`io::Provider`](crate::provider::io::Provider)
 //! using [AF_XDP](https://www.kernel.org/doc/html/latest/networking/af_xdp.html) sockets.
+//!
+//! [`interface::Provider`] sets up everything needed for an endpoint on a network interface:
+//!
+//! ```rust,no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use s2n_quic::provider::io::xdp;
+//!
+//! let io = xdp::interface::Provider::builder()
+//!     .with_interface("eth0")?
+//!     .with_receive_address("0.0.0.0:443".parse()?)?
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! If AF_XDP can't be configured on the interface, the provider falls back to a standard UDP
+//! socket. The lower-level [`Provider`] can be used to assemble the UMEM, rings and sockets
+//! directly.
 
 /// Export the platform items
 pub use s2n_quic_platform::io::xdp::*;
@@ -23,3 +41,16 @@ where
         Ok(local_addr)
     }
 }
+
+impl super::Provider for interface::Provider {
+    type PathHandle = PathHandle;
+    type Error = std::io::Error;
+
+    fn start<E: s2n_quic_core::endpoint::Endpoint<PathHandle = Self::PathHandle>>(
+        self,
+        endpoint: E,
+    ) -> Result<s2n_quic_core::inet::SocketAddress, Self::Error> {
+        let (_join_handle, local_addr) = interface::Provider::start(self, endpoint)?;
+        Ok(local_addr)
+    }
+}
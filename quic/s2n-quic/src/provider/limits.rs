@@ -3,8 +3,9 @@
 
 //! Provides limits support for a connection
 
-pub use s2n_quic_core::connection::limits::{
-    ConnectionInfo, HandshakeInfo, Limiter, Limits, UpdatableLimits,
+pub use s2n_quic_core::{
+    connection::limits::{ConnectionInfo, HandshakeInfo, Limiter, Limits, UpdatableLimits},
+    inet::Dscp,
 };
 
 pub trait Provider {
@@ -0,0 +1,171 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Provides host name resolution for [`Client::connect_host`](crate::Client::connect_host)
+//!
+//! By default, names are resolved with the operating system's resolver. Applications can
+//! implement [`Resolver`] to use a different source of addresses, or use [`Static`] to map names
+//! to a fixed set of addresses.
+
+use core::{future::Future, pin::Pin};
+use std::{
+    collections::HashMap,
+    io,
+    net::{IpAddr, SocketAddr},
+};
+
+pub trait Provider: 'static {
+    type Resolver: 'static + Resolver;
+    type Error: 'static + core::fmt::Display + Send + Sync;
+
+    fn start(self) -> Result<Self::Resolver, Self::Error>;
+}
+
+impl_provider_utils!();
+
+impl<T: Resolver> Provider for T {
+    type Resolver = T;
+    type Error = core::convert::Infallible;
+
+    fn start(self) -> Result<Self::Resolver, Self::Error> {
+        Ok(self)
+    }
+}
+
+/// The future returned by [`Resolver::resolve`]
+pub type Resolve = Pin<Box<dyn Future<Output = io::Result<Vec<SocketAddr>>> + Send>>;
+
+/// Resolves host names into the addresses they can be reached at
+pub trait Resolver: 'static + Send + Sync {
+    /// Resolves `host` into the socket addresses for `port`
+    ///
+    /// Addresses for both IPv6 and IPv4 should be returned, if available, so the client can
+    /// race connection attempts between them.
+    fn resolve(&self, host: &str, port: u16) -> Resolve;
+}
+
+pub use system::{System, System as Default};
+
+mod system {
+    use super::{Resolve, Resolver};
+    use std::{
+        io,
+        net::{IpAddr, SocketAddr, ToSocketAddrs},
+    };
+
+    /// Resolves names with the operating system's resolver
+    ///
+    /// Lookups block, so each one is performed on its own thread.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct System;
+
+    impl Resolver for System {
+        fn resolve(&self, host: &str, port: u16) -> Resolve {
+            // skip the lookup for IP address literals
+            if let Ok(ip) = host.parse::<IpAddr>() {
+                return Box::pin(async move { Ok(vec![SocketAddr::new(ip, port)]) });
+            }
+
+            let host = host.to_string();
+            let (sender, receiver) = futures::channel::oneshot::channel();
+
+            let spawned = std::thread::Builder::new()
+                .name("s2n-quic-resolver".into())
+                .spawn(move || {
+                    let addrs = (host.as_str(), port)
+                        .to_socket_addrs()
+                        .map(|addrs| addrs.collect());
+                    // the application may no longer be waiting on the result
+                    let _ = sender.send(addrs);
+                });
+
+            Box::pin(async move {
+                spawned?;
+                receiver
+                    .await
+                    .map_err(|_| io::Error::new(io::ErrorKind::Other, "resolver thread panicked"))?
+            })
+        }
+    }
+}
+
+/// Resolves names from a fixed set of addresses
+///
+/// This is useful for testing, or for applications which already know where a host can be
+/// reached. IP address literals resolve to themselves.
+///
+/// # Examples
+///
+/// ```rust
+/// use s2n_quic::provider::resolver::Static;
+///
+/// let resolver = Static::default()
+///     .with_host("example.com", ["2001:db8::1".parse().unwrap(), "192.0.2.1".parse().unwrap()]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Static {
+    hosts: HashMap<String, Vec<IpAddr>>,
+}
+
+impl Static {
+    /// Adds the addresses that `host` resolves to
+    ///
+    /// Host names are matched case-insensitively. Addresses are appended to any previously
+    /// added for the same host.
+    pub fn with_host<A: IntoIterator<Item = IpAddr>>(mut self, host: &str, addrs: A) -> Self {
+        self.hosts
+            .entry(host.to_ascii_lowercase())
+            .or_default()
+            .extend(addrs);
+        self
+    }
+
+    fn lookup(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+        if let Some(addrs) = self.hosts.get(&host.to_ascii_lowercase()) {
+            return Ok(addrs.iter().map(|ip| SocketAddr::new(*ip, port)).collect());
+        }
+
+        if let Ok(ip) = host.parse::<IpAddr>() {
+            return Ok(vec![SocketAddr::new(ip, port)]);
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no addresses configured for {host:?}"),
+        ))
+    }
+}
+
+impl Resolver for Static {
+    fn resolve(&self, host: &str, port: u16) -> Resolve {
+        let addrs = self.lookup(host, port);
+        Box::pin(async move { addrs })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_lookup() {
+        let v6: IpAddr = "2001:db8::1".parse().unwrap();
+        let v4: IpAddr = "192.0.2.1".parse().unwrap();
+        let resolver = Static::default()
+            .with_host("Example.com", [v6])
+            .with_host("example.com", [v4]);
+
+        assert_eq!(
+            resolver.lookup("EXAMPLE.com", 443).unwrap(),
+            vec![SocketAddr::new(v6, 443), SocketAddr::new(v4, 443)]
+        );
+        assert_eq!(
+            resolver.lookup("192.0.2.2", 443).unwrap(),
+            vec!["192.0.2.2:443".parse().unwrap()]
+        );
+        assert_eq!(
+            resolver.lookup("unknown.example", 443).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+}
@@ -1,15 +1,18 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{connection::Connection, provider::*};
+use crate::{application, connection::Connection, provider::*, shutdown};
 use core::{
     fmt,
     task::{Context, Poll},
+    time::Duration,
 };
 use s2n_quic_transport::endpoint::handle::Acceptor;
 
 mod builder;
 mod providers;
+#[cfg(target_os = "linux")]
+pub mod sharded;
 
 pub use builder::*;
 pub use providers::*;
@@ -103,6 +106,57 @@ impl Server {
         })
     }
 
+    /// Gracefully shuts down the [`Server`]
+    ///
+    /// The endpoint stops accepting new connections and closes any connections without open
+    /// streams. Connections with open streams are given until the `deadline` to finish, after
+    /// which they are closed as well. The returned future resolves with a [`shutdown::Summary`]
+    /// of how the connections ended, once all of them have closed.
+    ///
+    /// Connections are closed with an [`application::Error::UNKNOWN`] error code. Use
+    /// [`Self::shutdown_with_error`] to close them with a different code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::{error::Error, path::Path, time::Duration};
+    /// # use s2n_quic::Server;
+    /// #
+    /// # async fn shutdown() -> Result<(), Box<dyn Error>> {
+    /// let server = Server::builder()
+    ///     .with_tls((Path::new("./certs/cert.pem"), Path::new("./certs/key.pem")))?
+    ///     .with_io("127.0.0.1:443")?
+    ///     .start()?;
+    ///
+    /// let summary = server.shutdown(Duration::from_secs(30)).await;
+    /// println!("{} connections were forcibly closed", summary.forced);
+    /// #
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn shutdown(&self, deadline: Duration) -> shutdown::Shutdown {
+        self.shutdown_with_error(deadline, application::Error::UNKNOWN)
+    }
+
+    /// Gracefully shuts down the [`Server`], closing connections with the provided `error`
+    ///
+    /// See [`Self::shutdown`] for more details.
+    pub fn shutdown_with_error(
+        &self,
+        deadline: Duration,
+        error: application::Error,
+    ) -> shutdown::Shutdown {
+        self.acceptor.shutdown(deadline, error)
+    }
+
+    /// Returns a future which resolves once the [`Server`] starts shutting down
+    ///
+    /// Applications can use this to notify busy connections that they should finish their work
+    /// before the shutdown deadline.
+    pub fn shutdown_signal(&self) -> shutdown::Signal {
+        self.acceptor.shutdown_signal()
+    }
+
     /// Returns the local address that this listener is bound to.
     ///
     /// This can be useful, for example, when binding to port `0` to figure out which
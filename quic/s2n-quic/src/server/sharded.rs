@@ -0,0 +1,179 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runs a server across multiple endpoint workers which share a single UDP port
+//!
+//! Each worker owns a socket in a `SO_REUSEPORT` group. A BPF program attached to the group
+//! steers packets to the worker which issued the destination connection ID, so packets keep
+//! reaching the right worker after a connection migrates or the peer is rebound by a NAT.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use std::{error::Error, path::Path};
+//! # use s2n_quic::{server::sharded::Sharded, Server};
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn Error>> {
+//! let sharded = Sharded::bind("0.0.0.0:443".parse()?, 4)?;
+//!
+//! for worker in sharded.into_workers() {
+//!     let mut server = Server::builder()
+//!         .with_tls((Path::new("./certs/cert.pem"), Path::new("./certs/key.pem")))?
+//!         .with_connection_id(worker.connection_id())?
+//!         .with_io(worker.io()?.build()?)?
+//!         .start()?;
+//!
+//!     tokio::spawn(async move {
+//!         while let Some(connection) = server.accept().await {
+//!             // handle the connection
+//! #           drop(connection);
+//!         }
+//!     });
+//! }
+//! #
+//! #   Ok(())
+//! # }
+//! ```
+
+use crate::provider::{
+    connection_id::sharded::{Format, WORKER_OFFSET},
+    io::tokio,
+};
+use s2n_quic_platform::{
+    bpf::{cbpf::Program, reuseport},
+    syscall,
+};
+use std::{
+    io,
+    net::{SocketAddr, UdpSocket},
+};
+
+/// A set of sockets sharing a port, with connection ID-aware steering between them
+#[derive(Debug)]
+pub struct Sharded {
+    sockets: Vec<UdpSocket>,
+    local_addr: SocketAddr,
+}
+
+impl Sharded {
+    /// Binds `workers` sockets to `addr` and attaches the steering program to them
+    ///
+    /// If the port in `addr` is 0, all of the sockets are bound to the port assigned to the
+    /// first socket.
+    pub fn bind(addr: SocketAddr, workers: u8) -> io::Result<Self> {
+        if workers == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "at least one worker is required",
+            ));
+        }
+
+        let mut sockets = Vec::with_capacity(workers as usize);
+        let mut bind_addr = addr;
+
+        // the kernel indexes sockets in the group in the order they're bound, which
+        // needs to match the worker index encoded in the connection IDs
+        for _ in 0..workers {
+            let socket: UdpSocket = syscall::bind_udp(bind_addr, false, true, false)?.into();
+            bind_addr = socket.local_addr()?;
+            sockets.push(socket);
+        }
+
+        // programs apply to the whole group so we only need to attach to a single socket
+        let program = reuseport::connection_id_router(WORKER_OFFSET, workers);
+        Program::new(&program).attach(&sockets[0])?;
+
+        Ok(Self {
+            sockets,
+            local_addr: bind_addr,
+        })
+    }
+
+    /// Returns the address shared by all of the workers
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Returns the workers, ordered by their index
+    pub fn into_workers(self) -> Vec<Worker> {
+        let workers = self.sockets.len() as u8;
+        self.sockets
+            .into_iter()
+            .enumerate()
+            .map(|(index, socket)| Worker {
+                index: index as u8,
+                workers,
+                socket,
+            })
+            .collect()
+    }
+}
+
+/// The providers for a single worker in a [`Sharded`] server
+#[derive(Debug)]
+pub struct Worker {
+    index: u8,
+    workers: u8,
+    socket: UdpSocket,
+}
+
+impl Worker {
+    /// Returns the index of the worker
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the connection ID format which routes packets back to this worker
+    pub fn connection_id(&self) -> Format {
+        Format::new(self.index, self.workers)
+    }
+
+    /// Returns an IO builder for the worker's socket
+    ///
+    /// GRO is disabled, since it can coalesce packets which are meant for different workers.
+    pub fn io(self) -> io::Result<tokio::Builder> {
+        tokio::Builder::default()
+            .with_rx_socket(self.socket)?
+            .with_gro_disabled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::{connection::id::Generator, inet::SocketAddress};
+
+    #[test]
+    fn bind_test() {
+        let sharded = match Sharded::bind("127.0.0.1:0".parse().unwrap(), 3) {
+            Ok(sharded) => sharded,
+            Err(err) if err.kind() == io::ErrorKind::PermissionDenied => {
+                eprintln!("skipping test due to insufficient permissions");
+                return;
+            }
+            Err(err) => panic!("{err}"),
+        };
+
+        let addr = sharded.local_addr();
+        assert_ne!(addr.port(), 0);
+
+        let remote_address = SocketAddress::default();
+        let connection_info = s2n_quic_core::connection::id::ConnectionInfo::new(&remote_address);
+
+        for (index, worker) in sharded.into_workers().into_iter().enumerate() {
+            assert_eq!(worker.index() as usize, index);
+            assert_eq!(worker.socket.local_addr().unwrap(), addr);
+
+            let mut format = worker.connection_id();
+            let id = format.generate(&connection_info);
+            assert_eq!(format.worker(id.as_ref()), Some(worker.index()));
+        }
+    }
+
+    #[test]
+    fn no_workers_test() {
+        let err = Sharded::bind("127.0.0.1:0".parse().unwrap(), 0).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -32,16 +32,26 @@ use setup::*;
 
 mod blackhole;
 mod buffer_limit;
+mod connect_options;
 mod connection_migration;
 mod deduplicate;
+mod endpoint_limits;
 mod handshake_cid_rotation;
+mod happy_eyeballs;
 mod interceptor;
 mod mtu;
+mod network_impairments;
 mod no_tls;
 mod platform_events;
+mod pool;
 mod pto;
+#[cfg(feature = "s2n-quic-rustls")]
+mod raw_public_key;
+mod replay;
 mod self_test;
+mod shutdown;
 mod skip_packets;
+mod stats;
 mod tls_context;
 
 // TODO: https://github.com/aws/s2n-quic/issues/1726
@@ -0,0 +1,149 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::{connection::Error, provider::tls};
+use s2n_quic_core::connection::limits::Limits;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Counts the client connection attempts which failed
+#[derive(Clone, Default)]
+struct AttemptsFailed(Arc<AtomicUsize>);
+
+impl events::Subscriber for AttemptsFailed {
+    type ConnectionContext = ();
+
+    fn create_connection_context(
+        &mut self,
+        _meta: &events::ConnectionMeta,
+        _info: &events::ConnectionInfo,
+    ) -> Self::ConnectionContext {
+    }
+
+    fn on_endpoint_connection_attempt_failed(
+        &mut self,
+        _meta: &events::EndpointMeta,
+        _event: &events::EndpointConnectionAttemptFailed,
+    ) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn application_protocols() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let server_tls = tls::default::Server::builder()
+            .with_certificate(certificates::CERT_PEM, certificates::KEY_PEM)?
+            .with_application_protocols(["h3", "backend-a"].iter())?
+            .build()?;
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(server_tls)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+        let addr = server.local_addr()?;
+
+        spawn(async move {
+            while let Some(conn) = server.accept().await {
+                // hold on to the connection until the client is done
+                spawn(async move {
+                    let _ = conn;
+                    delay(Duration::from_secs(1)).await;
+                });
+            }
+        });
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            // the endpoint only offers "h3" by default
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let conn = client.connect(connect).await.unwrap();
+            assert_eq!(&conn.application_protocol().unwrap()[..], b"h3");
+
+            let connect = Connect::new(addr)
+                .with_server_name("localhost")
+                .with_application_protocols([&b"backend-a"[..]]);
+            let conn = client.connect(connect).await.unwrap();
+            assert_eq!(&conn.application_protocol().unwrap()[..], b"backend-a");
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+#[test]
+fn handshake_timeout() {
+    let model = Model::default();
+    // the server never responds
+    model.set_drop_rate(1.0);
+
+    test(model, |handle| {
+        let addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let limits = Limits::new()
+                .with_max_handshake_duration(Duration::from_secs(60))
+                .unwrap();
+            let timeout = Duration::from_secs(2);
+
+            let start = io::now();
+            let connect = Connect::new(addr)
+                .with_server_name("localhost")
+                .with_limits(limits)
+                .with_handshake_timeout(timeout);
+            let err = client.connect(connect).await.unwrap_err();
+
+            assert!(
+                matches!(err, Error::MaxHandshakeDurationExceeded { .. }),
+                "{err:?}"
+            );
+            // the per-connection timeout takes precedence over the limits
+            assert_eq!(io::now() - start, timeout);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+// the rustls provider loads identities on its own
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn unsupported_identity() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let addr = server(handle)?;
+        let attempts_failed = AttemptsFailed::default();
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((tracing_events(), attempts_failed.clone()))?
+            .with_random(Random::with_seed(123))?
+            .start()?;
+
+        primary::spawn(async move {
+            // the endpoint's TLS provider uses a single config so it can't present a different
+            // identity for each connection
+            let connect = Connect::new(addr)
+                .with_server_name("localhost")
+                .with_client_identity(tls::ClientIdentity::new(
+                    certificates::MTLS_CLIENT_CERT,
+                    certificates::MTLS_CLIENT_KEY,
+                ));
+            let err = client.connect(connect).await.unwrap_err();
+            assert!(matches!(err, Error::InvalidConfiguration { .. }), "{err:?}");
+
+            // the failed attempt is published to the endpoint's subscriber
+            assert_eq!(attempts_failed.0.load(Ordering::Relaxed), 1);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
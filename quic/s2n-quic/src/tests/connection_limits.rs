@@ -2,8 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::*;
-use s2n_quic_core::connection::limits::{
-    ConnectionInfo, HandshakeInfo, Limiter, Limits, UpdatableLimits,
+use crate::{application, connection::Error};
+use s2n_quic_core::{
+    connection::limits::{ConnectionInfo, HandshakeInfo, Limiter, Limits, UpdatableLimits},
+    endpoint,
+    inet::{Dscp, SocketAddress},
 };
 
 #[test]
@@ -48,3 +51,141 @@ fn connection_limits() {
     })
     .unwrap();
 }
+
+#[test]
+fn dscp_marking() {
+    let recorder = io::TxRecorder::default();
+    let network_packets = recorder.get_packets();
+    let mut server_addr = None;
+
+    test((recorder, Model::default()), |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_limits(Limits::default().with_dscp(Dscp::EF)?)?
+            .start()?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_limits(Limits::default().with_dscp(Dscp::AF41)?)?
+            .start()?;
+
+        let addr = start_server(server)?;
+        server_addr = Some(addr);
+        start_client(client, addr, Data::new(10_000))?;
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let server_addr = SocketAddress::from(server_addr.unwrap());
+    let network_packets = network_packets.lock().unwrap();
+
+    let mut server_packets = 0;
+    let mut client_packets = 0;
+    for packet in network_packets.iter() {
+        if packet.path.local_address.0 == server_addr {
+            assert_eq!(packet.dscp, Dscp::EF);
+            server_packets += 1;
+        } else {
+            assert_eq!(packet.path.remote_address.0, server_addr);
+            assert_eq!(packet.dscp, Dscp::AF41);
+            client_packets += 1;
+        }
+    }
+
+    assert!(server_packets > 0);
+    assert!(client_packets > 0);
+}
+
+/// Asserts the server closed the connection with the quota error code
+fn assert_quota_error(error: Error) {
+    match error {
+        Error::Application {
+            error, initiator, ..
+        } => {
+            assert_eq!(error, application::Error::new(42).unwrap());
+            assert_eq!(initiator, endpoint::Location::Remote);
+        }
+        error => panic!("unexpected error: {error:?}"),
+    }
+}
+
+fn start_quota_server(handle: &io::Handle, limits: Limits) -> io::Result<SocketAddr> {
+    let limits = limits
+        .with_quota_error_code(application::Error::new(42).unwrap())
+        .unwrap();
+    let server = Server::builder()
+        .with_io(handle.builder().build()?)?
+        .with_tls(SERVER_CERTS)?
+        .with_event(tracing_events())?
+        .with_random(Random::with_seed(456))?
+        .with_limits(limits)?
+        .start()?;
+    start_server(server)
+}
+
+#[test]
+fn max_received_bytes_quota() {
+    let model = Model::default();
+    test(model, |handle| {
+        let limits = Limits::default().with_max_received_bytes(50_000).unwrap();
+        let addr = start_quota_server(handle, limits)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            // keep sending until the server closes the connection
+            while stream.send(Bytes::from_static(&[42; 1024])).await.is_ok() {}
+
+            let error = connection.open_bidirectional_stream().await.unwrap_err();
+            assert_quota_error(error);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+#[test]
+fn max_stream_lifetime_quota() {
+    let model = Model::default();
+    test(model, |handle| {
+        let limits = Limits::default()
+            .with_max_stream_lifetime(Duration::from_secs(5))
+            .unwrap();
+        let addr = start_quota_server(handle, limits)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            // open the stream without ever finishing it
+            stream.send(Bytes::from_static(&[42])).await.unwrap();
+
+            let start = io::now();
+            while let Ok(Some(_)) = stream.receive().await {}
+
+            // the stream should be open for at least the lifetime and less than the idle timeout
+            let elapsed = io::now() - start;
+            assert!(elapsed >= Duration::from_secs(5), "{elapsed:?}");
+            assert!(elapsed < Duration::from_secs(10), "{elapsed:?}");
+
+            let error = connection.open_bidirectional_stream().await.unwrap_err();
+            assert_quota_error(error);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
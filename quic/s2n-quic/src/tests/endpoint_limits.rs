@@ -0,0 +1,106 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::endpoint_limits::rate_limiter;
+
+#[derive(Clone, Default)]
+struct LimitedAttempts(Arc<Mutex<Vec<events::ConnectionAttemptOutcome>>>);
+
+impl events::Subscriber for LimitedAttempts {
+    type ConnectionContext = ();
+
+    fn create_connection_context(
+        &mut self,
+        _meta: &events::ConnectionMeta,
+        _info: &events::ConnectionInfo,
+    ) -> Self::ConnectionContext {
+    }
+
+    fn on_endpoint_connection_attempt_limited(
+        &mut self,
+        _meta: &events::EndpointMeta,
+        event: &events::EndpointConnectionAttemptLimited,
+    ) {
+        self.0.lock().unwrap().push(event.outcome.clone());
+    }
+}
+
+#[test]
+fn rate_limited_source_is_retried() {
+    let model = Model::default();
+    let limited = LimitedAttempts::default();
+
+    test(model, |handle| {
+        let limits = rate_limiter::Limits::builder()
+            .with_source_rate(0, 1)?
+            .build()?;
+
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((tracing_events(), limited.clone()))?
+            .with_random(Random::with_seed(456))?
+            .with_endpoint_limits(limits)?
+            .start()?;
+        let addr = start_server(server)?;
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            // the second connection exceeds the rate limit but still succeeds after a Retry
+            for _ in 0..2 {
+                let connect = Connect::new(addr).with_server_name("localhost");
+                let mut connection = client.connect(connect).await.unwrap();
+                connection.ping().unwrap();
+            }
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let outcomes = limited.0.lock().unwrap();
+    assert_eq!(outcomes.len(), 1);
+    assert!(matches!(
+        outcomes[0],
+        events::ConnectionAttemptOutcome::Retry { .. }
+    ));
+}
+
+#[test]
+fn denied_source_is_dropped() {
+    let model = Model::default();
+    let limited = LimitedAttempts::default();
+
+    test(model, |handle| {
+        let limits = rate_limiter::Limits::builder()
+            .with_deny("0.0.0.0/0".parse()?)?
+            .build()?;
+
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((tracing_events(), limited.clone()))?
+            .with_random(Random::with_seed(456))?
+            .with_endpoint_limits(limits)?
+            .start()?;
+        let addr = start_server(server)?;
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            let connect = Connect::new(addr)
+                .with_server_name("localhost")
+                .with_handshake_timeout(Duration::from_secs(1));
+            client.connect(connect).await.unwrap_err();
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let outcomes = limited.0.lock().unwrap();
+    assert!(!outcomes.is_empty());
+    assert!(outcomes
+        .iter()
+        .all(|outcome| matches!(outcome, events::ConnectionAttemptOutcome::Drop { .. })));
+}
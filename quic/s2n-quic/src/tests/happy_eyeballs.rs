@@ -0,0 +1,182 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::{
+    client::ConnectHostError,
+    connection,
+    provider::{
+        io::testing::{Handle, Result},
+        resolver,
+    },
+};
+use s2n_quic_core::endpoint;
+use std::net::IpAddr;
+
+/// An address which never responds to connection attempts
+const UNREACHABLE_V6: &str = "2001:db8::1";
+
+#[derive(Clone, Default)]
+struct Recorder {
+    winners: Arc<Mutex<Vec<(events::AddressFamily, u32)>>>,
+    closed: Arc<Mutex<Vec<(u64, connection::Error)>>>,
+}
+
+impl events::Subscriber for Recorder {
+    type ConnectionContext = ();
+
+    fn create_connection_context(
+        &mut self,
+        _meta: &events::ConnectionMeta,
+        _info: &events::ConnectionInfo,
+    ) -> Self::ConnectionContext {
+    }
+
+    fn on_happy_eyeballs_winner(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &events::ConnectionMeta,
+        event: &events::HappyEyeballsWinner,
+    ) {
+        self.winners
+            .lock()
+            .unwrap()
+            .push((event.address_family.clone(), event.attempts));
+    }
+
+    fn on_connection_closed(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        meta: &events::ConnectionMeta,
+        event: &events::ConnectionClosed,
+    ) {
+        self.closed.lock().unwrap().push((meta.id, event.error));
+    }
+}
+
+fn build_client(
+    handle: &Handle,
+    resolver: resolver::Static,
+    recorder: &Recorder,
+) -> Result<Client> {
+    Ok(Client::builder()
+        .with_io(handle.builder().build().unwrap())?
+        .with_tls(certificates::CERT_PEM)?
+        .with_event((tracing_events(), recorder.clone()))?
+        .with_random(Random::with_seed(123))?
+        .with_resolver(resolver)?
+        .start()?)
+}
+
+#[test]
+fn single_address() {
+    let model = Model::default();
+    let recorder = Recorder::default();
+
+    test(model, |handle| {
+        let addr = server(handle)?;
+        let resolver = resolver::Static::default().with_host("localhost", [addr.ip()]);
+        let client = build_client(handle, resolver, &recorder)?;
+
+        primary::spawn(async move {
+            let start = io::now();
+            let mut connection = client.connect_host("localhost", addr.port()).await.unwrap();
+            assert_eq!(connection.remote_addr().unwrap(), addr);
+            connection.ping().unwrap();
+
+            // the attempt isn't delayed when there's nothing to race against
+            assert!(io::now() - start < Duration::from_millis(250));
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let winners = recorder.winners.lock().unwrap();
+    assert!(matches!(
+        winners[..],
+        [(events::AddressFamily::IpV4 { .. }, 1)]
+    ));
+}
+
+#[test]
+fn broken_ipv6_falls_back_to_ipv4() {
+    let model = Model::default();
+    let recorder = Recorder::default();
+
+    test(model, |handle| {
+        let addr = server(handle)?;
+        let v6: IpAddr = UNREACHABLE_V6.parse()?;
+        let resolver = resolver::Static::default().with_host("localhost", [addr.ip(), v6]);
+        let client = build_client(handle, resolver, &recorder)?;
+
+        primary::spawn(async move {
+            let start = io::now();
+            let mut connection = client.connect_host("localhost", addr.port()).await.unwrap();
+            assert_eq!(connection.remote_addr().unwrap(), addr);
+            connection.ping().unwrap();
+
+            // the IPv6 address is tried first so the IPv4 attempt waits for the attempt delay
+            assert!(io::now() - start >= Duration::from_millis(250));
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let winners = recorder.winners.lock().unwrap();
+    assert!(matches!(
+        winners[..],
+        [(events::AddressFamily::IpV4 { .. }, 2)]
+    ));
+
+    // the losing IPv6 attempt is closed as soon as the race is over instead of waiting for its
+    // handshake to time out
+    let closed = recorder.closed.lock().unwrap();
+    assert!(
+        closed.iter().any(|(id, error)| *id == 0
+            && matches!(
+                error,
+                connection::Error::Closed {
+                    initiator: endpoint::Location::Local,
+                    ..
+                }
+            )),
+        "{closed:?}"
+    );
+}
+
+#[test]
+fn unreachable_host() {
+    let model = Model::default();
+    let recorder = Recorder::default();
+
+    test(model, |handle| {
+        let addr = server(handle)?;
+        let v6: IpAddr = UNREACHABLE_V6.parse()?;
+        let resolver = resolver::Static::default().with_host("localhost", [v6]);
+        let client = build_client(handle, resolver, &recorder)?;
+
+        primary::spawn(async move {
+            let error = client
+                .connect_host("unknown.example", addr.port())
+                .await
+                .unwrap_err();
+            assert!(matches!(error, ConnectHostError::Resolve(_)), "{error:?}");
+
+            let error = client
+                .connect_host("localhost", addr.port())
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(error, ConnectHostError::Connection(_)),
+                "{error:?}"
+            );
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    assert!(recorder.winners.lock().unwrap().is_empty());
+}
@@ -153,3 +153,129 @@ fn mtls_auth_failure() {
     // confirm server connection was attempted but failed
     assert!(server_connection_closed.load(Ordering::SeqCst));
 }
+
+#[cfg(feature = "s2n-quic-tls")]
+#[test]
+fn mtls_per_connection_identity() {
+    use crate::provider::tls::{self, s2n_tls::ConnectionContext};
+
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+
+    test(model, |handle| {
+        let server_tls = build_server_mtls_provider(certificates::MTLS_CA_CERT)?;
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(server_tls)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        let addr = server.local_addr()?;
+        spawn(async move {
+            let mut conn = server.accept().await.unwrap();
+            let mut stream = conn.open_bidirectional_stream().await.unwrap();
+            stream.send(vec![42; 1000].into()).await.unwrap();
+            stream.flush().await.unwrap();
+        });
+
+        // the endpoint doesn't have an identity so it's only loaded for connections that ask
+        // for one
+        let client_tls = tls::s2n_tls::Client::from_loader(|cx: ConnectionContext| {
+            let mut config = tls::s2n_tls::config::Builder::new();
+            config.enable_quic().unwrap();
+            config
+                .set_security_policy(&tls::s2n_tls::security::DEFAULT_TLS13)
+                .unwrap();
+            config.set_application_protocol_preference([b"h3"]).unwrap();
+            config
+                .trust_pem(certificates::MTLS_CA_CERT.as_bytes())
+                .unwrap();
+            if let Some(identity) = cx.client_identity {
+                config
+                    .load_pem(&identity.certificate_chain, &identity.private_key)
+                    .unwrap();
+            }
+            config.build().unwrap()
+        });
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(client_tls)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        primary::spawn(async move {
+            let identity = tls::ClientIdentity::new(
+                certificates::MTLS_CLIENT_CERT,
+                certificates::MTLS_CLIENT_KEY,
+            );
+            let connect = Connect::new(addr)
+                .with_server_name("localhost")
+                .with_client_identity(identity);
+            let mut conn = client.connect(connect).await.unwrap();
+            let mut stream = conn.accept_bidirectional_stream().await.unwrap().unwrap();
+
+            let mut recv_len = 0;
+            while let Some(chunk) = stream.receive().await.unwrap() {
+                recv_len += chunk.len();
+            }
+            assert_eq!(recv_len, 1000);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+#[cfg(feature = "s2n-quic-rustls")]
+#[test]
+fn rustls_mtls_happy_case() {
+    use crate::provider::tls::rustls;
+
+    let model = Model::default();
+
+    test(model, |handle| {
+        let server_tls = rustls::Server::builder()
+            .with_certificate(
+                certificates::MTLS_SERVER_CERT,
+                certificates::MTLS_SERVER_KEY,
+            )
+            .unwrap()
+            .with_client_authentication()
+            .unwrap()
+            .with_trusted_certificate(certificates::MTLS_CA_CERT)
+            .unwrap()
+            .build()
+            .unwrap();
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(server_tls)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        let client_tls = rustls::Client::builder()
+            .with_certificate(certificates::MTLS_CA_CERT)
+            .unwrap()
+            .with_client_identity(
+                certificates::MTLS_CLIENT_CERT,
+                certificates::MTLS_CLIENT_KEY,
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(client_tls)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1000))?;
+
+        Ok(addr)
+    })
+    .unwrap();
+}
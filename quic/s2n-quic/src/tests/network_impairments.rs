@@ -0,0 +1,197 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::io::testing::Aqm;
+
+const LEN: u64 = 1_000_000;
+
+/// Echoes `len` bytes through a server which is subscribed to `server_events`
+fn transfer<S>(model: Model, len: u64, server_events: S)
+where
+    S: 'static + Send + events::Subscriber,
+{
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((tracing_events(), server_events))?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        let addr = start_server(server)?;
+        start_client(build_client(handle)?, addr, Data::new(len))?;
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+fn ecn_congestion_count(recorder: &recorder::Congestion) -> usize {
+    recorder
+        .events()
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|source| matches!(source, events::CongestionSource::Ecn { .. }))
+        .count()
+}
+
+#[test]
+fn reorder_and_duplicate_test() {
+    let model = Model::default();
+    model
+        .set_delay(Duration::from_millis(50))
+        .set_reorder_rate(0.2)
+        .set_reorder_delay(Duration::from_millis(20))
+        .set_duplicate_rate(0.1);
+
+    let duplicates = recorder::DuplicatePacket::new();
+    transfer(model, LEN, duplicates.clone());
+
+    assert!(!duplicates.events().lock().unwrap().is_empty());
+}
+
+#[test]
+fn asymmetric_link_test() {
+    let delay = Duration::from_millis(10);
+    let uplink_delay = Duration::from_millis(100);
+
+    let model = Model::default();
+    model
+        .set_delay(delay)
+        .set_uplink_delay(uplink_delay)
+        .set_downlink_drop_rate(0.05);
+
+    test(model, |handle| {
+        let addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            stream.send(Bytes::from_static(b"hello")).await.unwrap();
+            stream.finish().unwrap();
+            while stream.receive().await.unwrap().is_some() {}
+
+            // only the packets sent to the server take the longer link
+            let rtt = connection.stats().unwrap().path.rtt;
+            assert!(rtt.min > delay * 2 + uplink_delay / 2, "{rtt:?}");
+            assert!(rtt.min < (delay + uplink_delay) * 2, "{rtt:?}");
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+#[test]
+fn nat_rebind_test() {
+    let rebind_interval = Duration::from_millis(300);
+
+    let model = Model::default();
+    model
+        .set_delay(Duration::from_millis(50))
+        .set_nat_rebind_interval(rebind_interval);
+
+    let active_paths = recorder::ActivePathUpdated::new();
+    let active_path_sub = active_paths.clone();
+
+    test(model.clone(), |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event((tracing_events(), active_path_sub))?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        let addr = start_server(server)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut conn = client.connect(connect).await.unwrap();
+            let stream = conn.open_bidirectional_stream().await.unwrap();
+            let (mut recv, mut send) = stream.split();
+
+            // the server only learns about the new mapping when the client sends, so keep
+            // sending across a few rebinds while staying within the number of paths a
+            // connection tracks
+            let mut data = Data::new(100_000);
+            let chunk_delay = rebind_interval / 5;
+            while let Some(chunk) = data.send_one(10_000) {
+                send.send(chunk).await.unwrap();
+                delay(chunk_delay).await;
+            }
+            send.finish().unwrap();
+
+            // a client that's only receiving can't tell the server about a new mapping
+            model.set_nat_rebind_interval(Duration::ZERO);
+
+            let mut echo = Data::new(100_000);
+            while let Some(chunk) = recv.receive().await.unwrap() {
+                echo.receive(&[chunk]);
+            }
+            assert!(echo.is_finished());
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    // the server follows the client to each new port
+    let active_paths = active_paths.events();
+    let active_paths = active_paths.lock().unwrap();
+    assert!(active_paths.len() >= 2, "{active_paths:?}");
+    assert!(active_paths.windows(2).all(|paths| paths[0] != paths[1]));
+}
+
+#[test]
+fn ce_threshold_test() {
+    let model = Model::default();
+    model
+        .set_delay(Duration::from_millis(50))
+        .set_ce_threshold(20);
+
+    let congestion = recorder::Congestion::new();
+    transfer(model, LEN, congestion.clone());
+
+    assert!(ecn_congestion_count(&congestion) > 0);
+}
+
+#[test]
+fn red_test() {
+    let model = Model::default();
+    model
+        .set_delay(Duration::from_millis(50))
+        .set_max_inflight(200)
+        .set_aqm(Aqm::Red {
+            min_threshold: 20,
+            max_threshold: 100,
+            max_probability: 0.1,
+        });
+
+    let congestion = recorder::Congestion::new();
+    transfer(model, LEN, congestion.clone());
+
+    assert!(ecn_congestion_count(&congestion) > 0);
+}
+
+#[test]
+fn codel_test() {
+    let model = Model::default();
+    model
+        .set_delay(Duration::from_millis(50))
+        .set_inflight_delay(Duration::from_millis(1))
+        .set_inflight_delay_threshold(10)
+        .set_aqm(Aqm::CoDel {
+            target: Duration::from_millis(5),
+            interval: Duration::from_millis(100),
+        });
+
+    let congestion = recorder::Congestion::new();
+    transfer(model, LEN, congestion.clone());
+
+    assert!(ecn_congestion_count(&congestion) > 0);
+}
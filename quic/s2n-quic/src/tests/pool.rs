@@ -0,0 +1,143 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::{
+    application,
+    client::pool::{Key, Pool},
+    provider::limits,
+    stream::BidirectionalStream,
+};
+
+/// Sends a request on the stream and waits for the server to echo it back
+async fn echo(stream: &mut BidirectionalStream) {
+    stream.send(Bytes::from_static(b"hello")).await.unwrap();
+    stream.finish().unwrap();
+    while stream.receive().await.unwrap().is_some() {}
+}
+
+#[test]
+fn reuses_connections() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let addr = server(handle)?;
+        let pool = Pool::new(build_client(handle)?);
+
+        primary::spawn(async move {
+            let key = Key::new(addr).with_server_name("localhost");
+
+            let mut ids = vec![];
+            for _ in 0..3 {
+                let mut stream = pool.open_bidirectional_stream(&key).await.unwrap();
+                ids.push(stream.connection().id());
+                echo(&mut stream).await;
+            }
+
+            assert!(ids.iter().all(|id| *id == ids[0]), "{ids:?}");
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+#[test]
+fn stream_limit_opens_new_connection() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let limits = limits::Limits::new().with_max_open_remote_bidirectional_streams(1)?;
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_limits(limits)?
+            .start()?;
+        let addr = start_server(server)?;
+        let pool = Pool::new(build_client(handle)?).with_max_connections(2);
+
+        primary::spawn(async move {
+            let key = Key::new(addr).with_server_name("localhost");
+
+            // the server only allows a single stream per connection
+            let mut first = pool.open_bidirectional_stream(&key).await.unwrap();
+            let second = pool.open_bidirectional_stream(&key).await.unwrap();
+            let first_id = first.connection().id();
+            assert_ne!(first_id, second.connection().id());
+
+            // both connections are at their limit so the next stream waits for one to free up
+            let third = primary::spawn({
+                let pool = pool.clone();
+                let key = key.clone();
+                async move { pool.open_bidirectional_stream(&key).await.unwrap() }
+            });
+
+            delay(Duration::from_millis(100)).await;
+            echo(&mut first).await;
+            drop(first);
+
+            let third = third.await.unwrap();
+            assert_eq!(third.connection().id(), first_id);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+#[test]
+fn closed_connection_is_replaced() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let mut server = build_server(handle)?;
+        let addr = server.local_addr()?;
+
+        // close each connection once its first stream has been echoed back
+        spawn(async move {
+            while let Some(mut connection) = server.accept().await {
+                spawn(async move {
+                    let mut stream = connection
+                        .accept_bidirectional_stream()
+                        .await
+                        .unwrap()
+                        .unwrap();
+                    while let Some(chunk) = stream.receive().await.unwrap() {
+                        stream.send(chunk).await.unwrap();
+                    }
+                    stream.close().await.unwrap();
+                    connection.close(application::Error::new(1).unwrap());
+                });
+            }
+        });
+
+        let pool = Pool::new(build_client(handle)?);
+
+        primary::spawn(async move {
+            let key = Key::new(addr).with_server_name("localhost");
+
+            let mut stream = pool.open_bidirectional_stream(&key).await.unwrap();
+            let first_id = stream.connection().id();
+            echo(&mut stream).await;
+
+            // wait for the CONNECTION_CLOSE to arrive
+            delay(Duration::from_secs(1)).await;
+
+            // the closed connection is replaced when opening the next stream
+            let mut stream = pool.open_bidirectional_stream(&key).await.unwrap();
+            assert_ne!(stream.connection().id(), first_id);
+            echo(&mut stream).await;
+
+            delay(Duration::from_secs(1)).await;
+
+            // the health check finds the second closed connection
+            assert_eq!(pool.health_check().await, 1);
+            assert_eq!(pool.health_check().await, 0);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
@@ -0,0 +1,73 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+/// Records the key passed to the verifier and accepts it
+fn recorder(key: &Arc<Mutex<Option<Vec<u8>>>>) -> impl Fn(&[u8]) -> bool + Send + Sync {
+    let key = key.clone();
+    move |public_key| {
+        *key.lock().unwrap() = Some(public_key.to_vec());
+        true
+    }
+}
+
+#[test]
+fn rustls_raw_public_key() {
+    use crate::provider::tls::rustls;
+
+    let model = Model::default();
+    let client_key = Arc::new(Mutex::new(None));
+    let server_key = Arc::new(Mutex::new(None));
+
+    test(model, |handle| {
+        let server_tls = rustls::Server::builder()
+            .with_raw_public_key(certificates::KEY_PEM)
+            .unwrap()
+            .with_client_raw_public_key_verifier(recorder(&client_key))
+            .unwrap()
+            .build()
+            .unwrap();
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(server_tls)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+        let addr = server.local_addr()?;
+
+        let client_tls = rustls::Client::builder()
+            .with_raw_public_key_verifier(recorder(&server_key))
+            .unwrap()
+            .with_client_raw_public_key(certificates::MTLS_CLIENT_KEY)
+            .unwrap()
+            .build()
+            .unwrap();
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(client_tls)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .start()?;
+
+        let client_key = client_key.clone();
+        primary::spawn(async move {
+            let connection = server.accept().await.unwrap();
+            let public_key = connection.peer_public_key().unwrap();
+            assert!(public_key.is_some());
+            assert_eq!(public_key.as_deref(), client_key.lock().unwrap().as_deref());
+        });
+
+        let server_key = server_key.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+            let public_key = connection.peer_public_key().unwrap();
+            assert!(public_key.is_some());
+            assert_eq!(public_key.as_deref(), server_key.lock().unwrap().as_deref());
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
@@ -175,6 +175,20 @@ event_recorder!(
     }
 );
 
+event_recorder!(
+    Congestion,
+    Congestion,
+    on_congestion,
+    events::CongestionSource,
+    |event: &events::Congestion, storage: &mut Vec<events::CongestionSource>| {
+        storage.push(event.source.clone());
+    }
+);
+
+event_recorder!(DuplicatePacket, DuplicatePacket, on_duplicate_packet, (), {
+    |_event: &events::DuplicatePacket, storage: &mut Vec<()>| storage.push(())
+});
+
 event_recorder!(
     ConnectionStarted,
     ConnectionStarted,
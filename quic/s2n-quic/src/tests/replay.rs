@@ -0,0 +1,92 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::provider::io::testing::{replay, Result};
+use s2n_quic_platform::io::record::{Datagram, Direction, Recording};
+
+/// Records the first datagram that the client sends to the server
+fn record_client_initial() -> Recording {
+    let recorder = io::TxRecorder::default();
+    let network_packets = recorder.get_packets();
+    let mut server_addr = None;
+
+    test((recorder, Model::default()), |handle| {
+        let addr = server(handle)?;
+        server_addr = Some(addr);
+        client(handle, addr)?;
+        Ok(addr)
+    })
+    .unwrap();
+
+    let server_addr = server_addr.unwrap();
+    let network_packets = network_packets.lock().unwrap();
+    let packet = network_packets
+        .iter()
+        .find(|packet| SocketAddr::from(packet.path.remote_address.0) == server_addr)
+        .unwrap();
+
+    vec![Datagram {
+        direction: Direction::Rx,
+        time: Duration::from_millis(100),
+        ecn: packet.ecn,
+        local_address: packet.path.remote_address.0,
+        remote_address: packet.path.local_address.0,
+        payload: packet.payload.clone(),
+    }]
+    .into()
+}
+
+fn replay(recording: Recording) -> Vec<Datagram> {
+    let mut transmissions = None;
+
+    test(Model::default(), |handle| {
+        let io = replay::Io::new(handle, recording);
+        transmissions = Some(io.transmissions());
+
+        let server = build_server_with_io(io)?;
+        start_server(server)
+    })
+    .unwrap();
+
+    let transmissions = transmissions.unwrap();
+    let transmissions = transmissions.lock().unwrap();
+    transmissions.clone()
+}
+
+fn build_server_with_io(io: replay::Io) -> Result<Server> {
+    Ok(Server::builder()
+        .with_io(io)?
+        .with_tls(SERVER_CERTS)?
+        .with_event(tracing_events())?
+        .with_random(Random::with_seed(123))?
+        .start()?)
+}
+
+#[test]
+fn replay_client_initial_test() {
+    let recording = record_client_initial();
+    let client_initial = recording.datagrams()[0].clone();
+
+    let transmissions = replay(recording.clone());
+
+    // the server should respond to the client's Initial packet
+    assert!(!transmissions.is_empty());
+    for datagram in &transmissions {
+        assert_eq!(datagram.direction, Direction::Tx);
+        assert_eq!(datagram.remote_address, client_initial.remote_address);
+        assert_eq!(datagram.local_address, client_initial.local_address);
+        // nothing is sent before the Initial packet was replayed
+        assert!(datagram.time >= client_initial.time);
+    }
+    // the first response is a long header packet
+    assert_ne!(transmissions[0].payload[0] & 0x80, 0);
+
+    // replaying the same recording produces the same transmissions
+    let other = replay(recording);
+    assert_eq!(transmissions.len(), other.len());
+    for (a, b) in transmissions.iter().zip(&other) {
+        assert_eq!(a.time, b.time);
+        assert_eq!(a.payload.len(), b.payload.len());
+    }
+}
@@ -58,3 +58,148 @@ fn resumption_handshake() {
     })
     .unwrap();
 }
+
+/// Tests resuming a session with a ticket provided for a single connection
+#[cfg(feature = "s2n-quic-tls")]
+#[test]
+fn resumption_with_connect_ticket() {
+    use super::*;
+
+    let model = Model::default();
+    let handler = SessionTicketHandler::default();
+
+    test(model, |handle| {
+        let server_tls =
+            build_server_resumption_provider(certificates::CERT_PEM, certificates::KEY_PEM)?;
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(server_tls)?
+            .start()?;
+
+        let client_tls = build_client_resumption_provider(certificates::CERT_PEM, &handler)?;
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(client_tls)?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1000))?;
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    let ticket = handler.take_ticket().unwrap();
+
+    // The client endpoint doesn't set any tickets on its own so the resumption only succeeds with
+    // the ticket from the `Connect`
+    let model = Model::default();
+    test(model, |handle| {
+        let mut client_tls =
+            provider::tls::s2n_tls::Client::builder().with_certificate(certificates::CERT_PEM)?;
+        client_tls.config_mut().enable_session_tickets(true)?;
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(client_tls.build()?)?
+            .start()?;
+
+        let server_tls = build_server_resumption_provider(
+            certificates::UNTRUSTED_CERT_PEM,
+            certificates::UNTRUSTED_KEY_PEM,
+        )?;
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(server_tls)?
+            .start()?;
+        let addr = start_server(server)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr)
+                .with_server_name("localhost")
+                .with_session_ticket(ticket);
+            let mut conn = client.connect(connect).await.unwrap();
+            let mut stream = conn.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"hello")).await.unwrap();
+            stream.finish().unwrap();
+            assert_eq!(
+                stream.receive().await.unwrap(),
+                Some(Bytes::from_static(b"hello"))
+            );
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+/// Tests resumption handshake with the rustls provider
+#[cfg(feature = "s2n-quic-rustls")]
+#[test]
+fn rustls_resumption_handshake() {
+    use super::*;
+    use provider::tls::rustls;
+
+    // The client caches sessions in memory so the same provider is used for both handshakes
+    let client_tls = rustls::Client::builder()
+        .with_certificate(certificates::CERT_PEM)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // Both servers share the ticket keys so either can resume the other's sessions
+    #[allow(deprecated)]
+    let ticketer = rustls::rustls::crypto::aws_lc_rs::Ticketer::new().unwrap();
+    let build_server_tls = |cert: &str, key: &str| {
+        #[allow(deprecated)]
+        rustls::Server::builder()
+            .with_certificate(cert, key)
+            .unwrap()
+            .with_ticketer(ticketer.clone())
+            .unwrap()
+            .build()
+            .unwrap()
+    };
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(build_server_tls(
+                certificates::CERT_PEM,
+                certificates::KEY_PEM,
+            ))?
+            .start()?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(client_tls.clone())?
+            .start()?;
+        let addr = start_server(server)?;
+        start_client(client, addr, Data::new(1000))?;
+
+        Ok(addr)
+    })
+    .unwrap();
+
+    // The second handshake only succeeds if the session is resumed, since the client doesn't trust
+    // the server's certificate
+    let model = Model::default();
+    test(model, |handle| {
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(client_tls.clone())?
+            .start()?;
+
+        let server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(build_server_tls(
+                certificates::UNTRUSTED_CERT_PEM,
+                certificates::UNTRUSTED_KEY_PEM,
+            ))?
+            .start()?;
+        let addr = start_server(server)?;
+
+        start_client(client, addr, Data::new(1000))?;
+        Ok(addr)
+    })
+    .unwrap();
+}
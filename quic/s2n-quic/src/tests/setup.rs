@@ -5,7 +5,7 @@ use crate::{
     client::Connect,
     provider::{
         event,
-        io::testing::{primary, spawn, Handle, Result},
+        io::testing::{primary, spawn, tls_client, tls_server, Handle, Result},
     },
     stream::PeerStream,
     Client, Server,
@@ -99,7 +99,7 @@ pub fn server(handle: &Handle) -> Result<SocketAddr> {
 pub fn build_server(handle: &Handle) -> Result<Server> {
     Ok(Server::builder()
         .with_io(handle.builder().build().unwrap())?
-        .with_tls(SERVER_CERTS)?
+        .with_tls(tls_server(SERVER_CERTS.0, SERVER_CERTS.1)?)?
         .with_event(tracing_events())?
         .with_random(Random::with_seed(123))?
         .start()?)
@@ -144,7 +144,7 @@ pub fn start_client(client: Client, server_addr: SocketAddr, data: Data) -> Resu
 pub fn build_client(handle: &Handle) -> Result<Client> {
     Ok(Client::builder()
         .with_io(handle.builder().build().unwrap())?
-        .with_tls(certificates::CERT_PEM)?
+        .with_tls(tls_client(certificates::CERT_PEM)?)?
         .with_event(tracing_events())?
         .with_random(Random::with_seed(123))?
         .start()?)
@@ -289,6 +289,13 @@ mod resumption {
         ticket_storage: Arc<Mutex<VecDeque<Vec<u8>>>>,
     }
 
+    impl SessionTicketHandler {
+        /// Removes the most recently received ticket
+        pub fn take_ticket(&self) -> Option<Vec<u8>> {
+            self.ticket_storage.lock().unwrap().pop_back()
+        }
+    }
+
     impl SessionTicketCallback for SessionTicketHandler {
         fn on_session_ticket(&self, _connection: &mut Connection, session_ticket: &SessionTicket) {
             let size = session_ticket.len().unwrap();
@@ -0,0 +1,300 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::{application, connection::Error};
+use core::sync::atomic::{AtomicUsize, Ordering};
+use futures::FutureExt;
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+/// Returns the application error the peer closed the connection with
+fn peer_close_error(error: Error) -> application::Error {
+    match error {
+        Error::Application { error, .. } => error,
+        error => panic!("unexpected error: {error:?}"),
+    }
+}
+
+#[test]
+fn idle_connections() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let server = build_server(handle)?;
+        let error = application::Error::new(42).unwrap();
+        let shutdown = server.shutdown_with_error(DEADLINE, error);
+        let addr = start_server(server)?;
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            let mut connections = vec![];
+            for _ in 0..2 {
+                let connect = Connect::new(addr).with_server_name("localhost");
+                connections.push(client.connect(connect).await.unwrap());
+            }
+
+            let start = io::now();
+            let summary = shutdown.await;
+
+            // idle connections are closed without waiting for the deadline
+            assert!(io::now() - start < DEADLINE);
+            assert_eq!(summary.idle, 2);
+            assert_eq!(summary.finished, 0);
+            assert_eq!(summary.forced, 0);
+
+            for mut connection in connections {
+                let err = connection.accept().await.unwrap_err();
+                assert_eq!(peer_close_error(err), error);
+            }
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+#[test]
+fn busy_connections() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let mut server = build_server(handle)?;
+        let addr = server.local_addr()?;
+        let signal = server.shutdown_signal();
+        let shutdown = server.shutdown(DEADLINE);
+
+        spawn(async move {
+            while let Some(mut connection) = server.accept().await {
+                let signal = signal.clone();
+                spawn(async move {
+                    let mut stream = connection
+                        .accept_bidirectional_stream()
+                        .await
+                        .unwrap()
+                        .unwrap();
+
+                    // keep the stream open until the server starts shutting down
+                    signal.await;
+
+                    stream.send(Bytes::from_static(b"goodbye")).await.unwrap();
+                    stream.close().await.unwrap();
+                    connection.close(application::Error::new(1).unwrap());
+                });
+            }
+        });
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"hello")).await.unwrap();
+
+            let summary = primary::spawn(shutdown);
+
+            // the response is only sent once the shutdown starts
+            let chunk = stream.receive().await.unwrap().unwrap();
+            assert_eq!(&chunk[..], b"goodbye");
+
+            // new connections are rejected while the server is shutting down
+            let connect = Connect::new(addr)
+                .with_server_name("localhost")
+                .with_handshake_timeout(Duration::from_secs(1));
+            client.connect(connect).await.unwrap_err();
+
+            let summary = summary.await.unwrap();
+            assert_eq!(summary.idle, 0);
+            assert_eq!(summary.finished, 1);
+            assert_eq!(summary.forced, 0);
+            assert!(summary.rejected > 0);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+#[test]
+fn forced_connections() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let mut server = build_server(handle)?;
+        let addr = server.local_addr()?;
+        let shutdown = server.shutdown(DEADLINE);
+
+        spawn(async move {
+            while let Some(mut connection) = server.accept().await {
+                spawn(async move {
+                    // accept the stream but never respond
+                    let stream = connection.accept_bidirectional_stream().await;
+                    delay(DEADLINE * 2).await;
+                    drop(stream);
+                });
+            }
+        });
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"hello")).await.unwrap();
+
+            // give the server a chance to accept the stream
+            delay(Duration::from_millis(100)).await;
+
+            let start = io::now();
+            let summary = shutdown.await;
+
+            assert!(io::now() - start >= DEADLINE);
+            assert_eq!(summary.idle, 0);
+            assert_eq!(summary.finished, 0);
+            assert_eq!(summary.forced, 1);
+
+            let err = stream.receive().await.unwrap_err();
+            assert!(
+                matches!(err, crate::stream::Error::ConnectionError { .. }),
+                "{err:?}"
+            );
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+#[test]
+fn client_shutdown() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let signal = client.shutdown_signal();
+            assert!(!signal.is_shutting_down());
+
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            let summary = primary::spawn(client.shutdown(DEADLINE));
+            signal.clone().await;
+            assert!(signal.is_shutting_down());
+
+            // connection attempts fail while the client is shutting down
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let err = client.connect(connect).await.unwrap_err();
+            assert!(matches!(err, Error::EndpointClosing { .. }), "{err:?}");
+
+            // finish the request so the connection becomes idle
+            stream.send(Bytes::from_static(b"hello")).await.unwrap();
+            stream.finish().unwrap();
+            while stream.receive().await.unwrap().is_some() {}
+            drop(stream);
+
+            let summary = summary.await.unwrap();
+            assert_eq!(summary.idle, 1);
+            assert_eq!(summary.forced, 0);
+            assert_eq!(summary.rejected, 1);
+
+            // the endpoint has closed so the signal resolves immediately
+            assert!(signal.now_or_never().is_some());
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+/// Sends every new connection through a Retry and counts the attempts
+#[derive(Clone, Default)]
+struct AlwaysRetry(Arc<AtomicUsize>);
+
+impl crate::provider::endpoint_limits::Limiter for AlwaysRetry {
+    fn on_connection_attempt(
+        &mut self,
+        _info: &crate::provider::endpoint_limits::ConnectionAttempt<'_>,
+    ) -> crate::provider::endpoint_limits::Outcome {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        crate::provider::endpoint_limits::Outcome::retry()
+    }
+}
+
+#[test]
+fn retried_connections() {
+    let model = Model::default();
+
+    test(model, |handle| {
+        let attempts = AlwaysRetry::default();
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_event(tracing_events())?
+            .with_random(Random::with_seed(456))?
+            .with_endpoint_limits(attempts.clone())?
+            .start()?;
+        let addr = server.local_addr()?;
+        let signal = server.shutdown_signal();
+        let shutdown = server.shutdown(DEADLINE);
+
+        spawn(async move {
+            while let Some(mut connection) = server.accept().await {
+                let signal = signal.clone();
+                spawn(async move {
+                    let mut stream = connection
+                        .accept_bidirectional_stream()
+                        .await
+                        .unwrap()
+                        .unwrap();
+
+                    // keep the endpoint busy until well after the Retry round trip completes
+                    signal.await;
+                    delay(Duration::from_secs(1)).await;
+
+                    stream.send(Bytes::from_static(b"goodbye")).await.unwrap();
+                    stream.close().await.unwrap();
+                    connection.close(application::Error::new(1).unwrap());
+                });
+            }
+        });
+
+        let client = build_client(handle)?;
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"hello")).await.unwrap();
+            assert_eq!(attempts.0.load(Ordering::Relaxed), 1);
+
+            let connect = Connect::new(addr)
+                .with_server_name("localhost")
+                .with_handshake_timeout(Duration::from_secs(1));
+            let retried = primary::spawn(async move { client.connect(connect).await });
+
+            // start shutting down after the server sent the Retry but before the client's
+            // Initial with the Retry token arrives
+            while attempts.0.load(Ordering::Relaxed) < 2 {
+                delay(Duration::from_millis(1)).await;
+            }
+            let summary = primary::spawn(shutdown);
+
+            retried.await.unwrap().unwrap_err();
+
+            let chunk = stream.receive().await.unwrap().unwrap();
+            assert_eq!(&chunk[..], b"goodbye");
+
+            let summary = summary.await.unwrap();
+            assert_eq!(summary.finished, 1);
+            assert!(summary.rejected > 0);
+            // the Initial with the token doesn't go through the endpoint limits again
+            assert_eq!(attempts.0.load(Ordering::Relaxed), 2);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
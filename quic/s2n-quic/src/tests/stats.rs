@@ -0,0 +1,102 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+const LEN: usize = 100_000;
+
+#[test]
+fn active_connection() {
+    let delay = Duration::from_millis(50);
+    let model = Model::default();
+    model.set_delay(delay);
+
+    test(model, |handle| {
+        let addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            let mut data = Data::new(LEN as _);
+            while let Some(chunk) = data.send_one(usize::MAX) {
+                stream.send(chunk).await.unwrap();
+            }
+
+            // wait for the server to echo everything back
+            let mut received = 0;
+            while received < LEN {
+                received += stream.receive().await.unwrap().unwrap().len();
+            }
+
+            let stats = connection.stats().unwrap();
+
+            let path = stats.path;
+            assert_eq!(
+                SocketAddr::from(path.local_address),
+                connection.local_addr().unwrap()
+            );
+            assert_eq!(SocketAddr::from(path.remote_address), addr);
+            assert!(path.mtu >= 1200);
+            assert!(path.rtt.min > delay);
+            assert!(path.rtt.smoothed > delay);
+            assert!(path.congestion_window > 0);
+            assert!(path.pacing_rate.is_some());
+
+            // there's only a single path so the totals should match the active path
+            let counters = stats.counters;
+            assert_eq!(path.counters, counters);
+            assert!(counters.packets_sent > 0);
+            assert!(counters.bytes_sent >= LEN as u64);
+            assert!(counters.packets_received > 0);
+            assert!(counters.bytes_received >= LEN as u64);
+            assert_eq!(counters.packets_lost, 0);
+
+            // the stream is still open in both directions
+            let streams = stats.streams;
+            assert_eq!(streams.local_bidirectional, 1);
+            assert_eq!(streams.local_unidirectional, 0);
+            assert_eq!(streams.remote_bidirectional, 0);
+            assert_eq!(streams.remote_unidirectional, 0);
+            assert_eq!(streams.send.send, 1);
+            assert_eq!(streams.receive.recv, 1);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
+
+#[test]
+fn lossy_connection() {
+    let model = Model::default();
+    model.set_delay(Duration::from_millis(50));
+    model.set_drop_rate(0.05);
+
+    test(model, |handle| {
+        let addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+
+            let mut data = Data::new(LEN as _);
+            while let Some(chunk) = data.send_one(usize::MAX) {
+                stream.send(chunk).await.unwrap();
+            }
+            stream.finish().unwrap();
+            while stream.receive().await.unwrap().is_some() {}
+
+            let counters = connection.stats().unwrap().counters;
+            assert!(counters.packets_lost > 0);
+            assert!(counters.bytes_lost > 0);
+        });
+
+        Ok(addr)
+    })
+    .unwrap();
+}
@@ -9,6 +9,7 @@ use aya::{
 use aya_log::EbpfLogger;
 use clap::Parser;
 use log::{info, warn};
+use s2n_quic_platform::io::xdp::bpf;
 use tokio::signal;
 
 #[derive(Debug, Parser)]
@@ -35,9 +36,9 @@ async fn main() -> Result<(), anyhow::Error> {
     env_logger::init();
 
     let bpf = if opt.trace {
-        s2n_quic_xdp::bpf::DEFAULT_PROGRAM_TRACE
+        bpf::DEFAULT_PROGRAM_TRACE
     } else {
-        s2n_quic_xdp::bpf::DEFAULT_PROGRAM
+        bpf::DEFAULT_PROGRAM
     };
 
     let mut bpf = Ebpf::load(bpf)?;
@@ -49,7 +50,7 @@ async fn main() -> Result<(), anyhow::Error> {
     }
 
     let program: &mut Xdp = bpf
-        .program_mut(s2n_quic_xdp::bpf::PROGRAM_NAME)
+        .program_mut(bpf::PROGRAM_NAME)
         .unwrap()
         .try_into()?;
     program.load()?;
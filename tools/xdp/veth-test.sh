@@ -0,0 +1,54 @@
+#!/usr/bin/env bash
+
+#
+# Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+# SPDX-License-Identifier: Apache-2.0
+#
+
+# Runs the AF_XDP io provider tests over a veth pair with each end in its own network namespace.
+#
+# This requires root (or CAP_NET_ADMIN, CAP_NET_RAW, CAP_SYS_ADMIN and CAP_BPF).
+
+set -euo pipefail
+
+SERVER_NS=s2n-quic-xdp-server
+CLIENT_NS=s2n-quic-xdp-client
+SERVER_IF=s2n-xdp-srv
+CLIENT_IF=s2n-xdp-cli
+SERVER_ADDR=10.84.0.1
+CLIENT_ADDR=10.84.0.2
+
+cleanup() {
+  ip netns del "$SERVER_NS" 2>/dev/null || true
+  ip netns del "$CLIENT_NS" 2>/dev/null || true
+}
+trap cleanup EXIT
+cleanup
+
+ip netns add "$SERVER_NS"
+ip netns add "$CLIENT_NS"
+
+ip link add "$SERVER_IF" type veth peer name "$CLIENT_IF"
+ip link set "$SERVER_IF" netns "$SERVER_NS"
+ip link set "$CLIENT_IF" netns "$CLIENT_NS"
+
+ip -n "$SERVER_NS" addr add "$SERVER_ADDR/24" dev "$SERVER_IF"
+ip -n "$CLIENT_NS" addr add "$CLIENT_ADDR/24" dev "$CLIENT_IF"
+
+for ns in "$SERVER_NS" "$CLIENT_NS"; do
+  ip -n "$ns" link set lo up
+done
+ip -n "$SERVER_NS" link set "$SERVER_IF" up
+ip -n "$CLIENT_NS" link set "$CLIENT_IF" up
+
+cd "$(dirname "$0")/../.."
+
+# build outside of the namespace so dependencies can be fetched
+cargo test -p s2n-quic-platform --features xdp --no-run
+
+ip netns exec "$SERVER_NS" \
+  env \
+    S2N_QUIC_XDP_VETH_INTERFACE="$SERVER_IF" \
+    S2N_QUIC_XDP_VETH_ADDRESS="$SERVER_ADDR" \
+    S2N_QUIC_XDP_VETH_PEER_NETNS="$CLIENT_NS" \
+  cargo test -p s2n-quic-platform --features xdp io::xdp::interface -- --nocapture
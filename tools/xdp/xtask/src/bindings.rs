@@ -23,7 +23,7 @@ pub fn run() -> Result<(), anyhow::Error> {
         )
         .generate()?;
 
-    let out = root.join("../s2n-quic-xdp/src/bindings.rs");
+    let out = root.join("../../../quic/s2n-quic-platform/src/io/xdp/bindings.rs");
     bindings.write_to_file(out)?;
 
     Ok(())
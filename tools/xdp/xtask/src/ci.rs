@@ -3,7 +3,7 @@
 
 use crate::{build_ebpf, disasm, run};
 use anyhow::anyhow;
-use std::{io, path::Path, process::Command};
+use std::{io, process::Command};
 
 pub fn run() -> Result<(), anyhow::Error> {
     let before = dump()?;
@@ -48,7 +48,8 @@ fn dump() -> Result<String, anyhow::Error> {
 
 fn test() -> Result<(), anyhow::Error> {
     let status = Command::new("cargo")
-        .arg("test")
+        .args(["test", "-p", "s2n-quic-platform", "--features", "xdp", "io::xdp"])
+        .current_dir("../..")
         .status()
         .expect("failed to run tests");
     assert!(status.success());
@@ -58,22 +59,23 @@ fn test() -> Result<(), anyhow::Error> {
 fn cap_net_raw_tests() -> Result<(), anyhow::Error> {
     let mut at_least_one = false;
 
-    for file in std::fs::read_dir("target/debug/deps")?.flatten() {
+    for file in std::fs::read_dir("../../target/debug/deps")?.flatten() {
         let path = file.path();
 
         if path.extension().is_some()
             || !path
                 .file_name()
                 .and_then(|name| name.to_str())
-                .map_or(false, |name| name.starts_with("s2n_quic_xdp"))
+                .map_or(false, |name| name.starts_with("s2n_quic_platform"))
         {
             continue;
         }
 
         let status = Command::new("sudo")
-            .arg(Path::new("../").join(path))
+            .arg(path.canonicalize()?)
+            .arg("io::xdp")
             .arg("--nocapture")
-            .current_dir("s2n-quic-xdp")
+            .current_dir("../../quic/s2n-quic-platform")
             .env("CAP_NET_RAW_ENABLED", "1")
             .status()
             .expect("failed to run test case");